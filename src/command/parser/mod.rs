@@ -1,49 +1,194 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
-use anyhow::{bail, Context};
+use anyhow::{anyhow, bail};
 
-use crate::resp;
+use crate::resp::{self, FromValue};
 
 #[cfg(test)]
 mod tests;
 
+pub const SYNTAX_ERROR: &str = "ERR syntax error";
+
+/// Parses the arguments of a command into typed values.
+/// Missing arguments are reported as a wrong number of arguments,
+/// unexpected ones as a syntax error, like redis does.
 #[derive(Debug)]
 pub struct Parser {
-    value: std::vec::IntoIter<resp::Value>,
-    store: HashMap<String, String>,
+    command: String,
+    args: VecDeque<resp::Value>,
 }
 
 impl Parser {
     #[must_use]
-    pub fn new(value: Vec<resp::Value>) -> Self {
+    pub fn new(command: impl ToString, args: Vec<resp::Value>) -> Self {
         Self {
-            value: value.into_iter(),
-            store: HashMap::new(),
+            command: command.to_string().to_lowercase(),
+            args: args.into(),
         }
     }
 
-    pub fn ident(mut self, ident: &str) -> anyhow::Result<Self> {
-        if !self
-            .value
-            .next()
-            .context("no more values")?
-            .eq_ignore_ascii_case(ident)
-        {
-            bail!("ident: {ident} not found");
+    #[must_use]
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.args.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    #[must_use]
+    pub fn wrong_arity(&self) -> anyhow::Error {
+        anyhow!(
+            "ERR wrong number of arguments for '{}' command",
+            self.command
+        )
+    }
+
+    /// Whether the next argument is `ident`, without consuming it.
+    #[must_use]
+    pub fn peek_is(&self, ident: &str) -> bool {
+        self.args
+            .front()
+            .is_some_and(|arg| arg.eq_ignore_ascii_case(ident))
+    }
+
+    /// The next required argument.
+    pub fn arg<T>(&mut self) -> anyhow::Result<T>
+    where
+        T: FromValue,
+    {
+        let arg = self.args.pop_front().ok_or_else(|| self.wrong_arity())?;
+        T::from_value(arg)
+    }
+
+    /// The next argument if there is one.
+    pub fn optional<T>(&mut self) -> anyhow::Result<Option<T>>
+    where
+        T: FromValue,
+    {
+        self.args.pop_front().map(T::from_value).transpose()
+    }
+
+    /// Expects the next argument to be the keyword `ident`.
+    pub fn ident(&mut self, ident: &str) -> anyhow::Result<()> {
+        if !self.flag(ident) {
+            bail!(SYNTAX_ERROR);
+        }
+        Ok(())
+    }
+
+    /// Consumes the next argument if it is the keyword `ident`.
+    pub fn flag(&mut self, ident: &str) -> bool {
+        let found = self.peek_is(ident);
+        if found {
+            self.args.pop_front();
         }
-        Ok(self)
+        found
     }
-    pub fn value(mut self, key: impl ToString) -> anyhow::Result<Self> {
-        let value = self
-            .value
-            .next()
-            .context("no more values")?
-            .expect_string()?;
-        self.store.insert(key.to_string(), value);
-        Ok(self)
+
+    /// Parses `ident <value>` if the next argument is `ident`.
+    pub fn option<T>(&mut self, ident: &str) -> anyhow::Result<Option<T>>
+    where
+        T: FromValue,
+    {
+        if !self.flag(ident) {
+            return Ok(None);
+        }
+        let value = self.args.pop_front().ok_or_else(|| anyhow!(SYNTAX_ERROR))?;
+        T::from_value(value).map(Some)
     }
+
+    /// Consumes the options described by `spec` in any order,
+    /// stopping at the first argument that is not one of them.
+    pub fn options<const N: usize>(&mut self, spec: [Arg; N]) -> anyhow::Result<Options> {
+        let mut options = Options::default();
+        while let Some(arg) = spec.iter().find(|arg| self.peek_is(arg.name())).copied() {
+            self.args.pop_front();
+            let value = match arg {
+                Arg::Flag(_) => None,
+                Arg::Value(_) => Some(self.args.pop_front().ok_or_else(|| anyhow!(SYNTAX_ERROR))?),
+            };
+            options.values.insert(arg.name(), value);
+        }
+        Ok(options)
+    }
+
+    /// All remaining arguments.
+    pub fn rest<T>(&mut self) -> anyhow::Result<Vec<T>>
+    where
+        T: FromValue,
+    {
+        self.args.drain(..).map(T::from_value).collect()
+    }
+
+    /// All remaining arguments as pairs, an odd number of them is a wrong number of arguments.
+    pub fn pairs<A, B>(&mut self) -> anyhow::Result<Vec<(A, B)>>
+    where
+        A: FromValue,
+        B: FromValue,
+    {
+        if !self.args.len().is_multiple_of(2) {
+            return Err(self.wrong_arity());
+        }
+        let mut pairs = Vec::with_capacity(self.args.len() / 2);
+        while let (Some(a), Some(b)) = (self.args.pop_front(), self.args.pop_front()) {
+            pairs.push((A::from_value(a)?, B::from_value(b)?));
+        }
+        Ok(pairs)
+    }
+
+    /// Fails with a syntax error if any arguments are left.
+    pub fn finish(self) -> anyhow::Result<()> {
+        if !self.args.is_empty() {
+            bail!(SYNTAX_ERROR);
+        }
+        Ok(())
+    }
+}
+
+/// An option for [`Parser::options`].
+#[derive(Debug, Clone, Copy)]
+pub enum Arg {
+    /// A keyword on its own, like `NX`.
+    Flag(&'static str),
+    /// A keyword followed by a value, like `COUNT 10`.
+    Value(&'static str),
+}
+
+impl Arg {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Flag(name) | Self::Value(name) => name,
+        }
+    }
+}
+
+/// The options found by [`Parser::options`], a repeated option keeps the last value.
+#[derive(Debug, Default)]
+pub struct Options {
+    values: HashMap<&'static str, Option<resp::Value>>,
+}
+
+impl Options {
     #[must_use]
-    pub fn finish(self) -> HashMap<String, String> {
-        self.store
+    pub fn flag(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    pub fn take<T>(&mut self, name: &str) -> anyhow::Result<Option<T>>
+    where
+        T: FromValue,
+    {
+        self.values
+            .remove(name)
+            .flatten()
+            .map(T::from_value)
+            .transpose()
     }
 }
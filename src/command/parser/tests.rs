@@ -1,32 +1,94 @@
 use super::*;
 
+fn parser(args: &str) -> Parser {
+    Parser::new("SET", resp::Value::bulk_strings(args))
+}
+
 #[test]
 fn usage() {
-    let value = resp::Value::bulk_strings("SET; SomeKey; SomeValue");
-    let mut values = Parser::new(value)
-        .ident("SET")
-        .unwrap()
-        .value("key")
-        .unwrap()
-        .value("value")
-        .unwrap()
-        .finish();
-    let key = values.remove("key").unwrap();
-    let value = values.remove("value").unwrap();
-    assert_eq!(key, "SomeKey");
+    let mut parser = parser("SomeKey; SomeValue");
+    let key: Vec<u8> = parser.arg().unwrap();
+    let value: String = parser.arg().unwrap();
+    parser.finish().unwrap();
+    assert_eq!(key, b"SomeKey");
     assert_eq!(value, "SomeValue");
 }
 
 #[test]
 fn wrong_ident_is_err() {
-    let value = resp::Value::bulk_strings("SET; SomeKey; SomeValue");
-    let res = Parser::new(value).ident("PING");
-    assert!(res.is_err());
+    let res = parser("SET; SomeKey; SomeValue").ident("PING");
+    assert_eq!(res.unwrap_err().to_string(), SYNTAX_ERROR);
 }
 
 #[test]
 fn ident_advances() {
-    let value = resp::Value::bulk_strings("SET; SomeKey; SomeValue");
-    let res = Parser::new(value).ident("SET").unwrap().ident("SET");
-    assert!(res.is_err());
+    let mut parser = parser("SET; SomeKey; SomeValue");
+    parser.ident("SET").unwrap();
+    assert!(parser.ident("SET").is_err());
+}
+
+#[test]
+fn missing_arg_is_wrong_number_of_arguments() {
+    let mut parser = parser("SomeKey");
+    parser.arg::<Vec<u8>>().unwrap();
+    let err = parser.arg::<Vec<u8>>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "ERR wrong number of arguments for 'set' command"
+    );
+}
+
+#[test]
+fn leftover_args_are_syntax_error() {
+    let mut parser = parser("SomeKey; SomeValue; extra");
+    parser.arg::<Vec<u8>>().unwrap();
+    parser.arg::<Vec<u8>>().unwrap();
+    assert_eq!(parser.finish().unwrap_err().to_string(), SYNTAX_ERROR);
+}
+
+#[test]
+fn option_parses_value_after_ident() {
+    let mut parser = parser("count; 10; rest");
+    assert_eq!(parser.option::<usize>("BLOCK").unwrap(), None);
+    assert_eq!(parser.option::<usize>("COUNT").unwrap(), Some(10));
+    assert_eq!(parser.rest::<String>().unwrap(), vec!["rest".to_string()]);
+}
+
+#[test]
+fn option_without_value_is_syntax_error() {
+    let err = parser("COUNT").option::<usize>("COUNT").unwrap_err();
+    assert_eq!(err.to_string(), SYNTAX_ERROR);
+}
+
+#[test]
+fn options_are_parsed_in_any_order() {
+    let mut parser = parser("key; NX; ex; 10; GET; value");
+    parser.arg::<Vec<u8>>().unwrap();
+    let mut options = parser
+        .options([
+            Arg::Flag("NX"),
+            Arg::Flag("XX"),
+            Arg::Flag("GET"),
+            Arg::Value("EX"),
+        ])
+        .unwrap();
+    assert!(options.flag("NX"));
+    assert!(options.flag("GET"));
+    assert!(!options.flag("XX"));
+    assert_eq!(options.take::<u64>("EX").unwrap(), Some(10));
+    assert_eq!(parser.arg::<String>().unwrap(), "value");
+}
+
+#[test]
+fn options_report_invalid_values() {
+    let mut options = parser("COUNT; abc").options([Arg::Value("COUNT")]).unwrap();
+    let err = options.take::<usize>("COUNT").unwrap_err();
+    assert_eq!(err.to_string(), resp::value::convert::NOT_AN_INTEGER);
+}
+
+#[test]
+fn pairs_need_even_number_of_args() {
+    let pairs = parser("a; 1; b; 2").pairs::<String, i64>().unwrap();
+    assert_eq!(pairs, vec![("a".into(), 1), ("b".into(), 2)]);
+    assert!(parser("a; 1; b").pairs::<String, i64>().is_err());
 }
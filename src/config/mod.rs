@@ -1,23 +1,36 @@
 use std::net::SocketAddrV4;
 
+use crate::resp::value::deserialize::Limits;
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum Role {
     Leader,
     Follower(SocketAddrV4),
 }
 
-#[derive(Debug)]
+/// The configuration of the server, shared with every client so commands like `INFO` can
+/// report it.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RedisConfig {
     port: u16,
     leader_addr: Option<SocketAddrV4>,
+    limits: Limits,
+    hz: u32,
 }
 
 impl RedisConfig {
+    /// How often background tasks like the active expire cycle run per second.
+    pub const DEFAULT_HZ: u32 = 10;
+    /// The port redis listens on unless configured otherwise.
+    pub const DEFAULT_PORT: u16 = 6379;
+
     #[must_use]
     pub fn new(port: u16) -> Self {
         Self {
             port,
             leader_addr: None,
+            limits: Limits::default(),
+            hz: Self::DEFAULT_HZ,
         }
     }
 
@@ -26,6 +39,8 @@ impl RedisConfig {
         Self {
             port,
             leader_addr: Some(addr),
+            limits: Limits::default(),
+            hz: Self::DEFAULT_HZ,
         }
     }
 
@@ -38,4 +53,36 @@ impl RedisConfig {
     pub fn leader_addr(&self) -> Option<SocketAddrV4> {
         self.leader_addr
     }
+
+    #[must_use]
+    pub fn role(&self) -> Role {
+        match self.leader_addr {
+            Some(addr) => Role::Follower(addr),
+            None => Role::Leader,
+        }
+    }
+
+    #[must_use]
+    pub fn limits(&self) -> Limits {
+        self.limits
+    }
+
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.limits = limits;
+    }
+
+    #[must_use]
+    pub fn hz(&self) -> u32 {
+        self.hz
+    }
+
+    pub fn set_hz(&mut self, hz: u32) {
+        self.hz = hz;
+    }
+}
+
+impl Default for RedisConfig {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PORT)
+    }
 }
@@ -13,7 +13,7 @@ pub fn expected_input() -> [crate::Request; 4] {
         "PSYNC",
     ]
     .map(resp::Value::bulk_strings)
-    .map(|val| crate::Message::new(val.into_array(), 1).into())
+    .map(|val| crate::Message::new(val.into_array(), 1).try_into().unwrap())
 }
 
 #[must_use]
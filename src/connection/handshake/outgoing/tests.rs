@@ -52,7 +52,11 @@ fn handshake_try_advance_returns_correct_messages_on_sucessful_advance() {
     for (i, (msg, expected)) in in_out.enumerate() {
         let actual = handshake.try_advance(&msg).unwrap();
         if i > 0 {
-            assert_eq!(actual, Some(expected.into()), "i: {i}, msg: {msg:?}");
+            assert_eq!(
+                actual,
+                Some(expected.try_into().unwrap()),
+                "i: {i}, msg: {msg:?}"
+            );
         }
     }
 }
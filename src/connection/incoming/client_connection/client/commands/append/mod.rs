@@ -0,0 +1,71 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{kv_repo::Written, Repository},
+    resp::IntoValue,
+};
+
+pub struct Append;
+
+impl Append {
+    fn handle_request(
+        Request {
+            key,
+            value,
+            timestamp,
+            max_len,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let written = repo.kv_repo().append(&key, &value, max_len, timestamp)?;
+        Ok(Response { key, written })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for Append {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("APPEND")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    value: Vec<u8>,
+    timestamp: std::time::SystemTime,
+    /// The configured `proto-max-bulk-len`, the longest the string may grow.
+    max_len: usize,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let max_len = value.config.limits().max_bulk_len;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let value = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            value,
+            timestamp,
+            max_len,
+        })
+    }
+}
+
+struct Response {
+    key: Vec<u8>,
+    written: Written<usize>,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        let (result, event) = super::replicated(value.key, value.written);
+        Self::value_event(result.into_value(), event)
+    }
+}
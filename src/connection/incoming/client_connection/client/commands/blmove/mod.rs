@@ -0,0 +1,86 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{list_repo::End, BlockResult, Repository},
+    resp,
+};
+
+pub struct BLMove;
+
+impl BLMove {
+    fn handle_request(
+        Request {
+            source,
+            destination,
+            from,
+            to,
+            timeout,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        match repo
+            .list_repo()
+            .move_blocking(&source, &destination, from, to, timeout)
+        {
+            BlockResult::Found(element) => {
+                // followers move what was moved here instead of blocking
+                let event = super::replay(
+                    "LMOVE",
+                    [source, destination, from.name().into(), to.name().into()],
+                );
+                Ok(Response(Some((element, event))))
+            }
+            BlockResult::NotFound => Ok(Response(None)),
+            BlockResult::Err(err) => Err(err),
+        }
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for BLMove {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("BLMOVE")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    source: Vec<u8>,
+    destination: Vec<u8>,
+    from: End,
+    to: End,
+    timeout: Option<std::time::Duration>,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let mut parser = value.into_parser();
+        let source = parser.arg()?;
+        let destination = parser.arg()?;
+        let from = parser.arg()?;
+        let to = parser.arg()?;
+        let timeout = super::blocking_timeout(&mut parser)?;
+        parser.finish()?;
+        Ok(Self {
+            source,
+            destination,
+            from,
+            to,
+            timeout,
+        })
+    }
+}
+
+struct Response(Option<(Vec<u8>, crate::event::Kind)>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        match value.0 {
+            Some((element, event)) => Self::value_event(resp::Value::bulk_bytes(element), event),
+            None => Self::value(resp::Value::NullString),
+        }
+    }
+}
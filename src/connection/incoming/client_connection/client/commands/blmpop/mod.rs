@@ -0,0 +1,90 @@
+use anyhow::anyhow;
+
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{
+        list_repo::{End, Popped},
+        BlockResult, Repository,
+    },
+    resp::{self, IntoValue},
+};
+
+pub struct BLMPop;
+
+impl BLMPop {
+    fn handle_request(
+        Request {
+            keys,
+            end,
+            count,
+            timeout,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        match repo.list_repo().pop_blocking(&keys, end, count, timeout) {
+            BlockResult::Found(popped) => Ok(Response(Some((popped, end)))),
+            BlockResult::NotFound => Ok(Response(None)),
+            BlockResult::Err(err) => Err(err),
+        }
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for BLMPop {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("BLMPOP")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    keys: Vec<Vec<u8>>,
+    end: End,
+    count: usize,
+    timeout: Option<std::time::Duration>,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let mut parser = value.into_parser();
+        let timeout = super::blocking_timeout(&mut parser)?;
+        let keys = super::counted_keys(&mut parser)?;
+        let end = parser.arg()?;
+        let count = parser
+            .option::<i64>("COUNT")?
+            .map(|count| {
+                usize::try_from(count)
+                    .ok()
+                    .filter(|count| *count > 0)
+                    .ok_or_else(|| anyhow!("ERR count should be greater than 0"))
+            })
+            .transpose()?
+            .unwrap_or(1);
+        parser.finish()?;
+        Ok(Self {
+            keys,
+            end,
+            count,
+            timeout,
+        })
+    }
+}
+
+struct Response(Option<(Popped, End)>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        match value.0 {
+            Some(((key, elements), end)) => {
+                // followers pop what was popped here instead of blocking
+                let event = super::pop_event(key.clone(), end, elements.len());
+                Self::value_event((key, elements).into_value(), event)
+            }
+            None => Self::value(resp::Value::NullArray),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{list_repo::End, BlockResult, Repository},
+    resp,
+};
+
+pub struct BRPop;
+
+impl BRPop {
+    fn handle_request(
+        Request { keys, timeout }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        match repo.list_repo().pop_blocking(&keys, End::Right, 1, timeout) {
+            BlockResult::Found((key, mut elements)) => Ok(Response::Popped(
+                key,
+                elements.pop().expect("a found list is not empty"),
+            )),
+            BlockResult::NotFound => Ok(Response::TimedOut),
+            BlockResult::Err(err) => Err(err),
+        }
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for BRPop {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("BRPOP")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    keys: Vec<Vec<u8>>,
+    timeout: Option<std::time::Duration>,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let mut parser = value.into_parser();
+        if parser.remaining() < 2 {
+            return Err(parser.wrong_arity());
+        }
+        let mut keys = Vec::with_capacity(parser.remaining() - 1);
+        while parser.remaining() > 1 {
+            keys.push(parser.arg()?);
+        }
+        let timeout = super::blocking_timeout(&mut parser)?;
+        parser.finish()?;
+        Ok(Self { keys, timeout })
+    }
+}
+
+enum Response {
+    Popped(Vec<u8>, Vec<u8>),
+    TimedOut,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        match value {
+            Response::Popped(key, element) => {
+                // followers pop what was popped here instead of blocking
+                let event = super::pop_event(key.clone(), End::Right, 1);
+                let value = resp::Value::Array(vec![
+                    resp::Value::bulk_bytes(key),
+                    resp::Value::bulk_bytes(element),
+                ]);
+                Self::value_event(value, event)
+            }
+            Response::TimedOut => Self::value(resp::Value::NullArray),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{BlockResult, Repository},
+    resp::{self, IntoValue},
+};
+
+pub struct BZPopMin;
+
+impl BZPopMin {
+    fn handle_request(
+        Request { keys, timeout }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        match repo.zset_repo().pop_blocking(&keys, 1, false, timeout) {
+            BlockResult::Found((key, mut entries)) => {
+                // followers remove what was popped here instead of blocking
+                let event = super::zpop_event(key.clone(), &entries);
+                let popped = entries.pop().map(|(member, score)| (key, member, score));
+                Ok(Response(popped, event))
+            }
+            BlockResult::NotFound => Ok(Response(None, None)),
+            BlockResult::Err(err) => Err(err),
+        }
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for BZPopMin {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("BZPOPMIN")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    keys: Vec<Vec<u8>>,
+    timeout: Option<std::time::Duration>,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let mut parser = value.into_parser();
+        if parser.remaining() < 2 {
+            return Err(parser.wrong_arity());
+        }
+        let mut keys = Vec::with_capacity(parser.remaining() - 1);
+        while parser.remaining() > 1 {
+            keys.push(parser.arg()?);
+        }
+        let timeout = super::blocking_timeout(&mut parser)?;
+        parser.finish()?;
+        Ok(Self { keys, timeout })
+    }
+}
+
+struct Response(Option<(Vec<u8>, Vec<u8>, f64)>, Option<crate::event::Kind>);
+
+impl From<Response> for super::Response {
+    fn from(Response(popped, event): Response) -> Self {
+        (
+            popped.map_or(resp::Value::NullArray, IntoValue::into_value),
+            event,
+        )
+            .into()
+    }
+}
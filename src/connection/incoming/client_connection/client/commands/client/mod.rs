@@ -1,8 +1,4 @@
-use std::sync::Mutex;
-
-use crate::{command::Command, repository::Repository, resp};
-
-static ID: Mutex<usize> = Mutex::new(1);
+use crate::{command::Command, repository::Repository, resp::IntoValue};
 
 pub struct Client;
 
@@ -10,11 +6,7 @@ impl Client {
     fn handle_request(request: Request) -> Response {
         match request.cmd {
             Cmd::Other => Response::Ok,
-            Cmd::Id => {
-                let mut lock = ID.lock().unwrap();
-                *lock += 1;
-                Response::Id(*lock)
-            }
+            Cmd::Id => Response::Id(request.id),
         }
     }
 }
@@ -24,12 +16,13 @@ impl Command<super::Request, super::Response, Repository> for Client {
     }
 
     fn call(&self, request: super::Request, _repo: &Repository) -> anyhow::Result<super::Response> {
-        Ok(Self::handle_request(request.try_into().unwrap()).into())
+        Ok(Self::handle_request(request.try_into()?).into())
     }
 }
 
 struct Request {
     cmd: Cmd,
+    id: usize,
 }
 
 enum Cmd {
@@ -41,12 +34,18 @@ impl TryFrom<super::Request> for Request {
     type Error = anyhow::Error;
 
     fn try_from(value: super::Request) -> Result<Self, Self::Error> {
-        let mut iter = value.into_content().unwrap().into_iter();
-        let sub_cmd = iter.next().unwrap();
-        if sub_cmd.eq_ignore_ascii_case("ID") {
-            Ok(Request { cmd: Cmd::Id })
+        let id = value.state.id;
+        let mut parser = value.into_parser();
+        if parser.is_empty() {
+            return Err(parser.wrong_arity());
+        }
+        if parser.flag("ID") {
+            Ok(Request { cmd: Cmd::Id, id })
         } else {
-            Ok(Request { cmd: Cmd::Other })
+            Ok(Request {
+                cmd: Cmd::Other,
+                id,
+            })
         }
     }
 }
@@ -60,7 +59,7 @@ impl From<Response> for super::Response {
     fn from(value: Response) -> Self {
         match value {
             Response::Ok => Self::ok(),
-            Response::Id(id) => Self::value(resp::Value::Integer(id.try_into().unwrap())),
+            Response::Id(id) => Self::value(id.into_value()),
         }
     }
 }
@@ -7,13 +7,13 @@ use crate::{
 pub struct Config;
 
 impl Config {
-    fn handle_request(request: Request) -> Response {
+    fn handle_request(request: Request, repo: &Repository) -> Response {
         let value = match request.key.to_lowercase().as_str() {
-            "databases" => "16",
-            "slave-read-only" => "yes",
-            _ => todo!("{}", request.key),
-        }
-        .to_string();
+            "databases" => Some(repo.databases().to_string()),
+            "slave-read-only" => Some("yes".to_string()),
+            // like redis, a parameter that does not exist has no value to list
+            _ => None,
+        };
         Response {
             key: request.key,
             value,
@@ -25,8 +25,8 @@ impl Command<super::Request, super::Response, Repository> for Config {
         crate::command::CommandInfo::new_name("CONFIG")
     }
 
-    fn call(&self, request: super::Request, _repo: &Repository) -> anyhow::Result<super::Response> {
-        Ok(Self::handle_request(request.try_into().unwrap()).into())
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Ok(Self::handle_request(request.try_into()?, repo).into())
     }
 }
 
@@ -38,25 +38,29 @@ impl TryFrom<super::Request> for Request {
     type Error = anyhow::Error;
 
     fn try_from(value: super::Request) -> Result<Self, Self::Error> {
-        assert_eq!(value.command().unwrap().to_uppercase(), "CONFIG");
-        let mut content = value.into_content().unwrap();
-        let key = content.swap_remove(1);
+        let mut parser = value.into_parser();
+        parser.ident("GET")?;
+        let key = parser.arg()?;
+        parser.finish()?;
         Ok(Self { key })
     }
 }
 
 struct Response {
     key: String,
-    value: String,
+    value: Option<String>,
 }
 
 impl From<Response> for super::Response {
     fn from(value: Response) -> Self {
-        let res = [
-            resp::Value::bulk_string(value.key),
-            resp::Value::bulk_string(value.value),
-        ]
-        .to_vec();
+        let res = match value.value {
+            Some(parameter) => [
+                resp::Value::bulk_string(value.key),
+                resp::Value::bulk_string(parameter),
+            ]
+            .to_vec(),
+            None => Vec::new(),
+        };
         Self::value(res.into_array())
     }
 }
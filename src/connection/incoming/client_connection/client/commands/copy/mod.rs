@@ -0,0 +1,96 @@
+use crate::{
+    command::{parser::Arg, Command, CommandInfo},
+    event,
+    repository::Repository,
+    resp::IntoValue,
+};
+
+pub struct Copy;
+
+impl Copy {
+    fn handle_request(
+        Request {
+            source,
+            destination,
+            db,
+            replace,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let target = match db {
+            Some(db) => super::database(repo, db)?,
+            None => repo.clone(),
+        };
+        let copied =
+            repo.key_repo()
+                .copy(&source, target.key_repo(), &destination, replace, timestamp)?;
+        Ok(Response {
+            source,
+            destination,
+            db: (target.selected() != repo.selected()).then_some(target.selected()),
+            replace,
+            copied,
+        })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for Copy {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("COPY")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    source: Vec<u8>,
+    destination: Vec<u8>,
+    db: Option<i64>,
+    replace: bool,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let source = parser.arg()?;
+        let destination = parser.arg()?;
+        let mut options = parser.options([Arg::Value("DB"), Arg::Flag("REPLACE")])?;
+        let db = options.take("DB")?;
+        let replace = options.flag("REPLACE");
+        parser.finish()?;
+        Ok(Self {
+            source,
+            destination,
+            db,
+            replace,
+            timestamp,
+        })
+    }
+}
+
+struct Response {
+    source: Vec<u8>,
+    destination: Vec<u8>,
+    db: Option<usize>,
+    replace: bool,
+    copied: bool,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        let event = value.copied.then_some(event::Kind::Copy {
+            source: value.source,
+            destination: value.destination,
+            db: value.db,
+            replace: value.replace,
+        });
+        (value.copied.into_value(), event).into()
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp::IntoValue,
+};
+
+pub struct DbSize;
+
+impl DbSize {
+    fn handle_request(_: Request, repo: &Repository) -> anyhow::Result<Response> {
+        Ok(Response(repo.key_repo().len()))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for DbSize {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("DBSIZE")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request;
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        value.into_parser().finish()?;
+        Ok(Self)
+    }
+}
+
+struct Response(usize);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(value.0.into_value())
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{kv_repo::Written, Repository},
+    resp::IntoValue,
+};
+
+pub struct DecrBy;
+
+impl DecrBy {
+    fn handle_request(
+        Request {
+            key,
+            decrement,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let increment = decrement
+            .checked_neg()
+            .ok_or_else(|| anyhow::anyhow!("ERR decrement would overflow"))?;
+        let written = repo.kv_repo().incr_by(&key, increment, timestamp)?;
+        Ok(Response { key, written })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for DecrBy {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("DECRBY")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    decrement: i64,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let decrement = parser.arg::<i64>()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            decrement,
+            timestamp,
+        })
+    }
+}
+
+struct Response {
+    key: Vec<u8>,
+    written: Written<i64>,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        let (result, event) = super::replicated(value.key, value.written);
+        Self::value_event(result.into_value(), event)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{
+    command::{Command, CommandInfo},
+    event,
+    repository::Repository,
+    resp::IntoValue,
+};
+
+pub struct Del;
+
+impl Del {
+    fn handle_request(
+        Request { keys, timestamp }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let deleted = repo.key_repo().delete(&keys, timestamp);
+        Ok(Response { keys, deleted })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for Del {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("DEL")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    keys: Vec<Vec<u8>>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let keys = parser.rest()?;
+        if keys.is_empty() {
+            return Err(parser.wrong_arity());
+        }
+        parser.finish()?;
+        Ok(Self { keys, timestamp })
+    }
+}
+
+struct Response {
+    keys: Vec<Vec<u8>>,
+    deleted: usize,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        let event = (value.deleted > 0).then_some(event::Kind::Del { keys: value.keys });
+        (value.deleted.into_value(), event).into()
+    }
+}
@@ -22,7 +22,7 @@ impl Command<super::super::Request, super::super::Response, Repository> for Echo
         request: super::super::Request,
         _repo: &Repository,
     ) -> anyhow::Result<super::super::Response> {
-        Ok(Self::handle_request(request.try_into().unwrap()).into())
+        Ok(Self::handle_request(request.try_into()?).into())
     }
 }
 
@@ -34,13 +34,9 @@ impl TryFrom<super::Request> for Request {
     type Error = anyhow::Error;
 
     fn try_from(value: super::Request) -> Result<Self, Self::Error> {
-        let echo = value
-            .into_byte_content()
-            .unwrap()
-            .into_iter()
-            .next()
-            .unwrap();
-
+        let mut parser = value.into_parser();
+        let echo = parser.arg()?;
+        parser.finish()?;
         Ok(Self { echo })
     }
 }
@@ -0,0 +1,54 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp::IntoValue,
+};
+
+pub struct Exists;
+
+impl Exists {
+    fn handle_request(
+        Request { keys, timestamp }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        Ok(Response(repo.key_repo().exists(&keys, timestamp)))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for Exists {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("EXISTS")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    keys: Vec<Vec<u8>>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let keys = parser.rest()?;
+        if keys.is_empty() {
+            return Err(parser.wrong_arity());
+        }
+        parser.finish()?;
+        Ok(Self { keys, timestamp })
+    }
+}
+
+struct Response(usize);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(value.0.into_value())
+    }
+}
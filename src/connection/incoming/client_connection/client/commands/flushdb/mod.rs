@@ -0,0 +1,49 @@
+use crate::{
+    command::{Command, CommandInfo},
+    event,
+    repository::Repository,
+    resp,
+};
+
+pub struct FlushDb;
+
+impl FlushDb {
+    fn handle_request(_: Request, repo: &Repository) -> anyhow::Result<Response> {
+        repo.key_repo().clear();
+        Ok(Response)
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for FlushDb {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("FLUSHDB")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request;
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let mut parser = value.into_parser();
+        // everything is freed synchronously either way
+        if !parser.flag("ASYNC") {
+            parser.flag("SYNC");
+        }
+        parser.finish()?;
+        Ok(Self)
+    }
+}
+
+struct Response;
+
+impl From<Response> for super::Response {
+    fn from(_: Response) -> Self {
+        Self::value_event(resp::Value::ok(), event::Kind::FlushDb)
+    }
+}
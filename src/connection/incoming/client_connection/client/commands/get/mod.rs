@@ -1,5 +1,3 @@
-use anyhow::Context;
-
 use crate::{
     command::{Command, CommandInfo},
     repository::Repository,
@@ -9,8 +7,11 @@ use crate::{
 pub struct Get;
 
 impl Get {
-    fn handle_command(Request { key, timestamp }: Request, repo: &Repository) -> Response {
-        repo.kv_repo().get(&key, timestamp).unwrap().into()
+    fn handle_command(
+        Request { key, timestamp }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        Ok(repo.kv_repo().get(&key, timestamp)?.into())
     }
 }
 impl Command<super::Request, super::Response, Repository> for Get {
@@ -19,12 +20,12 @@ impl Command<super::Request, super::Response, Repository> for Get {
     }
 
     fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
-        Ok(Self::handle_command(request.try_into()?, repo).into())
+        Self::handle_command(request.try_into()?, repo).map(Into::into)
     }
 }
 
 struct Request {
-    key: String,
+    key: Vec<u8>,
     timestamp: std::time::SystemTime,
 }
 impl TryFrom<super::Request> for Request {
@@ -32,29 +33,30 @@ impl TryFrom<super::Request> for Request {
 
     fn try_from(value: super::Request) -> Result<Self, Self::Error> {
         let timestamp = value.timestamp;
-        let mut iter = value.into_content().unwrap().into_iter();
-        let key = iter.next().context("key missing")?;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        parser.finish()?;
         Ok(Self { key, timestamp })
     }
 }
 
 enum Response {
-    Value(String),
+    Value(Vec<u8>),
     Null,
 }
 
 impl From<Response> for super::Response {
     fn from(value: Response) -> Self {
         match value {
-            Response::Value(value) => resp::Value::simple_string(value),
+            Response::Value(value) => resp::Value::bulk_bytes(value),
             Response::Null => resp::Value::NullString,
         }
         .into()
     }
 }
 
-impl From<Option<String>> for Response {
-    fn from(value: Option<String>) -> Self {
+impl From<Option<Vec<u8>>> for Response {
+    fn from(value: Option<Vec<u8>>) -> Self {
         if let Some(value) = value {
             Self::Value(value)
         } else {
@@ -0,0 +1,62 @@
+use crate::{
+    command::{Command, CommandInfo},
+    event,
+    repository::Repository,
+    resp,
+};
+
+pub struct GetDel;
+
+impl GetDel {
+    fn handle_request(
+        Request { key, timestamp }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let value = repo.kv_repo().get_del(&key, timestamp)?;
+        Ok(Response { key, value })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for GetDel {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("GETDEL")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        parser.finish()?;
+        Ok(Self { key, timestamp })
+    }
+}
+
+struct Response {
+    key: Vec<u8>,
+    value: Option<Vec<u8>>,
+}
+
+impl From<Response> for super::Response {
+    fn from(Response { key, value }: Response) -> Self {
+        match value {
+            Some(value) => Self::value_event(
+                resp::Value::bulk_bytes(value),
+                event::Kind::Del { keys: vec![key] },
+            ),
+            None => Self::value(resp::Value::NullString),
+        }
+    }
+}
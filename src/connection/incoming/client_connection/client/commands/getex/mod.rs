@@ -0,0 +1,98 @@
+use anyhow::bail;
+
+use crate::{
+    command::{
+        parser::{Arg, SYNTAX_ERROR},
+        Command, CommandInfo,
+    },
+    repository::{
+        kv_repo::{Expiry, TtlChange},
+        Repository,
+    },
+    resp,
+};
+
+pub struct GetEx;
+
+impl GetEx {
+    fn handle_request(
+        Request {
+            key,
+            expiry,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let found = repo.kv_repo().get_ex(&key, expiry, timestamp)?;
+        Ok(Response { key, found })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for GetEx {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("GETEX")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    expiry: Expiry,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let mut options = parser.options([
+            Arg::Flag("PERSIST"),
+            Arg::Value("EX"),
+            Arg::Value("PX"),
+            Arg::Value("EXAT"),
+            Arg::Value("PXAT"),
+        ])?;
+        let expiry = match super::expire_at(&mut options, "getex", timestamp)? {
+            Some(_) if options.flag("PERSIST") => bail!(SYNTAX_ERROR),
+            Some(at) => Expiry::At(at),
+            None if options.flag("PERSIST") => Expiry::Persist,
+            None => Expiry::Keep,
+        };
+        parser.finish()?;
+        Ok(Self {
+            key,
+            expiry,
+            timestamp,
+        })
+    }
+}
+
+struct Response {
+    key: Vec<u8>,
+    found: Option<(Vec<u8>, TtlChange)>,
+}
+
+impl From<Response> for super::Response {
+    fn from(Response { key, found }: Response) -> Self {
+        let Some((value, change)) = found else {
+            return Self::value(resp::Value::NullString);
+        };
+        // followers get the same write a SET with the new expiry would replicate
+        let event = match change {
+            TtlChange::Unchanged => None,
+            TtlChange::Changed(expiry) => Some(crate::event::Kind::Set {
+                key,
+                value: value.clone(),
+                expiry,
+            }),
+            TtlChange::Expired => Some(crate::event::Kind::Del { keys: vec![key] }),
+        };
+        (resp::Value::bulk_bytes(value), event).into()
+    }
+}
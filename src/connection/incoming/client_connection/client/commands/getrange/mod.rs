@@ -0,0 +1,67 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp,
+};
+
+pub struct GetRange;
+
+impl GetRange {
+    fn handle_request(
+        Request {
+            key,
+            start,
+            end,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        repo.kv_repo()
+            .get_range(&key, start, end, timestamp)
+            .map(Response)
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for GetRange {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("GETRANGE")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    start: i64,
+    end: i64,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let start = parser.arg()?;
+        let end = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            start,
+            end,
+            timestamp,
+        })
+    }
+}
+
+struct Response(Vec<u8>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(resp::Value::bulk_bytes(value.0))
+    }
+}
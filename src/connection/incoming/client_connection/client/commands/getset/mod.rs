@@ -0,0 +1,85 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{kv_repo::SetOptions, Repository},
+    resp,
+};
+
+pub struct GetSet;
+
+impl GetSet {
+    fn handle_request(
+        Request {
+            key,
+            value,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let options = SetOptions {
+            get: true,
+            ..SetOptions::default()
+        };
+        let result = repo
+            .kv_repo()
+            .set_with(key.clone(), value.clone(), options, timestamp)?;
+        Ok(Response {
+            key,
+            value,
+            old: result.old,
+        })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for GetSet {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("GETSET")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    value: Vec<u8>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let value = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            value,
+            timestamp,
+        })
+    }
+}
+
+struct Response {
+    key: Vec<u8>,
+    value: Vec<u8>,
+    old: Option<Vec<u8>>,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value_event(
+            value
+                .old
+                .map_or(resp::Value::NullString, resp::Value::bulk_bytes),
+            crate::event::Kind::Set {
+                key: value.key,
+                value: value.value,
+                expiry: None,
+            },
+        )
+    }
+}
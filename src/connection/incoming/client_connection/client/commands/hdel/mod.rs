@@ -0,0 +1,67 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp::IntoValue,
+};
+
+pub struct HDel;
+
+impl HDel {
+    fn handle_request(
+        Request {
+            key,
+            fields,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let deleted = repo.hash_repo().delete(&key, &fields, timestamp)?;
+        let event =
+            (deleted > 0).then(|| super::replay("HDEL", std::iter::once(key).chain(fields)));
+        Ok(Response(deleted, event))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for HDel {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("HDEL")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    fields: Vec<Vec<u8>>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let fields = parser.rest()?;
+        if fields.is_empty() {
+            return Err(parser.wrong_arity());
+        }
+        parser.finish()?;
+        Ok(Self {
+            key,
+            fields,
+            timestamp,
+        })
+    }
+}
+
+struct Response(usize, Option<crate::event::Kind>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        (value.0.into_value(), value.1).into()
+    }
+}
@@ -0,0 +1,148 @@
+use anyhow::{anyhow, bail};
+
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp,
+};
+
+use super::super::ClientState;
+
+pub struct Hello;
+
+impl Hello {
+    fn handle_request(request: Request) -> Response {
+        if let Some((username, _password)) = &request.auth {
+            // there are no users configured so only the passwordless default user exists
+            if username != "default" {
+                return Response::Error(
+                    "WRONGPASS invalid username-password pair or user is disabled.".into(),
+                );
+            }
+        }
+        let mut state = request.state;
+        if let Some(protocol) = request.protocol {
+            state.protocol = protocol;
+        }
+        if let Some(name) = request.name {
+            state.name = (!name.is_empty()).then_some(name);
+        }
+        Response::Hello(state)
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for Hello {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("HELLO")
+    }
+
+    fn call(&self, request: super::Request, _repo: &Repository) -> anyhow::Result<super::Response> {
+        Ok(match Request::try_from(request) {
+            Ok(request) => Self::handle_request(request),
+            Err(err) => Response::Error(err.to_string()),
+        }
+        .into())
+    }
+}
+
+struct Request {
+    protocol: Option<resp::Protocol>,
+    auth: Option<(String, String)>,
+    name: Option<String>,
+    state: ClientState,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let state = value.state.clone();
+        let mut parser = value.into_parser();
+
+        let protocol = match parser.optional::<i64>() {
+            Ok(Some(version)) => Some(
+                resp::Protocol::from_version(version)
+                    .ok_or(anyhow!("NOPROTO unsupported protocol version"))?,
+            ),
+            Ok(None) => None,
+            Err(_) => bail!("ERR Protocol version is not an integer or out of range"),
+        };
+
+        let mut auth = None;
+        let mut name = None;
+        while let Some(option) = parser.optional::<String>()? {
+            if option.eq_ignore_ascii_case("AUTH") {
+                let (Some(username), Some(password)) = (parser.optional()?, parser.optional()?)
+                else {
+                    bail!("ERR Syntax error in HELLO option '{option}'");
+                };
+                auth = Some((username, password));
+            } else if option.eq_ignore_ascii_case("SETNAME") {
+                let Some(client_name) = parser.optional::<String>()? else {
+                    bail!("ERR Syntax error in HELLO option '{option}'");
+                };
+                if !client_name.bytes().all(|b| b.is_ascii_graphic()) {
+                    bail!(
+                        "ERR Client names cannot contain spaces, newlines or special characters."
+                    );
+                }
+                name = Some(client_name);
+            } else {
+                bail!("ERR Syntax error in HELLO option '{option}'");
+            }
+        }
+
+        Ok(Self {
+            protocol,
+            auth,
+            name,
+            state,
+        })
+    }
+}
+
+enum Response {
+    Hello(ClientState),
+    Error(String),
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        match value {
+            Response::Hello(state) => {
+                let info = resp::Value::Map(vec![
+                    (
+                        resp::Value::bulk_string("server"),
+                        resp::Value::bulk_string("redis"),
+                    ),
+                    (
+                        resp::Value::bulk_string("version"),
+                        resp::Value::bulk_string("7.2.6"),
+                    ),
+                    (
+                        resp::Value::bulk_string("proto"),
+                        resp::Value::Integer(state.protocol.version()),
+                    ),
+                    (
+                        resp::Value::bulk_string("id"),
+                        resp::Value::Integer(state.id.try_into().unwrap()),
+                    ),
+                    (
+                        resp::Value::bulk_string("mode"),
+                        resp::Value::bulk_string("standalone"),
+                    ),
+                    (
+                        resp::Value::bulk_string("role"),
+                        resp::Value::bulk_string("master"),
+                    ),
+                    (
+                        resp::Value::bulk_string("modules"),
+                        resp::Value::Array(Vec::new()),
+                    ),
+                ]);
+                Self::value(info).with_state(state)
+            }
+            Response::Error(err) => Self::value(resp::Value::SimpleError(err)),
+        }
+    }
+}
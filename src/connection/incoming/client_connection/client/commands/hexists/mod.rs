@@ -0,0 +1,63 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp,
+};
+
+pub struct HExists;
+
+impl HExists {
+    fn handle_request(
+        Request {
+            key,
+            field,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        repo.hash_repo()
+            .exists(&key, &field, timestamp)
+            .map(Response)
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for HExists {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("HEXISTS")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    field: Vec<u8>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let field = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            field,
+            timestamp,
+        })
+    }
+}
+
+struct Response(bool);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(resp::Value::Integer(value.0.into()))
+    }
+}
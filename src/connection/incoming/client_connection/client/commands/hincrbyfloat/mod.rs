@@ -0,0 +1,70 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp::IntoValue,
+};
+
+pub struct HIncrByFloat;
+
+impl HIncrByFloat {
+    fn handle_request(
+        Request {
+            key,
+            field,
+            increment,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let value = repo
+            .hash_repo()
+            .incr_by_float(&key, &field, increment, timestamp)?;
+        // followers set the result so rounding cannot make theirs differ
+        let event = super::replay("HSET", [key, field, value.clone()]);
+        Ok(Response(value, event))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for HIncrByFloat {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("HINCRBYFLOAT")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    field: Vec<u8>,
+    increment: f64,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let field = parser.arg()?;
+        let increment = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            field,
+            increment,
+            timestamp,
+        })
+    }
+}
+
+struct Response(Vec<u8>, crate::event::Kind);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value_event(value.0.into_value(), value.1)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp::IntoValue,
+};
+
+pub struct HKeys;
+
+impl HKeys {
+    fn handle_request(
+        Request { key, timestamp }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        repo.hash_repo().fields(&key, timestamp).map(Response)
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for HKeys {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("HKEYS")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        parser.finish()?;
+        Ok(Self { key, timestamp })
+    }
+}
+
+struct Response(Vec<Vec<u8>>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(value.0.into_value())
+    }
+}
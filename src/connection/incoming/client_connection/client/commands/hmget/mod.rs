@@ -0,0 +1,72 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp,
+};
+
+pub struct HMGet;
+
+impl HMGet {
+    fn handle_request(
+        Request {
+            key,
+            fields,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        repo.hash_repo()
+            .get_many(&key, &fields, timestamp)
+            .map(Response)
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for HMGet {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("HMGET")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    fields: Vec<Vec<u8>>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let fields = parser.rest()?;
+        if fields.is_empty() {
+            return Err(parser.wrong_arity());
+        }
+        parser.finish()?;
+        Ok(Self {
+            key,
+            fields,
+            timestamp,
+        })
+    }
+}
+
+struct Response(Vec<Option<Vec<u8>>>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(resp::Value::Array(
+            value
+                .0
+                .into_iter()
+                .map(|value| value.map_or(resp::Value::NullString, resp::Value::bulk_bytes))
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp::{self, IntoValue},
+};
+
+pub struct HRandField;
+
+impl HRandField {
+    fn handle_request(
+        Request {
+            key,
+            count,
+            with_values,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let pairs = repo
+            .hash_repo()
+            .random_pairs(&key, count.unwrap_or(1), timestamp)?;
+        Ok(match count {
+            None => Response::Field(pairs.into_iter().next().map(|(field, _)| field)),
+            Some(_) if with_values => Response::Pairs(pairs),
+            Some(_) => Response::Fields(pairs.into_iter().map(|(field, _)| field).collect()),
+        })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for HRandField {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("HRANDFIELD")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    count: Option<i64>,
+    with_values: bool,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let count = parser.optional()?;
+        let with_values = count.is_some() && parser.flag("WITHVALUES");
+        parser.finish()?;
+        Ok(Self {
+            key,
+            count,
+            with_values,
+            timestamp,
+        })
+    }
+}
+
+enum Response {
+    Field(Option<Vec<u8>>),
+    Fields(Vec<Vec<u8>>),
+    Pairs(Vec<(Vec<u8>, Vec<u8>)>),
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(match value {
+            Response::Field(field) => {
+                field.map_or(resp::Value::NullString, resp::Value::bulk_bytes)
+            }
+            Response::Fields(fields) => fields.into_value(),
+            // flat like resp2 replies, which every client understands
+            Response::Pairs(pairs) => pairs
+                .into_iter()
+                .flat_map(|(field, value)| [field, value])
+                .collect::<Vec<_>>()
+                .into_value(),
+        })
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp::IntoValue,
+};
+
+pub struct HSet;
+
+impl HSet {
+    fn handle_request(
+        Request {
+            key,
+            pairs,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let args = std::iter::once(key.clone())
+            .chain(
+                pairs
+                    .iter()
+                    .flat_map(|(field, value)| [field.clone(), value.clone()]),
+            )
+            .collect::<Vec<_>>();
+        let added = repo.hash_repo().set(&key, pairs, timestamp)?;
+        Ok(Response(added, super::replay("HSET", args)))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for HSet {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("HSET")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    pairs: Vec<(Vec<u8>, Vec<u8>)>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let pairs = parser.pairs()?;
+        if pairs.is_empty() {
+            return Err(parser.wrong_arity());
+        }
+        parser.finish()?;
+        Ok(Self {
+            key,
+            pairs,
+            timestamp,
+        })
+    }
+}
+
+struct Response(usize, crate::event::Kind);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value_event(value.0.into_value(), value.1)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp,
+};
+
+pub struct HSetNx;
+
+impl HSetNx {
+    fn handle_request(
+        Request {
+            key,
+            field,
+            value,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let args = [key.clone(), field.clone(), value.clone()];
+        let set = repo.hash_repo().set_nx(&key, field, value, timestamp)?;
+        Ok(Response(set.then(|| super::replay("HSET", args))))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for HSetNx {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("HSETNX")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    field: Vec<u8>,
+    value: Vec<u8>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let field = parser.arg()?;
+        let value = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            field,
+            value,
+            timestamp,
+        })
+    }
+}
+
+struct Response(Option<crate::event::Kind>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        let set = value.0.is_some();
+        (resp::Value::Integer(set.into()), value.0).into()
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{kv_repo::Written, Repository},
+    resp::IntoValue,
+};
+
+pub struct Incr;
+
+impl Incr {
+    fn handle_request(
+        Request { key, timestamp }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let written = repo.kv_repo().incr_by(&key, 1, timestamp)?;
+        Ok(Response { key, written })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for Incr {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("INCR")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        parser.finish()?;
+        Ok(Self { key, timestamp })
+    }
+}
+
+struct Response {
+    key: Vec<u8>,
+    written: Written<i64>,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        let (result, event) = super::replicated(value.key, value.written);
+        Self::value_event(result.into_value(), event)
+    }
+}
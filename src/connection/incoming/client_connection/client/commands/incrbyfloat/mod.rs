@@ -0,0 +1,67 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{kv_repo::Written, Repository},
+    resp,
+};
+
+pub struct IncrByFloat;
+
+impl IncrByFloat {
+    fn handle_request(
+        Request {
+            key,
+            increment,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let written = repo.kv_repo().incr_by_float(&key, increment, timestamp)?;
+        Ok(Response { key, written })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for IncrByFloat {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("INCRBYFLOAT")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    increment: f64,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let increment = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            increment,
+            timestamp,
+        })
+    }
+}
+
+struct Response {
+    key: Vec<u8>,
+    written: Written<f64>,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        let reply = resp::Value::bulk_bytes(value.written.value.clone());
+        let (_, event) = super::replicated(value.key, value.written);
+        Self::value_event(reply, event)
+    }
+}
@@ -1,5 +1,8 @@
+use std::sync::Arc;
+
 use crate::{
     command::{Command, CommandInfo},
+    config::{RedisConfig, Role},
     repository::Repository,
     resp,
 };
@@ -7,18 +10,75 @@ use crate::{
 pub struct Info;
 
 impl Info {
-    fn handle_request(request: Request) -> Response {
-        let s = match request.key.to_lowercase().as_str() {
-            "replication" => {
-                "# Replication\r\nrole:master\r\nconnected_slaves:0\r\nmaster_failover_state:no-failover\r\nmaster_replid:5e7a4edcb3a968adab189dfed97a982463d347a5\r\nmaster_repl_offset:0\r\nsecond_repl_offset:-1\r\nrepl_backlog_active:0\r\nrepl_backlog_size:1048576\r\nrepl_backlog_first_byte_offset:0\r\nrepl_backlog_histlen:0\r\n"
-            }
-
-            "server" => {
-                "# Server\r\nredis_version:7.2.6\r\nredis_git_sha1:00000000\r\nredis_git_dirty:0\r\nredis_build_id:bb0ef6c2693a5dd0\r\nredis_mode:standalone\r\nos:Linux 6.6.64 x86_64\r\narch_bits:64\r\nmonotonic_clock:POSIX clock_gettime\r\nmultiplexing_api:epoll\r\natomicvar_api:c11-builtin\r\ngcc_version:13.3.0\r\nprocess_id:96237\r\nprocess_supervised:no\r\nrun_id:16306cd80d0b82429a5f6a0cc2fc7d72b0c635bd\r\ntcp_port:6379\r\nserver_time_usec:1736605053230053\r\nuptime_in_seconds:5\r\nuptime_in_days:0\r\nhz:10\r\nconfigured_hz:10\r\nlru_clock:8551805\r\nexecutable:/home/eske/redis-server\r\nconfig_file:\r\nio_threads_active:0\r\nlistener0:name=tcp,bind=*,bind=-::*,port=6379\r\n"
+    fn handle_request(request: Request, repo: &Repository) -> Response {
+        let config = &request.config;
+        let server = || {
+            let port = config.port();
+            let hz = config.hz();
+            // fields that cannot be reported truthfully, like build ids, are left out
+            let executable = std::env::current_exe()
+                .map(|path| format!("executable:{}\r\n", path.display()))
+                .unwrap_or_default();
+            format!(
+                "# Server\r\nredis_version:7.2.6\r\nredis_mode:standalone\r\nos:{}\r\narch_bits:{}\r\nprocess_id:{}\r\ntcp_port:{port}\r\nhz:{hz}\r\nconfigured_hz:{hz}\r\n{executable}",
+                std::env::consts::OS,
+                usize::BITS,
+                std::process::id(),
+            )
+        };
+        let replication = || match config.role() {
+            Role::Leader => "# Replication\r\nrole:master\r\n".to_string(),
+            Role::Follower(addr) => format!(
+                "# Replication\r\nrole:slave\r\nmaster_host:{}\r\nmaster_port:{}\r\n",
+                addr.ip(),
+                addr.port()
+            ),
+        };
+        let stats = || {
+            // counted per database, the stalest one is the estimate like redis' worst case
+            let (expired_keys, stale_perc) = repo
+                .keyspaces()
+                .map(|keyspace| keyspace.lock().stats())
+                .fold((0, 0.0_f64), |(keys, perc), stats| {
+                    (
+                        keys + stats.expired_keys,
+                        perc.max(stats.expired_stale_perc),
+                    )
+                });
+            format!(
+                "# Stats\r\nexpired_keys:{expired_keys}\r\nexpired_stale_perc:{stale_perc:.2}\r\n"
+            )
+        };
+        let keyspace = || {
+            let databases: String = repo
+                .keyspaces()
+                .enumerate()
+                .filter_map(|(index, keyspace)| {
+                    let db = keyspace.lock();
+                    // keys that expired but were not removed yet are counted like in redis
+                    (!db.is_empty()).then(|| {
+                        format!(
+                            "db{index}:keys={},expires={},avg_ttl=0\r\n",
+                            db.len(),
+                            db.expires()
+                        )
+                    })
+                })
+                .collect();
+            format!("# Keyspace\r\n{databases}")
+        };
+        let section = request.section.map(|section| section.to_lowercase());
+        let s = match section.as_deref() {
+            Some("replication") => replication(),
+            Some("server") => server(),
+            Some("stats") => stats(),
+            Some("keyspace") => keyspace(),
+            None | Some("default" | "all" | "everything") => {
+                [server(), stats(), replication(), keyspace()].join("\r\n")
             }
-            _ => todo!(),
-        }
-        .to_string();
+            // like redis, sections it does not know are empty
+            Some(_) => String::new(),
+        };
         Response(s)
     }
 }
@@ -31,23 +91,26 @@ impl Command<super::super::Request, super::super::Response, Repository> for Info
     fn call(
         &self,
         request: super::super::Request,
-        _: &Repository,
+        repo: &Repository,
     ) -> anyhow::Result<super::super::Response> {
-        Ok(Self::handle_request(request.try_into().unwrap()).into())
+        Ok(Self::handle_request(request.try_into()?, repo).into())
     }
 }
 
 struct Request {
-    key: String,
+    section: Option<String>,
+    config: Arc<RedisConfig>,
 }
 
 impl TryFrom<super::Request> for Request {
     type Error = anyhow::Error;
 
     fn try_from(value: super::Request) -> Result<Self, Self::Error> {
-        let mut iter = value.into_content().unwrap().into_iter();
-        let key = iter.next().unwrap();
-        Ok(Self { key })
+        let config = Arc::clone(&value.config);
+        let mut parser = value.into_parser();
+        let section = parser.optional()?;
+        parser.finish()?;
+        Ok(Self { section, config })
     }
 }
 
@@ -0,0 +1,51 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp::IntoValue,
+};
+
+pub struct Keys;
+
+impl Keys {
+    fn handle_request(
+        Request { pattern, timestamp }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        Ok(Response(repo.key_repo().keys(&pattern, timestamp)))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for Keys {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("KEYS")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    pattern: Vec<u8>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let pattern = parser.arg()?;
+        parser.finish()?;
+        Ok(Self { pattern, timestamp })
+    }
+}
+
+struct Response(Vec<Vec<u8>>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(value.0.into_value())
+    }
+}
@@ -0,0 +1,146 @@
+use anyhow::bail;
+
+use crate::{
+    command::{parser::Arg, Command, CommandInfo},
+    repository::{kv_repo::lcs, Repository},
+    resp::{self, IntoValue},
+};
+
+pub struct Lcs;
+
+impl Lcs {
+    fn handle_request(
+        Request {
+            a,
+            b,
+            reply,
+            min_match_len,
+            with_match_len,
+            timestamp,
+            max_len,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let (a, b) = repo.kv_repo().get_pair(&a, &b, timestamp)?;
+        let mut lcs = lcs::lcs(&a, &b, max_len)?;
+        lcs.matches
+            .retain(|((start, end), _)| end - start + 1 >= min_match_len);
+        Ok(Response {
+            lcs,
+            reply,
+            with_match_len,
+        })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for Lcs {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("LCS")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    a: Vec<u8>,
+    b: Vec<u8>,
+    reply: Reply,
+    min_match_len: usize,
+    with_match_len: bool,
+    timestamp: std::time::SystemTime,
+    /// The configured `proto-max-bulk-len`, which also bounds the memory `LCS` may use.
+    max_len: usize,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let max_len = value.config.limits().max_bulk_len;
+        let mut parser = value.into_parser();
+        let a = parser.arg()?;
+        let b = parser.arg()?;
+        let mut options = parser.options([
+            Arg::Flag("LEN"),
+            Arg::Flag("IDX"),
+            Arg::Value("MINMATCHLEN"),
+            Arg::Flag("WITHMATCHLEN"),
+        ])?;
+        let reply = match (options.flag("LEN"), options.flag("IDX")) {
+            (true, true) => {
+                bail!("ERR If you want both the length and indexes, please just use IDX.")
+            }
+            (true, false) => Reply::Len,
+            (false, true) => Reply::Idx,
+            (false, false) => Reply::String,
+        };
+        // a negative minimum is no minimum
+        let min_match_len = options
+            .take::<i64>("MINMATCHLEN")?
+            .map_or(0, |len| usize::try_from(len).unwrap_or(0));
+        let with_match_len = options.flag("WITHMATCHLEN");
+        parser.finish()?;
+        Ok(Self {
+            a,
+            b,
+            reply,
+            min_match_len,
+            with_match_len,
+            timestamp,
+            max_len,
+        })
+    }
+}
+
+enum Reply {
+    String,
+    Len,
+    Idx,
+}
+
+struct Response {
+    lcs: lcs::Lcs,
+    reply: Reply,
+    with_match_len: bool,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        let Response {
+            lcs,
+            reply,
+            with_match_len,
+        } = value;
+        Self::value(match reply {
+            Reply::String => resp::Value::bulk_bytes(lcs.string),
+            Reply::Len => lcs.string.len().into_value(),
+            Reply::Idx => {
+                let matches = lcs
+                    .matches
+                    .into_iter()
+                    .map(|((a_start, a_end), (b_start, b_end))| {
+                        let mut ranges =
+                            vec![(a_start, a_end).into_value(), (b_start, b_end).into_value()];
+                        if with_match_len {
+                            ranges.push((a_end - a_start + 1).into_value());
+                        }
+                        resp::Value::Array(ranges)
+                    })
+                    .collect();
+                resp::Value::Map(vec![
+                    (
+                        resp::Value::bulk_string("matches"),
+                        resp::Value::Array(matches),
+                    ),
+                    (
+                        resp::Value::bulk_string("len"),
+                        lcs.string.len().into_value(),
+                    ),
+                ])
+            }
+        })
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp,
+};
+
+pub struct LIndex;
+
+impl LIndex {
+    fn handle_request(
+        Request {
+            key,
+            index,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        repo.list_repo().index(&key, index, timestamp).map(Response)
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for LIndex {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("LINDEX")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    index: i64,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let index = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            index,
+            timestamp,
+        })
+    }
+}
+
+struct Response(Option<Vec<u8>>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(
+            value
+                .0
+                .map_or(resp::Value::NullString, resp::Value::bulk_bytes),
+        )
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp,
+};
+
+pub struct LInsert;
+
+impl LInsert {
+    fn handle_request(
+        Request {
+            key,
+            before,
+            pivot,
+            element,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let len = repo
+            .list_repo()
+            .insert(&key, before, &pivot, element.clone(), timestamp)?;
+        let place = if before { "BEFORE" } else { "AFTER" };
+        let event =
+            (len > 0).then(|| super::replay("LINSERT", [key, place.into(), pivot, element]));
+        Ok(Response(len, event))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for LInsert {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("LINSERT")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    before: bool,
+    pivot: Vec<u8>,
+    element: Vec<u8>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let before = if parser.flag("BEFORE") {
+            true
+        } else {
+            parser.ident("AFTER")?;
+            false
+        };
+        let pivot = parser.arg()?;
+        let element = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            before,
+            pivot,
+            element,
+            timestamp,
+        })
+    }
+}
+
+struct Response(i64, Option<crate::event::Kind>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        (resp::Value::Integer(value.0), value.1).into()
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{list_repo::End, Repository},
+    resp,
+};
+
+pub struct LMove;
+
+impl LMove {
+    fn handle_request(
+        Request {
+            source,
+            destination,
+            from,
+            to,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let moved = repo
+            .list_repo()
+            .move_element(&source, &destination, from, to, timestamp)?;
+        Ok(Response(moved.map(|element| {
+            let event = super::replay(
+                "LMOVE",
+                [source, destination, from.name().into(), to.name().into()],
+            );
+            (element, event)
+        })))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for LMove {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("LMOVE")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    source: Vec<u8>,
+    destination: Vec<u8>,
+    from: End,
+    to: End,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let source = parser.arg()?;
+        let destination = parser.arg()?;
+        let from = parser.arg()?;
+        let to = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            source,
+            destination,
+            from,
+            to,
+            timestamp,
+        })
+    }
+}
+
+struct Response(Option<(Vec<u8>, crate::event::Kind)>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        match value.0 {
+            Some((element, event)) => Self::value_event(resp::Value::bulk_bytes(element), event),
+            None => Self::value(resp::Value::NullString),
+        }
+    }
+}
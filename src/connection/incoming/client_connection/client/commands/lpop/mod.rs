@@ -0,0 +1,95 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{list_repo::End, Repository},
+    resp,
+};
+
+pub struct LPop;
+
+impl LPop {
+    fn handle_request(
+        Request {
+            key,
+            count,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let popped = repo
+            .list_repo()
+            .pop(&key, End::Left, count.unwrap_or(1), timestamp)?;
+        let event = popped
+            .as_ref()
+            .filter(|popped| !popped.is_empty())
+            .map(|popped| super::pop_event(key, End::Left, popped.len()));
+        let reply = match (popped, count) {
+            (None, None) => Reply::Nil,
+            (None, Some(_)) => Reply::NilArray,
+            (Some(mut popped), None) => popped.pop().map_or(Reply::Nil, Reply::Element),
+            (Some(popped), Some(_)) => Reply::Elements(popped),
+        };
+        Ok(Response(reply, event))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for LPop {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("LPOP")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    count: Option<usize>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let count = parser.optional::<i64>()?;
+        parser.finish()?;
+        let count = count
+            .map(|count| {
+                usize::try_from(count)
+                    .map_err(|_| anyhow::anyhow!("ERR value is out of range, must be positive"))
+            })
+            .transpose()?;
+        Ok(Self {
+            key,
+            count,
+            timestamp,
+        })
+    }
+}
+
+enum Reply {
+    Element(Vec<u8>),
+    Elements(Vec<Vec<u8>>),
+    Nil,
+    NilArray,
+}
+
+struct Response(Reply, Option<crate::event::Kind>);
+
+impl From<Response> for super::Response {
+    fn from(Response(reply, event): Response) -> Self {
+        let value = match reply {
+            Reply::Element(element) => resp::Value::bulk_bytes(element),
+            Reply::Elements(elements) => {
+                resp::Value::Array(elements.into_iter().map(resp::Value::bulk_bytes).collect())
+            }
+            Reply::Nil => resp::Value::NullString,
+            Reply::NilArray => resp::Value::NullArray,
+        };
+        (value, event).into()
+    }
+}
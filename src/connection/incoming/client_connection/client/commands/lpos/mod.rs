@@ -0,0 +1,114 @@
+use anyhow::{anyhow, bail};
+
+use crate::{
+    command::{parser::Arg, Command, CommandInfo},
+    repository::Repository,
+    resp::{self, IntoValue},
+};
+
+pub struct LPos;
+
+impl LPos {
+    fn handle_request(
+        Request {
+            key,
+            element,
+            rank,
+            count,
+            max_len,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let positions = repo.list_repo().position(
+            &key,
+            &element,
+            rank,
+            count.unwrap_or(1),
+            max_len,
+            timestamp,
+        )?;
+        Ok(match count {
+            Some(_) => Response::Positions(positions),
+            None => Response::Position(positions.first().copied()),
+        })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for LPos {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("LPOS")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    element: Vec<u8>,
+    rank: i64,
+    count: Option<usize>,
+    max_len: usize,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let element = parser.arg()?;
+        let mut options = parser.options([
+            Arg::Value("RANK"),
+            Arg::Value("COUNT"),
+            Arg::Value("MAXLEN"),
+        ])?;
+        parser.finish()?;
+        let rank = options.take::<i64>("RANK")?.unwrap_or(1);
+        if rank == 0 {
+            bail!(
+                "ERR RANK can't be zero: use 1 to start from the first match, \
+                 2 from the second ... or use negative to start from the end of the list"
+            );
+        }
+        let count = options
+            .take::<i64>("COUNT")?
+            .map(|count| usize::try_from(count).map_err(|_| anyhow!("ERR COUNT can't be negative")))
+            .transpose()?;
+        let max_len = options
+            .take::<i64>("MAXLEN")?
+            .map(|max_len| {
+                usize::try_from(max_len).map_err(|_| anyhow!("ERR MAXLEN can't be negative"))
+            })
+            .transpose()?
+            .unwrap_or(0);
+        Ok(Self {
+            key,
+            element,
+            rank,
+            count,
+            max_len,
+            timestamp,
+        })
+    }
+}
+
+enum Response {
+    Position(Option<usize>),
+    Positions(Vec<usize>),
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(match value {
+            Response::Position(position) => {
+                position.map_or(resp::Value::NullString, IntoValue::into_value)
+            }
+            Response::Positions(positions) => positions.into_value(),
+        })
+    }
+}
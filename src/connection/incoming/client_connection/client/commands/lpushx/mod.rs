@@ -0,0 +1,68 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{list_repo::End, Repository},
+    resp::IntoValue,
+};
+
+pub struct LPushX;
+
+impl LPushX {
+    fn handle_request(
+        Request {
+            key,
+            values,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let len = repo
+            .list_repo()
+            .push(&key, values.clone(), End::Left, true, timestamp)?;
+        // the list existed if anything was pushed, so a plain push does the same
+        let event = (len > 0).then(|| super::replay("LPUSH", std::iter::once(key).chain(values)));
+        Ok(Response(len, event))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for LPushX {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("LPUSHX")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    values: Vec<Vec<u8>>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let values = parser.rest()?;
+        if values.is_empty() {
+            return Err(parser.wrong_arity());
+        }
+        Ok(Self {
+            key,
+            values,
+            timestamp,
+        })
+    }
+}
+
+struct Response(usize, Option<crate::event::Kind>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        (value.0.into_value(), value.1).into()
+    }
+}
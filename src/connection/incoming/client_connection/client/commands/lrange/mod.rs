@@ -0,0 +1,67 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp::IntoValue,
+};
+
+pub struct LRange;
+
+impl LRange {
+    fn handle_request(
+        Request {
+            key,
+            start,
+            stop,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        repo.list_repo()
+            .range(&key, start, stop, timestamp)
+            .map(Response)
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for LRange {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("LRANGE")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    start: i64,
+    stop: i64,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let start = parser.arg()?;
+        let stop = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            start,
+            stop,
+            timestamp,
+        })
+    }
+}
+
+struct Response(Vec<Vec<u8>>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(value.0.into_value())
+    }
+}
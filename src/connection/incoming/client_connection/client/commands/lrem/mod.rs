@@ -0,0 +1,68 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp::IntoValue,
+};
+
+pub struct LRem;
+
+impl LRem {
+    fn handle_request(
+        Request {
+            key,
+            count,
+            element,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let removed = repo.list_repo().remove(&key, count, &element, timestamp)?;
+        let event = (removed > 0)
+            .then(|| super::replay("LREM", [key, count.to_string().into_bytes(), element]));
+        Ok(Response(removed, event))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for LRem {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("LREM")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    count: i64,
+    element: Vec<u8>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let count = parser.arg()?;
+        let element = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            count,
+            element,
+            timestamp,
+        })
+    }
+}
+
+struct Response(usize, Option<crate::event::Kind>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        (value.0.into_value(), value.1).into()
+    }
+}
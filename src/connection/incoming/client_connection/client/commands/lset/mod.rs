@@ -0,0 +1,70 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp,
+};
+
+pub struct LSet;
+
+impl LSet {
+    fn handle_request(
+        Request {
+            key,
+            index,
+            element,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        repo.list_repo()
+            .set(&key, index, element.clone(), timestamp)?;
+        Ok(Response(super::replay(
+            "LSET",
+            [key, index.to_string().into_bytes(), element],
+        )))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for LSet {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("LSET")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    index: i64,
+    element: Vec<u8>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let index = parser.arg()?;
+        let element = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            index,
+            element,
+            timestamp,
+        })
+    }
+}
+
+struct Response(crate::event::Kind);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value_event(resp::Value::ok(), value.0)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp,
+};
+
+pub struct LTrim;
+
+impl LTrim {
+    fn handle_request(
+        Request {
+            key,
+            start,
+            stop,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        repo.list_repo().trim(&key, start, stop, timestamp)?;
+        Ok(Response(super::replay(
+            "LTRIM",
+            [
+                key,
+                start.to_string().into_bytes(),
+                stop.to_string().into_bytes(),
+            ],
+        )))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for LTrim {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("LTRIM")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    start: i64,
+    stop: i64,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let start = parser.arg()?;
+        let stop = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            start,
+            stop,
+            timestamp,
+        })
+    }
+}
+
+struct Response(crate::event::Kind);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value_event(resp::Value::ok(), value.0)
+    }
+}
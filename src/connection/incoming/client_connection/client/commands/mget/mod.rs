@@ -0,0 +1,60 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp,
+};
+
+pub struct MGet;
+
+impl MGet {
+    fn handle_request(
+        Request { keys, timestamp }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        Ok(Response(repo.kv_repo().get_many(&keys, timestamp)))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for MGet {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("MGET")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    keys: Vec<Vec<u8>>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let keys = parser.rest()?;
+        if keys.is_empty() {
+            return Err(parser.wrong_arity());
+        }
+        parser.finish()?;
+        Ok(Self { keys, timestamp })
+    }
+}
+
+struct Response(Vec<Option<Vec<u8>>>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(resp::Value::Array(
+            value
+                .0
+                .into_iter()
+                .map(|value| value.map_or(resp::Value::NullString, resp::Value::bulk_bytes))
+                .collect(),
+        ))
+    }
+}
@@ -1,16 +1,464 @@
+pub mod append;
+pub mod blmove;
+pub mod blmpop;
+pub mod blpop;
+pub mod brpop;
+pub mod bzpopmax;
+pub mod bzpopmin;
 pub mod client;
 pub mod cluster;
 pub mod config;
+pub mod copy;
+pub mod dbsize;
+pub mod decr;
+pub mod decrby;
+pub mod del;
 pub mod echo;
+pub mod exists;
+pub mod expire;
+pub mod expireat;
+pub mod expiretime;
+pub mod flushall;
+pub mod flushdb;
 pub mod get;
+pub mod getdel;
+pub mod getex;
+pub mod getrange;
+pub mod getset;
+pub mod hdel;
+pub mod hello;
+pub mod hexists;
+pub mod hget;
+pub mod hgetall;
+pub mod hincrby;
+pub mod hincrbyfloat;
+pub mod hkeys;
+pub mod hlen;
+pub mod hmget;
+pub mod hrandfield;
+pub mod hscan;
+pub mod hset;
+pub mod hsetnx;
+pub mod hstrlen;
+pub mod hvals;
+pub mod incr;
+pub mod incrby;
+pub mod incrbyfloat;
 pub mod info;
+pub mod keys;
+pub mod lcs;
+pub mod lindex;
+pub mod linsert;
+pub mod llen;
+pub mod lmove;
+pub mod lpop;
+pub mod lpos;
+pub mod lpush;
+pub mod lpushx;
+pub mod lrange;
+pub mod lrem;
+pub mod lset;
+pub mod ltrim;
+pub mod mget;
+pub mod r#move;
+pub mod mset;
+pub mod msetnx;
+pub mod persist;
+pub mod pexpire;
+pub mod pexpireat;
+pub mod pexpiretime;
 pub mod ping;
+pub mod psetex;
+pub mod pttl;
+pub mod randomkey;
+pub mod rename;
+pub mod renamenx;
+pub mod rpop;
+pub mod rpush;
+pub mod rpushx;
+pub mod sadd;
+pub mod scan;
+pub mod scard;
+pub mod sdiff;
+pub mod sdiffstore;
 pub mod select;
 pub mod set;
+pub mod setex;
+pub mod setnx;
+pub mod setrange;
+pub mod sinter;
+pub mod sintercard;
+pub mod sinterstore;
+pub mod sismember;
+pub mod smembers;
+pub mod smismember;
+pub mod smove;
+pub mod spop;
+pub mod srandmember;
+pub mod srem;
+pub mod sscan;
+pub mod strlen;
 pub mod subscribe;
+pub mod sunion;
+pub mod sunionstore;
+pub mod swapdb;
+pub mod touch;
+pub mod ttl;
+pub mod r#type;
+pub mod unlink;
 pub mod xadd;
 pub mod xrange;
 pub mod xread;
+pub mod xrevrange;
+pub mod zadd;
+pub mod zcard;
+pub mod zcount;
+pub mod zincrby;
+pub mod zinterstore;
+pub mod zlexcount;
+pub mod zmscore;
+pub mod zpopmax;
+pub mod zpopmin;
+pub mod zrange;
+pub mod zrangestore;
+pub mod zrank;
+pub mod zrem;
+pub mod zrevrank;
+pub mod zscan;
+pub mod zscore;
+pub mod zunionstore;
 
 type Request = super::Request;
 type Response = super::Response;
+
+/// Parses the timeout of a blocking command in seconds, 0 blocks forever.
+fn blocking_timeout(
+    parser: &mut crate::command::parser::Parser,
+) -> anyhow::Result<Option<std::time::Duration>> {
+    let timeout = parser
+        .arg::<f64>()
+        .ok()
+        .filter(|timeout| timeout.is_finite())
+        .ok_or_else(|| anyhow::anyhow!("ERR timeout is not a float or out of range"))?;
+    if timeout < 0.0 {
+        anyhow::bail!("ERR timeout is negative");
+    }
+    if timeout == 0.0 {
+        return Ok(None);
+    }
+    std::time::Duration::try_from_secs_f64(timeout)
+        .map(Some)
+        .map_err(|_| anyhow::anyhow!("ERR timeout is out of range"))
+}
+
+/// Parses `numkeys key [key ...]`, the keys of commands that take more arguments after them.
+fn counted_keys(parser: &mut crate::command::parser::Parser) -> anyhow::Result<Vec<Vec<u8>>> {
+    let numkeys = parser
+        .arg::<i64>()?
+        .try_into()
+        .ok()
+        .filter(|numkeys| *numkeys > 0)
+        .ok_or_else(|| anyhow::anyhow!("ERR numkeys should be greater than 0"))?;
+    if parser.remaining() < numkeys {
+        return Err(parser.wrong_arity());
+    }
+    (0..numkeys).map(|_| parser.arg()).collect()
+}
+
+/// The database at `index`, fails like redis if there is no such database.
+fn database(
+    repo: &crate::repository::Repository,
+    index: i64,
+) -> anyhow::Result<crate::repository::Repository> {
+    usize::try_from(index)
+        .ok()
+        .and_then(|index| repo.select(index))
+        .ok_or_else(|| anyhow::anyhow!("ERR DB index is out of range"))
+}
+
+/// Splits a string write into its result and the SET that replicates the string it left behind.
+fn replicated<T>(
+    key: Vec<u8>,
+    written: crate::repository::kv_repo::Written<T>,
+) -> (T, crate::event::Kind) {
+    let event = crate::event::Kind::Set {
+        key,
+        value: written.value,
+        expiry: written.expiry,
+    };
+    (written.result, event)
+}
+
+/// The event that replicates a write by having followers run `command` with `args`.
+fn replay(command: &str, args: impl IntoIterator<Item = Vec<u8>>) -> crate::event::Kind {
+    crate::event::Kind::Command {
+        args: std::iter::once(command.as_bytes().to_vec())
+            .chain(args)
+            .collect(),
+    }
+}
+
+/// Replicates popping `count` elements from the `end` of the list at `key`.
+fn pop_event(
+    key: Vec<u8>,
+    end: crate::repository::list_repo::End,
+    count: usize,
+) -> crate::event::Kind {
+    use crate::repository::list_repo::End;
+
+    let command = match end {
+        End::Left => "LPOP",
+        End::Right => "RPOP",
+    };
+    replay(command, [key, count.to_string().into_bytes()])
+}
+
+/// Replicates popping `entries` from the sorted set at `key`, `None` if nothing was popped.
+fn zpop_event(
+    key: Vec<u8>,
+    entries: &[crate::repository::zset_repo::Entry],
+) -> Option<crate::event::Kind> {
+    let members = entries.iter().map(|(member, _)| member.clone());
+    (!entries.is_empty()).then(|| replay("ZREM", std::iter::once(key).chain(members)))
+}
+
+/// Takes one of the EX, PX, EXAT or PXAT options of `command` as an absolute expiry.
+fn expire_at(
+    options: &mut crate::command::parser::Options,
+    command: &str,
+    now: std::time::SystemTime,
+) -> anyhow::Result<Option<std::time::SystemTime>> {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let given = ["EX", "PX", "EXAT", "PXAT"]
+        .into_iter()
+        .filter(|name| options.flag(name))
+        .collect::<Vec<_>>();
+    let [name] = given[..] else {
+        if given.is_empty() {
+            return Ok(None);
+        }
+        anyhow::bail!(crate::command::parser::SYNTAX_ERROR);
+    };
+    let invalid = || anyhow::anyhow!("ERR invalid expire time in '{command}' command");
+    let time = options
+        .take::<i64>(name)?
+        .and_then(|time| u64::try_from(time).ok())
+        .filter(|time| *time > 0)
+        .ok_or_else(invalid)?;
+    let (base, duration) = match name {
+        "EX" => (now, Duration::from_secs(time)),
+        "PX" => (now, Duration::from_millis(time)),
+        "EXAT" => (UNIX_EPOCH, Duration::from_secs(time)),
+        _ => (UNIX_EPOCH, Duration::from_millis(time)),
+    };
+    base.checked_add(duration).map(Some).ok_or_else(invalid)
+}
+
+/// Parses `time [NX | XX | GT | LT]` of the EXPIRE family, `time` counts in `unit` from `base`.
+fn expire_args(
+    parser: &mut crate::command::parser::Parser,
+    base: std::time::SystemTime,
+    unit: std::time::Duration,
+) -> anyhow::Result<(
+    std::time::SystemTime,
+    crate::repository::key_repo::ExpireOptions,
+)> {
+    use crate::command::parser::Arg;
+
+    let time = parser.arg::<i64>()?;
+    let flags = parser.options([
+        Arg::Flag("NX"),
+        Arg::Flag("XX"),
+        Arg::Flag("GT"),
+        Arg::Flag("LT"),
+    ])?;
+    let options = crate::repository::key_repo::ExpireOptions {
+        nx: flags.flag("NX"),
+        xx: flags.flag("XX"),
+        gt: flags.flag("GT"),
+        lt: flags.flag("LT"),
+    };
+    if options.nx && (options.xx || options.gt || options.lt) {
+        anyhow::bail!("ERR NX and XX, GT or LT options at the same time are not compatible");
+    }
+    if options.gt && options.lt {
+        anyhow::bail!("ERR GT and LT options at the same time are not compatible");
+    }
+    let invalid = || anyhow::anyhow!("ERR invalid expire time in '{}' command", parser.command());
+    let unit = i64::try_from(unit.as_millis()).map_err(|_| invalid())?;
+    let base = base
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    let base = i64::try_from(base).map_err(|_| invalid())?;
+    let millis = time
+        .checked_mul(unit)
+        .and_then(|time| base.checked_add(time))
+        .ok_or_else(invalid)?;
+    // times before the epoch are simply in the past
+    let at = std::time::UNIX_EPOCH
+        + std::time::Duration::from_millis(u64::try_from(millis).unwrap_or_default());
+    Ok((at, options))
+}
+
+/// What replicates a successful EXPIRE and its variants, an expiry in the past deleted the key.
+fn expire_event(
+    key: Vec<u8>,
+    at: std::time::SystemTime,
+    now: std::time::SystemTime,
+) -> crate::event::Kind {
+    if at <= now {
+        crate::event::Kind::Del { keys: vec![key] }
+    } else {
+        crate::event::Kind::Expire { key, at: Some(at) }
+    }
+}
+
+/// The reply of TTL and its variants, -2 for a missing key and -1 for one without expiry.
+/// `absolute` replies with the time since the epoch instead of the time left.
+fn ttl_reply(
+    ttl: crate::repository::key_repo::Ttl,
+    now: std::time::SystemTime,
+    absolute: bool,
+    millis: bool,
+) -> i64 {
+    use crate::repository::key_repo::Ttl;
+
+    let at = match ttl {
+        Ttl::Missing => return -2,
+        Ttl::Persistent => return -1,
+        Ttl::Expires(at) => at,
+    };
+    let base = if absolute { std::time::UNIX_EPOCH } else { now };
+    let left = at.duration_since(base).unwrap_or_default().as_millis();
+    // seconds are rounded like redis does
+    let left = if millis { left } else { (left + 500) / 1000 };
+    i64::try_from(left).unwrap_or(i64::MAX)
+}
+
+/// The arguments shared by ZRANGE and ZRANGESTORE.
+struct SortedSetRange {
+    range: crate::repository::zset_repo::Range,
+    rev: bool,
+    limit: Option<crate::repository::zset_repo::Limit>,
+    with_scores: bool,
+}
+
+/// Parses `min max [BYSCORE | BYLEX] [REV] [LIMIT offset count] [WITHSCORES]`,
+/// WITHSCORES only if `allow_with_scores`.
+fn sorted_set_range(
+    parser: &mut crate::command::parser::Parser,
+    allow_with_scores: bool,
+) -> anyhow::Result<SortedSetRange> {
+    use crate::{
+        command::parser::SYNTAX_ERROR,
+        repository::zset_repo::{LexBound, Limit, Range, ScoreBound},
+        resp::{self, FromValue},
+    };
+
+    let min = parser.arg::<resp::Value>()?;
+    let max = parser.arg::<resp::Value>()?;
+    let (mut by_score, mut by_lex, mut rev, mut limit, mut with_scores) =
+        (false, false, false, None, false);
+    while !parser.is_empty() {
+        if parser.flag("BYSCORE") {
+            by_score = true;
+        } else if parser.flag("BYLEX") {
+            by_lex = true;
+        } else if parser.flag("REV") {
+            rev = true;
+        } else if parser.flag("LIMIT") {
+            if parser.remaining() < 2 {
+                anyhow::bail!(SYNTAX_ERROR);
+            }
+            let offset = parser.arg::<i64>()?;
+            let count = parser.arg::<i64>()?;
+            limit = Some(Limit {
+                // a negative offset selects nothing
+                offset: usize::try_from(offset).unwrap_or(usize::MAX),
+                count: usize::try_from(count).ok(),
+            });
+        } else if allow_with_scores && parser.flag("WITHSCORES") {
+            with_scores = true;
+        } else {
+            anyhow::bail!(SYNTAX_ERROR);
+        }
+    }
+    if by_score && by_lex {
+        anyhow::bail!(SYNTAX_ERROR);
+    }
+    if limit.is_some() && !by_score && !by_lex {
+        anyhow::bail!(
+            "ERR syntax error, LIMIT is only supported in combination with either BYSCORE or BYLEX"
+        );
+    }
+    if with_scores && by_lex {
+        anyhow::bail!("ERR syntax error, WITHSCORES not supported in combination with BYLEX");
+    }
+    // reversed score and lex ranges are given from max to min
+    let (min, max) = if rev && (by_score || by_lex) {
+        (max, min)
+    } else {
+        (min, max)
+    };
+    let range = if by_score {
+        Range::Score(
+            ScoreBound::parse(&Vec::from_value(min)?)?,
+            ScoreBound::parse(&Vec::from_value(max)?)?,
+        )
+    } else if by_lex {
+        Range::Lex(
+            LexBound::parse(&Vec::from_value(min)?)?,
+            LexBound::parse(&Vec::from_value(max)?)?,
+        )
+    } else {
+        Range::Rank(i64::from_value(min)?, i64::from_value(max)?)
+    };
+    Ok(SortedSetRange {
+        range,
+        rev,
+        limit,
+        with_scores,
+    })
+}
+
+/// Members of a sorted set as a flat array, each followed by its score if `with_scores`.
+fn scored_members(
+    entries: Vec<crate::repository::zset_repo::Entry>,
+    with_scores: bool,
+) -> crate::resp::Value {
+    use crate::resp;
+
+    let mut members = Vec::with_capacity(entries.len() * if with_scores { 2 } else { 1 });
+    for (member, score) in entries {
+        members.push(resp::Value::bulk_bytes(member));
+        if with_scores {
+            members.push(resp::Value::Double(score));
+        }
+    }
+    resp::Value::Array(members)
+}
+
+/// Parses a bound of XRANGE or XREVRANGE, `-` and `+` are the smallest and largest ids.
+/// A bound without sequence number takes `sequence`, 0 for starts and the largest for ends.
+fn stream_range_bound(
+    parser: &mut crate::command::parser::Parser,
+    sequence: u64,
+) -> anyhow::Result<crate::repository::stream_repo::stream::EntryId> {
+    use crate::repository::stream_repo::stream::EntryId;
+    let bound: String = parser.arg()?;
+    let parse = |s: &str| {
+        s.parse().map_err(|_| {
+            anyhow::anyhow!("ERR Invalid stream ID specified as stream command argument")
+        })
+    };
+    Ok(match bound.as_str() {
+        "-" => EntryId::new(0, 0),
+        "+" => EntryId::max(),
+        bound => match bound.split_once('-') {
+            Some((timestamp, id)) => EntryId::new(parse(timestamp)?, parse(id)?),
+            None => EntryId::new(parse(bound)?, sequence),
+        },
+    })
+}
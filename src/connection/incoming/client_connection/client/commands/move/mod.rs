@@ -0,0 +1,70 @@
+use crate::{
+    command::{Command, CommandInfo},
+    event,
+    repository::Repository,
+    resp::IntoValue,
+};
+
+pub struct Move;
+
+impl Move {
+    fn handle_request(
+        Request { key, db, timestamp }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let target = super::database(repo, db)?;
+        let moved = repo
+            .key_repo()
+            .move_to(&key, target.key_repo(), timestamp)?;
+        Ok(Response {
+            key,
+            db: target.selected(),
+            moved,
+        })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for Move {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("MOVE")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    db: i64,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let db = parser.arg()?;
+        parser.finish()?;
+        Ok(Self { key, db, timestamp })
+    }
+}
+
+struct Response {
+    key: Vec<u8>,
+    db: usize,
+    moved: bool,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        let event = value.moved.then_some(event::Kind::Move {
+            key: value.key,
+            db: value.db,
+        });
+        (value.moved.into_value(), event).into()
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp,
+};
+
+pub struct MSet;
+
+impl MSet {
+    fn handle_request(
+        Request { pairs, timestamp }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let written = repo.kv_repo().set_many(pairs.clone(), false, timestamp);
+        Ok(Response { pairs, written })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for MSet {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("MSET")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    pairs: Vec<(Vec<u8>, Vec<u8>)>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let pairs = parser.pairs()?;
+        if pairs.is_empty() {
+            return Err(parser.wrong_arity());
+        }
+        parser.finish()?;
+        Ok(Self { pairs, timestamp })
+    }
+}
+
+struct Response {
+    pairs: Vec<(Vec<u8>, Vec<u8>)>,
+    written: bool,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        let events = if value.written {
+            value
+                .pairs
+                .into_iter()
+                .map(|(key, value)| crate::event::Kind::Set {
+                    key,
+                    value,
+                    expiry: None,
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        Self::value_events(resp::Value::ok(), events)
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp::IntoValue,
+};
+
+pub struct Persist;
+
+impl Persist {
+    fn handle_request(
+        Request { key, timestamp }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let persisted = repo.key_repo().persist(&key, timestamp);
+        Ok(Response(
+            persisted.then_some(crate::event::Kind::Expire { key, at: None }),
+        ))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for Persist {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("PERSIST")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        parser.finish()?;
+        Ok(Self { key, timestamp })
+    }
+}
+
+struct Response(Option<crate::event::Kind>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        (i64::from(value.0.is_some()).into_value(), value.0).into()
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{key_repo::ExpireOptions, Repository},
+    resp::IntoValue,
+};
+
+pub struct PExpireAt;
+
+impl PExpireAt {
+    fn handle_request(
+        Request {
+            key,
+            at,
+            options,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let expired = repo.key_repo().expire(&key, at, options, timestamp);
+        Ok(Response(
+            expired.then(|| super::expire_event(key, at, timestamp)),
+        ))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for PExpireAt {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("PEXPIREAT")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    at: std::time::SystemTime,
+    options: ExpireOptions,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let (at, options) = super::expire_args(
+            &mut parser,
+            std::time::UNIX_EPOCH,
+            std::time::Duration::from_millis(1),
+        )?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            at,
+            options,
+            timestamp,
+        })
+    }
+}
+
+struct Response(Option<crate::event::Kind>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        (i64::from(value.0.is_some()).into_value(), value.0).into()
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp,
+};
+
+pub struct RandomKey;
+
+impl RandomKey {
+    fn handle_request(
+        Request { timestamp }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        Ok(Response(repo.key_repo().random_key(timestamp)))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for RandomKey {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("RANDOMKEY")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        value.into_parser().finish()?;
+        Ok(Self { timestamp })
+    }
+}
+
+struct Response(Option<Vec<u8>>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(
+            value
+                .0
+                .map_or(resp::Value::NullString, resp::Value::bulk_bytes),
+        )
+    }
+}
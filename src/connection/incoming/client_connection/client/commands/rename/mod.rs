@@ -0,0 +1,78 @@
+use crate::{
+    command::{Command, CommandInfo},
+    event,
+    repository::Repository,
+    resp,
+};
+
+pub struct Rename;
+
+impl Rename {
+    fn handle_request(
+        Request {
+            key,
+            new_key,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let renamed = repo.key_repo().rename(&key, &new_key, false, timestamp)?;
+        Ok(Response {
+            key,
+            new_key,
+            renamed,
+        })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for Rename {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("RENAME")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    new_key: Vec<u8>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let new_key = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            new_key,
+            timestamp,
+        })
+    }
+}
+
+struct Response {
+    key: Vec<u8>,
+    new_key: Vec<u8>,
+    renamed: bool,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        let Response {
+            key,
+            new_key,
+            renamed,
+        } = value;
+        // renaming a key to itself changes nothing
+        let event = (renamed && key != new_key).then_some(event::Kind::Rename { key, new_key });
+        (resp::Value::ok(), event).into()
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{list_repo::End, Repository},
+    resp::IntoValue,
+};
+
+pub struct RPush;
+
+impl RPush {
+    fn handle_request(
+        Request {
+            key,
+            values,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let len = repo
+            .list_repo()
+            .push(&key, values.clone(), End::Right, false, timestamp)?;
+        let event = super::replay("RPUSH", std::iter::once(key).chain(values));
+        Ok(Response(len, event))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for RPush {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("RPUSH")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    values: Vec<Vec<u8>>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let values = parser.rest()?;
+        if values.is_empty() {
+            return Err(parser.wrong_arity());
+        }
+        Ok(Self {
+            key,
+            values,
+            timestamp,
+        })
+    }
+}
+
+struct Response(usize, crate::event::Kind);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value_event(value.0.into_value(), value.1)
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp::IntoValue,
+};
+
+pub struct SAdd;
+
+impl SAdd {
+    fn handle_request(
+        Request {
+            key,
+            members,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let args = std::iter::once(key.clone())
+            .chain(members.iter().cloned())
+            .collect::<Vec<_>>();
+        let added = repo.set_repo().add(&key, members, timestamp)?;
+        Ok(Response(
+            added,
+            (added > 0).then(|| super::replay("SADD", args)),
+        ))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for SAdd {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("SADD")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    members: Vec<Vec<u8>>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let members = parser.rest()?;
+        if members.is_empty() {
+            return Err(parser.wrong_arity());
+        }
+        parser.finish()?;
+        Ok(Self {
+            key,
+            members,
+            timestamp,
+        })
+    }
+}
+
+struct Response(usize, Option<crate::event::Kind>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        (value.0.into_value(), value.1).into()
+    }
+}
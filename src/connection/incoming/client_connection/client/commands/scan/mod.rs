@@ -0,0 +1,93 @@
+use crate::{
+    command::{
+        parser::{Arg, SYNTAX_ERROR},
+        Command, CommandInfo,
+    },
+    repository::Repository,
+    resp::{self, IntoValue},
+};
+
+pub struct Scan;
+
+impl Scan {
+    fn handle_request(
+        Request {
+            cursor,
+            pattern,
+            count,
+            type_name,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let (cursor, keys) = repo.key_repo().scan(
+            cursor,
+            pattern.as_deref(),
+            count,
+            type_name.as_deref(),
+            timestamp,
+        );
+        Ok(Response { cursor, keys })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for Scan {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("SCAN")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    cursor: usize,
+    pattern: Option<Vec<u8>>,
+    count: usize,
+    type_name: Option<String>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let cursor = parser
+            .arg()
+            .map_err(|_| anyhow::anyhow!("ERR invalid cursor"))?;
+        let mut options =
+            parser.options([Arg::Value("MATCH"), Arg::Value("COUNT"), Arg::Value("TYPE")])?;
+        let pattern = options.take("MATCH")?;
+        let count = options.take::<i64>("COUNT")?.unwrap_or(10);
+        let count = usize::try_from(count)
+            .ok()
+            .filter(|count| *count > 0)
+            .ok_or_else(|| anyhow::anyhow!(SYNTAX_ERROR))?;
+        let type_name = options.take("TYPE")?;
+        parser.finish()?;
+        Ok(Self {
+            cursor,
+            pattern,
+            count,
+            type_name,
+            timestamp,
+        })
+    }
+}
+
+struct Response {
+    cursor: usize,
+    keys: Vec<Vec<u8>>,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(resp::Value::Array(vec![
+            resp::Value::bulk_string(value.cursor.to_string()),
+            value.keys.into_value(),
+        ]))
+    }
+}
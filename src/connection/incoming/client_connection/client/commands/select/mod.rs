@@ -1,17 +1,53 @@
-use crate::{command::Command, repository::Repository};
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+};
+
+use super::super::ClientState;
 
 pub struct Select;
+
+impl Select {
+    fn handle_request(
+        Request { index, mut state }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        state.db = super::database(repo, index)?.selected();
+        Ok(Response(state))
+    }
+}
+
 impl Command<super::Request, super::Response, Repository> for Select {
-    fn info(&self) -> crate::command::CommandInfo {
-        crate::command::CommandInfo::new_name("SELECT")
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("SELECT")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
     }
+}
+
+struct Request {
+    index: i64,
+    state: ClientState,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let state = value.state.clone();
+        let mut parser = value.into_parser();
+        let index = parser.arg()?;
+        parser.finish()?;
+        Ok(Self { index, state })
+    }
+}
+
+struct Response(ClientState);
 
-    fn call(
-        &self,
-        _request: super::Request,
-        _state: &Repository,
-    ) -> anyhow::Result<super::Response> {
-        tracing::warn!("SELECT not implimented");
-        Ok(super::Response::ok())
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::ok().with_state(value.0)
     }
 }
@@ -1,59 +1,127 @@
 use anyhow::bail;
 
-use crate::{command::Command, repository::Repository, resp};
+use crate::{
+    command::{
+        parser::{Arg, SYNTAX_ERROR},
+        Command, CommandInfo,
+    },
+    repository::{
+        kv_repo::{Condition, Expiry, SetOptions, SetResult},
+        Repository,
+    },
+    resp,
+};
 
 pub struct Set;
 
 impl Set {
-    fn handle_request(Request { key, value }: Request, repo: &Repository) -> Response {
-        repo.kv_repo()
-            .set(key.clone(), value.clone(), None)
-            .unwrap();
-        Response { key, value }
+    fn handle_request(
+        Request {
+            key,
+            value,
+            options,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let result = repo
+            .kv_repo()
+            .set_with(key.clone(), value.clone(), options, timestamp)?;
+        Ok(Response {
+            key,
+            value,
+            get: options.get,
+            result,
+        })
     }
 }
+
 impl Command<super::Request, super::Response, Repository> for Set {
-    fn info(&self) -> crate::command::CommandInfo {
-        crate::command::CommandInfo::new_name("SET")
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("SET")
     }
 
     fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
-        Ok(Self::handle_request(request.try_into()?, repo).into())
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
     }
 }
 
 struct Request {
-    key: String,
-    value: String,
+    key: Vec<u8>,
+    value: Vec<u8>,
+    options: SetOptions,
+    timestamp: std::time::SystemTime,
 }
 
 impl TryFrom<super::Request> for Request {
     type Error = anyhow::Error;
 
     fn try_from(value: super::Request) -> Result<Self, Self::Error> {
-        let mut iter = value.into_content().unwrap().into_iter();
-
-        let (Some(key), Some(value)) = (iter.next(), iter.next()) else {
-            bail!("usage: SET <key> <value>")
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let value = parser.arg()?;
+        let mut options = parser.options([
+            Arg::Flag("NX"),
+            Arg::Flag("XX"),
+            Arg::Flag("GET"),
+            Arg::Flag("KEEPTTL"),
+            Arg::Value("EX"),
+            Arg::Value("PX"),
+            Arg::Value("EXAT"),
+            Arg::Value("PXAT"),
+        ])?;
+        parser.finish()?;
+        let condition = match (options.flag("NX"), options.flag("XX")) {
+            (false, false) => Condition::Always,
+            (true, false) => Condition::NotExists,
+            (false, true) => Condition::Exists,
+            (true, true) => bail!(SYNTAX_ERROR),
+        };
+        let keep_ttl = options.flag("KEEPTTL");
+        let expiry = match super::expire_at(&mut options, "set", timestamp)? {
+            Some(_) if keep_ttl => bail!(SYNTAX_ERROR),
+            Some(at) => Expiry::At(at),
+            None if keep_ttl => Expiry::Keep,
+            None => Expiry::Persist,
         };
-        Ok(Self { key, value })
+        Ok(Self {
+            key,
+            value,
+            options: SetOptions {
+                condition,
+                expiry,
+                get: options.flag("GET"),
+            },
+            timestamp,
+        })
     }
 }
 
 struct Response {
-    key: String,
-    value: String,
+    key: Vec<u8>,
+    value: Vec<u8>,
+    get: bool,
+    result: SetResult,
 }
 
 impl From<Response> for super::Response {
     fn from(value: Response) -> Self {
-        Self::value_event(
-            resp::Value::ok(),
-            crate::event::Kind::Set {
-                key: value.key,
-                value: value.value,
-                expiry: None,
-            },
-        )
+        let reply = if value.get {
+            value
+                .result
+                .old
+                .map_or(resp::Value::NullString, resp::Value::bulk_bytes)
+        } else if value.result.written {
+            resp::Value::ok()
+        } else {
+            resp::Value::NullString
+        };
+        let event = value.result.written.then_some(crate::event::Kind::Set {
+            key: value.key,
+            value: value.value,
+            expiry: value.result.expiry,
+        });
+        (reply, event).into()
     }
 }
@@ -0,0 +1,91 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{
+        kv_repo::{Expiry, SetOptions},
+        Repository,
+    },
+    resp,
+};
+
+pub struct SetEx;
+
+impl SetEx {
+    fn handle_request(
+        Request {
+            key,
+            value,
+            expiry,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let options = SetOptions {
+            expiry: Expiry::At(expiry),
+            ..SetOptions::default()
+        };
+        repo.kv_repo()
+            .set_with(key.clone(), value.clone(), options, timestamp)?;
+        Ok(Response { key, value, expiry })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for SetEx {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("SETEX")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    value: Vec<u8>,
+    expiry: std::time::SystemTime,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let expiry = parser
+            .arg::<i64>()?
+            .try_into()
+            .ok()
+            .filter(|seconds| *seconds > 0)
+            .and_then(|seconds| timestamp.checked_add(std::time::Duration::from_secs(seconds)))
+            .ok_or_else(|| anyhow::anyhow!("ERR invalid expire time in 'setex' command"))?;
+        let value = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            value,
+            expiry,
+            timestamp,
+        })
+    }
+}
+
+struct Response {
+    key: Vec<u8>,
+    value: Vec<u8>,
+    expiry: std::time::SystemTime,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value_event(
+            resp::Value::ok(),
+            crate::event::Kind::Set {
+                key: value.key,
+                value: value.value,
+                expiry: Some(value.expiry),
+            },
+        )
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{
+        kv_repo::{Condition, SetOptions},
+        Repository,
+    },
+    resp::IntoValue,
+};
+
+pub struct SetNx;
+
+impl SetNx {
+    fn handle_request(
+        Request {
+            key,
+            value,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let options = SetOptions {
+            condition: Condition::NotExists,
+            ..SetOptions::default()
+        };
+        let result = repo
+            .kv_repo()
+            .set_with(key.clone(), value.clone(), options, timestamp)?;
+        Ok(Response {
+            key,
+            value,
+            written: result.written,
+        })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for SetNx {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("SETNX")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    value: Vec<u8>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let value = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            value,
+            timestamp,
+        })
+    }
+}
+
+struct Response {
+    key: Vec<u8>,
+    value: Vec<u8>,
+    written: bool,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        let event = value.written.then_some(crate::event::Kind::Set {
+            key: value.key,
+            value: value.value,
+            expiry: None,
+        });
+        (i64::from(value.written).into_value(), event).into()
+    }
+}
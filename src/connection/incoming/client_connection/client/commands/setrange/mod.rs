@@ -0,0 +1,94 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{
+        kv_repo::{SetRangeResult, Written},
+        Repository,
+    },
+    resp::IntoValue,
+};
+
+pub struct SetRange;
+
+impl SetRange {
+    fn handle_request(
+        Request {
+            key,
+            offset,
+            value,
+            timestamp,
+            max_len,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let result = repo
+            .kv_repo()
+            .set_range(&key, offset, &value, max_len, timestamp)?;
+        Ok(match result {
+            SetRangeResult::Unchanged(len) => Response::Len(len),
+            SetRangeResult::Written(written) => Response::Written { key, written },
+        })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for SetRange {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("SETRANGE")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    offset: usize,
+    value: Vec<u8>,
+    timestamp: std::time::SystemTime,
+    /// The configured `proto-max-bulk-len`, the longest the string may grow.
+    max_len: usize,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let max_len = value.config.limits().max_bulk_len;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let offset = parser
+            .arg::<i64>()?
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("ERR offset is out of range"))?;
+        let value = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            offset,
+            value,
+            timestamp,
+            max_len,
+        })
+    }
+}
+
+enum Response {
+    Len(usize),
+    Written {
+        key: Vec<u8>,
+        written: Written<usize>,
+    },
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        match value {
+            Response::Len(len) => Self::value(len.into_value()),
+            Response::Written { key, written } => {
+                let (len, event) = super::replicated(key, written);
+                Self::value_event(len.into_value(), event)
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp::IntoValue,
+};
+
+pub struct SInterCard;
+
+impl SInterCard {
+    fn handle_request(
+        Request {
+            keys,
+            limit,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        repo.set_repo()
+            .intersection_len(&keys, limit, timestamp)
+            .map(Response)
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for SInterCard {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("SINTERCARD")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    keys: Vec<Vec<u8>>,
+    limit: usize,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let keys = super::counted_keys(&mut parser)?;
+        let limit = parser
+            .option::<i64>("LIMIT")?
+            .map(|limit| {
+                usize::try_from(limit).map_err(|_| anyhow::anyhow!("ERR LIMIT can't be negative"))
+            })
+            .transpose()?
+            .unwrap_or(0);
+        parser.finish()?;
+        Ok(Self {
+            keys,
+            limit,
+            timestamp,
+        })
+    }
+}
+
+struct Response(usize);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(value.0.into_value())
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{set_repo::Operation, Repository},
+    resp::IntoValue,
+};
+
+pub struct SInterStore;
+
+impl SInterStore {
+    fn handle_request(
+        Request {
+            destination,
+            keys,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let len = repo.set_repo().combine_store(
+            Operation::Intersection,
+            &destination,
+            &keys,
+            timestamp,
+        )?;
+        let event = super::replay("SINTERSTORE", std::iter::once(destination).chain(keys));
+        Ok(Response(len, event))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for SInterStore {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("SINTERSTORE")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    destination: Vec<u8>,
+    keys: Vec<Vec<u8>>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let destination = parser.arg()?;
+        let keys = parser.rest()?;
+        if keys.is_empty() {
+            return Err(parser.wrong_arity());
+        }
+        parser.finish()?;
+        Ok(Self {
+            destination,
+            keys,
+            timestamp,
+        })
+    }
+}
+
+struct Response(usize, crate::event::Kind);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value_event(value.0.into_value(), value.1)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp,
+};
+
+pub struct SIsMember;
+
+impl SIsMember {
+    fn handle_request(
+        Request {
+            key,
+            member,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        repo.set_repo()
+            .is_member(&key, &member, timestamp)
+            .map(Response)
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for SIsMember {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("SISMEMBER")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    member: Vec<u8>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let member = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            member,
+            timestamp,
+        })
+    }
+}
+
+struct Response(bool);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(resp::Value::Integer(value.0.into()))
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp,
+};
+
+pub struct SMIsMember;
+
+impl SMIsMember {
+    fn handle_request(
+        Request {
+            key,
+            members,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        repo.set_repo()
+            .are_members(&key, &members, timestamp)
+            .map(Response)
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for SMIsMember {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("SMISMEMBER")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    members: Vec<Vec<u8>>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let members = parser.rest()?;
+        if members.is_empty() {
+            return Err(parser.wrong_arity());
+        }
+        parser.finish()?;
+        Ok(Self {
+            key,
+            members,
+            timestamp,
+        })
+    }
+}
+
+struct Response(Vec<bool>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(resp::Value::Array(
+            value
+                .0
+                .into_iter()
+                .map(|found| resp::Value::Integer(found.into()))
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp,
+};
+
+pub struct SMove;
+
+impl SMove {
+    fn handle_request(
+        Request {
+            source,
+            destination,
+            member,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let args = [source.clone(), destination.clone(), member.clone()];
+        let moved = repo
+            .set_repo()
+            .move_member(&source, &destination, member, timestamp)?;
+        Ok(Response(moved.then(|| super::replay("SMOVE", args))))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for SMove {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("SMOVE")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    source: Vec<u8>,
+    destination: Vec<u8>,
+    member: Vec<u8>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let source = parser.arg()?;
+        let destination = parser.arg()?;
+        let member = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            source,
+            destination,
+            member,
+            timestamp,
+        })
+    }
+}
+
+struct Response(Option<crate::event::Kind>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        let moved = value.0.is_some();
+        (resp::Value::Integer(moved.into()), value.0).into()
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp,
+};
+
+pub struct SPop;
+
+impl SPop {
+    fn handle_request(
+        Request {
+            key,
+            count,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let popped = repo.set_repo().pop(&key, count.unwrap_or(1), timestamp)?;
+        // followers remove the members picked here instead of picking their own
+        let event = (!popped.is_empty())
+            .then(|| super::replay("SREM", std::iter::once(key).chain(popped.iter().cloned())));
+        let reply = match count {
+            Some(_) => Reply::Members(popped),
+            None => Reply::Member(popped.into_iter().next()),
+        };
+        Ok(Response(reply, event))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for SPop {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("SPOP")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    count: Option<usize>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let count = parser
+            .optional::<i64>()?
+            .map(|count| {
+                usize::try_from(count)
+                    .map_err(|_| anyhow::anyhow!("ERR value is out of range, must be positive"))
+            })
+            .transpose()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            count,
+            timestamp,
+        })
+    }
+}
+
+enum Reply {
+    Member(Option<Vec<u8>>),
+    Members(Vec<Vec<u8>>),
+}
+
+struct Response(Reply, Option<crate::event::Kind>);
+
+impl From<Response> for super::Response {
+    fn from(Response(reply, event): Response) -> Self {
+        let value = match reply {
+            Reply::Member(member) => {
+                member.map_or(resp::Value::NullString, resp::Value::bulk_bytes)
+            }
+            Reply::Members(members) => {
+                resp::Value::Set(members.into_iter().map(resp::Value::bulk_bytes).collect())
+            }
+        };
+        (value, event).into()
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp::{self, IntoValue},
+};
+
+pub struct SRandMember;
+
+impl SRandMember {
+    fn handle_request(
+        Request {
+            key,
+            count,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let members = repo
+            .set_repo()
+            .random_members(&key, count.unwrap_or(1), timestamp)?;
+        Ok(match count {
+            Some(_) => Response::Members(members),
+            None => Response::Member(members.into_iter().next()),
+        })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for SRandMember {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("SRANDMEMBER")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    count: Option<i64>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let count = parser.optional()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            count,
+            timestamp,
+        })
+    }
+}
+
+enum Response {
+    Member(Option<Vec<u8>>),
+    Members(Vec<Vec<u8>>),
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(match value {
+            Response::Member(member) => {
+                member.map_or(resp::Value::NullString, resp::Value::bulk_bytes)
+            }
+            Response::Members(members) => members.into_value(),
+        })
+    }
+}
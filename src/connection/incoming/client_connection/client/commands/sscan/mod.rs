@@ -0,0 +1,88 @@
+use crate::{
+    command::{
+        parser::{Arg, SYNTAX_ERROR},
+        Command, CommandInfo,
+    },
+    repository::Repository,
+    resp::{self, IntoValue},
+};
+
+pub struct SScan;
+
+impl SScan {
+    fn handle_request(
+        Request {
+            key,
+            cursor,
+            pattern,
+            count,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let (cursor, members) =
+            repo.set_repo()
+                .scan(&key, cursor, pattern.as_deref(), count, timestamp)?;
+        Ok(Response { cursor, members })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for SScan {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("SSCAN")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    cursor: usize,
+    pattern: Option<Vec<u8>>,
+    count: usize,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let cursor = parser
+            .arg()
+            .map_err(|_| anyhow::anyhow!("ERR invalid cursor"))?;
+        let mut options = parser.options([Arg::Value("MATCH"), Arg::Value("COUNT")])?;
+        let pattern = options.take("MATCH")?;
+        let count = options.take::<i64>("COUNT")?.unwrap_or(10);
+        let count = usize::try_from(count)
+            .ok()
+            .filter(|count| *count > 0)
+            .ok_or_else(|| anyhow::anyhow!(SYNTAX_ERROR))?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            cursor,
+            pattern,
+            count,
+            timestamp,
+        })
+    }
+}
+
+struct Response {
+    cursor: usize,
+    members: Vec<Vec<u8>>,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(resp::Value::Array(vec![
+            resp::Value::bulk_string(value.cursor.to_string()),
+            value.members.into_value(),
+        ]))
+    }
+}
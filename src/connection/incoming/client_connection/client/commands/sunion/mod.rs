@@ -0,0 +1,59 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{set_repo::Operation, Repository},
+    resp,
+};
+
+pub struct SUnion;
+
+impl SUnion {
+    fn handle_request(
+        Request { keys, timestamp }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let set = repo
+            .set_repo()
+            .combine(Operation::Union, &keys, timestamp)?;
+        Ok(Response(set.iter().collect()))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for SUnion {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("SUNION")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    keys: Vec<Vec<u8>>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let keys = parser.rest()?;
+        if keys.is_empty() {
+            return Err(parser.wrong_arity());
+        }
+        parser.finish()?;
+        Ok(Self { keys, timestamp })
+    }
+}
+
+struct Response(Vec<Vec<u8>>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(resp::Value::Set(
+            value.0.into_iter().map(resp::Value::bulk_bytes).collect(),
+        ))
+    }
+}
@@ -0,0 +1,61 @@
+use anyhow::anyhow;
+
+use crate::{
+    command::{Command, CommandInfo},
+    event,
+    repository::Repository,
+    resp,
+};
+
+pub struct SwapDb;
+
+impl SwapDb {
+    fn handle_request(Request { a, b }: Request, repo: &Repository) -> anyhow::Result<Response> {
+        let a = super::database(repo, a)?.selected();
+        let b = super::database(repo, b)?.selected();
+        repo.swap(a, b);
+        Ok(Response { a, b })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for SwapDb {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("SWAPDB")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    a: i64,
+    b: i64,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let mut parser = value.into_parser();
+        let a = parser
+            .arg()
+            .map_err(|_| anyhow!("ERR invalid first DB index"))?;
+        let b = parser
+            .arg()
+            .map_err(|_| anyhow!("ERR invalid second DB index"))?;
+        parser.finish()?;
+        Ok(Self { a, b })
+    }
+}
+
+struct Response {
+    a: usize,
+    b: usize,
+}
+
+impl From<Response> for super::Response {
+    fn from(Response { a, b }: Response) -> Self {
+        Self::value_event(resp::Value::ok(), event::Kind::SwapDb { a, b })
+    }
+}
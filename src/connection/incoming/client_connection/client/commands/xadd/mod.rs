@@ -10,22 +10,35 @@ use crate::{
 pub struct XAdd;
 
 impl XAdd {
-    fn handle_request(request: Request, repo: &Repository) -> Response {
+    fn handle_request(request: Request, repo: &Repository) -> anyhow::Result<Response> {
         let stream_repo = repo.stream_repo();
+        let fields = request
+            .pairs
+            .iter()
+            .map(|(name, value)| Field::new(name.clone(), value.clone()))
+            .collect();
         let key = match request.entry_id {
-            EntryIdKind::None(_) => stream_repo.add_auto_increment(
-                request.stream_key,
-                request.fields,
-                &request.timestamp,
-            ),
-            EntryIdKind::Timestamp(partial_entry_id) => stream_repo
-                .add(request.stream_key, partial_entry_id, request.fields)
-                .unwrap(),
-            EntryIdKind::Full(entry_id) => stream_repo
-                .add(request.stream_key, entry_id, request.fields)
-                .unwrap(),
+            EntryIdKind::None(_) => {
+                stream_repo.add_auto_increment(&request.stream_key, fields, &request.timestamp)?
+            }
+            EntryIdKind::Timestamp(partial_entry_id) => {
+                stream_repo.add(&request.stream_key, partial_entry_id, fields)?
+            }
+            EntryIdKind::Full(entry_id) => {
+                stream_repo.add(&request.stream_key, entry_id, fields)?
+            }
         };
-        Response::Ok(key)
+        // followers add the entry with the id assigned here instead of generating their own
+        let args = [request.stream_key, key.to_string().into_bytes()]
+            .into_iter()
+            .chain(
+                request
+                    .pairs
+                    .into_iter()
+                    .flat_map(|(name, value)| [name, value]),
+            );
+        let event = super::replay("XADD", args);
+        Ok(Response::Ok(key, event))
     }
 }
 
@@ -35,14 +48,14 @@ impl Command<super::Request, super::Response, Repository> for XAdd {
     }
 
     fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
-        Ok(Self::handle_request(Request::try_from(request)?, repo).into())
+        Self::handle_request(Request::try_from(request)?, repo).map(Into::into)
     }
 }
 
 struct Request {
-    stream_key: String,
+    stream_key: Vec<u8>,
     entry_id: EntryIdKind,
-    fields: Vec<Field>,
+    pairs: Vec<(Vec<u8>, Vec<u8>)>,
     timestamp: std::time::SystemTime,
 }
 
@@ -50,37 +63,33 @@ impl TryFrom<super::Request> for Request {
     type Error = anyhow::Error;
 
     fn try_from(value: super::Request) -> Result<Self, Self::Error> {
-        let mut iter = value.request.into_standard().unwrap().args.into_iter();
-        let stream_key = iter.next().unwrap();
-        let entry_id = iter.next().unwrap();
         let timestamp = value.timestamp;
-
-        let mut fields = Vec::new();
-        while let Some(name) = iter.next() {
-            let value = iter.next().unwrap();
-            fields.push(Field::new(name, value));
+        let mut parser = value.into_parser();
+        let stream_key = parser.arg()?;
+        let entry_id: EntryIdKind = parser.arg::<String>()?.parse()?;
+        let pairs = parser.pairs::<Vec<u8>, Vec<u8>>()?;
+        if pairs.is_empty() {
+            return Err(parser.wrong_arity());
         }
-
-        let entry_id: EntryIdKind = entry_id.parse().unwrap();
         Ok(Self {
             stream_key,
             entry_id,
-            fields,
+            pairs,
             timestamp,
         })
     }
 }
 
 enum Response {
-    Ok(EntryId),
-    KeyError,
+    Ok(EntryId, crate::event::Kind),
 }
 
 impl From<Response> for super::Response {
     fn from(value: Response) -> Self {
         match value {
-            Response::Ok(entry_id) => resp::Value::simple_string(entry_id).into(),
-            Response::KeyError => todo!(),
+            Response::Ok(entry_id, event) => {
+                Self::value_event(resp::Value::simple_string(entry_id), event)
+            }
         }
     }
 }
@@ -1,7 +1,7 @@
 use crate::{
     command::Command,
     repository::{
-        stream_repo::stream::{entry_id::EntryIdKind, Entry, EntryId, PartialEntryId},
+        stream_repo::stream::{Entry, EntryId},
         Repository,
     },
 };
@@ -10,8 +10,9 @@ pub struct XRange;
 
 impl XRange {
     fn handle_request(request: Request, repo: &Repository) -> anyhow::Result<Response> {
+        let count = request.count.unwrap_or(usize::MAX);
         repo.stream_repo()
-            .range(request.stream_key, &request.start, &request.end)
+            .range(request.stream_key, &request.start, &request.end, count)
             .map(Response::new)
     }
 }
@@ -28,7 +29,7 @@ impl Command<super::Request, super::Response, Repository> for XRange {
 }
 
 struct Request {
-    stream_key: String,
+    stream_key: Vec<u8>,
     start: EntryId,
     end: EntryId,
     count: Option<usize>,
@@ -38,13 +39,12 @@ impl TryFrom<super::Request> for Request {
     type Error = anyhow::Error;
 
     fn try_from(value: super::Request) -> Result<Self, Self::Error> {
-        let mut iter = value.into_content().unwrap().into_iter();
-        let key = iter.next().unwrap();
-        let start: EntryIdKind = iter.next().unwrap().parse().unwrap();
-        let end: EntryIdKind = iter.next().unwrap().parse().unwrap();
-        let start = start.into_entry_id_or_default(&EntryId::new(0, 0));
-        let end = end.into_entry_id_or_default(&EntryId::new(0, 0));
-        let count = iter.next().map(|count| count.parse().unwrap());
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let start = super::stream_range_bound(&mut parser, 0)?;
+        let end = super::stream_range_bound(&mut parser, u64::MAX)?;
+        let count = parser.option("COUNT")?;
+        parser.finish()?;
         Ok(Self {
             stream_key: key,
             start,
@@ -1,11 +1,10 @@
+use anyhow::{bail, Context};
+
 use crate::{
-    command::Command,
+    command::{parser::Arg, Command},
     repository::{
-        stream_repo::{
-            stream::{Entry, EntryId},
-            BlockResult,
-        },
-        Repository,
+        stream_repo::stream::{Entry, EntryId},
+        BlockResult, Repository,
     },
     resp::{self, value::IntoRespArray},
 };
@@ -13,7 +12,7 @@ use crate::{
 pub struct XRead;
 
 impl XRead {
-    fn handle_request(request: Request, repo: &Repository) -> Response {
+    fn handle_request(request: Request, repo: &Repository) -> anyhow::Result<Response> {
         let count = request.count.unwrap_or(1);
         let entries = if let Some(block_duration) = request.block {
             // TODO blocking does not work on mutliple streams
@@ -31,17 +30,17 @@ impl XRead {
                             count,
                             block_duration,
                         );
-                        StreamResponse::new(
+                        Ok(StreamResponse::new(
                             stream_key,
                             match entries {
                                 BlockResult::Found(entries) => entries,
                                 BlockResult::NotFound => Vec::new(),
-                                BlockResult::Err(_) => todo!(),
+                                BlockResult::Err(err) => return Err(err),
                             },
-                        )
+                        ))
                     },
                 )
-                .collect::<Vec<_>>()
+                .collect::<anyhow::Result<Vec<_>>>()?
         } else {
             request
                 .streams
@@ -51,17 +50,15 @@ impl XRead {
                          stream_key,
                          entry_id,
                      }| {
-                        let entries = repo
-                            .stream_repo()
-                            .read(stream_key.clone(), &entry_id, count)
-                            .unwrap();
+                        let entries =
+                            repo.stream_repo()
+                                .read(stream_key.clone(), &entry_id, count)?;
                         Ok(StreamResponse::new(stream_key, entries))
                     },
                 )
-                .collect::<Result<Vec<_>, anyhow::Error>>()
-                .unwrap()
+                .collect::<anyhow::Result<Vec<_>>>()?
         };
-        Response::Ok(entries)
+        Ok(Response::Ok(entries))
     }
 }
 
@@ -71,7 +68,7 @@ impl Command<super::Request, super::Response, Repository> for XRead {
     }
 
     fn call(&self, request: super::Request, state: &Repository) -> anyhow::Result<super::Response> {
-        Ok(Self::handle_request(request.try_into().unwrap(), state).into())
+        Self::handle_request(request.try_into()?, state).map(Into::into)
     }
 }
 
@@ -85,42 +82,27 @@ impl TryFrom<super::Request> for Request {
     type Error = anyhow::Error;
 
     fn try_from(value: super::Request) -> Result<Self, Self::Error> {
-        let content = value.into_content().unwrap();
-        let mut args = content.len();
-        let mut iter = content.into_iter().peekable();
-        let count = if iter.peek().unwrap().eq_ignore_ascii_case("COUNT") {
-            iter.next();
-            args -= 2;
-            Some(iter.next().unwrap().parse().unwrap())
-        } else {
-            None
-        };
-
-        let block = if iter.peek().unwrap().eq_ignore_ascii_case("BLOCK") {
-            iter.next();
-            args -= 2;
-            let millis = iter.next().unwrap().parse().unwrap();
-            if millis == 0 {
-                Some(None)
-            } else {
-                Some(Some(std::time::Duration::from_millis(millis)))
-            }
-        } else {
-            None
-        };
-
-        assert!(iter.next().unwrap().eq_ignore_ascii_case("STREAMS"));
-        args -= 1;
-
-        assert_eq!(args % 2, 0);
-        let streams = args / 2;
-        let (stream_keys, entry_ids): (Vec<_>, Vec<_>) =
-            iter.enumerate().partition(|(i, _)| *i < streams);
+        let mut parser = value.into_parser();
+        let mut options = parser.options([Arg::Value("COUNT"), Arg::Value("BLOCK")])?;
+        let count = options.take("COUNT")?;
+        let block = options
+            .take::<u64>("BLOCK")?
+            .map(|millis| (millis != 0).then(|| std::time::Duration::from_millis(millis)));
+        parser.ident("STREAMS")?;
+
+        let mut stream_keys: Vec<Vec<u8>> = parser.rest()?;
+        if stream_keys.is_empty() {
+            return Err(parser.wrong_arity());
+        }
+        if !stream_keys.len().is_multiple_of(2) {
+            bail!("ERR Unbalanced 'xread' list of streams: for each stream key an ID or '$' must be specified.");
+        }
+        let entry_ids = stream_keys.split_off(stream_keys.len() / 2);
         let streams = stream_keys
             .into_iter()
             .zip(entry_ids)
-            .map(|((_, key), (_, id))| Stream::new(key, &id))
-            .collect();
+            .map(|(key, id)| Stream::new(key, &id))
+            .collect::<anyhow::Result<_>>()?;
 
         Ok(Self {
             count,
@@ -131,33 +113,39 @@ impl TryFrom<super::Request> for Request {
 }
 
 struct Stream {
-    stream_key: String,
+    stream_key: Vec<u8>,
     entry_id: EntryId,
 }
 
 impl Stream {
-    fn new(stream_key: String, entry_id: &str) -> Self {
-        let id = if entry_id == "*" {
+    fn new(stream_key: Vec<u8>, entry_id: &[u8]) -> anyhow::Result<Self> {
+        let parse = |s: &[u8]| -> anyhow::Result<u64> {
+            std::str::from_utf8(s)
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .context("ERR Invalid stream ID specified as stream command argument")
+        };
+        let id = if entry_id == b"*" {
             EntryId::min()
-        } else if let Some((timestamp, id)) = entry_id.split_once('-') {
-            EntryId::new(timestamp.parse().unwrap(), id.parse().unwrap())
+        } else if let Some(split) = entry_id.iter().position(|b| *b == b'-') {
+            EntryId::new(parse(&entry_id[..split])?, parse(&entry_id[split + 1..])?)
         } else {
-            EntryId::new(entry_id.parse().unwrap(), 0)
+            EntryId::new(parse(entry_id)?, 0)
         };
-        Self {
+        Ok(Self {
             stream_key,
             entry_id: id,
-        }
+        })
     }
 }
 
 struct StreamResponse {
-    stream_key: String,
+    stream_key: Vec<u8>,
     entries: Vec<Entry>,
 }
 
 impl StreamResponse {
-    fn new(stream_key: String, entries: Vec<Entry>) -> Self {
+    fn new(stream_key: Vec<u8>, entries: Vec<Entry>) -> Self {
         Self {
             stream_key,
             entries,
@@ -168,7 +156,7 @@ impl StreamResponse {
 impl From<StreamResponse> for resp::Value {
     fn from(value: StreamResponse) -> Self {
         [
-            resp::Value::simple_string(value.stream_key),
+            resp::Value::bulk_bytes(value.stream_key),
             value
                 .entries
                 .into_iter()
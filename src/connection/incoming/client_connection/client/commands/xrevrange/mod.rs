@@ -0,0 +1,69 @@
+use crate::{
+    command::Command,
+    repository::{
+        stream_repo::stream::{Entry, EntryId},
+        Repository,
+    },
+};
+
+pub struct XRevRange;
+
+impl XRevRange {
+    fn handle_request(request: Request, repo: &Repository) -> anyhow::Result<Response> {
+        let count = request.count.unwrap_or(usize::MAX);
+        repo.stream_repo()
+            .rev_range(request.stream_key, &request.end, &request.start, count)
+            .map(Response::new)
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for XRevRange {
+    fn info(&self) -> crate::command::CommandInfo {
+        crate::command::CommandInfo::new_name("XREVRANGE")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        let request = Request::try_from(request)?;
+        Self::handle_request(request, repo).map(std::convert::Into::into)
+    }
+}
+
+struct Request {
+    stream_key: Vec<u8>,
+    start: EntryId,
+    end: EntryId,
+    count: Option<usize>,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let end = super::stream_range_bound(&mut parser, u64::MAX)?;
+        let start = super::stream_range_bound(&mut parser, 0)?;
+        let count = parser.option("COUNT")?;
+        parser.finish()?;
+        Ok(Self {
+            stream_key: key,
+            start,
+            end,
+            count,
+        })
+    }
+}
+
+struct Response(Vec<Entry>);
+
+impl Response {
+    fn new(entries: Vec<Entry>) -> Self {
+        Self(entries)
+    }
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(value.0.into_iter().map(std::convert::Into::into).collect())
+    }
+}
@@ -0,0 +1,130 @@
+use anyhow::bail;
+
+use crate::{
+    command::{
+        parser::{Arg, SYNTAX_ERROR},
+        Command, CommandInfo,
+    },
+    repository::{
+        zset_repo::{AddOptions, Entry},
+        Repository,
+    },
+    resp::{self, IntoValue},
+};
+
+pub struct ZAdd;
+
+impl ZAdd {
+    fn handle_request(
+        Request {
+            key,
+            entries,
+            options,
+            changed,
+            args,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let result = repo.zset_repo().add(&key, entries, options, timestamp)?;
+        let event = (result.added + result.updated > 0).then(|| super::replay("ZADD", args));
+        let reply = if options.incr {
+            Reply::Score(result.score)
+        } else if changed {
+            Reply::Count(result.added + result.updated)
+        } else {
+            Reply::Count(result.added)
+        };
+        Ok(Response(reply, event))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for ZAdd {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("ZADD")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    entries: Vec<Entry>,
+    options: AddOptions,
+    changed: bool,
+    /// The arguments as sent, followers replay the command unchanged.
+    args: Vec<Vec<u8>>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let args = value.request.clone().into_byte_args();
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let flags = parser.options([
+            Arg::Flag("NX"),
+            Arg::Flag("XX"),
+            Arg::Flag("GT"),
+            Arg::Flag("LT"),
+            Arg::Flag("CH"),
+            Arg::Flag("INCR"),
+        ])?;
+        let options = AddOptions {
+            nx: flags.flag("NX"),
+            xx: flags.flag("XX"),
+            gt: flags.flag("GT"),
+            lt: flags.flag("LT"),
+            incr: flags.flag("INCR"),
+        };
+        let changed = flags.flag("CH");
+        if options.nx && options.xx {
+            bail!("ERR XX and NX options at the same time are not compatible");
+        }
+        if (options.gt && options.lt) || (options.nx && (options.gt || options.lt)) {
+            bail!("ERR GT, LT, and/or NX options at the same time are not compatible");
+        }
+        if parser.is_empty() || !parser.remaining().is_multiple_of(2) {
+            bail!(SYNTAX_ERROR);
+        }
+        let entries = parser
+            .pairs::<f64, Vec<u8>>()?
+            .into_iter()
+            .map(|(score, member)| (member, score))
+            .collect::<Vec<_>>();
+        if options.incr && entries.len() != 1 {
+            bail!("ERR INCR option supports a single increment-element pair");
+        }
+        parser.finish()?;
+        Ok(Self {
+            key,
+            entries,
+            options,
+            changed,
+            args,
+            timestamp,
+        })
+    }
+}
+
+enum Reply {
+    Count(usize),
+    Score(Option<f64>),
+}
+
+struct Response(Reply, Option<crate::event::Kind>);
+
+impl From<Response> for super::Response {
+    fn from(Response(reply, event): Response) -> Self {
+        let value = match reply {
+            Reply::Count(count) => count.into_value(),
+            Reply::Score(score) => score.map_or(resp::Value::NullString, resp::Value::Double),
+        };
+        (value, event).into()
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{
+        zset_repo::{Range, ScoreBound},
+        Repository,
+    },
+    resp::IntoValue,
+};
+
+pub struct ZCount;
+
+impl ZCount {
+    fn handle_request(
+        Request {
+            key,
+            range,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        repo.zset_repo()
+            .count(&key, &range, timestamp)
+            .map(Response)
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for ZCount {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("ZCOUNT")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    range: Range,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let min = ScoreBound::parse(&parser.arg::<Vec<u8>>()?)?;
+        let max = ScoreBound::parse(&parser.arg::<Vec<u8>>()?)?;
+        let range = Range::Score(min, max);
+        parser.finish()?;
+        Ok(Self {
+            key,
+            range,
+            timestamp,
+        })
+    }
+}
+
+struct Response(usize);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(value.0.into_value())
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{zset_repo::AddOptions, Repository},
+    resp::{self, value::serialize::double::format_double},
+};
+
+pub struct ZIncrBy;
+
+impl ZIncrBy {
+    fn handle_request(
+        Request {
+            key,
+            increment,
+            member,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let options = AddOptions {
+            incr: true,
+            ..AddOptions::default()
+        };
+        let result =
+            repo.zset_repo()
+                .add(&key, vec![(member.clone(), increment)], options, timestamp)?;
+        let score = result.score.unwrap_or(increment);
+        // followers set the result so rounding cannot make theirs differ
+        let event = super::replay("ZADD", [key, format_double(score).into_bytes(), member]);
+        Ok(Response(score, event))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for ZIncrBy {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("ZINCRBY")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    increment: f64,
+    member: Vec<u8>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let increment = parser.arg()?;
+        let member = parser.arg()?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            increment,
+            member,
+            timestamp,
+        })
+    }
+}
+
+struct Response(f64, crate::event::Kind);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value_event(resp::Value::Double(value.0), value.1)
+    }
+}
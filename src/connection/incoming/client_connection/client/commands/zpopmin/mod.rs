@@ -0,0 +1,69 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{zset_repo::Entry, Repository},
+};
+
+pub struct ZPopMin;
+
+impl ZPopMin {
+    fn handle_request(
+        Request {
+            key,
+            count,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let popped = repo.zset_repo().pop(&key, count, false, timestamp)?;
+        let event = super::zpop_event(key, &popped);
+        Ok(Response(popped, event))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for ZPopMin {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("ZPOPMIN")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    count: usize,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let count = parser
+            .optional::<i64>()?
+            .map(|count| {
+                usize::try_from(count)
+                    .map_err(|_| anyhow::anyhow!("ERR value is out of range, must be positive"))
+            })
+            .transpose()?
+            .unwrap_or(1);
+        parser.finish()?;
+        Ok(Self {
+            key,
+            count,
+            timestamp,
+        })
+    }
+}
+
+struct Response(Vec<Entry>, Option<crate::event::Kind>);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        (super::scored_members(value.0, true), value.1).into()
+    }
+}
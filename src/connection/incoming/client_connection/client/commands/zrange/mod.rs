@@ -0,0 +1,69 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::{zset_repo::Entry, Repository},
+};
+
+pub struct ZRange;
+
+impl ZRange {
+    fn handle_request(
+        Request {
+            key,
+            range,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let entries =
+            repo.zset_repo()
+                .range(&key, &range.range, range.rev, range.limit, timestamp)?;
+        Ok(Response {
+            entries,
+            with_scores: range.with_scores,
+        })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for ZRange {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("ZRANGE")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    range: super::SortedSetRange,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let range = super::sorted_set_range(&mut parser, true)?;
+        parser.finish()?;
+        Ok(Self {
+            key,
+            range,
+            timestamp,
+        })
+    }
+}
+
+struct Response {
+    entries: Vec<Entry>,
+    with_scores: bool,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(super::scored_members(value.entries, value.with_scores))
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp::IntoValue,
+};
+
+pub struct ZRangeStore;
+
+impl ZRangeStore {
+    fn handle_request(
+        Request {
+            destination,
+            source,
+            range,
+            args,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let len = repo.zset_repo().range_store(
+            &destination,
+            &source,
+            &range.range,
+            range.rev,
+            range.limit,
+            timestamp,
+        )?;
+        Ok(Response(len, super::replay("ZRANGESTORE", args)))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for ZRangeStore {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("ZRANGESTORE")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    destination: Vec<u8>,
+    source: Vec<u8>,
+    range: super::SortedSetRange,
+    /// The arguments as sent, followers replay the command unchanged.
+    args: Vec<Vec<u8>>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let args = value.request.clone().into_byte_args();
+        let mut parser = value.into_parser();
+        let destination = parser.arg()?;
+        let source = parser.arg()?;
+        let range = super::sorted_set_range(&mut parser, false)?;
+        parser.finish()?;
+        Ok(Self {
+            destination,
+            source,
+            range,
+            args,
+            timestamp,
+        })
+    }
+}
+
+struct Response(usize, crate::event::Kind);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value_event(value.0.into_value(), value.1)
+    }
+}
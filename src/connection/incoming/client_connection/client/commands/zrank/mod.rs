@@ -0,0 +1,74 @@
+use crate::{
+    command::{Command, CommandInfo},
+    repository::Repository,
+    resp::{self, IntoValue},
+};
+
+pub struct ZRank;
+
+impl ZRank {
+    fn handle_request(
+        Request {
+            key,
+            member,
+            with_score,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let rank = repo.zset_repo().rank(&key, &member, false, timestamp)?;
+        Ok(Response { rank, with_score })
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for ZRank {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("ZRANK")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    key: Vec<u8>,
+    member: Vec<u8>,
+    with_score: bool,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let member = parser.arg()?;
+        let with_score = parser.flag("WITHSCORE");
+        parser.finish()?;
+        Ok(Self {
+            key,
+            member,
+            with_score,
+            timestamp,
+        })
+    }
+}
+
+struct Response {
+    rank: Option<(usize, f64)>,
+    with_score: bool,
+}
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value(match value.rank {
+            Some((rank, score)) if value.with_score => (rank, score).into_value(),
+            Some((rank, _)) => rank.into_value(),
+            None if value.with_score => resp::Value::NullArray,
+            None => resp::Value::NullString,
+        })
+    }
+}
@@ -0,0 +1,102 @@
+use anyhow::{anyhow, bail};
+
+use crate::{
+    command::{parser::SYNTAX_ERROR, Command, CommandInfo},
+    repository::{
+        zset_repo::{Aggregate, Operation},
+        Repository,
+    },
+    resp::IntoValue,
+};
+
+pub struct ZUnionStore;
+
+impl ZUnionStore {
+    fn handle_request(
+        Request {
+            destination,
+            keys,
+            weights,
+            aggregate,
+            args,
+            timestamp,
+        }: Request,
+        repo: &Repository,
+    ) -> anyhow::Result<Response> {
+        let len = repo.zset_repo().combine_store(
+            Operation::Union,
+            &destination,
+            &keys,
+            &weights,
+            aggregate,
+            timestamp,
+        )?;
+        Ok(Response(len, super::replay("ZUNIONSTORE", args)))
+    }
+}
+
+impl Command<super::Request, super::Response, Repository> for ZUnionStore {
+    fn info(&self) -> CommandInfo {
+        CommandInfo::new_name("ZUNIONSTORE")
+    }
+
+    fn call(&self, request: super::Request, repo: &Repository) -> anyhow::Result<super::Response> {
+        Self::handle_request(request.try_into()?, repo).map(Into::into)
+    }
+}
+
+struct Request {
+    destination: Vec<u8>,
+    keys: Vec<Vec<u8>>,
+    weights: Vec<f64>,
+    aggregate: Aggregate,
+    /// The arguments as sent, followers replay the command unchanged.
+    args: Vec<Vec<u8>>,
+    timestamp: std::time::SystemTime,
+}
+
+impl TryFrom<super::Request> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Request) -> Result<Self, Self::Error> {
+        let timestamp = value.timestamp;
+        let args = value.request.clone().into_byte_args();
+        let mut parser = value.into_parser();
+        let destination = parser.arg()?;
+        let keys = super::counted_keys(&mut parser)?;
+        let mut weights = Vec::new();
+        let mut aggregate = Aggregate::default();
+        while !parser.is_empty() {
+            if parser.flag("WEIGHTS") {
+                if parser.remaining() < keys.len() {
+                    bail!(SYNTAX_ERROR);
+                }
+                weights = (0..keys.len())
+                    .map(|_| parser.arg::<f64>())
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| anyhow!("ERR weight value is not a float"))?;
+            } else if let Some(value) = parser.option("AGGREGATE")? {
+                aggregate = value;
+            } else {
+                bail!(SYNTAX_ERROR);
+            }
+        }
+        parser.finish()?;
+        Ok(Self {
+            destination,
+            keys,
+            weights,
+            aggregate,
+            args,
+            timestamp,
+        })
+    }
+}
+
+struct Response(usize, crate::event::Kind);
+
+impl From<Response> for super::Response {
+    fn from(value: Response) -> Self {
+        Self::value_event(value.0.into_value(), value.1)
+    }
+}
@@ -31,13 +31,27 @@ where
         // so another request could come and write to the repo in the middle of the transaction
         // which does not follow the redis protocol
         // https://redis.io/docs/latest/develop/interact/transactions/ (bullet point 1)
-        let (values, events): (Vec<_>, Vec<_>) = request
-            .into_iter()
-            .map(|req| self.inner.call(req).unwrap())
-            .map(|res| (res.value, res.events))
-            .unzip();
+        // a command like SELECT changes the state the commands queued after it run with
+        let mut state: Option<client::ClientState> = None;
+        let (mut values, mut events) = (Vec::new(), Vec::new());
+        for req in request {
+            let req = match &state {
+                Some(state) => req.with_state(state.clone()),
+                None => req,
+            };
+            let res = self.inner.call(req).unwrap();
+            if res.state.is_some() {
+                state = res.state;
+            }
+            values.push(res.value);
+            events.push(res.events);
+        }
         let events = events.into_iter().flatten().flatten().collect::<Vec<_>>();
-        Response::value_events(values.into_array(), events)
+        let response = Response::value_events(values.into_array(), events);
+        match state {
+            Some(state) => response.with_state(state),
+            None => response,
+        }
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::connection::incoming::client_connection::client::{Request, Response, Router};
-use crate::{repository::Repository, resp, service::Service};
+use crate::{event, repository::Repository, resp, service::Service};
 
 pub struct Routing {
     pub repo: Repository,
@@ -33,6 +33,19 @@ impl Service<Request> for Routing {
                 "ERR unknown command 'SENTINEL', with args beginning with: 'masters'".into(),
             )));
         };
-        handler.call(request, &self.repo)
+        let db = request.state.db;
+        let repo = self
+            .repo
+            .select(db)
+            .expect("clients only select databases that exist");
+        // errors in a command are replies to the client, not connection errors
+        let mut response = handler
+            .call(request, &repo)
+            .unwrap_or_else(|err| Response::value(resp::Value::SimpleError(err.to_string())));
+        // followers need to know which database the writes happened in
+        if let Some(events) = response.events.as_mut().filter(|events| !events.is_empty()) {
+            events.insert(0, event::Kind::Select { db });
+        }
+        Ok(response)
     }
 }
@@ -0,0 +1,36 @@
+use crate::{
+    connection::incoming::client_connection::client::{default_router, ClientState, Request},
+    event,
+    message::request::Standard,
+    repository::Repository,
+    service::Service,
+};
+
+use super::Routing;
+
+#[test]
+fn routing_runs_in_the_selected_database_and_tags_events_with_it() {
+    let repo = Repository::default();
+    let mut routing = Routing::new(repo.clone(), default_router());
+    let state = ClientState {
+        db: 1,
+        ..ClientState::default()
+    };
+    let request = Request::epoch(Standard::new("SET", ["k", "v"]).into()).with_state(state);
+    let response = routing.call(request).unwrap();
+    assert_eq!(
+        response.events.unwrap(),
+        [
+            event::Kind::Select { db: 1 },
+            event::Kind::Set {
+                key: b"k".to_vec(),
+                value: b"v".to_vec(),
+                expiry: None,
+            },
+        ]
+    );
+    let now = std::time::UNIX_EPOCH;
+    assert_eq!(repo.kv_repo().get(b"k", now).unwrap(), None);
+    let db = repo.select(1).unwrap();
+    assert_eq!(db.kv_repo().get(b"k", now).unwrap(), Some(b"v".to_vec()));
+}
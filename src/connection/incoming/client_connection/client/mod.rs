@@ -1,14 +1,18 @@
-use crate::{repository::Repository, service::Service};
+use std::sync::Arc;
+
+use crate::{config::RedisConfig, repository::Repository, service::Service};
 
 pub mod commands;
 pub mod layers;
 pub mod request;
 pub mod response;
 pub mod router;
+pub mod state;
 
 pub use request::Request;
 pub use response::Response;
 pub use router::{default_router, Router};
+pub use state::ClientState;
 
 //#[cfg(test)]
 //mod tests;
@@ -17,19 +21,37 @@ type ClientService = layers::ReplicationService<layers::MultiLayer<layers::Routi
 
 pub struct Client {
     service: ClientService,
+    state: ClientState,
+    config: Arc<RedisConfig>,
 }
 
 impl Client {
     #[must_use]
-    pub fn new(router: &'static Router, repo: Repository) -> Self {
+    pub fn new(router: &'static Router, repo: Repository, id: usize) -> Self {
         Self {
             service: layers::ReplicationService::new(layers::MultiLayer::new(
                 layers::Routing::new(repo, router),
             )),
+            state: ClientState::new(id),
+            config: Arc::default(),
         }
     }
 
+    /// Runs the commands with the server's `config` instead of the default one.
+    #[must_use]
+    pub fn with_config(self, config: Arc<RedisConfig>) -> Self {
+        Self { config, ..self }
+    }
+
+    #[must_use]
+    pub fn state(&self) -> &ClientState {
+        &self.state
+    }
+
     pub fn handle_request(&mut self, request: Request) -> anyhow::Result<Result> {
+        let request = request
+            .with_state(self.state.clone())
+            .with_config(Arc::clone(&self.config));
         tracing::debug!("handling request: {request:?}");
         let result = self.service.call(request)?;
         tracing::debug!("{result:?}");
@@ -37,7 +59,13 @@ impl Client {
             layers::replication::ReplicationResponse::ReplicationRequest(value) => {
                 Result::ReplicationMessage(value)
             }
-            layers::replication::ReplicationResponse::Inner(response) => Result::Response(response),
+            layers::replication::ReplicationResponse::Inner(response) => {
+                if let Some(state) = &response.state {
+                    tracing::debug!("client state changed: {state:?}");
+                    self.state = state.clone();
+                }
+                Result::Response(response)
+            }
         })
     }
 }
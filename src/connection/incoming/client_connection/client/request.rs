@@ -1,39 +1,66 @@
+use std::sync::Arc;
+
+use super::ClientState;
+use crate::{command::parser::Parser, config::RedisConfig};
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Request {
     pub request: crate::Request,
     pub timestamp: std::time::SystemTime,
+    pub state: ClientState,
+    /// The configuration of the server the client is connected to.
+    pub config: Arc<RedisConfig>,
 }
 
 impl Request {
     #[must_use]
     pub fn new(request: crate::Request, timestamp: std::time::SystemTime) -> Self {
-        Self { request, timestamp }
+        Self {
+            request,
+            timestamp,
+            state: ClientState::default(),
+            config: Arc::default(),
+        }
     }
     #[must_use]
     pub fn now(request: crate::Request) -> Self {
         Self::new(request, std::time::SystemTime::now())
     }
 
+    #[must_use]
+    pub fn with_state(self, state: ClientState) -> Self {
+        Self { state, ..self }
+    }
+
+    #[must_use]
+    pub fn with_config(self, config: Arc<RedisConfig>) -> Self {
+        Self { config, ..self }
+    }
+
     #[allow(dead_code)]
     #[must_use]
     pub fn epoch(request: crate::Request) -> Self {
         Self::new(request, std::time::SystemTime::UNIX_EPOCH)
     }
 
-    pub fn into_content(self) -> Result<Vec<String>, Self> {
+    /// The arguments as strings, fails if any of them is not valid utf-8.
+    pub fn into_content(self) -> anyhow::Result<Vec<String>> {
         match self.request {
             crate::Request::Standard(s) => Ok(s.args),
-            crate::Request::StandardByteString(_) => todo!(),
-        }
-    }
-    pub fn into_byte_content(self) -> Result<Vec<Vec<u8>>, Self> {
-        match self.request {
-            crate::Request::Standard(s) => {
-                Ok(s.args.into_iter().map(|s| s.as_bytes().to_vec()).collect())
+            crate::Request::StandardByteString(_) => {
+                anyhow::bail!("ERR invalid argument, expected utf-8")
             }
-            crate::Request::StandardByteString(b) => Ok(b.args),
         }
     }
+    #[must_use]
+    pub fn into_parser(self) -> Parser {
+        self.request.into_parser()
+    }
+
+    #[must_use]
+    pub fn into_byte_content(self) -> Vec<Vec<u8>> {
+        self.request.into_byte_args()
+    }
 }
 
 impl std::ops::Deref for Request {
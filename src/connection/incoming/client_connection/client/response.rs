@@ -1,15 +1,22 @@
 use crate::{event, resp};
 
+use super::ClientState;
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Response {
     pub value: resp::Value,
     pub events: Option<Vec<event::Kind>>,
+    pub state: Option<ClientState>,
 }
 
 impl Response {
     #[must_use]
     pub fn new(value: resp::Value, events: Option<Vec<event::Kind>>) -> Self {
-        Self { value, events }
+        Self {
+            value,
+            events,
+            state: None,
+        }
     }
     #[must_use]
     pub fn value(value: resp::Value) -> Self {
@@ -28,6 +35,13 @@ impl Response {
         Self::value(resp::Value::ok())
     }
     #[must_use]
+    pub fn with_state(self, state: ClientState) -> Self {
+        Self {
+            state: Some(state),
+            ..self
+        }
+    }
+    #[must_use]
     pub fn into_value(self) -> resp::Value {
         self.value
     }
@@ -41,12 +41,128 @@ pub fn default_router() -> &'static Router {
         .add(super::commands::subscribe::Subscribe)
         .add(super::commands::ping::Ping)
         .add(super::commands::echo::Echo)
+        .add(super::commands::hello::Hello)
         .add(super::commands::get::Get)
         .add(super::commands::set::Set)
         .add(super::commands::xadd::XAdd)
         .add(super::commands::client::Client)
         .add(super::commands::config::Config)
         .add(super::commands::info::Info)
-        .add(super::commands::xrange::XRange);
+        .add(super::commands::xrange::XRange)
+        .add(super::commands::xrevrange::XRevRange)
+        .add(super::commands::lpush::LPush)
+        .add(super::commands::rpush::RPush)
+        .add(super::commands::lpushx::LPushX)
+        .add(super::commands::rpushx::RPushX)
+        .add(super::commands::lpop::LPop)
+        .add(super::commands::rpop::RPop)
+        .add(super::commands::llen::LLen)
+        .add(super::commands::lrange::LRange)
+        .add(super::commands::lindex::LIndex)
+        .add(super::commands::lset::LSet)
+        .add(super::commands::linsert::LInsert)
+        .add(super::commands::lrem::LRem)
+        .add(super::commands::ltrim::LTrim)
+        .add(super::commands::lpos::LPos)
+        .add(super::commands::lmove::LMove)
+        .add(super::commands::blpop::BLPop)
+        .add(super::commands::brpop::BRPop)
+        .add(super::commands::blmove::BLMove)
+        .add(super::commands::blmpop::BLMPop)
+        .add(super::commands::hset::HSet)
+        .add(super::commands::hsetnx::HSetNx)
+        .add(super::commands::hget::HGet)
+        .add(super::commands::hmget::HMGet)
+        .add(super::commands::hgetall::HGetAll)
+        .add(super::commands::hdel::HDel)
+        .add(super::commands::hexists::HExists)
+        .add(super::commands::hlen::HLen)
+        .add(super::commands::hkeys::HKeys)
+        .add(super::commands::hvals::HVals)
+        .add(super::commands::hincrby::HIncrBy)
+        .add(super::commands::hincrbyfloat::HIncrByFloat)
+        .add(super::commands::hstrlen::HStrLen)
+        .add(super::commands::hrandfield::HRandField)
+        .add(super::commands::hscan::HScan)
+        .add(super::commands::sadd::SAdd)
+        .add(super::commands::srem::SRem)
+        .add(super::commands::sismember::SIsMember)
+        .add(super::commands::smismember::SMIsMember)
+        .add(super::commands::smembers::SMembers)
+        .add(super::commands::scard::SCard)
+        .add(super::commands::spop::SPop)
+        .add(super::commands::srandmember::SRandMember)
+        .add(super::commands::smove::SMove)
+        .add(super::commands::sinter::SInter)
+        .add(super::commands::sunion::SUnion)
+        .add(super::commands::sdiff::SDiff)
+        .add(super::commands::sinterstore::SInterStore)
+        .add(super::commands::sunionstore::SUnionStore)
+        .add(super::commands::sdiffstore::SDiffStore)
+        .add(super::commands::sintercard::SInterCard)
+        .add(super::commands::sscan::SScan)
+        .add(super::commands::zadd::ZAdd)
+        .add(super::commands::zrem::ZRem)
+        .add(super::commands::zscore::ZScore)
+        .add(super::commands::zmscore::ZMScore)
+        .add(super::commands::zincrby::ZIncrBy)
+        .add(super::commands::zcard::ZCard)
+        .add(super::commands::zcount::ZCount)
+        .add(super::commands::zrank::ZRank)
+        .add(super::commands::zrevrank::ZRevRank)
+        .add(super::commands::zrange::ZRange)
+        .add(super::commands::zrangestore::ZRangeStore)
+        .add(super::commands::zpopmin::ZPopMin)
+        .add(super::commands::zpopmax::ZPopMax)
+        .add(super::commands::bzpopmin::BZPopMin)
+        .add(super::commands::bzpopmax::BZPopMax)
+        .add(super::commands::zunionstore::ZUnionStore)
+        .add(super::commands::zinterstore::ZInterStore)
+        .add(super::commands::zlexcount::ZLexCount)
+        .add(super::commands::zscan::ZScan)
+        .add(super::commands::setex::SetEx)
+        .add(super::commands::psetex::PSetEx)
+        .add(super::commands::setnx::SetNx)
+        .add(super::commands::getset::GetSet)
+        .add(super::commands::getdel::GetDel)
+        .add(super::commands::getex::GetEx)
+        .add(super::commands::expire::Expire)
+        .add(super::commands::pexpire::PExpire)
+        .add(super::commands::expireat::ExpireAt)
+        .add(super::commands::pexpireat::PExpireAt)
+        .add(super::commands::ttl::Ttl)
+        .add(super::commands::pttl::PTtl)
+        .add(super::commands::expiretime::ExpireTime)
+        .add(super::commands::pexpiretime::PExpireTime)
+        .add(super::commands::persist::Persist)
+        .add(super::commands::incr::Incr)
+        .add(super::commands::decr::Decr)
+        .add(super::commands::incrby::IncrBy)
+        .add(super::commands::decrby::DecrBy)
+        .add(super::commands::incrbyfloat::IncrByFloat)
+        .add(super::commands::append::Append)
+        .add(super::commands::strlen::StrLen)
+        .add(super::commands::getrange::GetRange)
+        .add(super::commands::setrange::SetRange)
+        .add(super::commands::mget::MGet)
+        .add(super::commands::mset::MSet)
+        .add(super::commands::msetnx::MSetNx)
+        .add(super::commands::lcs::Lcs)
+        .add(super::commands::del::Del)
+        .add(super::commands::unlink::Unlink)
+        .add(super::commands::exists::Exists)
+        .add(super::commands::r#type::Type)
+        .add(super::commands::rename::Rename)
+        .add(super::commands::renamenx::RenameNx)
+        .add(super::commands::copy::Copy)
+        .add(super::commands::touch::Touch)
+        .add(super::commands::randomkey::RandomKey)
+        .add(super::commands::dbsize::DbSize)
+        .add(super::commands::flushdb::FlushDb)
+        .add(super::commands::flushall::FlushAll)
+        .add(super::commands::keys::Keys)
+        .add(super::commands::scan::Scan)
+        .add(super::commands::r#move::Move)
+        .add(super::commands::swapdb::SwapDb);
     Box::leak(Box::new(router))
 }
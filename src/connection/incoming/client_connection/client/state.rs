@@ -0,0 +1,22 @@
+use crate::resp;
+
+/// Per connection state. Commands read it from the request and replace it
+/// by returning a new state with the response.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ClientState {
+    pub id: usize,
+    pub protocol: resp::Protocol,
+    pub name: Option<String>,
+    /// The database commands run in, changed by `SELECT`.
+    pub db: usize,
+}
+
+impl ClientState {
+    #[must_use]
+    pub fn new(id: usize) -> Self {
+        Self {
+            id,
+            ..Default::default()
+        }
+    }
+}
@@ -3,6 +3,7 @@ use tracing::instrument;
 use crate::{
     connection::stream::{self, PipelineBuffer, Stream},
     event::{EmitAll, EventEmitter},
+    resp,
 };
 
 pub mod client;
@@ -39,30 +40,39 @@ where
         let mut request_id = 0;
         loop {
             request_id += 1;
-            match self.handle_client_request(request_id).unwrap() {
-                ClientRequestResult::Ok => (),
-                ClientRequestResult::Close => return Err(Error::ConnectionClosed),
-                ClientRequestResult::ReplicationRequest(messages) => {
+            match self.handle_client_request(request_id) {
+                Ok(ClientRequestResult::Ok) => (),
+                Ok(ClientRequestResult::Close) | Err(Error::ConnectionClosed) => {
+                    tracing::info!("client connection closed");
+                    return Ok(ClientConnectionResult::Close);
+                }
+                Ok(ClientRequestResult::ReplicationRequest(messages)) => {
                     return Ok(ClientConnectionResult::ReplicationMessage(messages))
                 }
+                Err(err) => return Err(err),
             }
         }
     }
 
     #[instrument(name = "handle_client_request", skip(self))]
     fn handle_client_request(&mut self, request_id: usize) -> Result<ClientRequestResult> {
-        let message = match self.connection.read() {
+        let message = match self.connection.read_request() {
             Ok(msg) => msg,
             Err(stream::Error::StreamClosed) => return Err(error::Error::ConnectionClosed),
             Err(stream::Error::IoError(err)) => return Err(err.into()),
             Err(stream::Error::ConnectinClosedUnexpectedly(err)) => return Err(err.into()),
+            Err(stream::Error::Protocol(err)) => return self.protocol_error(&err),
         };
 
         tracing::trace!("handling request: {message:?}");
-        let request = client::Request::now(message.into());
+        let request = client::Request::now(message.into_content());
         let result = self.client.handle_request(request).unwrap();
         tracing::trace!("got result: {result:?}");
-        let client::Response { value, events } = match result {
+        let client::Response {
+            value,
+            events,
+            state,
+        } = match result {
             client::Result::Response(response) => response,
             client::Result::ReplicationMessage(request) => {
                 return Ok(ClientRequestResult::ReplicationRequest(request))
@@ -73,9 +83,23 @@ where
             events.emit_all(&self.emitter);
         }
 
+        if let Some(state) = state {
+            self.connection.set_protocol(state.protocol);
+        }
+
         self.connection.write(&value).unwrap();
         Ok(ClientRequestResult::Ok)
     }
+
+    /// Replies with the error and closes the connection,
+    /// since the rest of the input can not be trusted.
+    fn protocol_error(&mut self, err: &anyhow::Error) -> Result<ClientRequestResult> {
+        tracing::warn!("closing connection after protocol error: {err}");
+        self.connection.write(&resp::Value::SimpleError(format!(
+            "ERR Protocol error: {err}"
+        )))?;
+        Ok(ClientRequestResult::Close)
+    }
 }
 
 pub enum ClientConnectionResult {
@@ -96,5 +120,7 @@ pub mod error {
         ConnectionClosed,
         #[error("io error {0}")]
         IoError(#[from] std::io::Error),
+        #[error("stream error {0}")]
+        Stream(#[from] crate::connection::stream::Error),
     }
 }
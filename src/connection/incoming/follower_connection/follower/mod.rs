@@ -6,27 +6,126 @@ use crate::{
 //#[cfg(test)]
 //mod tests;
 
-pub struct Follower;
+pub struct Follower {
+    /// The database the follower was last told to select, `None` before the first one.
+    selected: Option<usize>,
+}
 
 impl Follower {
     pub fn new() -> Self {
-        Self
+        Self { selected: None }
     }
 
     pub fn handle_event(&mut self, event: Kind) -> anyhow::Result<Option<resp::Value>> {
         let res = match event {
-            Kind::Set {
-                key,
-                value,
-                expiry: _,
-            } => Some(
-                vec![
+            Kind::Set { key, value, expiry } => {
+                let mut command = vec![
                     resp::Value::bulk_string("SET"),
-                    resp::Value::bulk_string(key),
-                    resp::Value::bulk_string(value),
+                    resp::Value::bulk_bytes(key),
+                    resp::Value::bulk_bytes(value),
+                ];
+                // an absolute time so the expiry does not move with replication lag
+                if let Some(expiry) = expiry {
+                    let millis = expiry
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .unwrap_or_default()
+                        .as_millis();
+                    command.push(resp::Value::bulk_string("PXAT"));
+                    command.push(resp::Value::bulk_string(millis.max(1).to_string()));
+                }
+                Some(command.into_array())
+            }
+            Kind::Del { keys } => Some(
+                std::iter::once(resp::Value::bulk_string("DEL"))
+                    .chain(keys.into_iter().map(resp::Value::bulk_bytes))
+                    .collect::<Vec<_>>()
+                    .into_array(),
+            ),
+            Kind::Expire { key, at: Some(at) } => {
+                let millis = at
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis();
+                Some(
+                    vec![
+                        resp::Value::bulk_string("PEXPIREAT"),
+                        resp::Value::bulk_bytes(key),
+                        resp::Value::bulk_string(millis.to_string()),
+                    ]
+                    .into_array(),
+                )
+            }
+            Kind::Expire { key, at: None } => Some(
+                vec![
+                    resp::Value::bulk_string("PERSIST"),
+                    resp::Value::bulk_bytes(key),
                 ]
                 .into_array(),
             ),
+            Kind::Rename { key, new_key } => Some(
+                vec![
+                    resp::Value::bulk_string("RENAME"),
+                    resp::Value::bulk_bytes(key),
+                    resp::Value::bulk_bytes(new_key),
+                ]
+                .into_array(),
+            ),
+            Kind::Copy {
+                source,
+                destination,
+                db,
+                replace,
+            } => {
+                let mut command = vec![
+                    resp::Value::bulk_string("COPY"),
+                    resp::Value::bulk_bytes(source),
+                    resp::Value::bulk_bytes(destination),
+                ];
+                if let Some(db) = db {
+                    command.push(resp::Value::bulk_string("DB"));
+                    command.push(resp::Value::bulk_string(db.to_string()));
+                }
+                if replace {
+                    command.push(resp::Value::bulk_string("REPLACE"));
+                }
+                Some(command.into_array())
+            }
+            Kind::Move { key, db } => Some(
+                vec![
+                    resp::Value::bulk_string("MOVE"),
+                    resp::Value::bulk_bytes(key),
+                    resp::Value::bulk_string(db.to_string()),
+                ]
+                .into_array(),
+            ),
+            // only sent when it changes, every write is preceded by the database it happened in
+            Kind::Select { db } if self.selected == Some(db) => None,
+            Kind::Select { db } => {
+                self.selected = Some(db);
+                Some(
+                    vec![
+                        resp::Value::bulk_string("SELECT"),
+                        resp::Value::bulk_string(db.to_string()),
+                    ]
+                    .into_array(),
+                )
+            }
+            Kind::SwapDb { a, b } => Some(
+                vec![
+                    resp::Value::bulk_string("SWAPDB"),
+                    resp::Value::bulk_string(a.to_string()),
+                    resp::Value::bulk_string(b.to_string()),
+                ]
+                .into_array(),
+            ),
+            Kind::FlushDb => Some(vec![resp::Value::bulk_string("FLUSHDB")].into_array()),
+            Kind::FlushAll => Some(vec![resp::Value::bulk_string("FLUSHALL")].into_array()),
+            Kind::Command { args } => Some(
+                args.into_iter()
+                    .map(resp::Value::bulk_bytes)
+                    .collect::<Vec<_>>()
+                    .into_array(),
+            ),
         };
         Ok(res)
     }
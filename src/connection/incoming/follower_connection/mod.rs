@@ -52,8 +52,9 @@ where
         handler: &mut Follower,
     ) -> anyhow::Result<()> {
         let event = subscriber.recive();
-        let response = handler.handle_event(event).unwrap().unwrap();
-        self.connection.write(&response).unwrap();
+        if let Some(response) = handler.handle_event(event).unwrap() {
+            self.connection.write(&response).unwrap();
+        }
         Ok(())
     }
 
@@ -67,7 +68,7 @@ where
             if handshake.is_finished() {
                 break;
             }
-            starting_input = self.connection.read().unwrap().into();
+            starting_input = self.connection.read_request().unwrap().into_content();
         }
 
         tracing::debug!("sending rdb file");
@@ -2,7 +2,9 @@ use client_connection::ClientConnectionResult;
 use follower_connection::{follower::Follower, FollowerConnection};
 use tracing::instrument;
 
-use crate::{event::EventEmitter, repository::Repository};
+use std::sync::Arc;
+
+use crate::{config::RedisConfig, event::EventEmitter, repository::Repository};
 
 pub mod client_connection;
 mod follower_connection;
@@ -22,6 +24,7 @@ pub struct IncomingConnection<S> {
     client_router: &'static client_connection::client::Router,
     repo: Repository,
     emitter: EventEmitter,
+    config: Arc<RedisConfig>,
 }
 
 impl<S> IncomingConnection<S>
@@ -43,6 +46,7 @@ where
             client_router,
             repo,
             emitter,
+            config: Arc::default(),
         }
     }
 
@@ -56,8 +60,19 @@ where
         }
     }
 
+    /// Runs the client with the server's `config`, which also limits the lengths it can
+    /// declare in the frames it sends.
+    #[must_use]
+    pub fn with_config(mut self, config: Arc<RedisConfig>) -> Self {
+        self.connection.set_limits(config.limits());
+        self.config = config;
+        self
+    }
+
     fn handle_client_connection(&mut self) -> Result<ClientConnectionResult> {
-        let client = client_connection::client::Client::new(self.client_router, self.repo.clone());
+        let client =
+            client_connection::client::Client::new(self.client_router, self.repo.clone(), self.id)
+                .with_config(Arc::clone(&self.config));
         let mut client = client_connection::ClientConnection::new(
             &mut self.connection,
             self.emitter.clone(),
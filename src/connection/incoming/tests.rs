@@ -1,6 +1,13 @@
 use client_connection::client::default_router;
 
-use crate::{connection::DummyConnection, message::request::Standard, resp};
+use crate::{
+    connection::{stream::Stream, DummyConnection},
+    message::request::Standard,
+    resp::{
+        self,
+        value::{serialize_value, IntoRespArray},
+    },
+};
 
 use super::super::MockConnection;
 use super::*;
@@ -14,8 +21,6 @@ fn dummy_setup() -> IncomingConnection<DummyConnection> {
 
 struct Tester {
     connection: IncomingConnection<MockConnection>,
-    emitter: EventEmitter,
-    repo: Repository,
 }
 
 impl Tester {
@@ -26,18 +31,19 @@ impl Tester {
         <I as std::iter::IntoIterator>::IntoIter: std::iter::DoubleEndedIterator,
         <O as std::iter::IntoIterator>::IntoIter: std::iter::DoubleEndedIterator,
     {
-        let repo = Repository::default();
-        let emitter = EventEmitter::new();
         Self {
             connection: IncomingConnection::new(
                 MockConnection::new(input, expected_output),
                 default_router(),
-                emitter.clone(),
-                repo.clone(),
+                EventEmitter::new(),
+                Repository::default(),
                 123,
             ),
-            emitter,
-            repo,
+        }
+    }
+    fn with_config(self, config: RedisConfig) -> Self {
+        Self {
+            connection: self.connection.with_config(Arc::new(config)),
         }
     }
     fn run(self) -> Result<()> {
@@ -47,10 +53,7 @@ impl Tester {
 
 #[test]
 fn create_incoming_connection() {
-    let connection = DummyConnection;
-    let repo = Repository::default();
-    let emitter = EventEmitter::new();
-    let _ = IncomingConnection::new(connection, default_router(), emitter, repo, 123);
+    let _ = dummy_setup();
 }
 
 #[test]
@@ -79,6 +82,232 @@ fn handler_reads_two_inputs() {
     tester.run().unwrap();
 }
 
+fn hello_reply(protocol: i64) -> Vec<(resp::Value, resp::Value)> {
+    vec![
+        (
+            resp::Value::bulk_string("server"),
+            resp::Value::bulk_string("redis"),
+        ),
+        (
+            resp::Value::bulk_string("version"),
+            resp::Value::bulk_string("7.2.6"),
+        ),
+        (
+            resp::Value::bulk_string("proto"),
+            resp::Value::Integer(protocol),
+        ),
+        (resp::Value::bulk_string("id"), resp::Value::Integer(123)),
+        (
+            resp::Value::bulk_string("mode"),
+            resp::Value::bulk_string("standalone"),
+        ),
+        (
+            resp::Value::bulk_string("role"),
+            resp::Value::bulk_string("master"),
+        ),
+        (
+            resp::Value::bulk_string("modules"),
+            resp::Value::Array(Vec::new()),
+        ),
+    ]
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn hello_3_switches_connection_to_resp3() {
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("HELLO; 3").into_array(),
+            resp::Value::bulk_strings("HELLO").into_array(),
+        ],
+        [
+            resp::Value::Map(hello_reply(3)),
+            resp::Value::Map(hello_reply(3)),
+        ],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn hello_without_version_keeps_resp2_and_replies_with_flat_array() {
+    let tester = Tester::setup(
+        [resp::Value::bulk_strings("HELLO").into_array()],
+        [hello_reply(2)
+            .into_iter()
+            .flat_map(|(key, value)| [key, value])
+            .collect()],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn hello_with_auth_and_setname_is_ok() {
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("HELLO; 3; AUTH; default; secret; SETNAME; worker-1")
+                .into_array(),
+        ],
+        [resp::Value::Map(hello_reply(3))],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn hello_with_unsupported_version_replies_noproto() {
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("HELLO; 4").into_array(),
+            resp::Value::bulk_strings("HELLO; three").into_array(),
+            resp::Value::bulk_strings("HELLO; 3; AUTH; admin; secret").into_array(),
+            resp::Value::bulk_strings("HELLO; 3; SETNAME").into_array(),
+        ],
+        [
+            resp::Value::SimpleError("NOPROTO unsupported protocol version".into()),
+            resp::Value::SimpleError(
+                "ERR Protocol version is not an integer or out of range".into(),
+            ),
+            resp::Value::SimpleError(
+                "WRONGPASS invalid username-password pair or user is disabled.".into(),
+            ),
+            resp::Value::SimpleError("ERR Syntax error in HELLO option 'SETNAME'".into()),
+        ],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_accepts_inline_commands() {
+    let tester = Tester::setup(
+        [
+            resp::Value::Raw(b"PING\r\n".to_vec()),
+            resp::Value::Raw(b"SET abc \"hello world\"\nGET abc\n".to_vec()),
+        ],
+        [
+            resp::Value::simple_string("PONG"),
+            resp::Value::simple_string("OK"),
+            resp::Value::bulk_string("hello world"),
+        ],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn binary_values_round_trip_through_set_and_get() {
+    let key = vec![0xff, 0x00, b'k'];
+    let value = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x00, 0xfe];
+    let tester = Tester::setup(
+        [
+            resp::Value::Array(vec![
+                resp::Value::bulk_string("SET"),
+                resp::Value::BulkByteString(key.clone()),
+                resp::Value::BulkByteString(value.clone()),
+            ]),
+            resp::Value::Array(vec![
+                resp::Value::bulk_string("GET"),
+                resp::Value::BulkByteString(key),
+            ]),
+        ],
+        [resp::Value::ok(), resp::Value::BulkByteString(value)],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+fn handler_replies_protocol_error_and_closes_on_invalid_request() {
+    let tester = Tester::setup(
+        [resp::Value::Array(vec![resp::Value::Integer(1)])],
+        [resp::Value::SimpleError(
+            "ERR Protocol error: expected command name, got Integer(1)".into(),
+        )],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_replies_command_errors_and_keeps_connection_open() {
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("GET").into_array(),
+            resp::Value::bulk_strings("XREAD; COUNT; abc; STREAMS; s; 0").into_array(),
+            resp::Value::bulk_strings("XRANGE; s; 0-1; 0-2; LIMIT").into_array(),
+            resp::Value::bulk_strings("PING").into_array(),
+        ],
+        [
+            resp::Value::SimpleError("ERR wrong number of arguments for 'get' command".into()),
+            resp::Value::SimpleError("ERR value is not an integer or out of range".into()),
+            resp::Value::SimpleError("ERR syntax error".into()),
+            resp::Value::simple_string("PONG"),
+        ],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_replies_wrongtype_when_key_holds_other_type() {
+    let wrongtype = || {
+        resp::Value::SimpleError(
+            "WRONGTYPE Operation against a key holding the wrong kind of value".into(),
+        )
+    };
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("SET; string; value").into_array(),
+            resp::Value::bulk_strings("XADD; string; 1-1; field; value").into_array(),
+            resp::Value::bulk_strings("XADD; stream; 1-1; field; value").into_array(),
+            resp::Value::bulk_strings("GET; stream").into_array(),
+            resp::Value::bulk_strings("XRANGE; string; 0-0; 2-0").into_array(),
+        ],
+        [
+            resp::Value::ok(),
+            wrongtype(),
+            resp::Value::simple_string("1-1"),
+            wrongtype(),
+            wrongtype(),
+        ],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+fn handler_replies_protocol_error_and_closes_on_oversized_multibulk() {
+    let tester = Tester::setup(
+        [resp::Value::Raw(b"*2000000\r\n$4\r\nPING\r\n".to_vec())],
+        [resp::Value::SimpleError(
+            "ERR Protocol error: invalid multibulk length".into(),
+        )],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+fn handler_replies_protocol_error_and_closes_on_deeply_nested_request() {
+    let tester = Tester::setup(
+        [resp::Value::Raw(b"*1\r\n".repeat(500))],
+        [resp::Value::SimpleError(
+            "ERR Protocol error: invalid nesting depth".into(),
+        )],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+fn handler_replies_protocol_error_and_closes_on_negative_bulk_length() {
+    let tester = Tester::setup(
+        [resp::Value::Raw(b"*1\r\n$-5\r\nPING\r\n".to_vec())],
+        [resp::Value::SimpleError(
+            "ERR Protocol error: invalid bulk length".into(),
+        )],
+    );
+    tester.run().unwrap();
+}
+
 #[test]
 #[ignore = "todo"]
 fn handler_reads_until_end_of_input() {
@@ -164,13 +393,764 @@ fn handle_follower_connection_writes_same_output_as_follower_handler() {
     //todo!()
 }
 
+/// A stream that reads `input` and keeps what is written where the test can look at it
+/// while the connection runs on another thread.
+struct SharedConnection {
+    input: std::io::Cursor<Vec<u8>>,
+    output: std::sync::Arc<std::sync::Mutex<Vec<u8>>>,
+}
+
+impl std::io::Read for SharedConnection {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.input.read(buf)
+    }
+}
+
+impl std::io::Write for SharedConnection {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.output.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Stream for SharedConnection {
+    type Addr = ();
+    fn connect((): ()) -> anyhow::Result<Self> {
+        unimplemented!()
+    }
+
+    fn peer_addr(&self) -> Self::Addr {}
+}
+
+/// Runs a follower connection through its handshake and emits `events` until it forwards
+/// `expected`, returning everything written to the follower.
+fn forward_to_follower(events: &[crate::event::Kind], expected: &resp::Value) -> Vec<u8> {
+    let input = [
+        "REPLCONF; listening-port; 6380",
+        "REPLCONF; capa; psync2",
+        "PSYNC; ?; -1",
+    ]
+    .into_iter()
+    .flat_map(|command| serialize_value(&resp::Value::bulk_strings(command).into_array()))
+    .collect();
+    let output = std::sync::Arc::default();
+    let emitter = EventEmitter::new();
+    let connection = IncomingConnection::new(
+        SharedConnection {
+            input: std::io::Cursor::new(input),
+            output: std::sync::Arc::clone(&output),
+        },
+        default_router(),
+        emitter.clone(),
+        Repository::default(),
+        123,
+    );
+    // runs until the test ends, waiting for the next event to forward
+    std::thread::spawn(move || {
+        connection.handle_follower_connection(Standard::new_empty("PING").into())
+    });
+
+    let expected = serialize_value(expected);
+    // the follower only gets the events emitted after it subscribed, so keep emitting
+    (0..500)
+        .find_map(|_| {
+            for event in events {
+                event.clone().emit(&emitter);
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            let written = output.lock().unwrap().clone();
+            written.ends_with(&expected).then_some(written)
+        })
+        .expect("the event was never forwarded")
+}
+
 #[test]
 fn handle_follower_connection_call_runs_follower_connection() {
-    let test = Tester::setup([], [resp::Value::simple_string("PONG")]);
-    todo!();
-    let s = PipelineBuffer::new(DummyConnection);
-    let follower_connection = FollowerConnection::new(s, test.emitter.clone());
-    test.connection
-        .handle_follower_connection(Standard::new_empty("PING").into())
-        .unwrap();
+    let written = forward_to_follower(
+        &[crate::event::Kind::Set {
+            key: "key".into(),
+            value: "value".into(),
+            expiry: None,
+        }],
+        &resp::Value::bulk_strings("SET; key; value").into_array(),
+    );
+    let handshake =
+        b"+PONG\r\n+OK\r\n+OK\r\n+FULLRESYNC 8371b4fb1155b71f4a04d3e1bc3e18c4a990aeeb 0\r\n$";
+    assert!(written.starts_with(handshake), "{written:?}");
+}
+
+/// The events a client on the leader emits while running `requests`.
+fn client_events<const N: usize>(requests: [Standard; N]) -> Vec<crate::event::Kind> {
+    use client_connection::client;
+    let mut client = client::Client::new(default_router(), Repository::default(), 1);
+    requests
+        .into_iter()
+        .flat_map(|request| {
+            match client
+                .handle_request(client::Request::now(request.into()))
+                .unwrap()
+            {
+                client::Result::Response(response) => response.events.unwrap_or_default(),
+                client::Result::ReplicationMessage(request) => panic!("{request:?}"),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn follower_connection_forwards_list_writes() {
+    let events = client_events([
+        Standard::new("RPUSH", ["list", "a", "b", "c"]),
+        Standard::new("BLPOP", ["list", "0"]),
+        Standard::new("LMOVE", ["list", "other", "RIGHT", "LEFT"]),
+    ]);
+    let written = forward_to_follower(
+        &events,
+        &resp::Value::bulk_strings("LMOVE; list; other; RIGHT; LEFT").into_array(),
+    );
+    let expected = [
+        "SELECT; 0",
+        "RPUSH; list; a; b; c",
+        "LPOP; list; 1",
+        "LMOVE; list; other; RIGHT; LEFT",
+    ]
+    .into_iter()
+    .flat_map(|command| serialize_value(&resp::Value::bulk_strings(command).into_array()))
+    .collect::<Vec<_>>();
+    assert!(
+        written
+            .windows(expected.len())
+            .any(|window| window == expected),
+        "{written:?}"
+    );
+}
+
+#[test]
+fn follower_connection_forwards_hash_writes_as_their_result() {
+    let events = client_events([
+        Standard::new("HSET", ["hash", "a", "1"]),
+        Standard::new("HSETNX", ["hash", "a", "2"]),
+        Standard::new("HINCRBYFLOAT", ["hash", "b", "0.5"]),
+        Standard::new("HINCRBY", ["hash", "a", "2"]),
+        Standard::new("HDEL", ["hash", "b", "c"]),
+    ]);
+    let written = forward_to_follower(
+        &events,
+        &resp::Value::bulk_strings("HDEL; hash; b; c").into_array(),
+    );
+    let expected = [
+        "HSET; hash; a; 1",
+        "HSET; hash; b; 0.5",
+        "HSET; hash; a; 3",
+        "HDEL; hash; b; c",
+    ]
+    .into_iter()
+    .flat_map(|command| serialize_value(&resp::Value::bulk_strings(command).into_array()))
+    .collect::<Vec<_>>();
+    assert!(
+        written
+            .windows(expected.len())
+            .any(|window| window == expected),
+        "{written:?}"
+    );
+}
+
+#[test]
+fn follower_connection_forwards_the_members_spop_removed() {
+    let events = client_events([
+        Standard::new("SADD", ["set", "a"]),
+        Standard::new("SPOP", ["set"]),
+    ]);
+    forward_to_follower(
+        &events,
+        &resp::Value::bulk_strings("SREM; set; a").into_array(),
+    );
+}
+
+#[test]
+fn follower_connection_forwards_sorted_set_pops_and_increments_as_their_result() {
+    let events = client_events([
+        Standard::new("ZADD", ["zset", "1", "a", "2", "b"]),
+        Standard::new("ZINCRBY", ["zset", "0.5", "a"]),
+        Standard::new("BZPOPMAX", ["zset", "0"]),
+        Standard::new("ZPOPMIN", ["zset"]),
+    ]);
+    let written = forward_to_follower(
+        &events,
+        &resp::Value::bulk_strings("ZREM; zset; a").into_array(),
+    );
+    let expected = [
+        "ZADD; zset; 1; a; 2; b",
+        "ZADD; zset; 1.5; a",
+        "ZREM; zset; b",
+        "ZREM; zset; a",
+    ]
+    .into_iter()
+    .flat_map(|command| serialize_value(&resp::Value::bulk_strings(command).into_array()))
+    .collect::<Vec<_>>();
+    assert!(
+        written
+            .windows(expected.len())
+            .any(|window| window == expected),
+        "{written:?}"
+    );
+}
+
+#[test]
+fn follower_connection_forwards_xadd_with_the_assigned_id() {
+    let events = client_events([Standard::new("XADD", ["stream", "*", "f", "v"])]);
+    let Some(crate::event::Kind::Command { args }) = events.last() else {
+        panic!("{events:?}");
+    };
+    let id = String::from_utf8(args[2].clone()).unwrap();
+    assert!(id.split_once('-').is_some_and(|(millis, sequence)| {
+        millis.parse::<u64>().is_ok() && sequence.parse::<u64>().is_ok()
+    }));
+    forward_to_follower(
+        &events,
+        &resp::Value::bulk_strings(format!("XADD; stream; {id}; f; v")).into_array(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_runs_list_commands() {
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("RPUSH; list; a; b; c").into_array(),
+            resp::Value::bulk_strings("LPUSH; list; z").into_array(),
+            resp::Value::bulk_strings("LRANGE; list; 0; -1").into_array(),
+            resp::Value::bulk_strings("LINSERT; list; AFTER; a; x").into_array(),
+            resp::Value::bulk_strings("LPOS; list; x").into_array(),
+            resp::Value::bulk_strings("LPOP; list").into_array(),
+            resp::Value::bulk_strings("RPOP; list; 2").into_array(),
+            resp::Value::bulk_strings("LMOVE; list; other; LEFT; RIGHT").into_array(),
+            resp::Value::bulk_strings("LLEN; list").into_array(),
+            resp::Value::bulk_strings("LPOP; list; 1").into_array(),
+            resp::Value::bulk_strings("LPOP; list").into_array(),
+            resp::Value::bulk_strings("LPOS; other; a; RANK; 0").into_array(),
+        ],
+        [
+            resp::Value::Integer(3),
+            resp::Value::Integer(4),
+            resp::Value::Array(resp::Value::bulk_strings("z; a; b; c")),
+            resp::Value::Integer(5),
+            resp::Value::Integer(2),
+            resp::Value::bulk_string("z"),
+            resp::Value::Array(resp::Value::bulk_strings("c; b")),
+            resp::Value::bulk_string("a"),
+            resp::Value::Integer(1),
+            resp::Value::Array(resp::Value::bulk_strings("x")),
+            resp::Value::NullString,
+            resp::Value::SimpleError(
+                "ERR RANK can't be zero: use 1 to start from the first match, \
+                 2 from the second ... or use negative to start from the end of the list"
+                    .into(),
+            ),
+        ],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_runs_blocking_list_commands() {
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("RPUSH; list; a; b; c").into_array(),
+            resp::Value::bulk_strings("BLPOP; empty; list; 0").into_array(),
+            resp::Value::bulk_strings("BLMPOP; 0.01; 2; empty; list; RIGHT; COUNT; 5").into_array(),
+            resp::Value::bulk_strings("BRPOP; list; 0.01").into_array(),
+            resp::Value::bulk_strings("BLMOVE; list; other; LEFT; LEFT; 0.01").into_array(),
+            resp::Value::bulk_strings("BLPOP; list; -1").into_array(),
+        ],
+        [
+            resp::Value::Integer(3),
+            resp::Value::Array(resp::Value::bulk_strings("list; a")),
+            resp::Value::Array(vec![
+                resp::Value::bulk_string("list"),
+                resp::Value::Array(resp::Value::bulk_strings("c; b")),
+            ]),
+            resp::Value::NullArray,
+            resp::Value::NullString,
+            resp::Value::SimpleError("ERR timeout is negative".into()),
+        ],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_runs_hash_commands() {
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("HSET; h; a; 1; b; 2").into_array(),
+            resp::Value::bulk_strings("HSETNX; h; a; 3").into_array(),
+            resp::Value::bulk_strings("HMGET; h; a; missing").into_array(),
+            resp::Value::bulk_strings("HINCRBY; h; a; 5").into_array(),
+            resp::Value::bulk_strings("HINCRBYFLOAT; h; b; 0.5").into_array(),
+            resp::Value::bulk_strings("HDEL; h; a").into_array(),
+            resp::Value::bulk_strings("HGETALL; h").into_array(),
+            resp::Value::bulk_strings("HSCAN; h; 0; MATCH; b*").into_array(),
+            resp::Value::bulk_strings("HSET; h; a").into_array(),
+        ],
+        [
+            resp::Value::Integer(2),
+            resp::Value::Integer(0),
+            resp::Value::Array(vec![resp::Value::bulk_string("1"), resp::Value::NullString]),
+            resp::Value::Integer(6),
+            resp::Value::bulk_string("2.5"),
+            resp::Value::Integer(1),
+            // resp2 clients get maps as flat arrays
+            resp::Value::Array(resp::Value::bulk_strings("b; 2.5")),
+            resp::Value::Array(vec![
+                resp::Value::bulk_string("0"),
+                resp::Value::Array(resp::Value::bulk_strings("b; 2.5")),
+            ]),
+            resp::Value::SimpleError("ERR wrong number of arguments for 'hset' command".into()),
+        ],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_runs_set_commands() {
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("SADD; a; 1; 2; 3").into_array(),
+            resp::Value::bulk_strings("SADD; b; 3; 4").into_array(),
+            resp::Value::bulk_strings("SMISMEMBER; a; 1; 4").into_array(),
+            resp::Value::bulk_strings("SINTER; a; b").into_array(),
+            resp::Value::bulk_strings("SDIFFSTORE; c; a; b").into_array(),
+            resp::Value::bulk_strings("SINTERCARD; 2; a; c; LIMIT; 1").into_array(),
+            resp::Value::bulk_strings("SMOVE; b; a; 4").into_array(),
+            resp::Value::bulk_strings("SCARD; a").into_array(),
+            resp::Value::bulk_strings("SPOP; missing").into_array(),
+            resp::Value::bulk_strings("SINTERCARD; 0; a").into_array(),
+        ],
+        [
+            resp::Value::Integer(3),
+            resp::Value::Integer(2),
+            resp::Value::Array(vec![resp::Value::Integer(1), resp::Value::Integer(0)]),
+            resp::Value::Array(resp::Value::bulk_strings("3")),
+            resp::Value::Integer(2),
+            resp::Value::Integer(1),
+            resp::Value::Integer(1),
+            resp::Value::Integer(4),
+            resp::Value::NullString,
+            resp::Value::SimpleError("ERR numkeys should be greater than 0".into()),
+        ],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_runs_sorted_set_commands() {
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("ZADD; z; 1; a; 2; b; 3; c").into_array(),
+            resp::Value::bulk_strings("ZADD; z; CH; GT; 0; a; 5; b").into_array(),
+            resp::Value::bulk_strings("ZINCRBY; z; 1.5; a").into_array(),
+            resp::Value::bulk_strings("ZRANGE; z; 0; -1; WITHSCORES").into_array(),
+            resp::Value::bulk_strings("ZRANGE; z; (5; -inf; BYSCORE; REV; LIMIT; 0; 1")
+                .into_array(),
+            resp::Value::bulk_strings("ZREVRANK; z; c").into_array(),
+            resp::Value::bulk_strings("ZUNIONSTORE; u; 2; z; missing; WEIGHTS; 2; 1").into_array(),
+            resp::Value::bulk_strings("ZPOPMAX; u").into_array(),
+            resp::Value::bulk_strings("BZPOPMIN; missing; u; 0").into_array(),
+            resp::Value::bulk_strings("ZSCORE; z; missing").into_array(),
+            resp::Value::bulk_strings("ZADD; z; NX; XX; 1; a").into_array(),
+        ],
+        [
+            resp::Value::Integer(3),
+            resp::Value::Integer(1),
+            resp::Value::bulk_string("2.5"),
+            resp::Value::Array(resp::Value::bulk_strings("a; 2.5; c; 3; b; 5")),
+            resp::Value::Array(resp::Value::bulk_strings("c")),
+            resp::Value::Integer(1),
+            resp::Value::Integer(3),
+            resp::Value::Array(resp::Value::bulk_strings("b; 10")),
+            resp::Value::Array(resp::Value::bulk_strings("u; a; 5")),
+            resp::Value::NullString,
+            resp::Value::SimpleError(
+                "ERR XX and NX options at the same time are not compatible".into(),
+            ),
+        ],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_runs_set_options_and_string_getters() {
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("SET; k; a; XX").into_array(),
+            resp::Value::bulk_strings("SET; k; a; NX; EX; 100").into_array(),
+            resp::Value::bulk_strings("SET; k; b; GET; KEEPTTL").into_array(),
+            resp::Value::bulk_strings("SET; k; c; EX; 0").into_array(),
+            resp::Value::bulk_strings("SET; k; c; EX; 1; PX; 1").into_array(),
+            resp::Value::bulk_strings("SETNX; k; c").into_array(),
+            resp::Value::bulk_strings("GETSET; k; d").into_array(),
+            resp::Value::bulk_strings("GETEX; k; PERSIST").into_array(),
+            resp::Value::bulk_strings("GETDEL; k").into_array(),
+            resp::Value::bulk_strings("GET; k").into_array(),
+            resp::Value::bulk_strings("PSETEX; k; 1000; e").into_array(),
+        ],
+        [
+            resp::Value::NullString,
+            resp::Value::ok(),
+            resp::Value::bulk_string("a"),
+            resp::Value::SimpleError("ERR invalid expire time in 'set' command".into()),
+            resp::Value::SimpleError("ERR syntax error".into()),
+            resp::Value::Integer(0),
+            resp::Value::bulk_string("b"),
+            resp::Value::bulk_string("d"),
+            resp::Value::bulk_string("d"),
+            resp::Value::NullString,
+            resp::Value::ok(),
+        ],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_runs_expire_commands_on_any_type() {
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("RPUSH; l; a").into_array(),
+            resp::Value::bulk_strings("EXPIRE; l; 100; GT").into_array(),
+            resp::Value::bulk_strings("EXPIRE; l; 100").into_array(),
+            resp::Value::bulk_strings("TTL; l").into_array(),
+            resp::Value::bulk_strings("PERSIST; l").into_array(),
+            resp::Value::bulk_strings("PTTL; l").into_array(),
+            resp::Value::bulk_strings("EXPIRETIME; missing").into_array(),
+            resp::Value::bulk_strings("EXPIRE; l; 10; NX; XX").into_array(),
+            resp::Value::bulk_strings("PEXPIREAT; l; 1").into_array(),
+            resp::Value::bulk_strings("LLEN; l").into_array(),
+        ],
+        [
+            resp::Value::Integer(1),
+            resp::Value::Integer(0),
+            resp::Value::Integer(1),
+            resp::Value::Integer(100),
+            resp::Value::Integer(1),
+            resp::Value::Integer(-1),
+            resp::Value::Integer(-2),
+            resp::Value::SimpleError(
+                "ERR NX and XX, GT or LT options at the same time are not compatible".into(),
+            ),
+            resp::Value::Integer(1),
+            resp::Value::Integer(0),
+        ],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_reports_expired_keys_in_info_stats() {
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("SET; k; v; PXAT; 1").into_array(),
+            resp::Value::bulk_strings("GET; k").into_array(),
+            resp::Value::bulk_strings("INFO; stats").into_array(),
+        ],
+        [
+            resp::Value::ok(),
+            resp::Value::NullString,
+            resp::Value::bulk_string("# Stats\r\nexpired_keys:1\r\nexpired_stale_perc:0.00\r\n"),
+        ],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_reports_leader_role_in_info_replication() {
+    let tester = Tester::setup(
+        [resp::Value::bulk_strings("INFO; replication").into_array()],
+        [resp::Value::bulk_string("# Replication\r\nrole:master\r\n")],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_reports_configured_leader_in_info_replication() {
+    let leader = std::net::SocketAddrV4::new(std::net::Ipv4Addr::LOCALHOST, 6380);
+    let tester = Tester::setup(
+        [resp::Value::bulk_strings("INFO; replication").into_array()],
+        [resp::Value::bulk_string(
+            "# Replication\r\nrole:slave\r\nmaster_host:127.0.0.1\r\nmaster_port:6380\r\n",
+        )],
+    )
+    .with_config(RedisConfig::new_follower(6381, leader));
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_limits_strings_to_the_configured_bulk_len() {
+    let mut config = RedisConfig::default();
+    config.set_limits(resp::value::deserialize::Limits {
+        max_bulk_len: 8,
+        ..Default::default()
+    });
+    let too_long = || {
+        resp::Value::SimpleError(
+            "ERR string exceeds maximum allowed size (proto-max-bulk-len)".into(),
+        )
+    };
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("APPEND; k; Hello").into_array(),
+            resp::Value::bulk_strings("APPEND; k; abc").into_array(),
+            resp::Value::bulk_strings("APPEND; k; !").into_array(),
+            resp::Value::bulk_strings("SETRANGE; k; 8; !").into_array(),
+        ],
+        [
+            resp::Value::Integer(5),
+            resp::Value::Integer(8),
+            too_long(),
+            too_long(),
+        ],
+    )
+    .with_config(config);
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_runs_string_commands() {
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("INCR; n").into_array(),
+            resp::Value::bulk_strings("DECRBY; n; 5").into_array(),
+            resp::Value::bulk_strings("INCRBYFLOAT; n; 0.5").into_array(),
+            resp::Value::bulk_strings("MSET; a; ohmytext; b; mynewtext").into_array(),
+            resp::Value::bulk_strings("MSETNX; b; x; c; y").into_array(),
+            resp::Value::bulk_strings("MGET; a; c").into_array(),
+            resp::Value::bulk_strings("LCS; a; b").into_array(),
+            resp::Value::bulk_strings("LCS; a; b; IDX; MINMATCHLEN; 4").into_array(),
+            resp::Value::bulk_strings("SETRANGE; a; 0; OH").into_array(),
+            resp::Value::bulk_strings("GETRANGE; a; 0; 3").into_array(),
+            resp::Value::bulk_strings("INCR; a").into_array(),
+        ],
+        [
+            resp::Value::Integer(1),
+            resp::Value::Integer(-4),
+            resp::Value::bulk_string("-3.5"),
+            resp::Value::ok(),
+            resp::Value::Integer(0),
+            resp::Value::Array(vec![
+                resp::Value::bulk_string("ohmytext"),
+                resp::Value::NullString,
+            ]),
+            resp::Value::bulk_string("mytext"),
+            resp::Value::Array(vec![
+                resp::Value::bulk_string("matches"),
+                resp::Value::Array(vec![resp::Value::Array(vec![
+                    resp::Value::Array(vec![resp::Value::Integer(4), resp::Value::Integer(7)]),
+                    resp::Value::Array(vec![resp::Value::Integer(5), resp::Value::Integer(8)]),
+                ])]),
+                resp::Value::bulk_string("len"),
+                resp::Value::Integer(6),
+            ]),
+            resp::Value::Integer(8),
+            resp::Value::bulk_string("OHmy"),
+            resp::Value::SimpleError("ERR value is not an integer or out of range".into()),
+        ],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_runs_generic_key_commands() {
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("MSET; a; 1; b; 2").into_array(),
+            resp::Value::bulk_strings("RPUSH; l; x").into_array(),
+            resp::Value::bulk_strings("EXISTS; a; a; missing").into_array(),
+            resp::Value::bulk_strings("TYPE; l").into_array(),
+            resp::Value::bulk_strings("TYPE; missing").into_array(),
+            resp::Value::bulk_strings("RENAMENX; a; b").into_array(),
+            resp::Value::bulk_strings("RENAME; a; c").into_array(),
+            resp::Value::bulk_strings("RENAME; a; c").into_array(),
+            resp::Value::bulk_strings("COPY; l; m").into_array(),
+            resp::Value::bulk_strings("COPY; c; b; DB; 16").into_array(),
+            resp::Value::bulk_strings("COPY; c; b; REPLACE").into_array(),
+            resp::Value::bulk_strings("GET; b").into_array(),
+            resp::Value::bulk_strings("DBSIZE").into_array(),
+            resp::Value::bulk_strings("DEL; b; c; missing").into_array(),
+            resp::Value::bulk_strings("UNLINK; m").into_array(),
+            resp::Value::bulk_strings("TOUCH; l; m").into_array(),
+            resp::Value::bulk_strings("RANDOMKEY").into_array(),
+            resp::Value::bulk_strings("FLUSHDB; ASYNC").into_array(),
+            resp::Value::bulk_strings("RANDOMKEY").into_array(),
+            resp::Value::bulk_strings("DBSIZE").into_array(),
+        ],
+        [
+            resp::Value::ok(),
+            resp::Value::Integer(1),
+            resp::Value::Integer(2),
+            resp::Value::simple_string("list"),
+            resp::Value::simple_string("none"),
+            resp::Value::Integer(0),
+            resp::Value::ok(),
+            resp::Value::SimpleError("ERR no such key".into()),
+            resp::Value::Integer(1),
+            resp::Value::SimpleError("ERR DB index is out of range".into()),
+            resp::Value::Integer(1),
+            resp::Value::bulk_string("1"),
+            resp::Value::Integer(4),
+            resp::Value::Integer(2),
+            resp::Value::Integer(1),
+            resp::Value::Integer(1),
+            resp::Value::bulk_string("l"),
+            resp::Value::ok(),
+            resp::Value::NullString,
+            resp::Value::Integer(0),
+        ],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_runs_keys_and_scan() {
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("MSET; user:1; a; user:2; b").into_array(),
+            resp::Value::bulk_strings("RPUSH; user:list; x").into_array(),
+            resp::Value::bulk_strings("KEYS; user:[0-9]").into_array(),
+            resp::Value::bulk_strings("SCAN; 0; MATCH; *1").into_array(),
+            resp::Value::bulk_strings("SCAN; 0; TYPE; list").into_array(),
+            resp::Value::bulk_strings("SCAN; 0; MATCH; *2").into_array(),
+            resp::Value::bulk_strings("SCAN; x").into_array(),
+            resp::Value::bulk_strings("SCAN; 0; COUNT; 0").into_array(),
+        ],
+        [
+            resp::Value::ok(),
+            resp::Value::Integer(1),
+            resp::Value::Array(vec![
+                resp::Value::bulk_string("user:1"),
+                resp::Value::bulk_string("user:2"),
+            ]),
+            resp::Value::Array(vec![
+                resp::Value::bulk_string("0"),
+                resp::Value::Array(vec![resp::Value::bulk_string("user:1")]),
+            ]),
+            resp::Value::Array(vec![
+                resp::Value::bulk_string("0"),
+                resp::Value::Array(vec![resp::Value::bulk_string("user:list")]),
+            ]),
+            resp::Value::Array(vec![
+                resp::Value::bulk_string("0"),
+                resp::Value::Array(vec![resp::Value::bulk_string("user:2")]),
+            ]),
+            resp::Value::SimpleError("ERR invalid cursor".into()),
+            resp::Value::SimpleError("ERR syntax error".into()),
+        ],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_keeps_databases_apart() {
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("SET; k; zero").into_array(),
+            resp::Value::bulk_strings("SELECT; 1").into_array(),
+            resp::Value::bulk_strings("GET; k").into_array(),
+            resp::Value::bulk_strings("SET; k; one").into_array(),
+            resp::Value::bulk_strings("SET; only; one").into_array(),
+            resp::Value::bulk_strings("MOVE; only; 0").into_array(),
+            resp::Value::bulk_strings("MOVE; k; 0").into_array(),
+            resp::Value::bulk_strings("SWAPDB; 0; 1").into_array(),
+            resp::Value::bulk_strings("MGET; k; only").into_array(),
+            resp::Value::bulk_strings("FLUSHDB").into_array(),
+            resp::Value::bulk_strings("MULTI").into_array(),
+            resp::Value::bulk_strings("SELECT; 0").into_array(),
+            resp::Value::bulk_strings("GET; k").into_array(),
+            resp::Value::bulk_strings("EXEC").into_array(),
+            resp::Value::bulk_strings("DBSIZE").into_array(),
+            resp::Value::bulk_strings("SELECT; 16").into_array(),
+            resp::Value::bulk_strings("SWAPDB; x; 1").into_array(),
+            resp::Value::bulk_strings("CONFIG; GET; databases").into_array(),
+            resp::Value::bulk_strings("CONFIG; GET; no-such-parameter").into_array(),
+        ],
+        [
+            resp::Value::ok(),
+            resp::Value::ok(),
+            resp::Value::NullString,
+            resp::Value::ok(),
+            resp::Value::ok(),
+            resp::Value::Integer(1),
+            resp::Value::Integer(0),
+            resp::Value::ok(),
+            resp::Value::Array(vec![
+                resp::Value::bulk_string("zero"),
+                resp::Value::bulk_string("one"),
+            ]),
+            resp::Value::ok(),
+            resp::Value::ok(),
+            resp::Value::simple_string("QUEUED"),
+            resp::Value::simple_string("QUEUED"),
+            resp::Value::Array(vec![resp::Value::ok(), resp::Value::bulk_string("one")]),
+            resp::Value::Integer(1),
+            resp::Value::SimpleError("ERR DB index is out of range".into()),
+            resp::Value::SimpleError("ERR invalid first DB index".into()),
+            resp::Value::Array(vec![
+                resp::Value::bulk_string("databases"),
+                resp::Value::bulk_string("16"),
+            ]),
+            resp::Value::Array(Vec::new()),
+        ],
+    );
+    tester.run().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "EndOfInput")]
+fn handler_runs_stream_range_commands() {
+    let entry = |id: &str, value: &str| {
+        resp::Value::Array(vec![
+            resp::Value::simple_string(id),
+            resp::Value::bulk_strings(format!("field; {value}")).into_array(),
+        ])
+    };
+    let tester = Tester::setup(
+        [
+            resp::Value::bulk_strings("XADD; s; 1-1; field; a").into_array(),
+            resp::Value::bulk_strings("XADD; s; 1-2; field; b").into_array(),
+            resp::Value::bulk_strings("XADD; s; 2-0; field; c").into_array(),
+            resp::Value::bulk_strings("XADD; s; 2-0; field; d").into_array(),
+            resp::Value::bulk_strings("XRANGE; s; -; 1").into_array(),
+            resp::Value::bulk_strings("XREVRANGE; s; +; -; COUNT; 2").into_array(),
+            resp::Value::bulk_strings("XREVRANGE; s; 1-1; 2").into_array(),
+        ],
+        [
+            resp::Value::simple_string("1-1"),
+            resp::Value::simple_string("1-2"),
+            resp::Value::simple_string("2-0"),
+            resp::Value::SimpleError(
+                "ERR The ID specified in XADD is equal or smaller than the target stream top item"
+                    .into(),
+            ),
+            resp::Value::Array(vec![entry("1-1", "a"), entry("1-2", "b")]),
+            resp::Value::Array(vec![entry("2-0", "c"), entry("1-2", "b")]),
+            resp::Value::Array(Vec::new()),
+        ],
+    );
+    tester.run().unwrap();
 }
@@ -0,0 +1,64 @@
+use std::time::SystemTime;
+
+use crate::{command::Command, event, repository::Repository, Request};
+
+pub struct Copy;
+
+impl Copy {
+    fn handle_request(request: CopyRequest, repo: &Repository) -> anyhow::Result<event::Kind> {
+        let target = match request.db {
+            Some(db) => repo
+                .select(db)
+                .ok_or_else(|| anyhow::anyhow!("ERR DB index is out of range"))?,
+            None => repo.clone(),
+        };
+        repo.key_repo().copy(
+            &request.source,
+            target.key_repo(),
+            &request.destination,
+            request.replace,
+            SystemTime::now(),
+        )?;
+        Ok(event::Kind::Copy {
+            source: request.source,
+            destination: request.destination,
+            db: request.db,
+            replace: request.replace,
+        })
+    }
+}
+
+impl Command<Request, Option<event::Kind>, Repository> for Copy {
+    fn info(&self) -> crate::command::CommandInfo {
+        crate::command::CommandInfo::new_name("COPY")
+    }
+
+    fn call(&self, request: Request, state: &Repository) -> anyhow::Result<Option<event::Kind>> {
+        Self::handle_request(request.try_into()?, state).map(Some)
+    }
+}
+
+struct CopyRequest {
+    source: Vec<u8>,
+    destination: Vec<u8>,
+    db: Option<usize>,
+    replace: bool,
+}
+
+impl TryFrom<Request> for CopyRequest {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Request) -> Result<Self, Self::Error> {
+        let mut parser = value.into_parser();
+        let source = parser.arg()?;
+        let destination = parser.arg()?;
+        let db = parser.option("DB")?;
+        let replace = parser.flag("REPLACE");
+        Ok(Self {
+            source,
+            destination,
+            db,
+            replace,
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use std::time::SystemTime;
+
+use crate::{command::Command, event, repository::Repository, Request};
+
+pub struct Del;
+
+impl Del {
+    fn handle_request(request: DelRequest, repo: &Repository) -> event::Kind {
+        let _ = repo.key_repo().delete(&request.keys, SystemTime::now());
+        event::Kind::Del { keys: request.keys }
+    }
+}
+
+impl Command<Request, Option<event::Kind>, Repository> for Del {
+    fn info(&self) -> crate::command::CommandInfo {
+        crate::command::CommandInfo::new_name("DEL")
+    }
+
+    fn call(&self, request: Request, state: &Repository) -> anyhow::Result<Option<event::Kind>> {
+        Ok(Some(Self::handle_request(request.try_into()?, state)))
+    }
+}
+
+struct DelRequest {
+    keys: Vec<Vec<u8>>,
+}
+
+impl TryFrom<Request> for DelRequest {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Request) -> Result<Self, Self::Error> {
+        let mut parser = value.into_parser();
+        let keys = parser.rest()?;
+        Ok(Self { keys })
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{command::Command, event, repository::Repository, Request};
+
+pub struct FlushAll;
+
+impl FlushAll {
+    fn handle_request(_: FlushAllRequest, repo: &Repository) -> event::Kind {
+        repo.clear();
+        event::Kind::FlushAll
+    }
+}
+
+impl Command<Request, Option<event::Kind>, Repository> for FlushAll {
+    fn info(&self) -> crate::command::CommandInfo {
+        crate::command::CommandInfo::new_name("FLUSHALL")
+    }
+
+    fn call(&self, request: Request, state: &Repository) -> anyhow::Result<Option<event::Kind>> {
+        Ok(Some(Self::handle_request(request.try_into()?, state)))
+    }
+}
+
+struct FlushAllRequest;
+
+impl TryFrom<Request> for FlushAllRequest {
+    type Error = anyhow::Error;
+
+    fn try_from(_: Request) -> Result<Self, Self::Error> {
+        Ok(Self)
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{command::Command, event, repository::Repository, Request};
+
+pub struct FlushDb;
+
+impl FlushDb {
+    fn handle_request(_: FlushDbRequest, repo: &Repository) -> event::Kind {
+        repo.key_repo().clear();
+        event::Kind::FlushDb
+    }
+}
+
+impl Command<Request, Option<event::Kind>, Repository> for FlushDb {
+    fn info(&self) -> crate::command::CommandInfo {
+        crate::command::CommandInfo::new_name("FLUSHDB")
+    }
+
+    fn call(&self, request: Request, state: &Repository) -> anyhow::Result<Option<event::Kind>> {
+        Ok(Some(Self::handle_request(request.try_into()?, state)))
+    }
+}
+
+struct FlushDbRequest;
+
+impl TryFrom<Request> for FlushDbRequest {
+    type Error = anyhow::Error;
+
+    fn try_from(_: Request) -> Result<Self, Self::Error> {
+        Ok(Self)
+    }
+}
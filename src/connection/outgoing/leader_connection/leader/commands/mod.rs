@@ -1,2 +1,12 @@
+pub mod copy;
+pub mod del;
+pub mod flushall;
+pub mod flushdb;
+pub mod r#move;
+pub mod persist;
+pub mod pexpireat;
 pub mod ping;
+pub mod rename;
+pub mod replay;
 pub mod set;
+pub mod swapdb;
@@ -0,0 +1,45 @@
+use std::time::SystemTime;
+
+use crate::{command::Command, event, repository::Repository, Request};
+
+pub struct Move;
+
+impl Move {
+    fn handle_request(request: MoveRequest, repo: &Repository) -> anyhow::Result<event::Kind> {
+        let target = repo
+            .select(request.db)
+            .ok_or_else(|| anyhow::anyhow!("ERR DB index is out of range"))?;
+        repo.key_repo()
+            .move_to(&request.key, target.key_repo(), SystemTime::now())?;
+        Ok(event::Kind::Move {
+            key: request.key,
+            db: request.db,
+        })
+    }
+}
+
+impl Command<Request, Option<event::Kind>, Repository> for Move {
+    fn info(&self) -> crate::command::CommandInfo {
+        crate::command::CommandInfo::new_name("MOVE")
+    }
+
+    fn call(&self, request: Request, state: &Repository) -> anyhow::Result<Option<event::Kind>> {
+        Self::handle_request(request.try_into()?, state).map(Some)
+    }
+}
+
+struct MoveRequest {
+    key: Vec<u8>,
+    db: usize,
+}
+
+impl TryFrom<Request> for MoveRequest {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Request) -> Result<Self, Self::Error> {
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let db = parser.arg()?;
+        Ok(Self { key, db })
+    }
+}
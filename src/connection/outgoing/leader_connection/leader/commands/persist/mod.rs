@@ -0,0 +1,42 @@
+use std::time::SystemTime;
+
+use crate::{command::Command, event, repository::Repository, Request};
+
+pub struct Persist;
+
+impl Persist {
+    fn handle_request(
+        request: PersistRequest,
+        repo: &Repository,
+    ) -> anyhow::Result<Option<event::Kind>> {
+        let persisted = repo.key_repo().persist(&request.key, SystemTime::now());
+        Ok(persisted.then_some(event::Kind::Expire {
+            key: request.key,
+            at: None,
+        }))
+    }
+}
+
+impl Command<Request, Option<event::Kind>, Repository> for Persist {
+    fn info(&self) -> crate::command::CommandInfo {
+        crate::command::CommandInfo::new_name("PERSIST")
+    }
+
+    fn call(&self, request: Request, state: &Repository) -> anyhow::Result<Option<event::Kind>> {
+        Self::handle_request(request.try_into()?, state)
+    }
+}
+
+struct PersistRequest {
+    key: Vec<u8>,
+}
+
+impl TryFrom<Request> for PersistRequest {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Request) -> Result<Self, Self::Error> {
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        Ok(Self { key })
+    }
+}
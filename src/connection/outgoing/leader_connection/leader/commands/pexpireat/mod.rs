@@ -0,0 +1,57 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{
+    command::Command,
+    event,
+    repository::{key_repo::ExpireOptions, Repository},
+    Request,
+};
+
+pub struct PExpireAt;
+
+impl PExpireAt {
+    fn handle_request(
+        request: PExpireAtRequest,
+        repo: &Repository,
+    ) -> anyhow::Result<Option<event::Kind>> {
+        let expired = repo.key_repo().expire(
+            &request.key,
+            request.at,
+            ExpireOptions::default(),
+            SystemTime::now(),
+        );
+        Ok(expired.then_some(event::Kind::Expire {
+            key: request.key,
+            at: Some(request.at),
+        }))
+    }
+}
+
+impl Command<Request, Option<event::Kind>, Repository> for PExpireAt {
+    fn info(&self) -> crate::command::CommandInfo {
+        crate::command::CommandInfo::new_name("PEXPIREAT")
+    }
+
+    fn call(&self, request: Request, state: &Repository) -> anyhow::Result<Option<event::Kind>> {
+        Self::handle_request(request.try_into()?, state)
+    }
+}
+
+struct PExpireAtRequest {
+    key: Vec<u8>,
+    at: SystemTime,
+}
+
+impl TryFrom<Request> for PExpireAtRequest {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Request) -> Result<Self, Self::Error> {
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let millis: u64 = parser.arg()?;
+        Ok(Self {
+            key,
+            at: UNIX_EPOCH + Duration::from_millis(millis),
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{command::Command, repository::Repository, Request};
+use crate::{command::Command, event, repository::Repository, Request};
 
 pub struct Ping;
 
@@ -6,14 +6,14 @@ impl Ping {
     fn handle_request(_: PingRequest, _: &Repository) {}
 }
 
-impl Command<Request, (), Repository> for Ping {
+impl Command<Request, Option<event::Kind>, Repository> for Ping {
     fn info(&self) -> crate::command::CommandInfo {
         crate::command::CommandInfo::new_name("PING")
     }
 
-    fn call(&self, request: Request, state: &Repository) -> anyhow::Result<()> {
+    fn call(&self, request: Request, state: &Repository) -> anyhow::Result<Option<event::Kind>> {
         Self::handle_request(request.try_into().unwrap(), state);
-        Ok(())
+        Ok(None)
     }
 }
 
@@ -0,0 +1,42 @@
+use std::time::SystemTime;
+
+use crate::{command::Command, event, repository::Repository, Request};
+
+pub struct Rename;
+
+impl Rename {
+    fn handle_request(request: RenameRequest, repo: &Repository) -> anyhow::Result<event::Kind> {
+        repo.key_repo()
+            .rename(&request.key, &request.new_key, false, SystemTime::now())?;
+        Ok(event::Kind::Rename {
+            key: request.key,
+            new_key: request.new_key,
+        })
+    }
+}
+
+impl Command<Request, Option<event::Kind>, Repository> for Rename {
+    fn info(&self) -> crate::command::CommandInfo {
+        crate::command::CommandInfo::new_name("RENAME")
+    }
+
+    fn call(&self, request: Request, state: &Repository) -> anyhow::Result<Option<event::Kind>> {
+        Self::handle_request(request.try_into()?, state).map(Some)
+    }
+}
+
+struct RenameRequest {
+    key: Vec<u8>,
+    new_key: Vec<u8>,
+}
+
+impl TryFrom<Request> for RenameRequest {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Request) -> Result<Self, Self::Error> {
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let new_key = parser.arg()?;
+        Ok(Self { key, new_key })
+    }
+}
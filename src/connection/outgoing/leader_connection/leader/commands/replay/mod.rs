@@ -0,0 +1,26 @@
+use crate::{
+    command::Command, connection::incoming::client_connection::client, event,
+    repository::Repository, resp, Request,
+};
+
+/// Applies a write the leader replicated as the command that makes it, see
+/// [`event::Kind::Command`], by running the client command of that name.
+pub struct Replay<C>(pub C);
+
+impl<C> Command<Request, Option<event::Kind>, Repository> for Replay<C>
+where
+    C: Command<client::Request, client::Response, Repository>,
+{
+    fn info(&self) -> crate::command::CommandInfo {
+        self.0.info()
+    }
+
+    fn call(&self, request: Request, state: &Repository) -> anyhow::Result<Option<event::Kind>> {
+        let response = self.0.call(client::Request::now(request), state)?;
+        if let resp::Value::SimpleError(err) = response.value {
+            anyhow::bail!(err);
+        }
+        // the commands that are replayed replicate themselves with a single event
+        Ok(response.events.and_then(|events| events.into_iter().next()))
+    }
+}
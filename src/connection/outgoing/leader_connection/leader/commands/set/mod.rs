@@ -1,38 +1,48 @@
-use crate::{command::Command, repository::Repository, Request};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{command::Command, event, repository::Repository, Request};
 
 pub struct Set;
 
 impl Set {
-    fn handle_request(request: SetRequest, repo: &Repository) {
+    fn handle_request(request: SetRequest, repo: &Repository) -> anyhow::Result<event::Kind> {
         repo.kv_repo()
-            .set(request.key, request.value, None)
-            .unwrap();
+            .set(request.key.clone(), request.value.clone(), request.expiry)?;
+        Ok(event::Kind::Set {
+            key: request.key,
+            value: request.value,
+            expiry: request.expiry,
+        })
     }
 }
 
-impl Command<Request, (), Repository> for Set {
+impl Command<Request, Option<event::Kind>, Repository> for Set {
     fn info(&self) -> crate::command::CommandInfo {
         crate::command::CommandInfo::new_name("SET")
     }
 
-    fn call(&self, request: Request, state: &Repository) -> anyhow::Result<()> {
-        Self::handle_request(request.try_into().unwrap(), state);
-        Ok(())
+    fn call(&self, request: Request, state: &Repository) -> anyhow::Result<Option<event::Kind>> {
+        Self::handle_request(request.try_into()?, state).map(Some)
     }
 }
 
 struct SetRequest {
-    key: String,
-    value: String,
+    key: Vec<u8>,
+    value: Vec<u8>,
+    expiry: Option<SystemTime>,
 }
 
 impl TryFrom<Request> for SetRequest {
     type Error = anyhow::Error;
 
     fn try_from(value: Request) -> Result<Self, Self::Error> {
-        let mut args = value.into_standard().unwrap().args;
-        let value = args.swap_remove(1);
-        let key = args.swap_remove(0);
-        Ok(Self { key, value })
+        let mut parser = value.into_parser();
+        let key = parser.arg()?;
+        let value = parser.arg()?;
+        // the leader always sends expiries as PXAT
+        let expiry = parser
+            .option("PXAT")?
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        Ok(Self { key, value, expiry })
     }
 }
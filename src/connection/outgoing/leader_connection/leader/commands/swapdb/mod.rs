@@ -0,0 +1,41 @@
+use crate::{command::Command, event, repository::Repository, Request};
+
+pub struct SwapDb;
+
+impl SwapDb {
+    fn handle_request(request: SwapDbRequest, repo: &Repository) -> anyhow::Result<event::Kind> {
+        if !repo.swap(request.a, request.b) {
+            anyhow::bail!("ERR DB index is out of range");
+        }
+        Ok(event::Kind::SwapDb {
+            a: request.a,
+            b: request.b,
+        })
+    }
+}
+
+impl Command<Request, Option<event::Kind>, Repository> for SwapDb {
+    fn info(&self) -> crate::command::CommandInfo {
+        crate::command::CommandInfo::new_name("SWAPDB")
+    }
+
+    fn call(&self, request: Request, state: &Repository) -> anyhow::Result<Option<event::Kind>> {
+        Self::handle_request(request.try_into()?, state).map(Some)
+    }
+}
+
+struct SwapDbRequest {
+    a: usize,
+    b: usize,
+}
+
+impl TryFrom<Request> for SwapDbRequest {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Request) -> Result<Self, Self::Error> {
+        let mut parser = value.into_parser();
+        let a = parser.arg()?;
+        let b = parser.arg()?;
+        Ok(Self { a, b })
+    }
+}
@@ -1,4 +1,7 @@
-use crate::service::Service;
+use crate::{
+    repository::Repository,
+    service::{layers::command_router::CommandRouter, Service},
+};
 
 use super::Response;
 
@@ -44,14 +47,69 @@ impl<S> ResponseEater<S> {
 
 impl<Req, S> Service<Req> for ResponseEater<S>
 where
-    S: Service<Req, Error = anyhow::Error>,
+    S: Service<Req, Response = Option<crate::event::Kind>, Error = anyhow::Error>,
 {
     type Response = Response;
 
     type Error = anyhow::Error;
 
     fn call(&mut self, request: Req) -> Result<Self::Response, Self::Error> {
-        let _response = self.inner.call(request)?;
-        Ok(Response::NoResponse)
+        Ok(match self.inner.call(request)? {
+            Some(event) => Response::Replicate(event),
+            None => Response::NoResponse,
+        })
+    }
+}
+
+/// Runs commands in the database the leader last selected.
+/// The selection itself is replicated so followers of this server run them there too.
+pub struct Select {
+    repo: Repository,
+    router: &'static crate::command::CommandRouter<
+        crate::Request,
+        Option<crate::event::Kind>,
+        Repository,
+    >,
+    inner: CommandRouter<crate::Request, Option<crate::event::Kind>, Repository>,
+}
+
+impl Select {
+    pub fn new(
+        repo: Repository,
+        router: &'static crate::command::CommandRouter<
+            crate::Request,
+            Option<crate::event::Kind>,
+            Repository,
+        >,
+    ) -> Self {
+        Self {
+            inner: CommandRouter::new(repo.clone(), router),
+            repo,
+            router,
+        }
+    }
+}
+
+impl Service<crate::Request> for Select {
+    type Response = Option<crate::event::Kind>;
+
+    type Error = anyhow::Error;
+
+    fn call(&mut self, request: crate::Request) -> Result<Self::Response, Self::Error> {
+        if !request
+            .command()
+            .is_some_and(|command| command.eq_ignore_ascii_case("SELECT"))
+        {
+            return self.inner.call(request);
+        }
+        let mut parser = request.into_parser();
+        let index = parser.arg()?;
+        parser.finish()?;
+        let repo = self
+            .repo
+            .select(index)
+            .ok_or_else(|| anyhow::anyhow!("ERR DB index is out of range"))?;
+        self.inner = CommandRouter::new(repo, self.router);
+        Ok(Some(crate::event::Kind::Select { db: index }))
     }
 }
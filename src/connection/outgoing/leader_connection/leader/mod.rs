@@ -1,11 +1,11 @@
 use crate::service::layers::command_router::Routeable;
 use crate::service::Service;
+use crate::Request;
 use crate::{
     event::{self},
     repository::Repository,
     resp,
 };
-use crate::{service, Request};
 
 mod commands;
 mod layers;
@@ -16,9 +16,7 @@ mod tests;
 
 pub use response::Response;
 
-type LeaderService = layers::ReplConf<
-    layers::ResponseEater<service::layers::command_router::CommandRouter<Request, (), Repository>>,
->;
+type LeaderService = layers::ReplConf<layers::ResponseEater<layers::Select>>;
 
 impl Routeable for Request {
     fn route_name(&self) -> Vec<u8> {
@@ -28,17 +26,23 @@ impl Routeable for Request {
 
 pub struct Leader {
     service: LeaderService,
+    emitter: event::EventEmitter,
 }
 
 impl Leader {
     pub fn new(
-        router: &'static crate::command::CommandRouter<Request, (), Repository>,
+        router: &'static crate::command::CommandRouter<
+            Request,
+            Option<crate::event::Kind>,
+            Repository,
+        >,
+        emitter: event::EventEmitter,
         repo: Repository,
     ) -> Self {
-        let service = layers::ReplConf::new(layers::ResponseEater::new(
-            service::layers::command_router::CommandRouter::new(repo, router),
-        ));
-        Self { service }
+        let service = layers::ReplConf::new(layers::ResponseEater::new(layers::Select::new(
+            repo, router,
+        )));
+        Self { service, emitter }
     }
 
     pub fn handle_request(&mut self, request: Request) -> anyhow::Result<LeaderResponse> {
@@ -51,6 +55,10 @@ impl Leader {
         };
         Ok(match result {
             Response::NoResponse => LeaderResponse::NONE,
+            Response::Replicate(event) => {
+                self.emitter.emit(event);
+                LeaderResponse::NONE
+            }
         })
     }
 }
@@ -70,8 +78,46 @@ impl LeaderResponse {
 
 #[must_use]
 pub fn default_leader_router(
-) -> &'static crate::command::CommandRouter<crate::Request, (), Repository> {
+) -> &'static crate::command::CommandRouter<crate::Request, Option<crate::event::Kind>, Repository>
+{
+    use crate::connection::incoming::client_connection::client::commands as client;
+    use commands::replay::Replay;
+
     let mut router = crate::command::CommandRouter::new();
-    router.add(commands::set::Set).add(commands::ping::Ping);
+    router
+        .add(commands::set::Set)
+        .add(commands::ping::Ping)
+        .add(commands::del::Del)
+        .add(commands::rename::Rename)
+        .add(commands::copy::Copy)
+        .add(commands::flushdb::FlushDb)
+        .add(commands::flushall::FlushAll)
+        .add(commands::r#move::Move)
+        .add(commands::swapdb::SwapDb)
+        .add(commands::pexpireat::PExpireAt)
+        .add(commands::persist::Persist)
+        .add(Replay(client::lpush::LPush))
+        .add(Replay(client::rpush::RPush))
+        .add(Replay(client::lpop::LPop))
+        .add(Replay(client::rpop::RPop))
+        .add(Replay(client::lset::LSet))
+        .add(Replay(client::linsert::LInsert))
+        .add(Replay(client::lrem::LRem))
+        .add(Replay(client::ltrim::LTrim))
+        .add(Replay(client::lmove::LMove))
+        .add(Replay(client::hset::HSet))
+        .add(Replay(client::hdel::HDel))
+        .add(Replay(client::sadd::SAdd))
+        .add(Replay(client::srem::SRem))
+        .add(Replay(client::smove::SMove))
+        .add(Replay(client::sinterstore::SInterStore))
+        .add(Replay(client::sunionstore::SUnionStore))
+        .add(Replay(client::sdiffstore::SDiffStore))
+        .add(Replay(client::zadd::ZAdd))
+        .add(Replay(client::zrem::ZRem))
+        .add(Replay(client::zinterstore::ZInterStore))
+        .add(Replay(client::zunionstore::ZUnionStore))
+        .add(Replay(client::zrangestore::ZRangeStore))
+        .add(Replay(client::xadd::XAdd));
     Box::leak(Box::new(router))
 }
@@ -1,3 +1,5 @@
 pub enum Response {
     NoResponse,
+    /// Nothing is sent back to the leader, but the write is passed on to our own followers.
+    Replicate(crate::event::Kind),
 }
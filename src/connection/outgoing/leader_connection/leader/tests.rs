@@ -1,6 +1,6 @@
 use event::EventEmitter;
 
-use crate::message::request::Standard;
+use crate::{message::request::Standard, repository::key_repo};
 
 use super::*;
 
@@ -15,7 +15,7 @@ impl Test {
         let repo = Repository::default();
         let emitter = EventEmitter::new();
         Self {
-            leader: Leader::new(default_leader_router(), repo.clone()),
+            leader: Leader::new(default_leader_router(), emitter.clone(), repo.clone()),
             emitter,
             repo,
         }
@@ -39,15 +39,206 @@ fn leader_sets_and_emitts_event_silently() {
     let request = Standard::new("SET", [key, value]);
     test.send_request_assert_recive_none(request);
     assert_eq!(
-        test.repo.kv_repo().get(key, std::time::UNIX_EPOCH).unwrap(),
-        Some(value.to_string())
+        test.repo
+            .kv_repo()
+            .get(key.as_bytes(), std::time::UNIX_EPOCH)
+            .unwrap(),
+        Some(value.into())
     );
     assert_eq!(
         subscriber.try_recive().unwrap(),
         event::Kind::Set {
-            key: key.to_string(),
-            value: value.to_string(),
+            key: key.into(),
+            value: value.into(),
             expiry: None
         }
     );
 }
+
+#[test]
+fn leader_applies_replicated_key_commands() {
+    let mut test = Test::setup();
+    test.send_request_assert_recive_none(Standard::new("SET", ["a", "1", "PXAT", "1"]));
+    test.send_request_assert_recive_none(Standard::new("SET", ["b", "2"]));
+    test.send_request_assert_recive_none(Standard::new("COPY", ["b", "c", "REPLACE"]));
+    test.send_request_assert_recive_none(Standard::new("RENAME", ["c", "d"]));
+    test.send_request_assert_recive_none(Standard::new("DEL", ["b"]));
+    let now = std::time::SystemTime::now();
+    let keys = test.repo.key_repo();
+    assert_eq!(keys.exists(&[b"a".to_vec()], now), 0);
+    assert_eq!(keys.exists(&[b"b".to_vec(), b"c".to_vec()], now), 0);
+    assert_eq!(
+        test.repo.kv_repo().get(b"d", now).unwrap(),
+        Some(b"2".to_vec())
+    );
+    test.send_request_assert_recive_none(Standard::new("FLUSHALL", [] as [&str; 0]));
+    assert!(test.repo.key_repo().is_empty());
+}
+
+#[test]
+fn leader_selects_the_database_of_the_commands_after_select() {
+    let mut test = Test::setup();
+    test.send_request_assert_recive_none(Standard::new("SELECT", ["3"]));
+    test.send_request_assert_recive_none(Standard::new("SET", ["k", "v"]));
+    test.send_request_assert_recive_none(Standard::new("SWAPDB", ["3", "0"]));
+    let now = std::time::SystemTime::now();
+    assert_eq!(
+        test.repo.kv_repo().get(b"k", now).unwrap(),
+        Some(b"v".to_vec())
+    );
+    test.send_request_assert_recive_none(Standard::new("SELECT", ["0"]));
+    test.send_request_assert_recive_none(Standard::new("MOVE", ["k", "3"]));
+    assert!(test.repo.key_repo().is_empty());
+}
+
+#[test]
+fn leader_passes_selected_database_on_to_its_followers() {
+    let mut test = Test::setup();
+    let subscriber = test.emitter.subscribe();
+    test.send_request_assert_recive_none(Standard::new("SELECT", ["2"]));
+    test.send_request_assert_recive_none(Standard::new("DEL", ["k"]));
+    test.send_request_assert_recive_none(Standard::new("PING", [] as [&str; 0]));
+    assert_eq!(subscriber.try_recive(), Some(event::Kind::Select { db: 2 }));
+    assert_eq!(
+        subscriber.try_recive(),
+        Some(event::Kind::Del {
+            keys: vec![b"k".to_vec()]
+        })
+    );
+    assert_eq!(subscriber.try_recive(), None);
+}
+
+#[test]
+fn leader_applies_replicated_expiries() {
+    let mut test = Test::setup();
+    let subscriber = test.emitter.subscribe();
+    let millis = (std::time::SystemTime::now() + std::time::Duration::from_secs(100))
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis()
+        .to_string();
+    test.send_request_assert_recive_none(Standard::new("SET", ["k", "v"]));
+    test.send_request_assert_recive_none(Standard::new("PEXPIREAT", ["k", &millis]));
+    let now = std::time::SystemTime::now();
+    let at = std::time::UNIX_EPOCH + std::time::Duration::from_millis(millis.parse().unwrap());
+    assert_eq!(
+        test.repo.key_repo().ttl(b"k", now),
+        key_repo::Ttl::Expires(at)
+    );
+    test.send_request_assert_recive_none(Standard::new("PERSIST", ["k"]));
+    assert_eq!(
+        test.repo.key_repo().ttl(b"k", now),
+        key_repo::Ttl::Persistent
+    );
+    test.send_request_assert_recive_none(Standard::new("PEXPIREAT", ["k", "1"]));
+    assert_eq!(test.repo.key_repo().ttl(b"k", now), key_repo::Ttl::Missing);
+
+    let _set = subscriber.try_recive();
+    assert_eq!(
+        subscriber.try_recive(),
+        Some(event::Kind::Expire {
+            key: b"k".to_vec(),
+            at: Some(at)
+        })
+    );
+    assert_eq!(
+        subscriber.try_recive(),
+        Some(event::Kind::Expire {
+            key: b"k".to_vec(),
+            at: None
+        })
+    );
+}
+
+#[test]
+fn leader_applies_and_passes_on_replicated_list_writes() {
+    let mut test = Test::setup();
+    let subscriber = test.emitter.subscribe();
+    test.send_request_assert_recive_none(Standard::new("RPUSH", ["l", "a", "b", "c", "d"]));
+    test.send_request_assert_recive_none(Standard::new("LPUSH", ["l", "z"]));
+    test.send_request_assert_recive_none(Standard::new("LPOP", ["l", "1"]));
+    test.send_request_assert_recive_none(Standard::new("RPOP", ["l", "1"]));
+    test.send_request_assert_recive_none(Standard::new("LSET", ["l", "0", "x"]));
+    test.send_request_assert_recive_none(Standard::new("LINSERT", ["l", "AFTER", "x", "y"]));
+    test.send_request_assert_recive_none(Standard::new("LREM", ["l", "0", "b"]));
+    test.send_request_assert_recive_none(Standard::new("LTRIM", ["l", "0", "1"]));
+    test.send_request_assert_recive_none(Standard::new("LMOVE", ["l", "m", "LEFT", "RIGHT"]));
+    let now = std::time::SystemTime::now();
+    let lists = test.repo.list_repo();
+    assert_eq!(lists.range(b"l", 0, -1, now).unwrap(), vec![b"y".to_vec()]);
+    assert_eq!(lists.range(b"m", 0, -1, now).unwrap(), vec![b"x".to_vec()]);
+
+    assert_eq!(
+        subscriber.try_recive(),
+        Some(event::Kind::Command {
+            args: ["RPUSH", "l", "a", "b", "c", "d"]
+                .map(|arg| arg.as_bytes().to_vec())
+                .to_vec()
+        })
+    );
+    let replayed = std::iter::from_fn(|| subscriber.try_recive()).count();
+    assert_eq!(replayed, 8);
+}
+
+#[test]
+fn leader_applies_replicated_hash_writes() {
+    let mut test = Test::setup();
+    let subscriber = test.emitter.subscribe();
+    test.send_request_assert_recive_none(Standard::new("HSET", ["h", "a", "1", "b", "2"]));
+    test.send_request_assert_recive_none(Standard::new("HDEL", ["h", "a"]));
+    let now = std::time::SystemTime::now();
+    let hashes = test.repo.hash_repo();
+    assert_eq!(hashes.get(b"h", b"a", now).unwrap(), None);
+    assert_eq!(hashes.get(b"h", b"b", now).unwrap(), Some(b"2".to_vec()));
+    assert_eq!(std::iter::from_fn(|| subscriber.try_recive()).count(), 2);
+}
+
+#[test]
+fn leader_applies_replicated_set_writes() {
+    let mut test = Test::setup();
+    test.send_request_assert_recive_none(Standard::new("SADD", ["s", "a", "b", "c"]));
+    test.send_request_assert_recive_none(Standard::new("SREM", ["s", "a"]));
+    test.send_request_assert_recive_none(Standard::new("SMOVE", ["s", "t", "b"]));
+    test.send_request_assert_recive_none(Standard::new("SUNIONSTORE", ["u", "s", "t"]));
+    let now = std::time::SystemTime::now();
+    let sets = test.repo.set_repo();
+    assert_eq!(sets.members(b"s", now).unwrap(), vec![b"c".to_vec()]);
+    assert_eq!(sets.members(b"t", now).unwrap(), vec![b"b".to_vec()]);
+    assert_eq!(sets.len(b"u", now).unwrap(), 2);
+}
+
+#[test]
+fn leader_applies_replicated_sorted_set_writes() {
+    let mut test = Test::setup();
+    test.send_request_assert_recive_none(Standard::new(
+        "ZADD",
+        ["z", "1", "a", "2", "b", "3", "c"],
+    ));
+    test.send_request_assert_recive_none(Standard::new(
+        "ZADD",
+        ["z", "GT", "CH", "0", "a", "5", "b"],
+    ));
+    test.send_request_assert_recive_none(Standard::new("ZREM", ["z", "c"]));
+    test.send_request_assert_recive_none(Standard::new(
+        "ZUNIONSTORE",
+        ["u", "1", "z", "WEIGHTS", "2"],
+    ));
+    let now = std::time::SystemTime::now();
+    let zsets = test.repo.zset_repo();
+    assert_eq!(zsets.score(b"z", b"a", now).unwrap(), Some(1.0));
+    assert_eq!(zsets.score(b"z", b"b", now).unwrap(), Some(5.0));
+    assert_eq!(zsets.score(b"z", b"c", now).unwrap(), None);
+    assert_eq!(zsets.score(b"u", b"b", now).unwrap(), Some(10.0));
+}
+
+#[test]
+fn leader_adds_replicated_stream_entries_with_their_id() {
+    use crate::repository::stream_repo::stream::{Entry, EntryId, Field};
+
+    let mut test = Test::setup();
+    test.send_request_assert_recive_none(Standard::new("XADD", ["s", "5-1", "f", "v"]));
+    assert_eq!(
+        test.repo.stream_repo().read_last("s").unwrap(),
+        Entry::new(EntryId::new(5, 1), vec![Field::new("f", "v")])
+    );
+}
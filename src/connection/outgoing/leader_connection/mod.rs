@@ -8,7 +8,6 @@ use crate::{
     },
     event::{EmitAll, EventEmitter},
     repository::Repository,
-    resp::value::deserialize::util::GetHeader,
 };
 
 pub mod leader;
@@ -25,13 +24,17 @@ where
 {
     pub fn new(
         connection: PipelineBuffer<S>,
-        router: &'static crate::command::CommandRouter<crate::Request, (), Repository>,
+        router: &'static crate::command::CommandRouter<
+            crate::Request,
+            Option<crate::event::Kind>,
+            Repository,
+        >,
         emitter: EventEmitter,
         repo: Repository,
     ) -> Self {
         Self {
             connection,
-            leader: Leader::new(router, repo),
+            leader: Leader::new(router, emitter.clone(), repo),
             emitter,
         }
     }
@@ -54,11 +57,11 @@ where
 
     #[instrument(name = "handle_leader_request", skip(self))]
     fn handle_request(&mut self, request_id: usize) -> Result<(), error::Error> {
-        let message = self.connection.read()?;
+        let message = self.connection.read_request()?;
 
         tracing::debug!("got message from leader {message:?}");
         let leader::LeaderResponse { value, events } =
-            self.leader.handle_request(message.into()).unwrap();
+            self.leader.handle_request(message.into_content()).unwrap();
 
         if let Some(response) = value {
             tracing::debug!("sending value [{response:?}]");
@@ -76,38 +79,12 @@ where
         let mut response = None;
         while let Some(next) = handshake.try_advance(&response).unwrap() {
             self.connection.write(&next.into()).unwrap();
-            let message = self.connection.read().unwrap();
+            // read one value at a time so the rdb file is not decoded as a value
+            let message = self.connection.inner().read().unwrap();
             response = Some(message);
         }
-        let mut rdb_buf = [0; 1024];
-        let mut bytes_read = 0;
-        let max_reads = 30;
-        while bytes_read < max_reads && !rdb_buf.contains(&b'\n') {
-            if bytes_read > 0 {
-                assert_eq!(
-                    rdb_buf[0], b'$',
-                    "expected rdb file header got: {}",
-                    rdb_buf[0] as char
-                );
-            }
-            bytes_read += self
-                .connection
-                .inner()
-                .inner()
-                .read(&mut rdb_buf[bytes_read..])
-                .unwrap();
-        }
-        let (file_size, header_size) = rdb_buf.get_header().unwrap();
-        let read_of_file = bytes_read - header_size;
-        let file_bytes_remaining = TryInto::<usize>::try_into(file_size).unwrap() - read_of_file;
-        assert!(file_bytes_remaining < 1024, "rdb file too large");
-        self.connection
-            .inner()
-            .inner()
-            .read_exact(&mut rdb_buf[..file_bytes_remaining])
-            .unwrap();
-        //tracing::debug!("{:?}", &rdb_buf[..bytes_read]);
-        //tracing::debug!("{bytes_read}");
+        let rdb = self.connection.inner().read_rdb()?;
+        tracing::debug!("received rdb file of {} bytes", rdb.len());
         Ok(1)
     }
 }
@@ -117,5 +94,7 @@ pub mod error {
     pub enum Error {
         #[error("stream error: {0}")]
         Stream(#[from] crate::connection::stream::Error),
+        #[error("invalid request: {0}")]
+        Request(#[from] anyhow::Error),
     }
 }
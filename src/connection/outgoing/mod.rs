@@ -18,7 +18,11 @@ pub struct OutgoingConnection<S> {
     connection: PipelineBuffer<S>,
     emitter: EventEmitter,
     repo: Repository,
-    router: &'static crate::command::CommandRouter<crate::Request, (), Repository>,
+    router: &'static crate::command::CommandRouter<
+        crate::Request,
+        Option<crate::event::Kind>,
+        Repository,
+    >,
 }
 
 impl<S> OutgoingConnection<S>
@@ -27,7 +31,11 @@ where
 {
     pub fn new(
         connection: S,
-        router: &'static crate::command::CommandRouter<crate::Request, (), Repository>,
+        router: &'static crate::command::CommandRouter<
+            crate::Request,
+            Option<crate::event::Kind>,
+            Repository,
+        >,
         emitter: EventEmitter,
         repo: Repository,
     ) -> Self {
@@ -41,7 +49,11 @@ where
 
     pub fn connect(
         addr: std::net::SocketAddrV4,
-        router: &'static crate::command::CommandRouter<crate::Request, (), Repository>,
+        router: &'static crate::command::CommandRouter<
+            crate::Request,
+            Option<crate::event::Kind>,
+            Repository,
+        >,
         emitter: EventEmitter,
         repo: Repository,
     ) -> anyhow::Result<Self> {
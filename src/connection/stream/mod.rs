@@ -32,6 +32,8 @@ mod error {
         ConnectinClosedUnexpectedly(std::io::Error),
         #[error("io error: {0}")]
         IoError(std::io::Error),
+        #[error("protocol error: {0}")]
+        Protocol(anyhow::Error),
     }
 
     impl From<std::io::Error> for Error {
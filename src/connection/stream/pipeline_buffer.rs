@@ -1,7 +1,10 @@
 use std::fmt::Debug;
 
 use crate::{
-    resp::{self, value::serialize_value},
+    resp::{
+        self,
+        value::{deserialize::Limits, serialize_into_as},
+    },
     Message,
 };
 
@@ -11,6 +14,7 @@ use super::{RedisConnection, Stream};
 pub struct PipelineBuffer<S> {
     pub(super) connection: RedisConnection<S>,
     read_buffer: Vec<Message<resp::Value>>,
+    request_buffer: Vec<Message<crate::Request>>,
     write_buffer: Vec<u8>,
 }
 
@@ -22,6 +26,7 @@ where
         Self {
             connection: RedisConnection::new(stream),
             read_buffer: Vec::new(),
+            request_buffer: Vec::new(),
             write_buffer: Vec::new(),
         }
     }
@@ -39,15 +44,29 @@ where
         }
     }
 
+    /// Like [`Self::read`] but for requests, see [`RedisConnection::read_request`].
+    pub fn read_request(&mut self) -> super::Result<Message<crate::Request>> {
+        if let Some(request) = self.request_buffer.pop() {
+            return Ok(request);
+        }
+        self.request_buffer
+            .extend(self.connection.read_all_requests()?.into_iter().rev());
+        tracing::trace!("requests read into buffer: {:?}", self.request_buffer);
+        Ok(self
+            .request_buffer
+            .pop()
+            .expect("reading inner returns at least one request"))
+    }
+
     pub fn write(&mut self, value: &resp::Value) -> super::Result<usize> {
-        let value = serialize_value(value);
-        let len = value.len();
+        let start = self.write_buffer.len();
+        serialize_into_as(value, self.connection.protocol(), &mut self.write_buffer)?;
+        let len = self.write_buffer.len() - start;
         tracing::trace!(
-            "value written to buffer: {value:?}, {:?}",
-            String::from_utf8_lossy(&value)
+            "value written to buffer: {:?}",
+            String::from_utf8_lossy(&self.write_buffer[start..])
         );
-        self.write_buffer.extend(value);
-        if self.read_buffer.is_empty() {
+        if self.read_buffer.is_empty() && self.request_buffer.is_empty() {
             self.connection.inner().write_all(&self.write_buffer)?;
             tracing::trace!(
                 "values written from buffer {:?}, {:?}",
@@ -62,6 +81,19 @@ where
         }
     }
 
+    #[must_use]
+    pub fn protocol(&self) -> resp::Protocol {
+        self.connection.protocol()
+    }
+
+    pub fn set_protocol(&mut self, protocol: resp::Protocol) {
+        self.connection.set_protocol(protocol);
+    }
+
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.connection.set_limits(limits);
+    }
+
     pub fn inner(&mut self) -> &mut RedisConnection<S> {
         &mut self.connection
     }
@@ -3,7 +3,11 @@ use std::fmt::Debug;
 use crate::{
     resp::{
         self,
-        value::{deserialize_value, serialize_value},
+        value::{
+            deserialize::{Decoder, Limits},
+            serialize_into_as,
+        },
+        ValueRef,
     },
     Message,
 };
@@ -13,8 +17,11 @@ use super::Stream;
 #[derive(Debug)]
 pub struct RedisConnection<S> {
     pub(super) stream: S,
-    buf: [u8; 1024],
-    i: usize,
+    decoder: Decoder,
+    write_buffer: Vec<u8>,
+    protocol: resp::Protocol,
+    /// A request that was decoded but could not be read while reading ahead.
+    pending_error: Option<anyhow::Error>,
 }
 
 impl<S> RedisConnection<S>
@@ -22,73 +29,160 @@ where
     S: Stream,
 {
     pub fn read(&mut self) -> super::Result<Message<resp::Value>> {
-        if self.i == 0 {
-            tracing::trace!("reading from stream");
-            let bytes_read = self.stream.read(&mut self.buf)?;
-            tracing::trace!(
-                "read from stream: {:?}",
-                String::from_utf8_lossy(&self.buf[..bytes_read])
-            );
-            self.i = bytes_read;
+        loop {
+            if let Some((value, bytes_consumed)) =
+                self.decoder.decode().map_err(super::Error::Protocol)?
+            {
+                tracing::trace!("read value: [{value:?}]");
+                return Ok(Message::new(value, bytes_consumed));
+            }
+            self.fill_buffer()?;
         }
-        let (value, bytes_consumed) = deserialize_value(&self.buf).unwrap();
-        tracing::trace!("deserialized value: {value:?}");
-        self.buf.rotate_left(bytes_consumed);
-        self.i -= bytes_consumed;
-        tracing::trace!("read value: [{value:?}]");
-        Ok(Message::new(value, bytes_consumed))
     }
 
+    /// Reads at least one value, then every other value that is already fully buffered.
     pub fn read_all(&mut self) -> super::Result<Vec<Message<resp::Value>>> {
-        let mut values = Vec::new();
-        for i in 0..100 {
-            let value = self.read()?;
-            values.push(value);
-            assert!(i != 90);
-            if self.i == 0 {
-                break;
-            }
+        let mut values = vec![self.read()?];
+        // a decode error is left in the buffer and returned by the next read
+        while let Ok(Some((value, bytes_consumed))) = self.decoder.decode() {
+            tracing::trace!("read value: [{value:?}]");
+            values.push(Message::new(value, bytes_consumed));
         }
         Ok(values)
     }
 
-    pub fn write(&mut self, value: &resp::Value) -> super::Result<usize> {
-        tracing::trace!("serializing value: {value:?}");
-        let bytes = serialize_value(value);
-        tracing::trace!(
-            "value serialized: {bytes:?}, {:?}",
-            String::from_utf8_lossy(&bytes)
-        );
-        self.stream.write_all(&bytes)?;
-        self.stream.flush()?;
+    /// Reads the next request, built straight from the frame borrowed from the read buffer
+    /// so the arguments are copied once, into the request, without an owned value in between.
+    /// Requests still own their arguments since they outlive the buffer, queued by MULTI or
+    /// forwarded to followers, so commands that only read, like GET, copy them as well.
+    pub fn read_request(&mut self) -> super::Result<Message<crate::Request>> {
+        loop {
+            if let Some(request) = self.decode_request()? {
+                return Ok(request);
+            }
+            self.fill_buffer()?;
+        }
+    }
+
+    /// Reads at least one request, then every other request that is already fully buffered.
+    pub fn read_all_requests(&mut self) -> super::Result<Vec<Message<crate::Request>>> {
+        let mut requests = vec![self.read_request()?];
+        loop {
+            match self.decode_request() {
+                Ok(Some(request)) => requests.push(request),
+                Ok(None) => break,
+                // the frame is already consumed, so the error is kept for the next read
+                Err(super::Error::Protocol(err)) => {
+                    self.pending_error = Some(err);
+                    break;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(requests)
+    }
+
+    fn decode_request(&mut self) -> super::Result<Option<Message<crate::Request>>> {
+        if let Some(err) = self.pending_error.take() {
+            return Err(super::Error::Protocol(err));
+        }
+        loop {
+            let Some((value, bytes_consumed)) =
+                self.decoder.decode_ref().map_err(super::Error::Protocol)?
+            else {
+                return Ok(None);
+            };
+            tracing::trace!("read request: [{value:?}]");
+            // like redis, empty requests are skipped
+            if matches!(&value, ValueRef::Array(args) if args.is_empty()) {
+                continue;
+            }
+            let request = crate::Request::try_from(value).map_err(super::Error::Protocol)?;
+            return Ok(Some(Message::new(request, bytes_consumed)));
+        }
+    }
+
+    /// Reads the rdb file sent by the leader after a full resync.
+    pub fn read_rdb(&mut self) -> super::Result<Vec<u8>> {
+        loop {
+            if let Some(rdb) = self
+                .decoder
+                .decode_unterminated_bulk()
+                .map_err(super::Error::Protocol)?
+            {
+                return Ok(rdb);
+            }
+            self.fill_buffer()?;
+        }
+    }
+
+    fn fill_buffer(&mut self) -> super::Result<()> {
+        tracing::trace!("reading from stream");
+        let bytes_read = self.decoder.read_from(&mut self.stream)?;
+        if bytes_read == 0 {
+            return Err(if self.decoder.is_empty() {
+                super::Error::StreamClosed
+            } else {
+                super::Error::ConnectinClosedUnexpectedly(std::io::ErrorKind::UnexpectedEof.into())
+            });
+        }
         tracing::trace!(
-            "serialized value flushed to stream. length: {}",
-            bytes.len()
+            "read from stream: {:?}",
+            String::from_utf8_lossy(self.decoder.pending())
         );
-        Ok(bytes.len())
+        Ok(())
+    }
+
+    pub fn write(&mut self, value: &resp::Value) -> super::Result<usize> {
+        self.write_all(std::slice::from_ref(value))
     }
 
+    /// Serializes the values into the reused write buffer and sends them in a single write.
     pub fn write_all(&mut self, values: &[resp::Value]) -> super::Result<usize> {
         tracing::trace!("serializing values: {values:?}");
-        let bytes = values.iter().flat_map(serialize_value).collect::<Vec<u8>>();
+        self.write_buffer.clear();
+        for value in values {
+            serialize_into_as(value, self.protocol, &mut self.write_buffer)?;
+        }
         tracing::trace!(
-            "values serialized: {bytes:?}, {:?}",
-            String::from_utf8_lossy(&bytes)
+            "values serialized: {:?}",
+            String::from_utf8_lossy(&self.write_buffer)
         );
-        self.stream.write_all(&bytes)?;
+        self.stream.write_all(&self.write_buffer)?;
         self.stream.flush()?;
-        tracing::trace!("{}, bytes flushed to stream", bytes.len());
-        Ok(bytes.len())
+        tracing::trace!("{}, bytes flushed to stream", self.write_buffer.len());
+        Ok(self.write_buffer.len())
     }
 
     pub fn new(stream: S) -> Self {
         Self {
             stream,
-            buf: [0; 1024],
-            i: 0,
+            decoder: Decoder::new(),
+            write_buffer: Vec::new(),
+            protocol: resp::Protocol::default(),
+            pending_error: None,
         }
     }
 
+    #[must_use]
+    pub fn protocol(&self) -> resp::Protocol {
+        self.protocol
+    }
+
+    pub fn set_protocol(&mut self, protocol: resp::Protocol) {
+        tracing::debug!("switching protocol to {protocol:?}");
+        self.protocol = protocol;
+    }
+
+    #[must_use]
+    pub fn limits(&self) -> &Limits {
+        self.decoder.limits()
+    }
+
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.decoder.set_limits(limits);
+    }
+
     pub fn inner(&mut self) -> &mut S {
         &mut self.stream
     }
@@ -277,8 +277,7 @@ fn pipeline_buffer_writes_to_stream_on_first_write_after_read_buffer_is_empty()
             .collect::<Vec<_>>();
         assert_eq!(
             res,
-            std::iter::repeat(resp::Value::simple_string("dummy"))
-                .take(len)
+            std::iter::repeat_n(resp::Value::simple_string("dummy"), len)
                 .collect::<Vec<resp::Value>>()
         );
     }
@@ -288,6 +287,173 @@ fn pipeline_buffer_writes_to_stream_on_first_write_after_read_buffer_is_empty()
     }
 }
 
+fn resp3_map() -> resp::Value {
+    resp::Value::Map(vec![
+        (resp::Value::bulk_string("proto"), resp::Value::Integer(3)),
+        (resp::Value::bulk_string("flag"), resp::Value::Boolean(true)),
+    ])
+}
+
+#[test]
+fn connection_defaults_to_resp2() {
+    let conn = RedisConnection::new(empty_stream());
+    assert_eq!(conn.protocol(), resp::Protocol::Resp2);
+}
+
+#[test]
+fn resp2_connection_writes_map_as_flat_array() {
+    let mut conn = RedisConnection::new(empty_stream());
+    conn.write(&resp3_map()).unwrap();
+    conn.inner().set_position(0);
+    let value = conn.read().unwrap().into_content();
+    assert_eq!(
+        value,
+        resp::Value::Array(vec![
+            resp::Value::bulk_string("proto"),
+            resp::Value::Integer(3),
+            resp::Value::bulk_string("flag"),
+            resp::Value::Integer(1),
+        ])
+    );
+}
+
+#[test]
+fn resp3_connection_writes_map() {
+    let mut conn = RedisConnection::new(empty_stream());
+    conn.set_protocol(resp::Protocol::Resp3);
+    conn.write(&resp3_map()).unwrap();
+    conn.inner().set_position(0);
+    let value = conn.read().unwrap().into_content();
+    assert_eq!(value, resp3_map());
+}
+
+#[test]
+fn pipeline_buffer_writes_with_connection_protocol() {
+    let mut connection = PipelineBuffer::new(empty_stream());
+    connection.set_protocol(resp::Protocol::Resp3);
+    assert_eq!(connection.inner().protocol(), resp::Protocol::Resp3);
+    connection.write(&resp::Value::Null).unwrap();
+    assert_eq!(connection.connection.stream.get_ref(), b"_\r\n");
+
+    let mut connection = PipelineBuffer::new(empty_stream());
+    connection.write(&resp::Value::Null).unwrap();
+    assert_eq!(connection.connection.stream.get_ref(), b"$-1\r\n");
+}
+
+/// Returns at most `chunk_size` bytes per read, like a socket receiving a frame in pieces.
+#[derive(Debug)]
+struct ChunkedStream {
+    inner: std::io::Cursor<Vec<u8>>,
+    chunk_size: usize,
+}
+
+impl ChunkedStream {
+    fn new(bytes: Vec<u8>, chunk_size: usize) -> Self {
+        Self {
+            inner: std::io::Cursor::new(bytes),
+            chunk_size,
+        }
+    }
+}
+
+impl std::io::Read for ChunkedStream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = buf.len().min(self.chunk_size);
+        self.inner.read(&mut buf[..len])
+    }
+}
+
+impl std::io::Write for ChunkedStream {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl Stream for ChunkedStream {
+    type Addr = ();
+
+    fn connect((): Self::Addr) -> anyhow::Result<Self>
+    where
+        Self: Sized,
+    {
+        unimplemented!("called connect on dummy stream")
+    }
+
+    fn peer_addr(&self) -> Self::Addr {
+        unimplemented!("called peer_addr on dummy stream")
+    }
+}
+
+#[test]
+fn connection_reads_value_split_over_many_reads() {
+    let value = resp::Value::bulk_strings("SET; key; value").into_array();
+    let mut connection = RedisConnection::new(ChunkedStream::new(serialize_value(&value), 1));
+    assert_eq!(connection.read().unwrap().into_content(), value);
+}
+
+#[test]
+fn connection_reads_payload_larger_than_a_single_read() {
+    let payload = "x".repeat(1024 * 1024);
+    let value = resp::Value::Array(vec![
+        resp::Value::bulk_string("SET"),
+        resp::Value::bulk_string("key"),
+        resp::Value::bulk_string(&payload),
+    ]);
+    let mut connection = RedisConnection::new(ChunkedStream::new(serialize_value(&value), 1500));
+    assert_eq!(connection.read().unwrap().into_content(), value);
+}
+
+#[test]
+fn read_all_returns_every_value_of_a_large_pipeline() {
+    let values = vec![resp::Value::bulk_strings("PING").into_array(); 1000];
+    let mut connection = RedisConnection::new(stream(values.clone()));
+    let mut read = Vec::new();
+    loop {
+        match connection.read_all() {
+            Ok(messages) => read.extend(messages.into_iter().map(crate::Message::into_content)),
+            Err(Error::StreamClosed) => break,
+            Err(err) => panic!("{err}"),
+        }
+    }
+    assert_eq!(read, values);
+}
+
+#[test]
+fn connection_returns_stream_closed_on_end_of_stream() {
+    let mut connection = RedisConnection::new(empty_stream());
+    assert!(matches!(connection.read(), Err(Error::StreamClosed)));
+}
+
+#[test]
+fn connection_returns_err_when_stream_ends_inside_a_value() {
+    let mut connection = RedisConnection::new(std::io::Cursor::new(b"$10\r\nabc".to_vec()));
+    assert!(matches!(
+        connection.read(),
+        Err(Error::ConnectinClosedUnexpectedly(_))
+    ));
+}
+
+#[test]
+fn connection_returns_protocol_error_on_invalid_value() {
+    let mut connection = RedisConnection::new(std::io::Cursor::new(b"$3\r\nabcd\r\n".to_vec()));
+    assert!(matches!(connection.read(), Err(Error::Protocol(_))));
+}
+
+#[test]
+fn connection_reads_rdb_file_followed_by_value() {
+    let mut connection =
+        RedisConnection::new(ChunkedStream::new(b"$5\r\nREDIS+PING\r\n".to_vec(), 3));
+    assert_eq!(connection.read_rdb().unwrap(), b"REDIS");
+    assert_eq!(
+        connection.read().unwrap().into_content(),
+        resp::Value::simple_string("PING")
+    );
+}
+
 impl Stream for std::io::Cursor<Vec<u8>> {
     type Addr = ();
 
@@ -317,3 +483,40 @@ impl Stream for &mut std::io::Cursor<Vec<u8>> {
         unimplemented!("called peer_addr on dummy stream")
     }
 }
+
+#[test]
+fn read_all_requests_skips_empty_requests_and_keeps_errors_for_the_next_read() {
+    let values = [
+        resp::Value::bulk_strings("SET; key; value").into_array(),
+        resp::Value::Array(Vec::new()),
+        resp::Value::Array(vec![
+            resp::Value::bulk_string("SET"),
+            resp::Value::BulkByteString(vec![0xff]),
+        ]),
+        resp::Value::Array(vec![resp::Value::Integer(1)]),
+        resp::Value::bulk_strings("PING").into_array(),
+    ];
+    let mut connection = RedisConnection::new(stream(values));
+    let requests = connection
+        .read_all_requests()
+        .unwrap()
+        .into_iter()
+        .map(crate::Message::into_content)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        requests,
+        [
+            crate::message::request::Standard::new("SET", ["key", "value"]).into(),
+            crate::message::request::StandrardByteString::new("SET".into(), vec![vec![0xff]])
+                .into(),
+        ]
+    );
+    let Err(Error::Protocol(err)) = connection.read_request() else {
+        panic!("expected protocol error");
+    };
+    assert_eq!(err.to_string(), "expected command name, got Integer(1)");
+    assert_eq!(
+        connection.read_request().unwrap().into_content().command(),
+        Some("PING")
+    );
+}
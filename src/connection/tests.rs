@@ -68,6 +68,7 @@ impl MockConnection {
         }
     }
 
+    #[allow(dead_code)]
     pub(crate) fn empty() -> MockConnection {
         Self {
             input: Vec::new(),
@@ -88,8 +89,13 @@ impl std::io::Read for MockConnection {
 
 impl std::io::Write for MockConnection {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        let value = deserialize_value(buf).unwrap().0;
-        self.write_value(value).unwrap();
+        // pipelined responses are flushed together so every value in `buf` is checked
+        let mut bytes = buf;
+        while !bytes.is_empty() {
+            let (value, bytes_consumed) = deserialize_value(bytes).unwrap();
+            self.write_value(value).unwrap();
+            bytes = &bytes[bytes_consumed..];
+        }
         Ok(buf.len())
     }
 
@@ -12,10 +12,48 @@ pub type EventSubscriber = ChannelEventSubscriber;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Kind {
     Set {
-        key: String,
-        value: String,
+        key: Vec<u8>,
+        value: Vec<u8>,
         expiry: Option<std::time::SystemTime>,
     },
+    Del {
+        keys: Vec<Vec<u8>>,
+    },
+    /// The key expires `at` the time, `None` removes its expiry.
+    Expire {
+        key: Vec<u8>,
+        at: Option<std::time::SystemTime>,
+    },
+    Rename {
+        key: Vec<u8>,
+        new_key: Vec<u8>,
+    },
+    Copy {
+        source: Vec<u8>,
+        destination: Vec<u8>,
+        /// The database it was copied to if not the one it was copied from.
+        db: Option<usize>,
+        replace: bool,
+    },
+    Move {
+        key: Vec<u8>,
+        db: usize,
+    },
+    /// The database the events after it happened in.
+    Select {
+        db: usize,
+    },
+    SwapDb {
+        a: usize,
+        b: usize,
+    },
+    FlushDb,
+    FlushAll,
+    /// A write of a type without events of its own, followers replay it as the command in
+    /// `args`, name first.
+    Command {
+        args: Vec<Vec<u8>>,
+    },
 }
 
 impl Kind {
@@ -33,7 +71,7 @@ where
     T: IntoIterator<Item = Kind>,
 {
     fn emit_all(self, emitter: &EventEmitter) {
-        self.into_iter().for_each(|e| e.emit(emitter));
+        emitter.emit_batch(self);
     }
 }
 
@@ -51,9 +89,17 @@ impl LockEventProducer {
     }
 
     pub fn emit(&self, kind: Kind) {
-        tracing::debug!("emitting event: {kind:?}");
+        self.emit_batch([kind]);
+    }
+
+    /// Emits `events` under a single lock, so events emitted from other threads cannot land
+    /// between them, like a write of another client between a select and the write after it.
+    pub fn emit_batch(&self, events: impl IntoIterator<Item = Kind>) {
         let mut lock = self.subscribers.lock().unwrap();
-        lock.retain(|tx| tx.send(kind.clone()).is_ok());
+        for kind in events {
+            tracing::debug!("emitting event: {kind:?}");
+            lock.retain(|tx| tx.send(kind.clone()).is_ok());
+        }
     }
 
     #[must_use]
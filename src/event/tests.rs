@@ -9,8 +9,8 @@ fn create_subscriber() {
 fn emit_event() {
     let emitter = EventEmitter::new();
     emitter.emit(Kind::Set {
-        key: "key".to_string(),
-        value: "value".to_string(),
+        key: "key".into(),
+        value: "value".into(),
         expiry: None,
     });
 }
@@ -70,3 +70,35 @@ fn iter_events() {
     let recived_events = subscriber.into_iter().collect::<Vec<_>>();
     assert_eq!(recived_events, events);
 }
+
+#[test]
+fn batches_emitted_from_other_threads_do_not_interleave() {
+    const THREADS: usize = 4;
+    const BATCHES: usize = 1000;
+    let emitter = EventEmitter::new();
+    let subscriber = emitter.subscribe();
+    let start = Arc::new(std::sync::Barrier::new(THREADS));
+    let threads = (0..THREADS)
+        .map(|db| {
+            let (emitter, start) = (emitter.clone(), Arc::clone(&start));
+            std::thread::spawn(move || {
+                start.wait();
+                for _ in 0..BATCHES {
+                    [Kind::Select { db }, Kind::FlushDb].emit_all(&emitter);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    threads
+        .into_iter()
+        .for_each(|thread| thread.join().unwrap());
+    drop(emitter);
+    let events = subscriber.into_iter().collect::<Vec<_>>();
+    assert_eq!(events.len(), THREADS * BATCHES * 2);
+    for batch in events.chunks(2) {
+        assert!(
+            matches!(batch, [Kind::Select { .. }, Kind::FlushDb]),
+            "{batch:?}"
+        );
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests;
+
+/// Matches `string` against a redis glob pattern.
+/// `*` matches any bytes, `?` a single byte, `[abc]`, `[a-z]` and `[^x]` a class of bytes
+/// and `\` escapes the next byte.
+#[must_use]
+pub fn matches(pattern: &[u8], string: &[u8]) -> bool {
+    let (mut p, mut s) = (0, 0);
+    // where to resume after the last `*` if the rest does not match
+    let mut backtrack: Option<(usize, usize)> = None;
+    while s < string.len() {
+        let step = match pattern.get(p) {
+            Some(b'*') => {
+                backtrack = Some((p, s));
+                p += 1;
+                continue;
+            }
+            Some(b'?') => Some(p + 1),
+            Some(b'[') => match_class(pattern, p, string[s]),
+            Some(b'\\') if p + 1 < pattern.len() => (pattern[p + 1] == string[s]).then_some(p + 2),
+            Some(byte) => (*byte == string[s]).then_some(p + 1),
+            None => None,
+        };
+        match (step, backtrack) {
+            (Some(next), _) => {
+                p = next;
+                s += 1;
+            }
+            (None, Some((star, star_s))) => {
+                p = star + 1;
+                s = star_s + 1;
+                backtrack = Some((star, star_s + 1));
+            }
+            (None, None) => return false,
+        }
+    }
+    pattern[p.min(pattern.len())..]
+        .iter()
+        .all(|byte| *byte == b'*')
+}
+
+/// Matches `byte` against the class starting at `pattern[start] == b'['`,
+/// returns the index after the class if it matched.
+fn match_class(pattern: &[u8], start: usize, byte: u8) -> Option<usize> {
+    let mut p = start + 1;
+    let negate = pattern.get(p) == Some(&b'^');
+    if negate {
+        p += 1;
+    }
+    let mut matched = false;
+    // an unterminated class runs to the end of the pattern like in redis
+    while p < pattern.len() && pattern[p] != b']' {
+        if pattern[p] == b'\\' && p + 1 < pattern.len() {
+            matched |= pattern[p + 1] == byte;
+            p += 2;
+        } else if pattern.get(p + 1) == Some(&b'-') && p + 2 < pattern.len() {
+            let (a, b) = (pattern[p], pattern[p + 2]);
+            matched |= (a.min(b)..=a.max(b)).contains(&byte);
+            p += 3;
+        } else {
+            matched |= pattern[p] == byte;
+            p += 1;
+        }
+    }
+    (matched != negate).then_some(p + 1)
+}
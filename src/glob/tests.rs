@@ -0,0 +1,47 @@
+use super::matches;
+
+fn m(pattern: &str, string: &str) -> bool {
+    matches(pattern.as_bytes(), string.as_bytes())
+}
+
+#[test]
+fn literal() {
+    assert!(m("hello", "hello"));
+    assert!(!m("hello", "hell"));
+    assert!(!m("hell", "hello"));
+    assert!(m("", ""));
+}
+
+#[test]
+fn star() {
+    assert!(m("*", ""));
+    assert!(m("*", "anything"));
+    assert!(m("h*o", "hello"));
+    assert!(m("h*llo*", "heeeello world"));
+    assert!(m("*a*b", "xaxxab"));
+    assert!(!m("h*o", "help"));
+}
+
+#[test]
+fn question_mark() {
+    assert!(m("h?llo", "hallo"));
+    assert!(!m("h?llo", "hllo"));
+}
+
+#[test]
+fn classes() {
+    assert!(m("h[ae]llo", "hello"));
+    assert!(!m("h[ae]llo", "hillo"));
+    assert!(m("h[^e]llo", "hallo"));
+    assert!(!m("h[^e]llo", "hello"));
+    assert!(m("h[a-b]llo", "hbllo"));
+    assert!(m("h[b-a]llo", "hallo"));
+    assert!(!m("h[a-b]llo", "hcllo"));
+}
+
+#[test]
+fn escapes() {
+    assert!(m("h\\*llo", "h*llo"));
+    assert!(!m("h\\*llo", "hello"));
+    assert!(m("[\\]]", "]"));
+}
@@ -2,9 +2,11 @@ pub mod command;
 pub mod config;
 pub mod connection;
 pub mod event;
+pub mod glob;
 pub mod listner;
 pub mod message;
 pub mod radix;
+pub mod random;
 pub mod redis;
 pub mod repository;
 pub mod resp;
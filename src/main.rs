@@ -2,11 +2,13 @@ use std::net::{Ipv4Addr, SocketAddrV4};
 
 use clap::Parser;
 use rustis::{
+    config::RedisConfig,
     connection::stream::{self, Stream},
     event::EventEmitter,
     listner::{RedisListner, RedisTcpListner},
     redis::builder::RedisBuilder,
     repository::Repository,
+    resp::value::deserialize::Limits,
 };
 
 fn main() {
@@ -15,13 +17,19 @@ fn main() {
 
     let port = args.port.unwrap_or(6379);
     let addr = SocketAddrV4::new(Ipv4Addr::LOCALHOST, port);
-    let repo = Repository::default();
+    let repo = Repository::with_databases(args.databases);
     let emitter = EventEmitter::new();
 
     let builder = RedisBuilder::<RedisTcpListner, stream::TcpStream>::new()
         .listner(RedisTcpListner::bind(port).unwrap())
         .repo(repo)
-        .emitter(emitter);
+        .emitter(emitter)
+        .limits(Limits {
+            max_bulk_len: args.proto_max_bulk_len,
+            max_multibulk_len: args.max_multibulk_len,
+            ..Limits::default()
+        })
+        .hz(args.hz);
 
     let redis = if let Some(leader_port) = args.replicaof {
         builder.leader_connection(
@@ -49,4 +57,20 @@ struct Args {
 
     #[arg(long)]
     replicaof: Option<u16>,
+
+    /// Largest bulk string a client may send, in bytes.
+    #[arg(long, default_value_t = Limits::DEFAULT_MAX_BULK_LEN)]
+    proto_max_bulk_len: usize,
+
+    /// Largest number of elements in an array a client may send.
+    #[arg(long, default_value_t = Limits::DEFAULT_MAX_MULTIBULK_LEN)]
+    max_multibulk_len: usize,
+
+    /// How often background tasks like expiring keys run per second.
+    #[arg(long, default_value_t = RedisConfig::DEFAULT_HZ, value_parser = clap::value_parser!(u32).range(1..=500))]
+    hz: u32,
+
+    /// The number of databases clients can SELECT.
+    #[arg(long, default_value_t = Repository::DEFAULT_DATABASES, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    databases: usize,
 }
@@ -2,6 +2,9 @@ use crate::resp;
 
 pub mod request;
 
+#[cfg(test)]
+mod tests;
+
 #[derive(Debug)]
 pub struct Message<T> {
     content: T,
@@ -1,4 +1,6 @@
-use crate::resp;
+use anyhow::{anyhow, bail};
+
+use crate::{command::parser::Parser, resp};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Request {
@@ -15,6 +17,26 @@ impl Request {
         }
     }
 
+    #[must_use]
+    pub fn into_byte_args(self) -> Vec<Vec<u8>> {
+        match self {
+            Self::Standard(s) => s.args.into_iter().map(String::into_bytes).collect(),
+            Self::StandardByteString(b) => b.args,
+        }
+    }
+
+    /// A parser over the arguments, named after the command for its error messages.
+    #[must_use]
+    pub fn into_parser(self) -> Parser {
+        let command = self.command().unwrap_or_default().to_string();
+        let args = self
+            .into_byte_args()
+            .into_iter()
+            .map(resp::Value::BulkByteString)
+            .collect();
+        Parser::new(command, args)
+    }
+
     pub fn into_standard(self) -> Result<Standard, Self> {
         match self {
             Self::Standard(s) => Ok(s),
@@ -79,43 +101,85 @@ impl From<StandrardByteString> for Request {
     }
 }
 
-impl From<super::Message<resp::Value>> for Request {
-    fn from(value: super::Message<resp::Value>) -> Self {
+impl TryFrom<super::Message<resp::Value>> for Request {
+    type Error = anyhow::Error;
+
+    fn try_from(value: super::Message<resp::Value>) -> Result<Self, Self::Error> {
         match value.content.into_array() {
             Ok(arr) => {
-                if arr.iter().all(resp::value::Value::is_string) {
-                    let mut req = arr
+                let mut iter = arr.into_iter();
+                let command = match iter.next() {
+                    Some(command) => command
+                        .into_string()
+                        .map_err(|value| anyhow!("expected command name, got {value:?}"))?,
+                    None => bail!("empty request"),
+                };
+                let args = iter
+                    .map(|arg| {
+                        arg.into_byte_string()
+                            .map_err(|value| anyhow!("expected bulk string, got {value:?}"))
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                if args.iter().all(|arg| std::str::from_utf8(arg).is_ok()) {
+                    let args = args
                         .into_iter()
-                        .map(|v| v.expect_string().unwrap())
-                        .collect::<Vec<_>>();
-                    let command = req.remove(0);
-                    let args = req;
-                    Standard::new(command, args).into()
-                } else if arr.first().unwrap().is_string()
-                    && arr
-                        .iter()
-                        .skip(1)
-                        .all(resp::value::Value::is_into_byte_string)
-                {
-                    let mut iter = arr.into_iter();
-                    let cmd = iter.next().unwrap().expect_string().unwrap();
-                    let args = iter
-                        .map(resp::value::Value::into_byte_string)
-                        .collect::<Result<Vec<_>, _>>()
-                        .unwrap();
-                    StandrardByteString::new(cmd, args).into()
+                        .map(|arg| String::from_utf8(arg).expect("checked above"));
+                    Ok(Standard::new(command, args).into())
                 } else {
-                    todo!()
+                    Ok(StandrardByteString::new(command, args).into())
                 }
             }
-            Err(value) => {
-                if let Ok(s) = value.into_string() {
+            Err(value) => match value.into_string() {
+                Ok(s) => {
                     tracing::warn!("got request from single (`SimpleString` or `BulkString`) instead of `Array`: [{s}]");
-                    Standard::new_empty(s).into()
-                } else {
-                    todo!()
+                    Ok(Standard::new_empty(s).into())
                 }
-            }
+                Err(value) => bail!("expected array, got {value:?}"),
+            },
+        }
+    }
+}
+
+impl TryFrom<resp::ValueRef<'_>> for Request {
+    type Error = anyhow::Error;
+
+    /// Builds the request from a frame borrowed from the read buffer,
+    /// copying every argument once instead of going through an owned [`resp::Value`].
+    fn try_from(value: resp::ValueRef<'_>) -> Result<Self, Self::Error> {
+        let resp::ValueRef::Array(values) = value else {
+            return match value.as_bytes().map(std::str::from_utf8) {
+                Some(Ok(s)) => {
+                    tracing::warn!("got request from single (`SimpleString` or `BulkString`) instead of `Array`: [{s}]");
+                    Ok(Standard::new_empty(s).into())
+                }
+                _ => bail!("expected array, got {value:?}"),
+            };
+        };
+        let mut iter = values.iter();
+        let command = match iter.next() {
+            Some(command) => command
+                .as_bytes()
+                .and_then(|bytes| std::str::from_utf8(bytes).ok())
+                .ok_or_else(|| anyhow!("expected command name, got {command:?}"))?,
+            None => bail!("empty request"),
+        };
+        let args = iter
+            .map(|arg| {
+                arg.as_bytes()
+                    .ok_or_else(|| anyhow!("expected bulk string, got {arg:?}"))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        match args
+            .iter()
+            .map(|arg| std::str::from_utf8(arg))
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(args) => Ok(Standard::new(command, args).into()),
+            Err(_) => Ok(StandrardByteString::new(
+                command.to_string(),
+                args.into_iter().map(<[u8]>::to_vec).collect(),
+            )
+            .into()),
         }
     }
 }
@@ -124,11 +188,19 @@ impl From<Request> for resp::Value {
     fn from(value: Request) -> Self {
         match value {
             Request::Standard(s) => s.into(),
-            Request::StandardByteString(_) => todo!(),
+            Request::StandardByteString(b) => b.into(),
         }
     }
 }
 
+impl From<StandrardByteString> for resp::Value {
+    fn from(value: StandrardByteString) -> Self {
+        std::iter::once(resp::Value::BulkString(value.command))
+            .chain(value.args.into_iter().map(resp::Value::BulkByteString))
+            .collect()
+    }
+}
+
 impl From<Standard> for resp::Value {
     fn from(mut value: Standard) -> Self {
         value.args.insert(0, value.command);
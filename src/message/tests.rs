@@ -0,0 +1,14 @@
+use request::{Request, Standard, StandrardByteString};
+
+use super::*;
+
+#[test]
+fn request_converts_to_the_array_it_was_read_from() {
+    let standard: Request = Standard::new("SET", ["key", "value"]).into();
+    let binary: Request =
+        StandrardByteString::new("SET".into(), vec![b"key".to_vec(), vec![0xff, 0]]).into();
+    for request in [standard, binary] {
+        let value = resp::Value::from(request.clone());
+        assert_eq!(Request::try_from(Message::new(value, 0)).unwrap(), request);
+    }
+}
@@ -1,132 +1,287 @@
-// TODO finish
-#![allow(clippy::all, dead_code, unused)]
-
-use crate::repository::stream_repo::stream::EntryId;
+use std::mem::size_of;
 
 #[cfg(test)]
 mod tests;
 
-/// Append only radix
-#[derive(Debug)]
-pub enum Radix<V> {
-    Node { edge: Vec<u8>, children: Vec<Self> },
-    Leaf { edge: Vec<u8>, value: V },
+/// Compressed radix tree mapping byte keys to values, ordered by key.
+///
+/// Every node holds the bytes of the edge leading to it, the value of the key ending
+/// there if any, and its children sorted by the first byte of their edge. Nodes
+/// without a value always have at least two children, removals merge nodes back
+/// together to keep it that way.
+#[derive(Debug, Clone)]
+pub struct Radix<V> {
+    root: Node<V>,
+    len: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Node<V> {
+    edge: Vec<u8>,
+    value: Option<V>,
+    children: Vec<Node<V>>,
+}
+
+impl<V> Node<V> {
+    fn new(edge: Vec<u8>, value: Option<V>) -> Self {
+        Self {
+            edge,
+            value,
+            children: Vec::new(),
+        }
+    }
+
+    fn child(&self, byte: u8) -> Result<usize, usize> {
+        self.children
+            .binary_search_by_key(&byte, |child| child.edge[0])
+    }
+
+    fn get(&self, key: &[u8]) -> Option<&Self> {
+        let Some(&first) = key.first() else {
+            return Some(self);
+        };
+        let child = &self.children[self.child(first).ok()?];
+        child.get(key.strip_prefix(child.edge.as_slice())?)
+    }
+
+    fn get_mut(&mut self, key: &[u8]) -> Option<&mut Self> {
+        let Some(&first) = key.first() else {
+            return Some(self);
+        };
+        let index = self.child(first).ok()?;
+        let child = &mut self.children[index];
+        let rest = key.strip_prefix(child.edge.as_slice())?;
+        child.get_mut(rest)
+    }
+
+    fn insert(&mut self, key: &[u8], value: V) -> Option<V> {
+        let Some(&first) = key.first() else {
+            return self.value.replace(value);
+        };
+        let index = match self.child(first) {
+            Ok(index) => index,
+            Err(index) => {
+                self.children
+                    .insert(index, Self::new(key.to_vec(), Some(value)));
+                return None;
+            }
+        };
+        let child = &mut self.children[index];
+        let common = key.common_prefix(&child.edge).map_or(0, <[u8]>::len);
+        if common < child.edge.len() {
+            // the key diverges inside the edge, split it
+            let rest = Self {
+                edge: child.edge.split_off(common),
+                value: child.value.take(),
+                children: std::mem::take(&mut child.children),
+            };
+            child.children.push(rest);
+        }
+        child.insert(&key[common..], value)
+    }
+
+    fn remove(&mut self, key: &[u8]) -> Option<V> {
+        let Some(&first) = key.first() else {
+            return self.value.take();
+        };
+        let index = self.child(first).ok()?;
+        let child = &mut self.children[index];
+        let rest = key.strip_prefix(child.edge.as_slice())?;
+        let value = child.remove(rest)?;
+        if child.value.is_none() {
+            match child.children.len() {
+                0 => {
+                    self.children.remove(index);
+                }
+                1 => {
+                    // a node without value only splits paths, merge it with its child
+                    let only = child.children.pop().expect("has one child");
+                    child.edge.extend_from_slice(&only.edge);
+                    child.value = only.value;
+                    child.children = only.children;
+                }
+                _ => {}
+            }
+        }
+        Some(value)
+    }
+
+    fn memory_usage(&self) -> usize {
+        self.edge.capacity()
+            + self.children.capacity() * size_of::<Self>()
+            + self.children.iter().map(Self::memory_usage).sum::<usize>()
+    }
 }
 
 impl<V> Radix<V> {
     #[must_use]
     pub fn new() -> Self {
-        Self::Node {
-            edge: Vec::new(),
-            children: Vec::new(),
+        Self {
+            root: Node::new(Vec::new(), None),
+            len: 0,
         }
     }
+
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        match self {
-            Radix::Node { edge: _, children } => children.is_empty(),
-            Radix::Leaf { .. } => false,
-        }
+        self.len == 0
     }
 
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Adds `value` under `key`, giving it back if the key is already taken.
     pub fn add(&mut self, key: &[u8], value: V) -> Result<(), V> {
-        match self {
-            Radix::Node { edge: _, children } => {
-                if children.iter().any(|child| child.edge() == key) {
-                    return Err(value);
-                }
-                if let Some(next) = children
-                    .iter_mut()
-                    .find(|child| key.common_prefix(child.edge()).is_some())
-                {
-                    match next {
-                        Radix::Node { edge, children } => {
-                            let key = key.strip_common_prefix(edge);
-                            next.add(key, value)
-                        }
-                        Radix::Leaf { edge: _, value: _ } => {
-                            let Radix::Leaf {
-                                edge,
-                                value: leaf_value,
-                            } = std::mem::take(next)
-                            else {
-                                unreachable!()
-                            };
-                            let common = key.common_prefix(&edge).unwrap();
-                            let n1 = Self::Leaf {
-                                edge: edge.strip_common_prefix(common).to_vec(),
-                                value: leaf_value,
-                            };
-                            let n2 = Self::Leaf {
-                                edge: key.strip_common_prefix(common).to_vec(),
-                                value,
-                            };
-                            *next = Self::Node {
-                                edge: common.to_vec(),
-                                children: vec![n1, n2],
-                            };
-                            Ok(())
-                        }
-                    }
-                } else {
-                    children.push(Self::Leaf {
-                        edge: key.to_vec(),
-                        value,
-                    });
-                    Ok(())
-                }
-            }
-            Radix::Leaf { edge, value: _ } => {
-                // same prefix
-                // if this is empty make other child of this
-                // if other is empty make this child of other
-                // find common prefix and make both children of new node
-                assert!(!(edge.is_empty() && key.is_empty()), "duplicate i think");
-                assert!(!edge.is_empty());
-                assert!(!key.is_empty());
-                *self = Self::new();
-                todo!("");
-            }
+        if self.contains_key(key) {
+            return Err(value);
         }
+        self.insert(key, value);
+        Ok(())
     }
 
-    fn edge(&self) -> &[u8] {
-        match self {
-            Radix::Node { edge, children: _ } | Radix::Leaf { edge, value: _ } => edge,
+    /// Sets the value of `key`, returning the one it replaced.
+    pub fn insert(&mut self, key: &[u8], value: V) -> Option<V> {
+        let old = self.root.insert(key, value);
+        if old.is_none() {
+            self.len += 1;
         }
+        old
+    }
+
+    pub fn remove(&mut self, key: &[u8]) -> Option<V> {
+        let value = self.root.remove(key)?;
+        self.len -= 1;
+        Some(value)
     }
 
+    #[must_use]
     pub fn get(&self, key: &[u8]) -> Option<&V> {
-        match self {
-            Radix::Node { edge, children } => {
-                let child = children
-                    .iter()
-                    .find(|child| child.edge().common_prefix(key).is_some())?;
-                match child {
-                    Radix::Node { edge, children } => child.get(key.strip_common_prefix(edge)),
-                    Radix::Leaf { edge, value } if edge == key => Some(value),
-                    Radix::Leaf { .. } => None,
+        self.root.get(key)?.value.as_ref()
+    }
+
+    pub fn get_mut(&mut self, key: &[u8]) -> Option<&mut V> {
+        self.root.get_mut(key)?.value.as_mut()
+    }
+
+    #[must_use]
+    pub fn contains_key(&self, key: &[u8]) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// All entries in key order.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, V> {
+        self.seek(&[])
+    }
+
+    /// All entries in reverse key order.
+    #[must_use]
+    pub fn iter_rev(&self) -> Iter<'_, V> {
+        let mut iter = Iter::new(true);
+        iter.stack.push(Frame::Enter(&self.root, 0));
+        iter
+    }
+
+    /// Entries with keys greater than or equal to `key`, in key order.
+    #[must_use]
+    pub fn seek(&self, key: &[u8]) -> Iter<'_, V> {
+        let mut iter = Iter::new(false);
+        let mut node = &self.root;
+        let mut rest = key;
+        loop {
+            let depth = iter.key.len();
+            let common = rest.common_prefix(&node.edge).map_or(0, <[u8]>::len);
+            if common < node.edge.len() {
+                // the whole subtree is either before or after the key
+                if common == rest.len() || node.edge[common] > rest[common] {
+                    iter.stack.push(Frame::Enter(node, depth));
                 }
+                return iter;
+            }
+            rest = &rest[common..];
+            let Some(&first) = rest.first() else {
+                iter.stack.push(Frame::Enter(node, depth));
+                return iter;
+            };
+            // the key of this node is a prefix of the one sought so it comes before it
+            iter.key.extend_from_slice(&node.edge);
+            let after = match node.child(first) {
+                Ok(index) => index + 1,
+                Err(index) => index,
+            };
+            for child in node.children[after..].iter().rev() {
+                iter.stack.push(Frame::Enter(child, iter.key.len()));
+            }
+            match node.child(first) {
+                Ok(index) => node = &node.children[index],
+                Err(_) => return iter,
             }
-            Radix::Leaf { edge, value } => todo!(),
         }
     }
 
-    fn get_next_node(&self, key: &[u8]) -> Option<&Self> {
-        match self {
-            Radix::Node { edge, children } => {
-                let child = children
-                    .iter()
-                    .find(|child| child.edge().common_prefix(key).is_some())?;
-                match child {
-                    Radix::Node { edge, children } => Some(child),
-                    Radix::Leaf { edge, value } if edge == key => todo!(),
-                    Radix::Leaf { .. } => None,
+    /// Entries with keys less than or equal to `key`, in reverse key order.
+    #[must_use]
+    pub fn seek_rev(&self, key: &[u8]) -> Iter<'_, V> {
+        let mut iter = Iter::new(true);
+        let mut node = &self.root;
+        let mut rest = key;
+        loop {
+            let depth = iter.key.len();
+            let common = rest.common_prefix(&node.edge).map_or(0, <[u8]>::len);
+            if common < node.edge.len() {
+                if common < rest.len() && node.edge[common] < rest[common] {
+                    iter.stack.push(Frame::Enter(node, depth));
                 }
+                return iter;
+            }
+            rest = &rest[common..];
+            // the key of this node is at most the one sought, its children are after it
+            iter.stack.push(Frame::Emit(node, depth));
+            let Some(&first) = rest.first() else {
+                return iter;
+            };
+            iter.key.extend_from_slice(&node.edge);
+            let (before, found) = match node.child(first) {
+                Ok(index) => (index, Some(index)),
+                Err(index) => (index, None),
+            };
+            for child in &node.children[..before] {
+                iter.stack.push(Frame::Enter(child, iter.key.len()));
+            }
+            match found {
+                Some(index) => node = &node.children[index],
+                None => return iter,
             }
-            Radix::Leaf { edge, value } => todo!(),
         }
     }
+
+    /// Entries whose key starts with `prefix`, in key order.
+    pub fn prefix<'a>(&'a self, prefix: &'a [u8]) -> impl Iterator<Item = (Vec<u8>, &'a V)> {
+        self.seek(prefix)
+            .take_while(move |(key, _)| key.starts_with(prefix))
+    }
+
+    #[must_use]
+    pub fn first(&self) -> Option<(Vec<u8>, &V)> {
+        self.iter().next()
+    }
+
+    #[must_use]
+    pub fn last(&self) -> Option<(Vec<u8>, &V)> {
+        self.iter_rev().next()
+    }
+
+    /// Bytes used by the tree itself, not counting memory owned by the values.
+    #[must_use]
+    pub fn memory_usage(&self) -> usize {
+        size_of::<Self>() + self.root.memory_usage()
+    }
 }
 
 impl<T> Default for Radix<T> {
@@ -135,27 +290,70 @@ impl<T> Default for Radix<T> {
     }
 }
 
-trait RadixCmp {
-    fn find_common_prefix(&self, other: &Self) -> Option<Self>
-    where
-        Self: Sized;
+impl<'a, V> IntoIterator for &'a Radix<V> {
+    type Item = (Vec<u8>, &'a V);
+    type IntoIter = Iter<'a, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+enum Frame<'a, V> {
+    /// Visit the node and its subtree, the key before its edge is `depth` long.
+    Enter(&'a Node<V>, usize),
+    /// Only yield the value of the node, used when walking in reverse.
+    Emit(&'a Node<V>, usize),
+}
+
+/// Iterator over the entries of a [`Radix`], in or against key order.
+pub struct Iter<'a, V> {
+    stack: Vec<Frame<'a, V>>,
+    key: Vec<u8>,
+    rev: bool,
+}
+
+impl<V> Iter<'_, V> {
+    fn new(rev: bool) -> Self {
+        Self {
+            stack: Vec::new(),
+            key: Vec::new(),
+            rev,
+        }
+    }
 }
 
-impl RadixCmp for &str {
-    fn find_common_prefix(&self, other: &Self) -> Option<Self>
-    where
-        Self: Sized,
-    {
-        let count = self
-            .chars()
-            .zip(other.chars())
-            .take_while(|(this, other)| this == other)
-            .count();
-        if count > 0 {
-            Some(&self[0..count])
-        } else {
-            None
+impl<'a, V> Iterator for Iter<'a, V> {
+    type Item = (Vec<u8>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(frame) = self.stack.pop() {
+            let (node, depth) = match frame {
+                Frame::Enter(node, depth) | Frame::Emit(node, depth) => (node, depth),
+            };
+            self.key.truncate(depth);
+            self.key.extend_from_slice(&node.edge);
+            let value = match frame {
+                Frame::Emit(..) => node.value.as_ref(),
+                Frame::Enter(..) if self.rev => {
+                    self.stack.push(Frame::Emit(node, depth));
+                    for child in &node.children {
+                        self.stack.push(Frame::Enter(child, self.key.len()));
+                    }
+                    None
+                }
+                Frame::Enter(..) => {
+                    for child in node.children.iter().rev() {
+                        self.stack.push(Frame::Enter(child, self.key.len()));
+                    }
+                    node.value.as_ref()
+                }
+            };
+            if let Some(value) = value {
+                return Some((self.key.clone(), value));
+            }
         }
+        None
     }
 }
 
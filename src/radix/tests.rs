@@ -92,3 +92,144 @@ fn add_nested() {
         "{radix:?}"
     );
 }
+
+#[test]
+fn get_keys_sharing_prefixes() {
+    let mut radix = Radix::<String>::new();
+    let keys = [
+        "RPUSH", "RPUSHX", "RPOP", "RANGE", "LPUSH", "LPUSHX", "LPOS", "LPOP",
+    ];
+    for key in keys {
+        radix.add(key.as_bytes(), key.into()).unwrap();
+    }
+    for key in keys {
+        assert_eq!(radix.get(key.as_bytes()), Some(&key.to_string()));
+    }
+    assert_eq!(radix.get(b"RP"), None);
+    assert_eq!(radix.get(b"RPUSHY"), None);
+    assert!(radix.add(b"RPUSH", "again".into()).is_err());
+}
+
+fn radix_of(keys: &[&str]) -> Radix<String> {
+    let mut radix = Radix::new();
+    for key in keys {
+        radix.add(key.as_bytes(), (*key).to_string()).unwrap();
+    }
+    radix
+}
+
+fn keys(iter: impl Iterator<Item = (Vec<u8>, impl Sized)>) -> Vec<String> {
+    iter.map(|(key, _)| String::from_utf8(key).unwrap())
+        .collect()
+}
+
+#[test]
+fn add_below_existing_key() {
+    let radix = radix_of(&["abc", "abcdef", "ab", "", "abd"]);
+    for key in ["abc", "abcdef", "ab", "", "abd"] {
+        assert_eq!(radix.get(key.as_bytes()), Some(&key.to_string()));
+    }
+    assert_eq!(radix.get(b"abcd"), None);
+    assert_eq!(radix.len(), 5);
+}
+
+#[test]
+fn insert_replaces_value() {
+    let mut radix = radix_of(&["key"]);
+    assert_eq!(radix.insert(b"key", "new".into()), Some("key".into()));
+    *radix.get_mut(b"key").unwrap() += "er";
+    assert_eq!(radix.get(b"key"), Some(&"newer".to_string()));
+    assert_eq!(radix.len(), 1);
+}
+
+#[test]
+fn remove_merges_nodes() {
+    let mut radix = radix_of(&["RPUSH", "RPUSHX", "RPOP", "LPOP"]);
+    let before = radix.memory_usage();
+    assert_eq!(radix.remove(b"RPUSH"), Some("RPUSH".into()));
+    assert!(radix.memory_usage() < before);
+    assert_eq!(radix.remove(b"RPUSH"), None);
+    assert_eq!(radix.remove(b"RP"), None);
+    assert_eq!(radix.remove(b"RPOP"), Some("RPOP".into()));
+    assert_eq!(radix.remove(b"LPOP"), Some("LPOP".into()));
+    assert_eq!(radix.get(b"RPUSHX"), Some(&"RPUSHX".to_string()));
+    assert_eq!(radix.len(), 1);
+    assert_eq!(radix.remove(b"RPUSHX"), Some("RPUSHX".into()));
+    assert!(radix.is_empty());
+    assert_eq!(radix.first(), None);
+}
+
+#[test]
+fn iterates_in_key_order() {
+    let radix = radix_of(&["b", "abc", "a", "ab", "", "ba", "c"]);
+    let expected = ["", "a", "ab", "abc", "b", "ba", "c"];
+    assert_eq!(keys(radix.iter()), expected);
+    let mut reversed = expected.to_vec();
+    reversed.reverse();
+    assert_eq!(keys(radix.iter_rev()), reversed);
+    assert_eq!(radix.first().unwrap().0, b"");
+    assert_eq!(radix.last().unwrap().0, b"c");
+}
+
+#[test]
+fn seek_starts_at_lower_bound() {
+    let radix = radix_of(&["a", "ab", "abc", "b", "ba", "c"]);
+    assert_eq!(keys(radix.seek(b"ab")), ["ab", "abc", "b", "ba", "c"]);
+    assert_eq!(keys(radix.seek(b"abb")), ["abc", "b", "ba", "c"]);
+    assert_eq!(keys(radix.seek(b"abcd")), ["b", "ba", "c"]);
+    assert_eq!(keys(radix.seek(b"")), ["a", "ab", "abc", "b", "ba", "c"]);
+    assert!(keys(radix.seek(b"d")).is_empty());
+}
+
+#[test]
+fn seek_rev_starts_at_upper_bound() {
+    let radix = radix_of(&["a", "ab", "abc", "b", "ba", "c"]);
+    assert_eq!(keys(radix.seek_rev(b"b")), ["b", "abc", "ab", "a"]);
+    assert_eq!(keys(radix.seek_rev(b"abb")), ["ab", "a"]);
+    assert_eq!(keys(radix.seek_rev(b"bz")), ["ba", "b", "abc", "ab", "a"]);
+    assert!(keys(radix.seek_rev(b"")).is_empty());
+    assert!(keys(radix.seek_rev(b"0")).is_empty());
+}
+
+#[test]
+fn prefix_yields_keys_starting_with_it() {
+    let radix = radix_of(&["LPOP", "LPOS", "LPUSH", "LPUSHX", "RPOP"]);
+    assert_eq!(keys(radix.prefix(b"LPO")), ["LPOP", "LPOS"]);
+    assert_eq!(keys(radix.prefix(b"LPUSH")), ["LPUSH", "LPUSHX"]);
+    assert!(keys(radix.prefix(b"X")).is_empty());
+}
+
+#[test]
+fn matches_ordered_map_under_random_operations() {
+    let mut radix = Radix::new();
+    let mut map = std::collections::BTreeMap::new();
+    let mut seed = 42_u64;
+    let mut next = || {
+        seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+        seed >> 33
+    };
+    for _ in 0..5000 {
+        let key: Vec<u8> = (0..next() % 4)
+            .map(|_| b"abc"[next() as usize % 3])
+            .collect();
+        if next() % 3 == 0 {
+            assert_eq!(radix.remove(&key), map.remove(&key));
+        } else {
+            let value = next();
+            assert_eq!(radix.insert(&key, value), map.insert(key.clone(), value));
+        }
+        let bound = [b'b', b'a'];
+        assert!(radix
+            .seek(&bound)
+            .map(|(key, _)| key)
+            .eq(map.range(bound.to_vec()..).map(|(key, _)| key.clone())));
+        assert!(radix.seek_rev(&bound).map(|(key, _)| key).eq(map
+            .range(..=bound.to_vec())
+            .rev()
+            .map(|(key, _)| key.clone())));
+    }
+    assert!(radix
+        .iter()
+        .map(|(key, value)| (key, *value))
+        .eq(map.into_iter()));
+}
@@ -0,0 +1,111 @@
+use std::{
+    cell::Cell,
+    collections::{hash_map::RandomState, HashMap},
+    hash::{BuildHasher, Hasher},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A xorshift64* generator, good enough for sampling keys but not for anything secret.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        // xorshift gets stuck on 0
+        Self { state: seed | 1 }
+    }
+
+    /// Seeded from the random keys std uses for `HashMap`.
+    #[must_use]
+    pub fn from_entropy() -> Self {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u128(
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |now| now.as_nanos()),
+        );
+        Self::new(hasher.finish())
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// A number in `0..n`, `n` must not be 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "empty range");
+        let n = n as u64;
+        // rejects the uneven tail so every number is equally likely
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return usize::try_from(value % n).expect("below n which is a usize");
+            }
+        }
+    }
+}
+
+thread_local! {
+    static STATE: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// Runs `f` with a generator local to the current thread.
+pub fn with_rng<F, T>(f: F) -> T
+where
+    F: FnOnce(&mut Rng) -> T,
+{
+    STATE.with(|state| {
+        let mut rng = state.get().map_or_else(Rng::from_entropy, Rng::new);
+        let result = f(&mut rng);
+        state.set(Some(rng.state));
+        result
+    })
+}
+
+/// A random number in `0..n` from the thread local generator, `n` must not be 0.
+#[must_use]
+pub fn below(n: usize) -> usize {
+    with_rng(|rng| rng.below(n))
+}
+
+/// `count` distinct indexes into `0..len`, all of them if `count >= len`.
+/// Takes time and memory for the `count` indexes only, however large `len` is.
+#[must_use]
+pub fn sample_distinct(len: usize, count: usize) -> Vec<usize> {
+    if count >= len {
+        return (0..len).collect();
+    }
+    // a partial fisher-yates shuffle of `0..len` that only keeps the swapped indexes
+    let mut swapped = HashMap::with_capacity(count * 2);
+    with_rng(|rng| {
+        (0..count)
+            .map(|i| {
+                let j = i + rng.below(len - i);
+                let picked = swapped.get(&j).copied().unwrap_or(j);
+                swapped.insert(j, swapped.get(&i).copied().unwrap_or(i));
+                picked
+            })
+            .collect()
+    })
+}
+
+/// Most repeated members one request may ask for, so it can not allocate without bound.
+pub const MAX_REPEATED: usize = 1024 * 1024;
+
+/// `count` indexes into `0..len` that may repeat, none if `len` is 0.
+#[must_use]
+pub fn sample_repeated(len: usize, count: usize) -> Vec<usize> {
+    if len == 0 {
+        return Vec::new();
+    }
+    with_rng(|rng| (0..count).map(|_| rng.below(len)).collect())
+}
@@ -0,0 +1,57 @@
+use super::*;
+
+#[test]
+fn same_seed_gives_same_numbers() {
+    let (mut a, mut b) = (Rng::new(42), Rng::new(42));
+    for _ in 0..10 {
+        assert_eq!(a.next_u64(), b.next_u64());
+    }
+}
+
+#[test]
+fn zero_seed_does_not_get_stuck() {
+    let mut rng = Rng::new(0);
+    assert_ne!(rng.next_u64(), rng.next_u64());
+}
+
+#[test]
+fn below_stays_in_range_and_hits_every_number() {
+    let mut rng = Rng::new(7);
+    let mut seen = [false; 5];
+    for _ in 0..1000 {
+        seen[rng.below(5)] = true;
+    }
+    assert_eq!(seen, [true; 5]);
+}
+
+#[test]
+fn thread_local_generator_advances() {
+    let first: Vec<_> = (0..4).map(|_| below(1 << 20)).collect();
+    let second: Vec<_> = (0..4).map(|_| below(1 << 20)).collect();
+    assert_ne!(first, second);
+}
+
+#[test]
+fn sample_distinct_does_not_repeat() {
+    let mut sample = sample_distinct(10, 5);
+    assert_eq!(sample.len(), 5);
+    sample.sort_unstable();
+    sample.dedup();
+    assert_eq!(sample.len(), 5);
+    assert!(sample.iter().all(|i| *i < 10));
+    assert_eq!(sample_distinct(3, 10), [0, 1, 2]);
+}
+
+#[test]
+fn sample_distinct_does_not_depend_on_len() {
+    let mut sample = sample_distinct(usize::MAX, 3);
+    sample.sort_unstable();
+    sample.dedup();
+    assert_eq!(sample.len(), 3);
+}
+
+#[test]
+fn sample_repeated_has_exact_count() {
+    assert_eq!(sample_repeated(2, 10).len(), 10);
+    assert!(sample_repeated(0, 10).is_empty());
+}
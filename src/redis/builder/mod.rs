@@ -2,7 +2,7 @@ use std::fmt::Debug;
 
 use crate::{
     config::RedisConfig, connection::stream::Stream, event::EventEmitter, listner::RedisListner,
-    repository::Repository,
+    repository::Repository, resp::value::deserialize::Limits,
 };
 use anyhow::Context;
 
@@ -11,11 +11,14 @@ mod tests;
 
 #[derive(Debug)]
 pub struct RedisBuilder<L, C> {
+    #[allow(dead_code)]
     config: Option<RedisConfig>,
     listner: Option<L>,
     leader_connection: Option<C>,
     repo: Option<Repository>,
     emitter: Option<EventEmitter>,
+    limits: Limits,
+    hz: u32,
 }
 
 impl<L, S> RedisBuilder<L, S>
@@ -31,6 +34,8 @@ where
             leader_connection: None,
             repo: None,
             emitter: None,
+            limits: Limits::default(),
+            hz: RedisConfig::DEFAULT_HZ,
         }
     }
 
@@ -40,7 +45,9 @@ where
             self.leader_connection,
             self.repo.context("repo missing")?,
             self.emitter.context("emitter missing")?,
-        ))
+        )
+        .with_limits(self.limits)
+        .with_hz(self.hz))
     }
 
     pub fn bind(self, port: u16) -> anyhow::Result<Self> {
@@ -86,6 +93,16 @@ where
         }
     }
 
+    #[must_use]
+    pub fn limits(self, limits: Limits) -> Self {
+        Self { limits, ..self }
+    }
+
+    #[must_use]
+    pub fn hz(self, hz: u32) -> Self {
+        Self { hz, ..self }
+    }
+
     #[must_use]
     pub fn leader_addr(self) -> Self {
         todo!()
@@ -102,3 +119,13 @@ where
         }
     }
 }
+
+impl<L, S> Default for RedisBuilder<L, S>
+where
+    L: RedisListner,
+    S: Stream<Addr = std::net::SocketAddrV4>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
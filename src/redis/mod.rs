@@ -8,8 +8,11 @@ use crate::{
     },
     event::EventEmitter,
     listner::RedisListner,
-    repository::Repository,
+    repository::{Keyspace, Repository},
+    resp::value::deserialize::Limits,
 };
+use std::sync::Arc;
+
 use tracing::{error, info, instrument};
 
 pub mod builder;
@@ -20,9 +23,11 @@ mod tests;
 pub struct Redis<L, C> {
     config: RedisConfig,
     listner: L,
+    #[allow(dead_code)]
     leader_connection: Option<C>,
     client_router: &'static client::Router,
-    leader_router: &'static command::CommandRouter<crate::Request, (), Repository>,
+    leader_router:
+        &'static command::CommandRouter<crate::Request, Option<crate::event::Kind>, Repository>,
     repo: Repository,
     emitter: EventEmitter,
 }
@@ -55,6 +60,20 @@ where
         }
     }
 
+    /// Sets the protocol limits enforced on incoming connections.
+    #[must_use]
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.config.set_limits(limits);
+        self
+    }
+
+    /// Sets how often background tasks run per second.
+    #[must_use]
+    pub fn with_hz(mut self, hz: u32) -> Self {
+        self.config.set_hz(hz);
+        self
+    }
+
     #[must_use]
     pub fn get_port(&self) -> u16 {
         self.config.port()
@@ -80,6 +99,7 @@ where
         <L as RedisListner>::Stream: std::marker::Send + 'static,
     {
         info!("accepting incoming connections");
+        let config = Arc::new(self.config);
         for (id, connection) in self.listner.incoming().enumerate() {
             info!("connection accepted");
             let connection = IncomingConnection::new(
@@ -88,7 +108,8 @@ where
                 self.emitter.clone(),
                 self.repo.clone(),
                 id,
-            );
+            )
+            .with_config(Arc::clone(&config));
             connection.spawn_handler();
         }
     }
@@ -107,6 +128,7 @@ where
                 "follower"
             }
         );
+        Keyspace::spawn_active_expire(self.repo.keyspaces(), self.config.hz());
         if self.is_follower() {
             let connection_to_leader = self.connect_to_leader().unwrap();
             info!("connected to leader");
@@ -125,10 +147,7 @@ where
     }
 
     pub fn role(&self) -> Role {
-        match self.config.leader_addr() {
-            Some(addr) => Role::Follower(addr),
-            None => Role::Leader,
-        }
+        self.config.role()
     }
 
     pub fn is_leader(&self) -> bool {
@@ -109,7 +109,7 @@ fn creates_incoming_connection_on_listner_output() {
 #[should_panic(expected = "is not follower")]
 fn creating_outgoing_connection_as_leader_panics() {
     let mut redis = setup_leader();
-    redis.connect_to_leader();
+    _ = redis.connect_to_leader();
 }
 
 #[test]
@@ -187,3 +187,9 @@ impl RedisListner for MockOnceListner {
         0
     }
 }
+
+#[test]
+fn hz_is_kept_in_the_config() {
+    let redis = setup_leader().with_hz(25);
+    assert_eq!(redis.config.hz(), 25);
+}
@@ -0,0 +1,197 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+pub use block_result::BlockResult;
+
+pub mod block_result;
+
+#[cfg(test)]
+mod tests;
+
+/// Lets clients wait for keys to change, shared by every view of a keyspace.
+/// Waiters on a key are queued in the order they blocked, so the client that blocked first
+/// is served first when the key is written to.
+#[derive(Debug, Clone, Default)]
+pub struct Blocking {
+    waiters: Arc<Mutex<Waiters>>,
+    next_id: Arc<AtomicUsize>,
+}
+
+#[derive(Debug, Default)]
+struct Waiters {
+    queues: HashMap<Vec<u8>, VecDeque<Waiter>>,
+    /// Waiters that were woken and have not tried yet, with the clients that blocked
+    /// after they were woken and hold back their first try until they had theirs.
+    woken: HashMap<usize, Vec<usize>>,
+    /// Clients holding back their first try, with how many woken waiters they still let go first.
+    held: HashMap<usize, (usize, Sender<()>)>,
+}
+
+#[derive(Debug)]
+struct Waiter {
+    id: usize,
+    sender: Sender<()>,
+}
+
+impl Blocking {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `f` until it finds something, waiting for one of `keys` to be woken between the tries.
+    /// Gives up with [`BlockResult::NotFound`] once `timeout` has passed, `None` waits forever.
+    ///
+    /// A client blocking while an older waiter on one of `keys` has been woken skips its first
+    /// try until that waiter had its turn, so it can not take what was meant for the older one.
+    pub fn block<F, T>(
+        &self,
+        keys: &[Vec<u8>],
+        timeout: Option<Duration>,
+        mut f: F,
+    ) -> BlockResult<T>
+    where
+        F: FnMut() -> BlockResult<T>,
+    {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        // registered before the first try so a write in between is not missed
+        let (id, receiver, mut held) = self.register(keys);
+        loop {
+            if !std::mem::take(&mut held) {
+                let result = f();
+                if !result.is_not_found() {
+                    self.unregister(id, keys, &receiver);
+                    // the keys may hold more than this client took, let the next waiter try
+                    keys.iter().for_each(|key| self.wake(key));
+                    return result;
+                }
+                self.tried(id);
+            }
+            let woken = match deadline {
+                Some(deadline) => receiver
+                    .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                    .is_ok(),
+                None => receiver.recv().is_ok(),
+            };
+            if !woken {
+                self.unregister(id, keys, &receiver);
+                return BlockResult::NotFound;
+            }
+        }
+    }
+
+    /// Wakes the client that has been waiting on `key` the longest.
+    pub fn wake(&self, key: &[u8]) {
+        let mut waiters = self.waiters.lock().unwrap();
+        let Some(queue) = waiters.queues.get_mut(key) else {
+            return;
+        };
+        while let Some(waiter) = queue.front() {
+            if waiter.sender.send(()).is_ok() {
+                let id = waiter.id;
+                waiters.woken.entry(id).or_default();
+                return;
+            }
+            queue.pop_front();
+        }
+        waiters.queues.remove(key);
+    }
+
+    /// Wakes every client waiting on `key`, for reads that do not consume what they find.
+    pub fn wake_all(&self, key: &[u8]) {
+        let mut waiters = self.waiters.lock().unwrap();
+        if let Some(queue) = waiters.queues.get_mut(key) {
+            queue.retain(|waiter| waiter.sender.send(()).is_ok());
+        }
+    }
+
+    /// Wakes every waiting client, for when the whole keyspace changed.
+    pub fn wake_every(&self) {
+        let mut waiters = self.waiters.lock().unwrap();
+        waiters.queues.retain(|_, queue| {
+            queue.retain(|waiter| waiter.sender.send(()).is_ok());
+            !queue.is_empty()
+        });
+    }
+
+    /// The number of clients waiting on `key`.
+    #[must_use]
+    pub fn waiting(&self, key: &[u8]) -> usize {
+        self.waiters
+            .lock()
+            .unwrap()
+            .queues
+            .get(key)
+            .map_or(0, VecDeque::len)
+    }
+
+    /// Queues a new waiter on `keys`, also returning whether it has to hold back its first try.
+    fn register(&self, keys: &[Vec<u8>]) -> (usize, Receiver<()>, bool) {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = mpsc::channel();
+        let mut waiters = self.waiters.lock().unwrap();
+        let mut ahead = Vec::new();
+        for key in keys {
+            let queue = waiters.queues.entry(key.clone()).or_default();
+            ahead.extend(queue.iter().map(|waiter| waiter.id));
+            queue.push_back(Waiter {
+                id,
+                sender: sender.clone(),
+            });
+        }
+        ahead.sort_unstable();
+        ahead.dedup();
+        ahead.retain(|waiter| waiters.woken.contains_key(waiter));
+        for waiter in &ahead {
+            waiters.woken.entry(*waiter).or_default().push(id);
+        }
+        let held = !ahead.is_empty();
+        if held {
+            waiters.held.insert(id, (ahead.len(), sender));
+        }
+        (id, receiver, held)
+    }
+
+    /// Marks the waiter as having had its try, letting the clients that held back for it go.
+    fn tried(&self, id: usize) {
+        let mut waiters = self.waiters.lock().unwrap();
+        waiters.held.remove(&id);
+        for newcomer in waiters.woken.remove(&id).unwrap_or_default() {
+            let Some((ahead, _)) = waiters.held.get_mut(&newcomer) else {
+                continue;
+            };
+            *ahead -= 1;
+            if *ahead == 0 {
+                if let Some((_, sender)) = waiters.held.remove(&newcomer) {
+                    let _ = sender.send(());
+                }
+            }
+        }
+    }
+
+    fn unregister(&self, id: usize, keys: &[Vec<u8>], receiver: &Receiver<()>) {
+        {
+            let mut waiters = self.waiters.lock().unwrap();
+            for key in keys {
+                if let Some(queue) = waiters.queues.get_mut(key) {
+                    queue.retain(|waiter| waiter.id != id);
+                    if queue.is_empty() {
+                        waiters.queues.remove(key);
+                    }
+                }
+            }
+        }
+        self.tried(id);
+        // a wakeup that arrived after the last try was meant for whoever is next
+        if receiver.try_recv().is_ok() {
+            keys.iter().for_each(|key| self.wake(key));
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use super::{BlockResult, Blocking};
+
+const KEY: &[u8] = b"key";
+
+fn take(items: &Mutex<Vec<u32>>) -> BlockResult<u32> {
+    items
+        .lock()
+        .unwrap()
+        .pop()
+        .map_or(BlockResult::NotFound, BlockResult::Found)
+}
+
+fn wait_for_waiters(blocking: &Blocking, count: usize) {
+    while blocking.waiting(KEY) < count {
+        thread::sleep(Duration::from_millis(1));
+    }
+}
+
+#[test]
+fn block_returns_immediately_when_found() {
+    let blocking = Blocking::new();
+    let result = blocking.block(&[KEY.to_vec()], None, || BlockResult::Found(1));
+    assert_eq!(result, BlockResult::Found(1));
+    assert_eq!(blocking.waiting(KEY), 0);
+}
+
+#[test]
+fn block_times_out() {
+    let blocking = Blocking::new();
+    let timeout = Duration::from_millis(10);
+    let start = std::time::Instant::now();
+    let result = blocking.block(&[KEY.to_vec()], Some(timeout), || {
+        BlockResult::<()>::NotFound
+    });
+    assert_eq!(result, BlockResult::NotFound);
+    assert!(start.elapsed() >= timeout);
+    assert_eq!(blocking.waiting(KEY), 0);
+}
+
+#[test]
+fn waiters_are_served_in_order() {
+    let blocking = Blocking::new();
+    let items = Arc::new(Mutex::new(Vec::new()));
+    let served = Arc::new(Mutex::new(Vec::new()));
+    let handles: Vec<_> = (0..3)
+        .map(|i| {
+            let (waiter, items, served) = (blocking.clone(), items.clone(), served.clone());
+            let handle = thread::spawn(move || {
+                let result = waiter.block(&[KEY.to_vec()], None, || take(&items));
+                served.lock().unwrap().push((i, result));
+            });
+            wait_for_waiters(&blocking, i + 1);
+            handle
+        })
+        .collect();
+
+    items.lock().unwrap().extend([3, 2, 1]);
+    blocking.wake(KEY);
+    handles
+        .into_iter()
+        .for_each(|handle| handle.join().unwrap());
+
+    let mut served = std::mem::take(&mut *served.lock().unwrap());
+    served.sort_by_key(|(i, _)| *i);
+    assert_eq!(
+        served,
+        [
+            (0, BlockResult::Found(1)),
+            (1, BlockResult::Found(2)),
+            (2, BlockResult::Found(3))
+        ]
+    );
+}
+
+#[test]
+fn wake_without_data_keeps_waiting() {
+    let blocking = Blocking::new();
+    let items = Arc::new(Mutex::new(Vec::new()));
+    let handle = {
+        let (blocking, items) = (blocking.clone(), items.clone());
+        thread::spawn(move || blocking.block(&[KEY.to_vec()], None, || take(&items)))
+    };
+    wait_for_waiters(&blocking, 1);
+    blocking.wake(KEY);
+    thread::sleep(Duration::from_millis(5));
+    assert!(!handle.is_finished());
+
+    items.lock().unwrap().push(7);
+    blocking.wake(KEY);
+    assert_eq!(handle.join().unwrap(), BlockResult::Found(7));
+}
+
+#[test]
+fn newcomer_does_not_take_what_was_woken_for_an_older_waiter() {
+    let blocking = Blocking::new();
+    let items = Arc::new(Mutex::new(Vec::new()));
+    let served = Arc::new(Mutex::new(Vec::new()));
+    let handles: Vec<_> = (0..2)
+        .map(|i| {
+            let (waiter, items, served) = (blocking.clone(), items.clone(), served.clone());
+            let handle = thread::spawn(move || {
+                let result = waiter.block(&[KEY.to_vec()], None, || {
+                    // a slow client, the newcomer would get to the item first
+                    thread::sleep(Duration::from_millis(5));
+                    take(&items)
+                });
+                served.lock().unwrap().push((i, result));
+            });
+            wait_for_waiters(&blocking, i + 1);
+            handle
+        })
+        .collect();
+
+    items.lock().unwrap().push(1);
+    blocking.wake(KEY);
+    let newcomer = blocking.block(&[KEY.to_vec()], Some(Duration::from_millis(50)), || {
+        take(&items)
+    });
+    assert_eq!(newcomer, BlockResult::NotFound);
+
+    items.lock().unwrap().push(2);
+    blocking.wake(KEY);
+    handles
+        .into_iter()
+        .for_each(|handle| handle.join().unwrap());
+
+    let mut served = std::mem::take(&mut *served.lock().unwrap());
+    served.sort_by_key(|(i, _)| *i);
+    assert_eq!(
+        served,
+        [(0, BlockResult::Found(1)), (1, BlockResult::Found(2))]
+    );
+    assert_eq!(blocking.waiting(KEY), 0);
+}
@@ -0,0 +1,271 @@
+use std::time::SystemTime;
+
+use anyhow::{anyhow, bail};
+
+use super::{
+    keyspace::{Db, Keyspace, WrongType},
+    kv_repo::parse_float,
+    table::Table,
+};
+use crate::{glob, random, resp::value::convert::parse_integer};
+
+#[cfg(test)]
+mod tests;
+
+pub type Hash = Table<Vec<u8>>;
+
+/// A field and its value.
+pub type Pair = (Vec<u8>, Vec<u8>);
+
+/// Hashes stored in the keyspace, a hash that becomes empty is removed like in redis.
+#[derive(Debug, Clone, Default)]
+pub struct HashRepository {
+    keyspace: Keyspace,
+}
+
+impl HashRepository {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_keyspace(keyspace: Keyspace) -> Self {
+        Self { keyspace }
+    }
+
+    /// Runs `f` on the hash at `key`, returns `None` if there is no such key.
+    fn with_hash<F, T>(&self, key: &[u8], now: SystemTime, f: F) -> Result<Option<T>, WrongType>
+    where
+        F: FnOnce(&mut Hash) -> T,
+    {
+        let mut db = self.keyspace.lock();
+        Self::update(&mut db, key, now, f)
+    }
+
+    fn update<F, T>(db: &mut Db, key: &[u8], now: SystemTime, f: F) -> Result<Option<T>, WrongType>
+    where
+        F: FnOnce(&mut Hash) -> T,
+    {
+        let Some(hash) = db.get_as::<Hash>(key, now)? else {
+            return Ok(None);
+        };
+        let result = f(hash);
+        if hash.is_empty() {
+            db.remove(key, now);
+        }
+        Ok(Some(result))
+    }
+
+    /// Runs `f` on the hash at `key`, creating it if it does not exist.
+    fn with_hash_or_default<F, T>(&self, key: &[u8], now: SystemTime, f: F) -> anyhow::Result<T>
+    where
+        F: FnOnce(&mut Hash) -> anyhow::Result<T>,
+    {
+        let mut db = self.keyspace.lock();
+        let hash = db.get_or_default::<Hash>(key, now)?;
+        let result = f(hash);
+        // a failed write must not leave behind the hash it created
+        if hash.is_empty() {
+            db.remove(key, now);
+        }
+        result
+    }
+
+    /// Sets every pair and returns how many fields were new.
+    pub fn set(&self, key: &[u8], pairs: Vec<Pair>, now: SystemTime) -> anyhow::Result<usize> {
+        self.with_hash_or_default(key, now, |hash| {
+            Ok(pairs
+                .into_iter()
+                .filter(|(field, value)| hash.insert(field.clone(), value.clone()).is_none())
+                .count())
+        })
+    }
+
+    /// Sets `field` only if it does not exist yet, returns whether it was set.
+    pub fn set_nx(
+        &self,
+        key: &[u8],
+        field: Vec<u8>,
+        value: Vec<u8>,
+        now: SystemTime,
+    ) -> anyhow::Result<bool> {
+        self.with_hash_or_default(key, now, |hash| {
+            if hash.contains_key(&field) {
+                return Ok(false);
+            }
+            hash.insert(field, value);
+            Ok(true)
+        })
+    }
+
+    pub fn get(
+        &self,
+        key: &[u8],
+        field: &[u8],
+        now: SystemTime,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        Ok(self
+            .with_hash(key, now, |hash| hash.get(field).cloned())?
+            .flatten())
+    }
+
+    pub fn get_many(
+        &self,
+        key: &[u8],
+        fields: &[Vec<u8>],
+        now: SystemTime,
+    ) -> anyhow::Result<Vec<Option<Vec<u8>>>> {
+        let values = self.with_hash(key, now, |hash| {
+            fields
+                .iter()
+                .map(|field| hash.get(field).cloned())
+                .collect()
+        })?;
+        Ok(values.unwrap_or_else(|| vec![None; fields.len()]))
+    }
+
+    pub fn get_all(&self, key: &[u8], now: SystemTime) -> anyhow::Result<Vec<Pair>> {
+        let pairs = self.with_hash(key, now, |hash| {
+            hash.iter()
+                .map(|(field, value)| (field.clone(), value.clone()))
+                .collect()
+        })?;
+        Ok(pairs.unwrap_or_default())
+    }
+
+    /// Removes `fields` and returns how many of them existed.
+    pub fn delete(&self, key: &[u8], fields: &[Vec<u8>], now: SystemTime) -> anyhow::Result<usize> {
+        let removed = self.with_hash(key, now, |hash| {
+            fields
+                .iter()
+                .filter(|field| hash.remove(field).is_some())
+                .count()
+        })?;
+        Ok(removed.unwrap_or(0))
+    }
+
+    pub fn exists(&self, key: &[u8], field: &[u8], now: SystemTime) -> anyhow::Result<bool> {
+        let exists = self.with_hash(key, now, |hash| hash.contains_key(field))?;
+        Ok(exists.unwrap_or(false))
+    }
+
+    pub fn len(&self, key: &[u8], now: SystemTime) -> anyhow::Result<usize> {
+        Ok(self.with_hash(key, now, |hash| hash.len())?.unwrap_or(0))
+    }
+
+    pub fn fields(&self, key: &[u8], now: SystemTime) -> anyhow::Result<Vec<Vec<u8>>> {
+        let fields = self.with_hash(key, now, |hash| hash.keys().cloned().collect())?;
+        Ok(fields.unwrap_or_default())
+    }
+
+    pub fn values(&self, key: &[u8], now: SystemTime) -> anyhow::Result<Vec<Vec<u8>>> {
+        let values = self.with_hash(key, now, |hash| hash.values().cloned().collect())?;
+        Ok(values.unwrap_or_default())
+    }
+
+    /// The length of the value at `field`, 0 if there is none.
+    pub fn value_len(&self, key: &[u8], field: &[u8], now: SystemTime) -> anyhow::Result<usize> {
+        let len = self.with_hash(key, now, |hash| hash.get(field).map_or(0, Vec::len))?;
+        Ok(len.unwrap_or(0))
+    }
+
+    /// Adds `increment` to the integer at `field`, a missing field counts as 0.
+    pub fn incr_by(
+        &self,
+        key: &[u8],
+        field: &[u8],
+        increment: i64,
+        now: SystemTime,
+    ) -> anyhow::Result<i64> {
+        self.with_hash_or_default(key, now, |hash| {
+            let current: i64 = match hash.get(field) {
+                Some(value) => parse_integer(value)
+                    .ok_or_else(|| anyhow!("ERR hash value is not an integer"))?,
+                None => 0,
+            };
+            let value = current
+                .checked_add(increment)
+                .ok_or_else(|| anyhow!("ERR increment or decrement would overflow"))?;
+            hash.insert(field.to_vec(), value.to_string().into_bytes());
+            Ok(value)
+        })
+    }
+
+    /// Adds `increment` to the float at `field`, a missing field counts as 0.
+    /// Returns the new value as it is stored.
+    pub fn incr_by_float(
+        &self,
+        key: &[u8],
+        field: &[u8],
+        increment: f64,
+        now: SystemTime,
+    ) -> anyhow::Result<Vec<u8>> {
+        self.with_hash_or_default(key, now, |hash| {
+            let current = match hash.get(field) {
+                Some(value) => {
+                    parse_float(value).ok_or_else(|| anyhow!("ERR hash value is not a float"))?
+                }
+                None => 0.0,
+            };
+            let value = current + increment;
+            if !value.is_finite() {
+                bail!("ERR increment would produce NaN or Infinity");
+            }
+            let value = value.to_string().into_bytes();
+            hash.insert(field.to_vec(), value.clone());
+            Ok(value)
+        })
+    }
+
+    /// Random pairs, `count` distinct ones if it is positive
+    /// and `-count` possibly repeated ones if it is negative, up to [`random::MAX_REPEATED`].
+    pub fn random_pairs(
+        &self,
+        key: &[u8],
+        count: i64,
+        now: SystemTime,
+    ) -> anyhow::Result<Vec<Pair>> {
+        let n = usize::try_from(count.unsigned_abs()).unwrap_or(usize::MAX);
+        // redis streams up to -(i64::MAX / 2) repeated ones, the reply is built whole here
+        if count < 0 && n > random::MAX_REPEATED {
+            bail!("ERR value is out of range");
+        }
+        let pairs = self.with_hash(key, now, |hash| {
+            let picked = if count < 0 {
+                random::sample_repeated(hash.len(), n)
+            } else {
+                random::sample_distinct(hash.len(), n)
+            };
+            picked
+                .into_iter()
+                .filter_map(|i| hash.get_index(i))
+                .map(|(field, value)| (field.clone(), value.clone()))
+                .collect()
+        })?;
+        Ok(pairs.unwrap_or_default())
+    }
+
+    /// Up to `count` pairs from `cursor` on, filtered by the glob `pattern`.
+    /// Fields that exist for the whole scan are returned at least once.
+    /// Returns the cursor to continue from, 0 once every field was visited.
+    pub fn scan(
+        &self,
+        key: &[u8],
+        cursor: usize,
+        pattern: Option<&[u8]>,
+        count: usize,
+        now: SystemTime,
+    ) -> anyhow::Result<(usize, Vec<Pair>)> {
+        let scanned = self.with_hash(key, now, |hash| {
+            let (cursor, pairs) = hash.scan(cursor, count);
+            let pairs = pairs
+                .into_iter()
+                .filter(|(field, _)| pattern.is_none_or(|pattern| glob::matches(pattern, field)))
+                .map(|(field, value)| (field.clone(), value.clone()))
+                .collect();
+            (cursor, pairs)
+        })?;
+        Ok(scanned.unwrap_or_default())
+    }
+}
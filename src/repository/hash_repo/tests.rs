@@ -0,0 +1,196 @@
+use std::time::SystemTime;
+
+use super::HashRepository;
+use crate::{
+    random,
+    repository::{kv_repo::KvRepository, Keyspace},
+};
+
+const NOW: SystemTime = SystemTime::UNIX_EPOCH;
+
+fn pair(field: &str, value: &str) -> (Vec<u8>, Vec<u8>) {
+    (field.into(), value.into())
+}
+
+fn repo(pairs: &[(&str, &str)]) -> HashRepository {
+    let repo = HashRepository::new();
+    let pairs = pairs.iter().map(|(f, v)| pair(f, v)).collect();
+    repo.set(b"hash", pairs, NOW).unwrap();
+    repo
+}
+
+#[test]
+fn set_counts_new_fields() {
+    let repo = repo(&[("a", "1")]);
+    let added = repo
+        .set(b"hash", vec![pair("a", "2"), pair("b", "3")], NOW)
+        .unwrap();
+    assert_eq!(added, 1);
+    assert_eq!(repo.get(b"hash", b"a", NOW).unwrap(), Some(b"2".to_vec()));
+    assert_eq!(repo.len(b"hash", NOW).unwrap(), 2);
+}
+
+#[test]
+fn set_nx_keeps_existing_value() {
+    let repo = repo(&[("a", "1")]);
+    assert!(!repo
+        .set_nx(b"hash", b"a".to_vec(), b"2".to_vec(), NOW)
+        .unwrap());
+    assert!(repo
+        .set_nx(b"hash", b"b".to_vec(), b"2".to_vec(), NOW)
+        .unwrap());
+    assert_eq!(repo.get(b"hash", b"a", NOW).unwrap(), Some(b"1".to_vec()));
+}
+
+#[test]
+fn get_many_on_missing_key_is_all_none() {
+    let repo = HashRepository::new();
+    let values = repo
+        .get_many(b"hash", &[b"a".to_vec(), b"b".to_vec()], NOW)
+        .unwrap();
+    assert_eq!(values, [None, None]);
+}
+
+#[test]
+fn deleting_last_field_removes_key() {
+    let keyspace = Keyspace::new();
+    let repo = HashRepository::with_keyspace(keyspace.clone());
+    repo.set(b"hash", vec![pair("a", "1")], NOW).unwrap();
+    let removed = repo
+        .delete(b"hash", &[b"a".to_vec(), b"b".to_vec()], NOW)
+        .unwrap();
+    assert_eq!(removed, 1);
+    assert!(keyspace.lock().is_empty());
+}
+
+#[test]
+fn hash_commands_on_string_are_wrong_type() {
+    let keyspace = Keyspace::new();
+    KvRepository::with_keyspace(keyspace.clone())
+        .set(b"hash".to_vec(), b"value".to_vec(), None)
+        .unwrap();
+    let repo = HashRepository::with_keyspace(keyspace);
+    let err = repo.set(b"hash", vec![pair("a", "1")], NOW).unwrap_err();
+    assert!(err.to_string().starts_with("WRONGTYPE"));
+    assert!(repo.get(b"hash", b"a", NOW).is_err());
+}
+
+#[test]
+fn incr_by_parses_integers() {
+    let repo = repo(&[("n", "10"), ("s", "abc"), ("max", &i64::MAX.to_string())]);
+    assert_eq!(repo.incr_by(b"hash", b"n", -3, NOW).unwrap(), 7);
+    assert_eq!(repo.incr_by(b"hash", b"new", 5, NOW).unwrap(), 5);
+    let err = repo.incr_by(b"hash", b"s", 1, NOW).unwrap_err();
+    assert_eq!(err.to_string(), "ERR hash value is not an integer");
+    let err = repo.incr_by(b"hash", b"max", 1, NOW).unwrap_err();
+    assert_eq!(err.to_string(), "ERR increment or decrement would overflow");
+}
+
+#[test]
+fn incr_by_float_formats_result() {
+    let repo = repo(&[("f", "10.5"), ("s", "abc")]);
+    assert_eq!(
+        repo.incr_by_float(b"hash", b"f", 0.1, NOW).unwrap(),
+        b"10.6"
+    );
+    assert_eq!(repo.incr_by_float(b"hash", b"new", 3.0, NOW).unwrap(), b"3");
+    let err = repo.incr_by_float(b"hash", b"s", 1.0, NOW).unwrap_err();
+    assert_eq!(err.to_string(), "ERR hash value is not a float");
+}
+
+#[test]
+fn failed_incr_does_not_create_key() {
+    let keyspace = Keyspace::new();
+    let repo = HashRepository::with_keyspace(keyspace.clone());
+    repo.incr_by_float(b"hash", b"f", f64::INFINITY, NOW)
+        .unwrap_err();
+    assert!(keyspace.lock().is_empty());
+}
+
+#[test]
+fn random_pairs_by_count_sign() {
+    let repo = repo(&[("a", "1"), ("b", "2"), ("c", "3")]);
+    let mut distinct = repo.random_pairs(b"hash", 2, NOW).unwrap();
+    distinct.sort();
+    distinct.dedup();
+    assert_eq!(distinct.len(), 2);
+    assert_eq!(repo.random_pairs(b"hash", 10, NOW).unwrap().len(), 3);
+    assert_eq!(repo.random_pairs(b"hash", -10, NOW).unwrap().len(), 10);
+    assert!(repo.random_pairs(b"missing", 1, NOW).unwrap().is_empty());
+}
+
+#[test]
+fn random_pairs_rejects_huge_negative_counts() {
+    let repo = repo(&[("a", "1")]);
+    let err = repo.random_pairs(b"hash", i64::MIN, NOW).unwrap_err();
+    assert_eq!(err.to_string(), "ERR value is out of range");
+    assert!(repo.random_pairs(b"missing", i64::MIN, NOW).is_err());
+    let max = i64::try_from(random::MAX_REPEATED).unwrap();
+    assert!(repo.random_pairs(b"hash", -max - 1, NOW).is_err());
+    assert_eq!(
+        repo.random_pairs(b"hash", -max, NOW).unwrap().len(),
+        random::MAX_REPEATED
+    );
+}
+
+/// Every pair from `cursor` on until the scan is done.
+fn scan_from(repo: &HashRepository, mut cursor: usize, count: usize) -> Vec<(Vec<u8>, Vec<u8>)> {
+    let mut pairs = Vec::new();
+    loop {
+        let (next, scanned) = repo.scan(b"hash", cursor, None, count, NOW).unwrap();
+        pairs.extend(scanned);
+        if next == 0 {
+            return pairs;
+        }
+        cursor = next;
+    }
+}
+
+#[test]
+fn scan_visits_every_field() {
+    let repo = repo(&[("a", "1"), ("b", "2"), ("c", "3"), ("ab", "4")]);
+    let mut pairs = scan_from(&repo, 0, 3);
+    pairs.sort();
+    assert_eq!(
+        pairs,
+        [
+            pair("a", "1"),
+            pair("ab", "4"),
+            pair("b", "2"),
+            pair("c", "3")
+        ]
+    );
+    let (cursor, mut matched) = repo.scan(b"hash", 0, Some(b"a*"), 10, NOW).unwrap();
+    matched.sort();
+    assert_eq!(
+        (cursor, matched),
+        (0, vec![pair("a", "1"), pair("ab", "4")])
+    );
+}
+
+#[test]
+fn scan_returns_fields_that_exist_for_the_whole_scan() {
+    let repo = repo(&[("a", "1"), ("b", "2"), ("c", "3"), ("d", "4")]);
+    let (cursor, first) = repo.scan(b"hash", 0, None, 1, NOW).unwrap();
+    assert!(cursor != 0 && !first.is_empty());
+    let gone: Vec<_> = first.iter().map(|(field, _)| field.clone()).collect();
+    repo.delete(b"hash", &gone, NOW).unwrap();
+    // grows the table during the scan
+    repo.set(
+        b"hash",
+        vec![pair("e", "5"), pair("f", "6"), pair("g", "7")],
+        NOW,
+    )
+    .unwrap();
+    let rest = scan_from(&repo, cursor, 10);
+    let kept = [
+        pair("a", "1"),
+        pair("b", "2"),
+        pair("c", "3"),
+        pair("d", "4"),
+    ];
+    assert!(kept
+        .iter()
+        .filter(|(field, _)| !gone.contains(field))
+        .all(|pair| rest.contains(pair)));
+}
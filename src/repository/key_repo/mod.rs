@@ -0,0 +1,239 @@
+use std::time::SystemTime;
+
+use anyhow::bail;
+
+use super::keyspace::Keyspace;
+use crate::glob;
+
+#[cfg(test)]
+mod tests;
+
+/// When `EXPIRE` may change the expiry of a key, a key without one counts as never expiring.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExpireOptions {
+    /// Only if the key has no expiry.
+    pub nx: bool,
+    /// Only if the key has an expiry.
+    pub xx: bool,
+    /// Only if the new expiry is later.
+    pub gt: bool,
+    /// Only if the new expiry is earlier.
+    pub lt: bool,
+}
+
+/// The time to live of a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ttl {
+    Missing,
+    Persistent,
+    Expires(SystemTime),
+}
+
+/// Operations on keys that work for every type of value.
+#[derive(Debug, Clone, Default)]
+pub struct KeyRepository {
+    keyspace: Keyspace,
+}
+
+impl KeyRepository {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_keyspace(keyspace: Keyspace) -> Self {
+        Self { keyspace }
+    }
+
+    /// Sets the key to expire `at`, returns whether the key exists and the options allowed it.
+    /// An expiry that is not in the future deletes the key.
+    pub fn expire(
+        &self,
+        key: &[u8],
+        at: SystemTime,
+        options: ExpireOptions,
+        now: SystemTime,
+    ) -> bool {
+        let mut db = self.keyspace.lock();
+        let Some(item) = db.get(key, now) else {
+            return false;
+        };
+        let allowed = match item.expiry {
+            Some(current) => {
+                let rejected =
+                    options.nx || (options.gt && at <= current) || (options.lt && at >= current);
+                !rejected
+            }
+            None => !options.xx && !options.gt,
+        };
+        if !allowed {
+            return false;
+        }
+        if at <= now {
+            db.remove(key, now);
+        } else {
+            db.set_expiry(key, Some(at), now);
+        }
+        true
+    }
+
+    /// Removes the expiry of the key, returns whether it had one.
+    pub fn persist(&self, key: &[u8], now: SystemTime) -> bool {
+        let mut db = self.keyspace.lock();
+        let had_expiry = db.get(key, now).is_some_and(|item| item.expiry.is_some());
+        had_expiry && db.set_expiry(key, None, now)
+    }
+
+    #[must_use]
+    pub fn ttl(&self, key: &[u8], now: SystemTime) -> Ttl {
+        match self.keyspace.lock().get(key, now) {
+            None => Ttl::Missing,
+            Some(item) => item.expiry.map_or(Ttl::Persistent, Ttl::Expires),
+        }
+    }
+
+    /// Removes `keys` and returns how many of them existed.
+    #[must_use]
+    pub fn delete(&self, keys: &[Vec<u8>], now: SystemTime) -> usize {
+        let mut db = self.keyspace.lock();
+        keys.iter()
+            .filter(|key| db.remove(key, now).is_some())
+            .count()
+    }
+
+    /// How many of `keys` exist, a key given twice counts twice.
+    #[must_use]
+    pub fn exists(&self, keys: &[Vec<u8>], now: SystemTime) -> usize {
+        let mut db = self.keyspace.lock();
+        keys.iter().filter(|key| db.get(key, now).is_some()).count()
+    }
+
+    /// The name of the type the key holds, `None` if it does not exist.
+    #[must_use]
+    pub fn type_of(&self, key: &[u8], now: SystemTime) -> Option<&'static str> {
+        self.keyspace
+            .lock()
+            .get(key, now)
+            .map(|item| item.value.type_name())
+    }
+
+    /// Moves the value and expiry of `key` to `new_key`, overwriting it unless `only_new`.
+    /// Returns whether it was moved, fails if `key` does not exist.
+    pub fn rename(
+        &self,
+        key: &[u8],
+        new_key: &[u8],
+        only_new: bool,
+        now: SystemTime,
+    ) -> anyhow::Result<bool> {
+        let mut db = self.keyspace.lock();
+        if db.get(key, now).is_none() {
+            bail!("ERR no such key");
+        }
+        if only_new && db.get(new_key, now).is_some() {
+            return Ok(false);
+        }
+        if key != new_key {
+            let item = db.remove(key, now).expect("checked above");
+            db.insert(new_key.to_vec(), item);
+            self.keyspace.blocking().wake(new_key);
+        }
+        Ok(true)
+    }
+
+    /// Copies the value and expiry of `source` to `destination` in the database of `target`,
+    /// overwriting it if `replace`. Returns whether it was copied.
+    pub fn copy(
+        &self,
+        source: &[u8],
+        target: &Self,
+        destination: &[u8],
+        replace: bool,
+        now: SystemTime,
+    ) -> anyhow::Result<bool> {
+        let (mut db, mut target_db) = self.keyspace.lock_pair(&target.keyspace);
+        if target_db.is_none() && source == destination {
+            bail!("ERR source and destination objects are the same");
+        }
+        let Some(item) = db.get(source, now).cloned() else {
+            return Ok(false);
+        };
+        let target_db = target_db.as_deref_mut().unwrap_or(&mut db);
+        if !replace && target_db.get(destination, now).is_some() {
+            return Ok(false);
+        }
+        target_db.insert(destination.to_vec(), item);
+        target.keyspace.blocking().wake(destination);
+        Ok(true)
+    }
+
+    /// Moves `key` with its expiry to the database of `target` unless it exists there.
+    /// Returns whether it was moved.
+    pub fn move_to(&self, key: &[u8], target: &Self, now: SystemTime) -> anyhow::Result<bool> {
+        let (mut db, target_db) = self.keyspace.lock_pair(&target.keyspace);
+        let Some(mut target_db) = target_db else {
+            bail!("ERR source and destination objects are the same");
+        };
+        if db.get(key, now).is_none() || target_db.get(key, now).is_some() {
+            return Ok(false);
+        }
+        let item = db.remove(key, now).expect("checked above");
+        target_db.insert(key.to_vec(), item);
+        target.keyspace.blocking().wake(key);
+        Ok(true)
+    }
+
+    #[must_use]
+    pub fn random_key(&self, now: SystemTime) -> Option<Vec<u8>> {
+        self.keyspace.lock().random_key(now)
+    }
+
+    /// The number of keys, including expired ones that were not removed yet like redis.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.keyspace.lock().len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes every key.
+    pub fn clear(&self) {
+        self.keyspace.lock().clear();
+    }
+
+    /// The keys matching the glob `pattern`.
+    #[must_use]
+    pub fn keys(&self, pattern: &[u8], now: SystemTime) -> Vec<Vec<u8>> {
+        let mut keys = self.keyspace.lock().keys(now);
+        keys.retain(|key| glob::matches(pattern, key));
+        keys
+    }
+
+    /// Up to `count` keys from `cursor` on, filtered by the glob `pattern` and the type name
+    /// `TYPE` replies with. Keys that exist for the whole scan are returned at least once.
+    /// Returns the cursor to continue from, 0 once every key was visited.
+    #[must_use]
+    pub fn scan(
+        &self,
+        cursor: usize,
+        pattern: Option<&[u8]>,
+        count: usize,
+        type_name: Option<&str>,
+        now: SystemTime,
+    ) -> (usize, Vec<Vec<u8>>) {
+        let mut db = self.keyspace.lock();
+        let (cursor, mut keys) = db.scan(cursor, count, now);
+        keys.retain(|key| pattern.is_none_or(|pattern| glob::matches(pattern, key)));
+        if let Some(type_name) = type_name {
+            keys.retain(|key| {
+                db.get(key, now)
+                    .is_some_and(|item| item.value.type_name().eq_ignore_ascii_case(type_name))
+            });
+        }
+        (cursor, keys)
+    }
+}
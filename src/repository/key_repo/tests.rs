@@ -0,0 +1,203 @@
+use std::time::{Duration, SystemTime};
+
+use super::{ExpireOptions, KeyRepository, Ttl};
+use crate::repository::{
+    list_repo::{End, ListRepository},
+    Keyspace,
+};
+
+const NOW: SystemTime = SystemTime::UNIX_EPOCH;
+
+fn repo() -> (KeyRepository, ListRepository) {
+    let keyspace = Keyspace::new();
+    (
+        KeyRepository::with_keyspace(keyspace.clone()),
+        ListRepository::with_keyspace(keyspace),
+    )
+}
+
+const NX: ExpireOptions = ExpireOptions {
+    nx: true,
+    xx: false,
+    gt: false,
+    lt: false,
+};
+const XX: ExpireOptions = ExpireOptions {
+    xx: true,
+    nx: false,
+    gt: false,
+    lt: false,
+};
+const GT: ExpireOptions = ExpireOptions {
+    gt: true,
+    nx: false,
+    xx: false,
+    lt: false,
+};
+const LT: ExpireOptions = ExpireOptions {
+    lt: true,
+    nx: false,
+    xx: false,
+    gt: false,
+};
+
+fn seconds(seconds: u64) -> SystemTime {
+    NOW + Duration::from_secs(seconds)
+}
+
+#[test]
+fn expire_works_for_any_type() {
+    let (keys, lists) = repo();
+    assert!(!keys.expire(b"l", seconds(10), ExpireOptions::default(), NOW));
+    lists
+        .push(b"l", vec![b"a".to_vec()], End::Left, false, NOW)
+        .unwrap();
+    assert_eq!(keys.ttl(b"l", NOW), Ttl::Persistent);
+    assert!(keys.expire(b"l", seconds(10), ExpireOptions::default(), NOW));
+    assert_eq!(keys.ttl(b"l", NOW), Ttl::Expires(seconds(10)));
+    assert_eq!(keys.ttl(b"l", seconds(11)), Ttl::Missing);
+}
+
+#[test]
+fn expire_conditions_treat_no_expiry_as_infinite() {
+    let (keys, lists) = repo();
+    lists
+        .push(b"l", vec![b"a".to_vec()], End::Left, false, NOW)
+        .unwrap();
+    assert!(!keys.expire(b"l", seconds(10), XX, NOW));
+    assert!(!keys.expire(b"l", seconds(10), GT, NOW));
+    assert!(keys.expire(b"l", seconds(10), LT, NOW));
+    assert!(!keys.expire(b"l", seconds(20), NX, NOW));
+    assert!(!keys.expire(b"l", seconds(20), LT, NOW));
+    assert!(keys.expire(b"l", seconds(20), GT, NOW));
+    assert_eq!(keys.ttl(b"l", NOW), Ttl::Expires(seconds(20)));
+    let xx_lt = ExpireOptions { xx: true, ..LT };
+    assert!(keys.expire(b"l", seconds(15), xx_lt, NOW));
+}
+
+#[test]
+fn expire_in_the_past_deletes_and_persist_removes_expiry() {
+    let (keys, lists) = repo();
+    lists
+        .push(b"l", vec![b"a".to_vec()], End::Left, false, NOW)
+        .unwrap();
+    assert!(!keys.persist(b"l", NOW));
+    keys.expire(b"l", seconds(10), ExpireOptions::default(), NOW);
+    assert!(keys.persist(b"l", NOW));
+    assert_eq!(keys.ttl(b"l", NOW), Ttl::Persistent);
+    assert!(keys.expire(b"l", seconds(5), ExpireOptions::default(), seconds(5)));
+    assert_eq!(keys.ttl(b"l", NOW), Ttl::Missing);
+}
+
+fn push(lists: &ListRepository, key: &[u8]) {
+    lists
+        .push(key, vec![b"a".to_vec()], End::Left, false, NOW)
+        .unwrap();
+}
+
+#[test]
+fn delete_and_exists_count_keys() {
+    let (keys, lists) = repo();
+    push(&lists, b"a");
+    push(&lists, b"b");
+    let names = [b"a".to_vec(), b"a".to_vec(), b"c".to_vec()];
+    assert_eq!(keys.exists(&names, NOW), 2);
+    assert_eq!(keys.type_of(b"a", NOW), Some("list"));
+    assert_eq!(keys.delete(&names, NOW), 1);
+    assert_eq!(keys.type_of(b"a", NOW), None);
+    assert_eq!(keys.len(), 1);
+    keys.clear();
+    assert!(keys.is_empty());
+    assert_eq!(keys.random_key(NOW), None);
+}
+
+#[test]
+fn rename_keeps_the_expiry() {
+    let (keys, lists) = repo();
+    assert!(keys.rename(b"a", b"b", false, NOW).is_err());
+    push(&lists, b"a");
+    push(&lists, b"b");
+    keys.expire(b"a", seconds(10), ExpireOptions::default(), NOW);
+    assert!(!keys.rename(b"a", b"b", true, NOW).unwrap());
+    assert!(keys.rename(b"a", b"b", false, NOW).unwrap());
+    assert_eq!(keys.exists(&[b"a".to_vec()], NOW), 0);
+    assert_eq!(keys.ttl(b"b", NOW), Ttl::Expires(seconds(10)));
+    assert!(!keys.rename(b"b", b"b", true, NOW).unwrap());
+    assert!(keys.rename(b"b", b"b", false, NOW).unwrap());
+    assert_eq!(keys.random_key(NOW), Some(b"b".to_vec()));
+}
+
+#[test]
+fn copy_respects_replace() {
+    let (keys, lists) = repo();
+    assert!(!keys.copy(b"a", &keys, b"b", false, NOW).unwrap());
+    push(&lists, b"a");
+    assert!(keys.copy(b"a", &keys, b"a", true, NOW).is_err());
+    assert!(keys.copy(b"a", &keys, b"b", false, NOW).unwrap());
+    lists
+        .push(b"a", vec![b"b".to_vec()], End::Left, false, NOW)
+        .unwrap();
+    assert!(!keys.copy(b"a", &keys, b"b", false, NOW).unwrap());
+    assert_eq!(lists.len(b"b", NOW).unwrap(), 1);
+    assert!(keys.copy(b"a", &keys, b"b", true, NOW).unwrap());
+    assert_eq!(lists.len(b"b", NOW).unwrap(), 2);
+}
+
+#[test]
+fn keys_and_scan_filter_by_pattern_and_type() {
+    let (keys, lists) = repo();
+    for key in ["one", "two", "three"] {
+        push(&lists, key.as_bytes());
+    }
+    let mut matched = keys.keys(b"t*", NOW);
+    matched.sort();
+    assert_eq!(matched, [b"three".to_vec(), b"two".to_vec()]);
+    let scan_all = |pattern: Option<&[u8]>, kind: Option<&str>| {
+        let (mut cursor, mut found) = (0, Vec::new());
+        loop {
+            let (next, scanned) = keys.scan(cursor, pattern, 2, kind, NOW);
+            found.extend(scanned);
+            if next == 0 {
+                found.sort();
+                return found;
+            }
+            cursor = next;
+        }
+    };
+    assert_eq!(
+        scan_all(Some(b"t*"), None),
+        [b"three".to_vec(), b"two".to_vec()]
+    );
+    assert_eq!(scan_all(None, Some("LIST")).len(), 3);
+    assert!(keys.scan(0, None, 10, Some("string"), NOW).1.is_empty());
+}
+
+#[test]
+fn scan_skips_expired_keys() {
+    let (keys, lists) = repo();
+    push(&lists, b"a");
+    push(&lists, b"b");
+    keys.expire(b"a", seconds(10), ExpireOptions::default(), NOW);
+    assert_eq!(
+        keys.scan(0, None, 10, None, seconds(11)),
+        (0, vec![b"b".to_vec()])
+    );
+    assert_eq!(keys.len(), 1);
+}
+
+#[test]
+fn move_and_copy_between_databases() {
+    let (keys, lists) = repo();
+    let (other, other_lists) = repo();
+    push(&lists, b"a");
+    push(&other_lists, b"b");
+    assert!(keys.move_to(b"a", &keys, NOW).is_err());
+    assert!(keys.copy(b"a", &other, b"a", false, NOW).unwrap());
+    assert!(!keys.move_to(b"a", &other, NOW).unwrap());
+    assert_eq!(other.delete(&[b"a".to_vec()], NOW), 1);
+    keys.expire(b"a", seconds(10), ExpireOptions::default(), NOW);
+    assert!(keys.move_to(b"a", &other, NOW).unwrap());
+    assert!(keys.is_empty());
+    assert_eq!(other.ttl(b"a", NOW), Ttl::Expires(seconds(10)));
+    assert!(!other.move_to(b"missing", &keys, NOW).unwrap());
+}
@@ -0,0 +1,78 @@
+//! The active expire cycle, which removes expired keys that are never read again.
+
+use std::{
+    sync::{Arc, Mutex},
+    thread::JoinHandle,
+    time::{Duration, Instant, SystemTime},
+};
+
+use super::{Db, Keyspace};
+
+/// Keys sampled each time the keyspace is locked.
+const KEYS_PER_LOOP: usize = 20;
+/// Sampling goes on while more than this percentage of the sampled keys was expired.
+const ACCEPTABLE_STALE_PERC: usize = 10;
+/// The percentage of every tick a cycle may spend.
+const TIME_PERC: u32 = 25;
+
+impl Keyspace {
+    /// Samples keys with an expiry and removes the expired ones until few of them are expired
+    /// or `budget` is spent. Returns how many keys were removed.
+    pub fn active_expire_cycle(&self, budget: Duration) -> usize {
+        active_expire_cycle(&self.db, budget)
+    }
+
+    /// Runs [`Self::active_expire_cycle`] on every one of `keyspaces` `hz` times a second
+    /// on a background thread, all of them within a quarter of the tick like redis does.
+    /// The thread stops once the keyspaces are dropped.
+    pub fn spawn_active_expire<'a>(
+        keyspaces: impl IntoIterator<Item = &'a Self>,
+        hz: u32,
+    ) -> JoinHandle<()> {
+        let dbs: Vec<_> = keyspaces
+            .into_iter()
+            .map(|keyspace| Arc::downgrade(&keyspace.db))
+            .collect();
+        let tick = Duration::from_secs(1) / hz.max(1);
+        let budget = tick * TIME_PERC / 100;
+        std::thread::spawn(move || loop {
+            std::thread::sleep(tick);
+            let start = Instant::now();
+            for db in &dbs {
+                let Some(db) = db.upgrade() else {
+                    return;
+                };
+                active_expire_cycle(&db, budget.saturating_sub(start.elapsed()));
+            }
+        })
+    }
+}
+
+fn active_expire_cycle(db: &Mutex<Db>, budget: Duration) -> usize {
+    let start = Instant::now();
+    let (mut sampled, mut expired) = (0, 0);
+    loop {
+        // the lock is released between samples so clients are not blocked for the whole cycle
+        let (loop_sampled, loop_expired) = db
+            .lock()
+            .unwrap()
+            .expire_sample(KEYS_PER_LOOP, SystemTime::now());
+        sampled += loop_sampled;
+        expired += loop_expired;
+        if loop_sampled == 0
+            || loop_expired * 100 <= loop_sampled * ACCEPTABLE_STALE_PERC
+            || start.elapsed() >= budget
+        {
+            break;
+        }
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let perc = if sampled == 0 {
+        0.0
+    } else {
+        expired as f64 * 100.0 / sampled as f64
+    };
+    let mut db = db.lock().unwrap();
+    db.stats.expired_stale_perc = perc * 0.05 + db.stats.expired_stale_perc * 0.95;
+    expired
+}
@@ -0,0 +1,388 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+    time::SystemTime,
+};
+
+use super::{
+    blocking::Blocking, hash_repo::Hash, list_repo::list::List, set_repo::set::Set,
+    stream_repo::stream::Stream, table::Table, zset_repo::SortedSet,
+};
+use crate::random;
+
+mod expire;
+
+#[cfg(test)]
+mod tests;
+
+/// Returned when a command is used on a key holding another type.
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("WRONGTYPE Operation against a key holding the wrong kind of value")]
+pub struct WrongType;
+
+/// A value stored in the keyspace, every key holds exactly one type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    String(Vec<u8>),
+    List(List),
+    Hash(Hash),
+    Set(Set),
+    SortedSet(SortedSet),
+    Stream(Stream),
+}
+
+impl Value {
+    /// The name `TYPE` replies with.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::String(_) => "string",
+            Self::List(_) => "list",
+            Self::Hash(_) => "hash",
+            Self::Set(_) => "set",
+            Self::SortedSet(_) => "zset",
+            Self::Stream(_) => "stream",
+        }
+    }
+}
+
+/// A type that can be stored in the keyspace.
+pub trait ValueType: Sized {
+    fn from_value_mut(value: &mut Value) -> Option<&mut Self>;
+    fn into_value(self) -> Value;
+}
+
+impl ValueType for Vec<u8> {
+    fn from_value_mut(value: &mut Value) -> Option<&mut Self> {
+        match value {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn into_value(self) -> Value {
+        Value::String(self)
+    }
+}
+
+impl ValueType for List {
+    fn from_value_mut(value: &mut Value) -> Option<&mut Self> {
+        match value {
+            Value::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    fn into_value(self) -> Value {
+        Value::List(self)
+    }
+}
+
+impl ValueType for Hash {
+    fn from_value_mut(value: &mut Value) -> Option<&mut Self> {
+        match value {
+            Value::Hash(hash) => Some(hash),
+            _ => None,
+        }
+    }
+
+    fn into_value(self) -> Value {
+        Value::Hash(self)
+    }
+}
+
+impl ValueType for Set {
+    fn from_value_mut(value: &mut Value) -> Option<&mut Self> {
+        match value {
+            Value::Set(set) => Some(set),
+            _ => None,
+        }
+    }
+
+    fn into_value(self) -> Value {
+        Value::Set(self)
+    }
+}
+
+impl ValueType for SortedSet {
+    fn from_value_mut(value: &mut Value) -> Option<&mut Self> {
+        match value {
+            Value::SortedSet(set) => Some(set),
+            _ => None,
+        }
+    }
+
+    fn into_value(self) -> Value {
+        Value::SortedSet(self)
+    }
+}
+
+impl ValueType for Stream {
+    fn from_value_mut(value: &mut Value) -> Option<&mut Self> {
+        match value {
+            Value::Stream(stream) => Some(stream),
+            _ => None,
+        }
+    }
+
+    fn into_value(self) -> Value {
+        Value::Stream(self)
+    }
+}
+
+/// A value together with the metadata shared by all types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    pub value: Value,
+    pub expiry: Option<SystemTime>,
+}
+
+impl Item {
+    #[must_use]
+    pub fn new(value: Value) -> Self {
+        Self {
+            value,
+            expiry: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_expiry(self, expiry: Option<SystemTime>) -> Self {
+        Self { expiry, ..self }
+    }
+
+    #[must_use]
+    pub fn is_expired(&self, now: SystemTime) -> bool {
+        self.expiry.is_some_and(|expiry| expiry < now)
+    }
+}
+
+/// The keys of a database, expired keys are removed lazily when they are looked up.
+#[derive(Debug, Default)]
+pub struct Db {
+    items: Table<Item>,
+    /// The keys with an expiry, so the active expire cycle can sample them.
+    volatile: Vec<Vec<u8>>,
+    volatile_index: HashMap<Vec<u8>, usize>,
+    stats: Stats,
+}
+
+/// Counters reported by `INFO stats`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Stats {
+    /// Keys removed because they expired, lazily or by the active expire cycle.
+    pub expired_keys: u64,
+    /// A running estimate of the percentage of sampled keys that were expired.
+    pub expired_stale_perc: f64,
+}
+
+impl Db {
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn get(&mut self, key: &[u8], now: SystemTime) -> Option<&mut Item> {
+        if self.items.get(key).is_some_and(|item| item.is_expired(now)) {
+            self.remove(key, now);
+            return None;
+        }
+        self.items.get_mut(key)
+    }
+
+    /// Changes the expiry of an existing key, returns whether the key exists.
+    /// Expiries should be changed through here so the active expire cycle sees them.
+    pub fn set_expiry(&mut self, key: &[u8], expiry: Option<SystemTime>, now: SystemTime) -> bool {
+        let Some(item) = self.get(key, now) else {
+            return false;
+        };
+        item.expiry = expiry;
+        self.track(key, expiry.is_some());
+        true
+    }
+
+    /// The value at `key` if it holds a `T`, fails with [`WrongType`] if it holds another type.
+    pub fn get_as<T>(&mut self, key: &[u8], now: SystemTime) -> Result<Option<&mut T>, WrongType>
+    where
+        T: ValueType,
+    {
+        match self.get(key, now) {
+            Some(item) => T::from_value_mut(&mut item.value)
+                .map(Some)
+                .ok_or(WrongType),
+            None => Ok(None),
+        }
+    }
+
+    /// Like [`Db::get_as`] but creates an empty `T` if the key does not exist.
+    pub fn get_or_default<T>(&mut self, key: &[u8], now: SystemTime) -> Result<&mut T, WrongType>
+    where
+        T: ValueType + Default,
+    {
+        if self.get(key, now).is_none() {
+            self.items
+                .insert(key.to_vec(), Item::new(T::default().into_value()));
+        }
+        let item = self.items.get_mut(key).expect("inserted above");
+        T::from_value_mut(&mut item.value).ok_or(WrongType)
+    }
+
+    pub fn insert(&mut self, key: Vec<u8>, item: Item) -> Option<Item> {
+        self.track(&key, item.expiry.is_some());
+        self.items.insert(key, item)
+    }
+
+    pub fn remove(&mut self, key: &[u8], now: SystemTime) -> Option<Item> {
+        self.track(key, false);
+        let item = self.items.remove(key)?;
+        if item.is_expired(now) {
+            self.stats.expired_keys += 1;
+            return None;
+        }
+        Some(item)
+    }
+
+    /// Removes every key, the stats are kept.
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.volatile.clear();
+        self.volatile_index.clear();
+    }
+
+    /// A random key that has not expired.
+    pub fn random_key(&mut self, now: SystemTime) -> Option<Vec<u8>> {
+        while let Some((key, _)) = self.items.random() {
+            let key = key.clone();
+            if self.get(&key, now).is_some() {
+                return Some(key);
+            }
+        }
+        None
+    }
+
+    /// The keys that have not expired among up to `count` from the bucket `cursor` on,
+    /// see [`Table::scan`].
+    pub fn scan(&mut self, cursor: usize, count: usize, now: SystemTime) -> (usize, Vec<Vec<u8>>) {
+        let (cursor, keys) = self.items.scan(cursor, count);
+        let keys: Vec<_> = keys.into_iter().map(|(key, _)| key.clone()).collect();
+        // expired keys are removed on the way, the cursor stays valid if that shrinks the table
+        let keys = keys
+            .into_iter()
+            .filter(|key| self.get(key, now).is_some())
+            .collect();
+        (cursor, keys)
+    }
+
+    /// Every key that has not expired.
+    pub fn keys(&mut self, now: SystemTime) -> Vec<Vec<u8>> {
+        let keys: Vec<_> = self.items.keys().cloned().collect();
+        keys.into_iter()
+            .filter(|key| self.get(key, now).is_some())
+            .collect()
+    }
+
+    /// The number of keys with an expiry.
+    #[must_use]
+    pub fn expires(&self) -> usize {
+        self.volatile.len()
+    }
+
+    #[must_use]
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    fn track(&mut self, key: &[u8], volatile: bool) {
+        match (self.volatile_index.get(key).copied(), volatile) {
+            (None, true) => {
+                self.volatile_index
+                    .insert(key.to_vec(), self.volatile.len());
+                self.volatile.push(key.to_vec());
+            }
+            (Some(index), false) => {
+                self.volatile_index.remove(key);
+                self.volatile.swap_remove(index);
+                if let Some(moved) = self.volatile.get(index) {
+                    self.volatile_index.insert(moved.clone(), index);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Checks up to `count` random keys with an expiry and removes the expired ones.
+    /// Returns how many keys were sampled and how many of them were expired.
+    fn expire_sample(&mut self, count: usize, now: SystemTime) -> (usize, usize) {
+        let (mut sampled, mut expired) = (0, 0);
+        while sampled < count && !self.volatile.is_empty() {
+            let key = self.volatile[random::below(self.volatile.len())].clone();
+            sampled += 1;
+            match self.items.get(&key) {
+                Some(item) if item.is_expired(now) => {
+                    self.remove(&key, now);
+                    expired += 1;
+                }
+                Some(item) if item.expiry.is_some() => (),
+                // the expiry was removed without going through `set_expiry`
+                _ => self.track(&key, false),
+            }
+        }
+        (sampled, expired)
+    }
+}
+
+/// The single keyspace shared by every connection.
+#[derive(Debug, Clone, Default)]
+pub struct Keyspace {
+    db: Arc<Mutex<Db>>,
+    blocking: Blocking,
+}
+
+impl Keyspace {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn lock(&self) -> MutexGuard<'_, Db> {
+        self.db.lock().unwrap()
+    }
+
+    /// Locks this keyspace and `other` in a fixed order, so two threads locking the same pair
+    /// can not deadlock. There is no second guard if `other` is this keyspace.
+    pub fn lock_pair<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> (MutexGuard<'a, Db>, Option<MutexGuard<'a, Db>>) {
+        if Arc::ptr_eq(&self.db, &other.db) {
+            return (self.lock(), None);
+        }
+        if Arc::as_ptr(&self.db) < Arc::as_ptr(&other.db) {
+            let db = self.lock();
+            (db, Some(other.lock()))
+        } else {
+            let other = other.lock();
+            (self.lock(), Some(other))
+        }
+    }
+
+    /// Swaps every key with `other`, blocked clients of both are woken to look again.
+    pub fn swap(&self, other: &Self) {
+        if let (mut db, Some(mut other_db)) = self.lock_pair(other) {
+            std::mem::swap(&mut *db, &mut *other_db);
+        }
+        self.blocking.wake_every();
+        other.blocking.wake_every();
+    }
+
+    /// The clients blocked on keys of this keyspace.
+    #[must_use]
+    pub fn blocking(&self) -> &Blocking {
+        &self.blocking
+    }
+}
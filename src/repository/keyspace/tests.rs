@@ -0,0 +1,122 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use super::*;
+use crate::repository::BlockResult;
+
+#[test]
+fn get_as_returns_value_of_matching_type() {
+    let mut db = Db::default();
+    db.insert(b"key".to_vec(), Item::new(Value::String(b"value".to_vec())));
+    let value = db.get_as::<Vec<u8>>(b"key", UNIX_EPOCH).unwrap();
+    assert_eq!(value.map(|v| v.as_slice()), Some(&b"value"[..]));
+}
+
+#[test]
+fn get_as_fails_with_wrongtype_on_other_type() {
+    let mut db = Db::default();
+    db.insert(b"key".to_vec(), Item::new(Value::Stream(Stream::new())));
+    assert_eq!(db.get_as::<Vec<u8>>(b"key", UNIX_EPOCH), Err(WrongType));
+    assert_eq!(
+        WrongType.to_string(),
+        "WRONGTYPE Operation against a key holding the wrong kind of value"
+    );
+}
+
+#[test]
+fn get_or_default_creates_missing_value_but_keeps_type_check() {
+    let mut db = Db::default();
+    db.get_or_default::<Stream>(b"stream", UNIX_EPOCH).unwrap();
+    assert_eq!(db.len(), 1);
+    db.insert(b"string".to_vec(), Item::new(Value::String(Vec::new())));
+    assert!(db.get_or_default::<Stream>(b"string", UNIX_EPOCH).is_err());
+}
+
+#[test]
+fn expired_items_are_removed_on_lookup() {
+    let mut db = Db::default();
+    let item = Item::new(Value::String(Vec::new())).with_expiry(Some(UNIX_EPOCH));
+    db.insert(b"key".to_vec(), item);
+    assert!(db.get(b"key", UNIX_EPOCH).is_some());
+    assert!(db
+        .get(b"key", UNIX_EPOCH + Duration::from_secs(1))
+        .is_none());
+    assert!(db.is_empty());
+}
+
+#[test]
+fn expired_key_can_be_reused_for_another_type() {
+    let mut db = Db::default();
+    let item = Item::new(Value::String(Vec::new())).with_expiry(Some(UNIX_EPOCH));
+    db.insert(b"key".to_vec(), item);
+    let later = UNIX_EPOCH + Duration::from_secs(1);
+    assert!(db.get_or_default::<Stream>(b"key", later).is_ok());
+}
+
+#[test]
+fn expired_keys_are_counted_in_stats() {
+    let mut db = Db::default();
+    let item = Item::new(Value::String(Vec::new())).with_expiry(Some(UNIX_EPOCH));
+    db.insert(b"key".to_vec(), item);
+    db.get(b"key", UNIX_EPOCH + Duration::from_secs(1));
+    assert_eq!(db.stats().expired_keys, 1);
+}
+
+#[test]
+fn expire_sample_only_sees_keys_with_expiry() {
+    let mut db = Db::default();
+    let later = UNIX_EPOCH + Duration::from_secs(1);
+    db.insert(b"persistent".to_vec(), Item::new(Value::String(Vec::new())));
+    for key in [b"a", b"b", b"c"] {
+        let item = Item::new(Value::String(Vec::new())).with_expiry(Some(UNIX_EPOCH));
+        db.insert(key.to_vec(), item);
+    }
+    db.set_expiry(b"c", None, UNIX_EPOCH);
+    let (sampled, expired) = db.expire_sample(10, later);
+    assert_eq!((sampled, expired), (2, 2));
+    assert_eq!(db.len(), 2);
+    assert_eq!(db.expire_sample(10, later), (0, 0));
+}
+
+#[test]
+fn active_expire_cycle_removes_keys_nobody_reads() {
+    let keyspace = Keyspace::new();
+    {
+        let mut db = keyspace.lock();
+        for i in 0..1000u32 {
+            let item = Item::new(Value::String(Vec::new())).with_expiry(Some(UNIX_EPOCH));
+            db.insert(i.to_be_bytes().to_vec(), item);
+        }
+    }
+    let removed = keyspace.active_expire_cycle(Duration::from_secs(10));
+    assert_eq!(removed, 1000);
+    let db = keyspace.lock();
+    assert!(db.is_empty());
+    assert_eq!(db.stats().expired_keys, 1000);
+    assert!(db.stats().expired_stale_perc > 0.0);
+}
+
+#[test]
+fn swap_exchanges_keys_and_wakes_blocked_clients() {
+    let (a, b) = (Keyspace::new(), Keyspace::new());
+    a.lock()
+        .insert(b"key".to_vec(), Item::new(Value::String(b"a".to_vec())));
+    let waiter = b.clone();
+    let handle = std::thread::spawn(move || {
+        waiter.blocking().block(
+            &[b"key".to_vec()],
+            Some(Duration::from_secs(5)),
+            || match waiter.lock().get(b"key", UNIX_EPOCH) {
+                Some(_) => BlockResult::Found(()),
+                None => BlockResult::NotFound,
+            },
+        )
+    });
+    while b.blocking().waiting(b"key") == 0 {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    b.swap(&a);
+    assert!(!handle.join().unwrap().is_not_found());
+    assert!(a.lock().is_empty());
+    let (db, same) = a.lock_pair(&a);
+    assert!(db.is_empty() && same.is_none());
+}
@@ -0,0 +1,78 @@
+//! The longest common subsequence of two strings, for `LCS`.
+
+#[cfg(test)]
+mod tests;
+
+/// A common run of bytes, as inclusive ranges in both strings.
+pub type Match = ((usize, usize), (usize, usize));
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Lcs {
+    pub string: Vec<u8>,
+    /// The runs that make up `string` from the last to the first, like redis lists them.
+    pub matches: Vec<Match>,
+}
+
+/// Finds the longest common subsequence with the dynamic programming table redis uses,
+/// so ties are broken the same way.
+/// Fails like redis if the table would take more than `max_table_bytes`,
+/// which redis limits to `proto-max-bulk-len`.
+pub fn lcs(a: &[u8], b: &[u8], max_table_bytes: usize) -> anyhow::Result<Lcs> {
+    let width = b.len() + 1;
+    let cells = (a.len() + 1)
+        .checked_mul(width)
+        .filter(|cells| {
+            cells
+                .checked_mul(std::mem::size_of::<u32>())
+                .is_some_and(|bytes| bytes <= max_table_bytes)
+        })
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "ERR Insufficient memory, transient memory for LCS exceeds proto-max-bulk-len"
+            )
+        })?;
+    // table[i * width + j] is the length of the LCS of a[..i] and b[..j]
+    let mut table = vec![0u32; cells];
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            table[i * width + j] = if a[i - 1] == b[j - 1] {
+                table[(i - 1) * width + j - 1] + 1
+            } else {
+                table[(i - 1) * width + j].max(table[i * width + j - 1])
+            };
+        }
+    }
+
+    let mut string = Vec::with_capacity(table[a.len() * width + b.len()] as usize);
+    let mut matches = Vec::new();
+    let mut current: Option<Match> = None;
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 && j > 0 {
+        let emit = if a[i - 1] == b[j - 1] {
+            string.push(a[i - 1]);
+            current = Some(match current {
+                // contiguous with the run found so far, extend it backwards
+                Some(((a_start, a_end), (b_start, b_end))) if a_start == i && b_start == j => {
+                    ((i - 1, a_end), (j - 1, b_end))
+                }
+                _ => ((i - 1, i - 1), (j - 1, j - 1)),
+            });
+            let at_start = i == 1 || j == 1;
+            i -= 1;
+            j -= 1;
+            at_start
+        } else {
+            if table[(i - 1) * width + j] > table[i * width + j - 1] {
+                i -= 1;
+            } else {
+                j -= 1;
+            }
+            current.is_some()
+        };
+        if emit {
+            matches.extend(current.take());
+        }
+    }
+    string.reverse();
+    Ok(Lcs { string, matches })
+}
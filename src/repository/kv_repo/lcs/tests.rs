@@ -0,0 +1,25 @@
+use super::*;
+
+#[test]
+fn finds_subsequence_and_matches_like_redis() {
+    let lcs = lcs(b"ohmytext", b"mynewtext", usize::MAX).unwrap();
+    assert_eq!(lcs.string, b"mytext");
+    assert_eq!(lcs.matches, [((4, 7), (5, 8)), ((2, 3), (0, 1))]);
+}
+
+#[test]
+fn empty_strings_have_empty_lcs() {
+    assert_eq!(lcs(b"", b"abc", usize::MAX).unwrap(), Lcs::default());
+    assert_eq!(lcs(b"abc", b"xyz", usize::MAX).unwrap().string, b"");
+}
+
+#[test]
+fn fails_when_the_table_exceeds_the_limit() {
+    // a 4 by 4 table of u32
+    assert!(lcs(b"abc", b"abc", 64).is_ok());
+    let err = lcs(b"abcd", b"abc", 64).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "ERR Insufficient memory, transient memory for LCS exceeds proto-max-bulk-len"
+    );
+}
@@ -1,72 +1,416 @@
-use std::{
-    collections::HashMap,
-    sync::{Arc, Mutex},
+use std::time::SystemTime;
+
+use anyhow::{anyhow, bail};
+
+use super::{
+    keyspace::{Item, Keyspace, Value},
+    WrongType,
 };
+use crate::resp::value::convert::{parse_integer, NOT_AN_INTEGER, NOT_A_FLOAT};
+
+pub mod lcs;
 
 #[cfg(test)]
 mod tests;
 
 pub type KvRepository = LockingMemoryRepository;
 
+/// Whether `SET` writes depending on the key existing, `NX` and `XX`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Condition {
+    #[default]
+    Always,
+    NotExists,
+    Exists,
+}
+
+/// What happens to the time to live of a key that is written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Expiry {
+    /// Removes any time to live.
+    #[default]
+    Persist,
+    /// Keeps the current time to live, `KEEPTTL`.
+    Keep,
+    At(SystemTime),
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SetOptions {
+    pub condition: Condition,
+    pub expiry: Expiry,
+    /// Return the old value, which then has to be a string.
+    pub get: bool,
+}
+
+/// The result of a write together with the string it left behind, so it can be replicated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Written<T> {
+    pub result: T,
+    pub value: Vec<u8>,
+    pub expiry: Option<SystemTime>,
+}
+
+/// What [`LockingMemoryRepository::set_with`] did.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SetResult {
+    pub written: bool,
+    /// The old value if it was asked for.
+    pub old: Option<Vec<u8>>,
+    /// The expiry of the key after the write.
+    pub expiry: Option<SystemTime>,
+}
+
+/// What [`LockingMemoryRepository::set_range`] did.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SetRangeResult {
+    /// Nothing was written for an empty range, with the length of the string, 0 if it is missing.
+    Unchanged(usize),
+    Written(Written<usize>),
+}
+
+/// How [`LockingMemoryRepository::get_ex`] changed the time to live of the key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TtlChange {
+    Unchanged,
+    /// The key now expires at the time, or never.
+    Changed(Option<SystemTime>),
+    /// The expiry was in the past so the key was deleted.
+    Expired,
+}
+
 #[derive(Debug, Clone)]
 pub struct LockingMemoryRepository {
-    kv_store: Arc<Mutex<HashMap<String, String>>>,
-    kv_store_expiry: Arc<Mutex<HashMap<String, std::time::SystemTime>>>,
+    keyspace: Keyspace,
 }
 
 impl LockingMemoryRepository {
     #[must_use]
     pub fn new() -> Self {
-        Self {
-            kv_store: Arc::new(Mutex::new(HashMap::new())),
-            kv_store_expiry: Arc::new(Mutex::new(HashMap::new())),
-        }
+        Self::with_keyspace(Keyspace::new())
     }
 
+    #[must_use]
+    pub fn with_keyspace(keyspace: Keyspace) -> Self {
+        Self { keyspace }
+    }
+
+    /// Fails with [`super::WrongType`] if the key holds something other than a string.
     pub fn get(
         &self,
-        key: &str,
+        key: &[u8],
         timestamp: std::time::SystemTime,
-    ) -> anyhow::Result<Option<String>> {
-        let mut expiry_lock = self.kv_store_expiry.lock().unwrap();
-        let mut store_lock = self.kv_store.lock().unwrap();
-        let key_has_expiry_time_and_is_expired = expiry_lock
-            .get(key)
-            .is_some_and(|expiry| *expiry < timestamp);
-        if key_has_expiry_time_and_is_expired {
-            expiry_lock.remove(key);
-            store_lock.remove(key);
-            Ok(None)
-        } else {
-            Ok(store_lock.get(key).cloned())
-        }
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        Ok(self
+            .keyspace
+            .lock()
+            .get_as::<Vec<u8>>(key, timestamp)?
+            .cloned())
     }
 
+    /// Overwrites the key whatever type it held, like `SET` does.
     pub fn set(
         &self,
-        key: String,
-        value: String,
+        key: Vec<u8>,
+        value: Vec<u8>,
         expiry: Option<std::time::SystemTime>,
-    ) -> anyhow::Result<Option<String>> {
-        let mut lock = self.kv_store_expiry.lock().unwrap();
-        if let Some(expiry) = expiry {
-            lock.insert(key.clone(), expiry);
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        let item = Item::new(Value::String(value)).with_expiry(expiry);
+        Ok(match self.keyspace.lock().insert(key, item) {
+            Some(Item {
+                value: Value::String(old),
+                ..
+            }) => Some(old),
+            _ => None,
+        })
+    }
+
+    /// `SET` with all its options. Overwrites any type unless the old value is asked for,
+    /// then the key has to hold a string.
+    pub fn set_with(
+        &self,
+        key: Vec<u8>,
+        value: Vec<u8>,
+        options: SetOptions,
+        now: SystemTime,
+    ) -> anyhow::Result<SetResult> {
+        let mut db = self.keyspace.lock();
+        let current = db.get(&key, now);
+        let exists = current.is_some();
+        let current_expiry = current.as_ref().and_then(|item| item.expiry);
+        let old = match current.map(|item| &item.value) {
+            Some(Value::String(old)) if options.get => Some(old.clone()),
+            Some(_) if options.get => return Err(WrongType.into()),
+            _ => None,
+        };
+        let written = match options.condition {
+            Condition::Always => true,
+            Condition::NotExists => !exists,
+            Condition::Exists => exists,
+        };
+        if !written {
+            return Ok(SetResult {
+                written,
+                old,
+                expiry: current_expiry,
+            });
+        }
+        let expiry = match options.expiry {
+            Expiry::Persist => None,
+            Expiry::Keep => current_expiry,
+            Expiry::At(at) => Some(at),
+        };
+        db.insert(key, Item::new(Value::String(value)).with_expiry(expiry));
+        Ok(SetResult {
+            written,
+            old,
+            expiry,
+        })
+    }
+
+    /// Removes the key and returns its value, it has to hold a string.
+    pub fn get_del(&self, key: &[u8], now: SystemTime) -> anyhow::Result<Option<Vec<u8>>> {
+        let mut db = self.keyspace.lock();
+        if db.get_as::<Vec<u8>>(key, now)?.is_none() {
+            return Ok(None);
+        }
+        Ok(db.remove(key, now).and_then(|item| match item.value {
+            Value::String(value) => Some(value),
+            _ => None,
+        }))
+    }
+
+    /// Returns the value of the key and changes its time to live, it has to hold a string.
+    pub fn get_ex(
+        &self,
+        key: &[u8],
+        expiry: Expiry,
+        now: SystemTime,
+    ) -> anyhow::Result<Option<(Vec<u8>, TtlChange)>> {
+        let mut db = self.keyspace.lock();
+        let Some(value) = db.get_as::<Vec<u8>>(key, now)?.cloned() else {
+            return Ok(None);
+        };
+        let current = db.get(key, now).and_then(|item| item.expiry);
+        let change = match expiry {
+            Expiry::Keep => TtlChange::Unchanged,
+            Expiry::Persist if current.is_none() => TtlChange::Unchanged,
+            Expiry::At(at) if current == Some(at) => TtlChange::Unchanged,
+            // an expiry in the past deletes the key right away
+            Expiry::At(at) if at <= now => {
+                db.remove(key, now);
+                TtlChange::Expired
+            }
+            Expiry::Persist => {
+                db.set_expiry(key, None, now);
+                TtlChange::Changed(None)
+            }
+            Expiry::At(at) => {
+                db.set_expiry(key, Some(at), now);
+                TtlChange::Changed(Some(at))
+            }
+        };
+        Ok(Some((value, change)))
+    }
+
+    /// Runs `f` on the string at `key` and whether it existed, it is created empty if not.
+    /// Nothing is created if `f` fails.
+    fn update<F, T>(&self, key: &[u8], now: SystemTime, f: F) -> anyhow::Result<Written<T>>
+    where
+        F: FnOnce(&mut Vec<u8>, bool) -> anyhow::Result<T>,
+    {
+        let mut db = self.keyspace.lock();
+        let existed = db.get(key, now).is_some();
+        let result = match f(db.get_or_default::<Vec<u8>>(key, now)?, existed) {
+            Ok(result) => result,
+            Err(err) => {
+                if !existed {
+                    db.remove(key, now);
+                }
+                return Err(err);
+            }
+        };
+        let item = db.get(key, now).expect("created above");
+        let Value::String(value) = &item.value else {
+            unreachable!("checked above")
+        };
+        Ok(Written {
+            result,
+            value: value.clone(),
+            expiry: item.expiry,
+        })
+    }
+
+    /// Adds `increment` to the integer at `key`, a missing key counts as 0.
+    pub fn incr_by(
+        &self,
+        key: &[u8],
+        increment: i64,
+        now: SystemTime,
+    ) -> anyhow::Result<Written<i64>> {
+        self.update(key, now, |value, existed| {
+            let current: i64 = if existed {
+                parse_integer(value).ok_or_else(|| anyhow!(NOT_AN_INTEGER))?
+            } else {
+                0
+            };
+            let new = current
+                .checked_add(increment)
+                .ok_or_else(|| anyhow!("ERR increment or decrement would overflow"))?;
+            *value = new.to_string().into_bytes();
+            Ok(new)
+        })
+    }
+
+    /// Adds `increment` to the float at `key`, a missing key counts as 0.
+    pub fn incr_by_float(
+        &self,
+        key: &[u8],
+        increment: f64,
+        now: SystemTime,
+    ) -> anyhow::Result<Written<f64>> {
+        self.update(key, now, |value, existed| {
+            let current = if existed {
+                parse_float(value).ok_or_else(|| anyhow!(NOT_A_FLOAT))?
+            } else {
+                0.0
+            };
+            let new = current + increment;
+            if !new.is_finite() {
+                bail!("ERR increment would produce NaN or Infinity");
+            }
+            *value = new.to_string().into_bytes();
+            Ok(new)
+        })
+    }
+
+    /// Appends to the string at `key` and returns its new length.
+    /// Fails if the string would grow longer than `max_len`.
+    pub fn append(
+        &self,
+        key: &[u8],
+        suffix: &[u8],
+        max_len: usize,
+        now: SystemTime,
+    ) -> anyhow::Result<Written<usize>> {
+        self.update(key, now, |value, _| {
+            check_len(value.len().saturating_add(suffix.len()), max_len)?;
+            value.extend_from_slice(suffix);
+            Ok(value.len())
+        })
+    }
+
+    /// Overwrites the string at `key` from `offset`, padding it with zero bytes if it is shorter.
+    /// Returns the new length. Like redis, empty `bytes` neither create nor pad the string.
+    /// Fails if the string would grow longer than `max_len`.
+    pub fn set_range(
+        &self,
+        key: &[u8],
+        offset: usize,
+        bytes: &[u8],
+        max_len: usize,
+        now: SystemTime,
+    ) -> anyhow::Result<SetRangeResult> {
+        if bytes.is_empty() {
+            let len = self
+                .keyspace
+                .lock()
+                .get_as::<Vec<u8>>(key, now)?
+                .map_or(0, |value| value.len());
+            return Ok(SetRangeResult::Unchanged(len));
+        }
+        self.update(key, now, |value, _| {
+            let end = offset.saturating_add(bytes.len());
+            check_len(end, max_len)?;
+            if value.len() < end {
+                value.resize(end, 0);
+            }
+            value[offset..end].copy_from_slice(bytes);
+            Ok(value.len())
+        })
+        .map(SetRangeResult::Written)
+    }
+
+    pub fn len(&self, key: &[u8], now: SystemTime) -> anyhow::Result<usize> {
+        Ok(self
+            .keyspace
+            .lock()
+            .get_as::<Vec<u8>>(key, now)?
+            .map_or(0, |value| value.len()))
+    }
+
+    /// The bytes from `start` to `end` inclusive, negative offsets count from the end.
+    pub fn get_range(
+        &self,
+        key: &[u8],
+        start: i64,
+        end: i64,
+        now: SystemTime,
+    ) -> anyhow::Result<Vec<u8>> {
+        let mut db = self.keyspace.lock();
+        let Some(value) = db.get_as::<Vec<u8>>(key, now)? else {
+            return Ok(Vec::new());
+        };
+        let len = i64::try_from(value.len()).expect("strings are smaller than i64::MAX");
+        let start = if start < 0 {
+            (len + start).max(0)
         } else {
-            lock.remove(&key);
+            start
+        };
+        let end = if end < 0 { len + end } else { end.min(len - 1) };
+        if start > end || start >= len {
+            return Ok(Vec::new());
         }
-        Ok(self.kv_store.lock().unwrap().insert(key, value))
+        let start = usize::try_from(start).expect("not negative");
+        let end = usize::try_from(end).expect("not negative");
+        Ok(value[start..=end].to_vec())
     }
 
+    /// The strings at `keys`, keys holding other types count as missing.
     #[must_use]
-    pub fn is_empty(&self) -> bool {
-        let is_empty = self.kv_store.lock().unwrap().is_empty();
-        if is_empty {
-            assert!(
-                self.kv_store_expiry.lock().unwrap().is_empty(),
-                "expiry should be empty if key value store is empty"
-            );
+    pub fn get_many(&self, keys: &[Vec<u8>], now: SystemTime) -> Vec<Option<Vec<u8>>> {
+        let mut db = self.keyspace.lock();
+        keys.iter()
+            .map(|key| db.get_as::<Vec<u8>>(key, now).ok().flatten().cloned())
+            .collect()
+    }
+
+    /// Sets every pair at once, only if none of the keys exist if `only_new`.
+    /// Returns whether they were set.
+    pub fn set_many(
+        &self,
+        pairs: Vec<(Vec<u8>, Vec<u8>)>,
+        only_new: bool,
+        now: SystemTime,
+    ) -> bool {
+        let mut db = self.keyspace.lock();
+        if only_new && pairs.iter().any(|(key, _)| db.get(key, now).is_some()) {
+            return false;
+        }
+        for (key, value) in pairs {
+            db.insert(key, Item::new(Value::String(value)));
         }
-        is_empty
+        true
+    }
+
+    /// The strings at `a` and `b` for `LCS`, missing keys count as empty strings.
+    pub fn get_pair(
+        &self,
+        a: &[u8],
+        b: &[u8],
+        now: SystemTime,
+    ) -> anyhow::Result<(Vec<u8>, Vec<u8>)> {
+        let mut db = self.keyspace.lock();
+        let mut get = |key| match db.get_as::<Vec<u8>>(key, now) {
+            Ok(value) => Ok(value.cloned().unwrap_or_default()),
+            Err(WrongType) => Err(anyhow!("ERR The specified keys must contain string values")),
+        };
+        Ok((get(a)?, get(b)?))
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.keyspace.lock().is_empty()
     }
 }
 
@@ -75,3 +419,19 @@ impl Default for LockingMemoryRepository {
         Self::new()
     }
 }
+
+/// Finite floats only, like redis stores them.
+pub(crate) fn parse_float(bytes: &[u8]) -> Option<f64> {
+    std::str::from_utf8(bytes)
+        .ok()?
+        .parse()
+        .ok()
+        .filter(|float: &f64| float.is_finite())
+}
+
+fn check_len(len: usize, max_len: usize) -> anyhow::Result<()> {
+    if len > max_len {
+        bail!("ERR string exceeds maximum allowed size (proto-max-bulk-len)");
+    }
+    Ok(())
+}
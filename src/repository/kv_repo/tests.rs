@@ -1,4 +1,4 @@
-use super::KvRepository;
+use super::{KvRepository, SetRangeResult, TtlChange};
 
 #[test]
 fn new_repository_is_empty() {
@@ -9,15 +9,14 @@ fn new_repository_is_empty() {
 #[test]
 fn repository_is_not_empty_after_setting_value() {
     let repo = KvRepository::new();
-    repo.set("key".to_string(), "value".to_string(), None)
-        .unwrap();
+    repo.set("key".into(), "value".into(), None).unwrap();
     assert!(!repo.is_empty());
 }
 
 #[test]
 fn getting_empty_repository_returns_none() {
     let repo = KvRepository::new();
-    let none = repo.get("key", std::time::SystemTime::UNIX_EPOCH).unwrap();
+    let none = repo.get(b"key", std::time::SystemTime::UNIX_EPOCH).unwrap();
     assert_eq!(none, None);
 }
 
@@ -25,9 +24,10 @@ fn getting_empty_repository_returns_none() {
 fn getting_set_value_returns_some() {
     let repo = KvRepository::new();
     let key = "key";
-    repo.set(key.to_string(), "value".to_string(), None)
+    repo.set(key.into(), "value".into(), None).unwrap();
+    let some = repo
+        .get(key.as_bytes(), std::time::SystemTime::UNIX_EPOCH)
         .unwrap();
-    let some = repo.get(key, std::time::SystemTime::UNIX_EPOCH).unwrap();
     assert!(some.is_some());
 }
 
@@ -36,21 +36,26 @@ fn getting_set_value_returns_same_value() {
     let repo = KvRepository::new();
     let key = "key";
     let value = "value";
-    repo.set(key.to_string(), value.to_string(), None).unwrap();
-    let some_value = repo.get(key, std::time::SystemTime::UNIX_EPOCH).unwrap();
-    assert_eq!(some_value, Some(value.to_string()));
+    repo.set(key.into(), value.into(), None).unwrap();
+    let some_value = repo
+        .get(key.as_bytes(), std::time::SystemTime::UNIX_EPOCH)
+        .unwrap();
+    assert_eq!(some_value, Some(value.into()));
 }
 
+#[test]
 fn getting_other_value_still_returns_none() {
     let repo = KvRepository::new();
     let key = "key";
     let value = "value";
-    repo.set(key.to_string(), value.to_string(), None).unwrap();
-    let some_value = repo.get(key, std::time::SystemTime::UNIX_EPOCH).unwrap();
-    assert_eq!(some_value, Some(value.to_string()));
+    repo.set(key.into(), value.into(), None).unwrap();
+    let some_value = repo
+        .get(key.as_bytes(), std::time::SystemTime::UNIX_EPOCH)
+        .unwrap();
+    assert_eq!(some_value, Some(value.into()));
 
     let none = repo
-        .get("other_key", std::time::SystemTime::UNIX_EPOCH)
+        .get(b"other_key", std::time::SystemTime::UNIX_EPOCH)
         .unwrap();
     assert_eq!(none, None);
 }
@@ -59,8 +64,8 @@ fn getting_other_value_still_returns_none() {
 fn set_value_with_expiry() {
     let repo = KvRepository::new();
     repo.set(
-        "key".to_string(),
-        "value".to_string(),
+        "key".into(),
+        "value".into(),
         Some(std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(10)),
     )
     .unwrap();
@@ -77,7 +82,7 @@ fn get_value_with_expiry_is_some_before_timestamp() {
         Some(timestamp + std::time::Duration::from_secs(1)),
     )
     .unwrap();
-    let some_value = repo.get(key, timestamp).unwrap();
+    let some_value = repo.get(key.as_bytes(), timestamp).unwrap();
     assert_eq!(some_value, Some(value.into()));
 }
 #[test]
@@ -87,7 +92,10 @@ fn get_value_with_expiry_is_none_after_timestamp() {
     let timestamp = std::time::SystemTime::UNIX_EPOCH;
     repo.set(key.into(), value.into(), Some(timestamp)).unwrap();
     let none = repo
-        .get(key, timestamp + std::time::Duration::from_secs(1))
+        .get(
+            key.as_bytes(),
+            timestamp + std::time::Duration::from_secs(1),
+        )
         .unwrap();
     assert_eq!(none, None);
 }
@@ -98,12 +106,278 @@ fn get_value_with_expiry_is_always_none_after_one_get_after_timestamp() {
     let timestamp = std::time::SystemTime::UNIX_EPOCH;
     repo.set(key.into(), value.into(), Some(timestamp)).unwrap();
     let none_value = repo
-        .get(key, timestamp + std::time::Duration::from_secs(1))
+        .get(
+            key.as_bytes(),
+            timestamp + std::time::Duration::from_secs(1),
+        )
         .unwrap();
     assert_eq!(none_value, None);
 
     let none = repo
-        .get(key, timestamp - std::time::Duration::from_secs(1))
+        .get(
+            key.as_bytes(),
+            timestamp - std::time::Duration::from_secs(1),
+        )
         .unwrap();
     assert_eq!(none, None);
 }
+
+#[test]
+fn binary_key_and_value_round_trip() {
+    let repo = KvRepository::new();
+    let key = vec![0xff, 0x00, b'k'];
+    let value = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x00, 0xfe];
+    repo.set(key.clone(), value.clone(), None).unwrap();
+    let some_value = repo.get(&key, std::time::SystemTime::UNIX_EPOCH).unwrap();
+    assert_eq!(some_value, Some(value));
+}
+
+#[test]
+fn set_overwrites_other_types_and_get_rejects_them() {
+    use crate::repository::{keyspace, Keyspace, WrongType};
+
+    let keyspace = Keyspace::new();
+    let repo = KvRepository::with_keyspace(keyspace.clone());
+    keyspace.lock().insert(
+        b"key".to_vec(),
+        keyspace::Item::new(keyspace::Value::Stream(Default::default())),
+    );
+    let err = repo
+        .get(b"key", std::time::SystemTime::UNIX_EPOCH)
+        .unwrap_err();
+    assert_eq!(err.downcast::<WrongType>().unwrap(), WrongType);
+
+    repo.set(b"key".to_vec(), b"value".to_vec(), None).unwrap();
+    let value = repo.get(b"key", std::time::SystemTime::UNIX_EPOCH).unwrap();
+    assert_eq!(value, Some(b"value".to_vec()));
+}
+
+#[test]
+fn set_with_conditions_only_writes_when_met() {
+    use super::{Condition, SetOptions};
+
+    let repo = KvRepository::new();
+    let now = std::time::SystemTime::UNIX_EPOCH;
+    let xx = SetOptions {
+        condition: Condition::Exists,
+        ..SetOptions::default()
+    };
+    let result = repo
+        .set_with(b"k".to_vec(), b"a".to_vec(), xx, now)
+        .unwrap();
+    assert!(!result.written);
+    assert!(repo.is_empty());
+
+    let nx = SetOptions {
+        condition: Condition::NotExists,
+        get: true,
+        ..SetOptions::default()
+    };
+    let result = repo
+        .set_with(b"k".to_vec(), b"a".to_vec(), nx, now)
+        .unwrap();
+    assert!(result.written);
+    let result = repo
+        .set_with(b"k".to_vec(), b"b".to_vec(), nx, now)
+        .unwrap();
+    assert!(!result.written);
+    assert_eq!(result.old, Some(b"a".to_vec()));
+}
+
+#[test]
+fn set_with_keep_ttl_keeps_expiry() {
+    use super::{Expiry, SetOptions};
+
+    let repo = KvRepository::new();
+    let now = std::time::SystemTime::UNIX_EPOCH;
+    let expiry = now + std::time::Duration::from_secs(10);
+    repo.set(b"k".to_vec(), b"a".to_vec(), Some(expiry))
+        .unwrap();
+    let keep = SetOptions {
+        expiry: Expiry::Keep,
+        ..SetOptions::default()
+    };
+    let result = repo
+        .set_with(b"k".to_vec(), b"b".to_vec(), keep, now)
+        .unwrap();
+    assert_eq!(result.expiry, Some(expiry));
+    let result = repo
+        .set_with(b"k".to_vec(), b"c".to_vec(), SetOptions::default(), now)
+        .unwrap();
+    assert_eq!(result.expiry, None);
+}
+
+#[test]
+fn set_with_get_rejects_other_types() {
+    use super::SetOptions;
+    use crate::repository::{keyspace, Keyspace};
+
+    let keyspace = Keyspace::new();
+    let repo = KvRepository::with_keyspace(keyspace.clone());
+    keyspace.lock().insert(
+        b"k".to_vec(),
+        keyspace::Item::new(keyspace::Value::Stream(Default::default())),
+    );
+    let get = SetOptions {
+        get: true,
+        ..SetOptions::default()
+    };
+    let err = repo
+        .set_with(b"k".to_vec(), b"a".to_vec(), get, std::time::UNIX_EPOCH)
+        .unwrap_err();
+    assert!(err.to_string().starts_with("WRONGTYPE"));
+}
+
+#[test]
+fn get_del_and_get_ex_change_the_key() {
+    use super::Expiry;
+
+    let repo = KvRepository::new();
+    let now = std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(10);
+    repo.set(b"k".to_vec(), b"a".to_vec(), None).unwrap();
+    let value = repo
+        .get_ex(b"k", Expiry::At(std::time::UNIX_EPOCH), now)
+        .unwrap();
+    assert_eq!(value, Some((b"a".to_vec(), TtlChange::Expired)));
+    assert!(repo.is_empty());
+
+    repo.set(b"k".to_vec(), b"a".to_vec(), None).unwrap();
+    assert_eq!(repo.get_del(b"k", now).unwrap(), Some(b"a".to_vec()));
+    assert_eq!(repo.get_del(b"k", now).unwrap(), None);
+    assert!(repo.is_empty());
+}
+
+#[test]
+fn get_ex_reports_only_actual_ttl_changes() {
+    use super::Expiry;
+
+    let repo = KvRepository::new();
+    let now = std::time::SystemTime::UNIX_EPOCH;
+    let at = now + std::time::Duration::from_secs(10);
+    repo.set(b"k".to_vec(), b"a".to_vec(), None).unwrap();
+    let change = |expiry| repo.get_ex(b"k", expiry, now).unwrap().unwrap().1;
+    assert_eq!(change(Expiry::Persist), TtlChange::Unchanged);
+    assert_eq!(change(Expiry::At(at)), TtlChange::Changed(Some(at)));
+    assert_eq!(change(Expiry::At(at)), TtlChange::Unchanged);
+    assert_eq!(change(Expiry::Keep), TtlChange::Unchanged);
+    assert_eq!(change(Expiry::Persist), TtlChange::Changed(None));
+    assert_eq!(repo.get_ex(b"missing", Expiry::Persist, now).unwrap(), None);
+}
+
+#[test]
+fn incr_by_is_atomic_across_threads() {
+    let repo = KvRepository::new();
+    let threads: Vec<_> = (0..8)
+        .map(|_| {
+            let repo = repo.clone();
+            std::thread::spawn(move || {
+                for _ in 0..1000 {
+                    repo.incr_by(b"counter", 1, std::time::UNIX_EPOCH).unwrap();
+                }
+            })
+        })
+        .collect();
+    threads
+        .into_iter()
+        .for_each(|thread| thread.join().unwrap());
+    let value = repo.get(b"counter", std::time::UNIX_EPOCH).unwrap();
+    assert_eq!(value, Some(b"8000".to_vec()));
+}
+
+#[test]
+fn incr_by_keeps_expiry_and_rejects_non_integers() {
+    let repo = KvRepository::new();
+    let now = std::time::UNIX_EPOCH;
+    let expiry = now + std::time::Duration::from_secs(10);
+    repo.set(b"n".to_vec(), b"10".to_vec(), Some(expiry))
+        .unwrap();
+    let written = repo.incr_by(b"n", -15, now).unwrap();
+    assert_eq!(written.result, -5);
+    assert_eq!(written.value, b"-5");
+    assert_eq!(written.expiry, Some(expiry));
+
+    repo.set(b"s".to_vec(), b"".to_vec(), None).unwrap();
+    assert!(repo.incr_by(b"s", 1, now).is_err());
+    repo.set(b"z".to_vec(), b"007".to_vec(), None).unwrap();
+    assert!(repo.incr_by(b"z", 1, now).is_err());
+    assert!(repo.incr_by_float(b"missing", f64::INFINITY, now).is_err());
+    assert_eq!(repo.get(b"missing", now).unwrap(), None);
+    assert_eq!(repo.incr_by_float(b"f", 1.5, now).unwrap().value, b"1.5");
+}
+
+#[test]
+fn ranges_of_strings() {
+    let repo = KvRepository::new();
+    let now = std::time::UNIX_EPOCH;
+    assert_eq!(
+        repo.append(b"k", b"Hello", usize::MAX, now).unwrap().result,
+        5
+    );
+    assert_eq!(
+        repo.append(b"k", b" World", usize::MAX, now)
+            .unwrap()
+            .result,
+        11
+    );
+    assert_eq!(repo.get_range(b"k", -5, -1, now).unwrap(), b"World");
+    assert_eq!(repo.get_range(b"k", 5, 3, now).unwrap(), b"");
+    let written = |result| match result {
+        SetRangeResult::Written(written) => written.value,
+        SetRangeResult::Unchanged(len) => panic!("nothing written, length {len}"),
+    };
+    let value = written(repo.set_range(b"k", 6, b"Redis", usize::MAX, now).unwrap());
+    assert_eq!(value, b"Hello Redis");
+    let value = written(repo.set_range(b"pad", 2, b"x", usize::MAX, now).unwrap());
+    assert_eq!(value, b"\0\0x");
+    assert_eq!(repo.len(b"pad", now).unwrap(), 3);
+}
+
+#[test]
+fn strings_cannot_grow_past_the_max_len() {
+    let repo = KvRepository::new();
+    let now = std::time::UNIX_EPOCH;
+    assert_eq!(repo.append(b"k", b"Hello", 5, now).unwrap().result, 5);
+    let err = repo.append(b"k", b"!", 5, now).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "ERR string exceeds maximum allowed size (proto-max-bulk-len)"
+    );
+    assert!(repo.set_range(b"k", 4, b"!", 5, now).is_ok());
+    assert!(repo.set_range(b"k", 5, b"!", 5, now).is_err());
+    assert_eq!(repo.get(b"k", now).unwrap(), Some(b"Hell!".to_vec()));
+}
+
+#[test]
+fn set_range_with_empty_bytes_writes_nothing() {
+    let repo = KvRepository::new();
+    let now = std::time::UNIX_EPOCH;
+    assert_eq!(
+        repo.set_range(b"missing", 5, b"", usize::MAX, now).unwrap(),
+        SetRangeResult::Unchanged(0)
+    );
+    assert!(repo.is_empty());
+    repo.set(b"k".to_vec(), b"abc".to_vec(), None).unwrap();
+    assert_eq!(
+        repo.set_range(b"k", 10, b"", usize::MAX, now).unwrap(),
+        SetRangeResult::Unchanged(3)
+    );
+    assert_eq!(repo.get(b"k", now).unwrap(), Some(b"abc".to_vec()));
+}
+
+#[test]
+fn set_many_only_new_sets_nothing_if_any_key_exists() {
+    let repo = KvRepository::new();
+    let now = std::time::UNIX_EPOCH;
+    let pairs = |pairs: &[(&str, &str)]| {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.as_bytes().to_vec(), value.as_bytes().to_vec()))
+            .collect::<Vec<_>>()
+    };
+    assert!(repo.set_many(pairs(&[("a", "1"), ("b", "2")]), true, now));
+    assert!(!repo.set_many(pairs(&[("b", "3"), ("c", "3")]), true, now));
+    assert_eq!(
+        repo.get_many(&[b"a".to_vec(), b"c".to_vec()], now),
+        [Some(b"1".to_vec()), None]
+    );
+}
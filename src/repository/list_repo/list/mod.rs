@@ -0,0 +1,284 @@
+use std::collections::VecDeque;
+
+use anyhow::bail;
+
+use crate::{
+    command::parser::SYNTAX_ERROR,
+    resp::{self, FromValue},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The most elements kept in one chunk, full chunks are split in half on insert.
+const CHUNK_SIZE: usize = 128;
+
+/// A deque of small chunks like redis' quicklist.
+/// Pushing and popping at either end is O(1), indexing only walks the chunks.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct List {
+    chunks: VecDeque<VecDeque<Vec<u8>>>,
+    len: usize,
+}
+
+/// One end of a list, `LEFT` is the head and `RIGHT` the tail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum End {
+    Left,
+    Right,
+}
+
+impl List {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push(&mut self, end: End, value: Vec<u8>) {
+        match end {
+            End::Left => self.push_front(value),
+            End::Right => self.push_back(value),
+        }
+    }
+
+    pub fn pop(&mut self, end: End) -> Option<Vec<u8>> {
+        match end {
+            End::Left => self.pop_front(),
+            End::Right => self.pop_back(),
+        }
+    }
+
+    pub fn push_front(&mut self, value: Vec<u8>) {
+        match self.chunks.front_mut() {
+            Some(chunk) if chunk.len() < CHUNK_SIZE => chunk.push_front(value),
+            _ => self.chunks.push_front(VecDeque::from([value])),
+        }
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, value: Vec<u8>) {
+        match self.chunks.back_mut() {
+            Some(chunk) if chunk.len() < CHUNK_SIZE => chunk.push_back(value),
+            _ => self.chunks.push_back(VecDeque::from([value])),
+        }
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<Vec<u8>> {
+        let chunk = self.chunks.front_mut()?;
+        let value = chunk.pop_front();
+        if chunk.is_empty() {
+            self.chunks.pop_front();
+        }
+        self.len -= 1;
+        value
+    }
+
+    pub fn pop_back(&mut self) -> Option<Vec<u8>> {
+        let chunk = self.chunks.back_mut()?;
+        let value = chunk.pop_back();
+        if chunk.is_empty() {
+            self.chunks.pop_back();
+        }
+        self.len -= 1;
+        value
+    }
+
+    /// The chunk and the offset in it of the element at `index`,
+    /// walking from whichever end is closer.
+    fn locate(&self, index: usize) -> Option<(usize, usize)> {
+        if index >= self.len {
+            return None;
+        }
+        if index < self.len / 2 {
+            let mut offset = index;
+            for (i, chunk) in self.chunks.iter().enumerate() {
+                if offset < chunk.len() {
+                    return Some((i, offset));
+                }
+                offset -= chunk.len();
+            }
+        } else {
+            let mut offset = self.len - 1 - index;
+            for (i, chunk) in self.chunks.iter().enumerate().rev() {
+                if offset < chunk.len() {
+                    return Some((i, chunk.len() - 1 - offset));
+                }
+                offset -= chunk.len();
+            }
+        }
+        unreachable!("`len` is the sum of the chunk lengths")
+    }
+
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&Vec<u8>> {
+        let (chunk, offset) = self.locate(index)?;
+        self.chunks[chunk].get(offset)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Vec<u8>> {
+        let (chunk, offset) = self.locate(index)?;
+        self.chunks[chunk].get_mut(offset)
+    }
+
+    /// Inserts `value` so it ends up at `index`, `index == len` appends.
+    pub fn insert(&mut self, index: usize, value: Vec<u8>) {
+        if index == self.len {
+            return self.push_back(value);
+        }
+        let (i, offset) = self.locate(index).expect("index out of bounds");
+        let chunk = &mut self.chunks[i];
+        chunk.insert(offset, value);
+        if chunk.len() > CHUNK_SIZE {
+            let tail = chunk.split_off(chunk.len() / 2);
+            self.chunks.insert(i + 1, tail);
+        }
+        self.len += 1;
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<Vec<u8>> {
+        let (i, offset) = self.locate(index)?;
+        let chunk = &mut self.chunks[i];
+        let value = chunk.remove(offset);
+        if chunk.is_empty() {
+            self.chunks.remove(i);
+        }
+        self.len -= 1;
+        value
+    }
+
+    /// Keeps only the elements from `start` to `end`, both inclusive.
+    pub fn trim(&mut self, start: usize, end: usize) {
+        if start > end || start >= self.len {
+            *self = Self::new();
+            return;
+        }
+        let back = self.len - 1 - end.min(self.len - 1);
+        self.drop_front(start);
+        self.drop_back(back);
+    }
+
+    fn drop_front(&mut self, mut n: usize) {
+        while let Some(chunk) = self.chunks.front_mut() {
+            if n < chunk.len() {
+                chunk.drain(..n);
+                self.len -= n;
+                return;
+            }
+            n -= chunk.len();
+            self.len -= chunk.len();
+            self.chunks.pop_front();
+        }
+    }
+
+    fn drop_back(&mut self, mut n: usize) {
+        while let Some(chunk) = self.chunks.back_mut() {
+            if n < chunk.len() {
+                chunk.truncate(chunk.len() - n);
+                self.len -= n;
+                return;
+            }
+            n -= chunk.len();
+            self.len -= chunk.len();
+            self.chunks.pop_back();
+        }
+    }
+
+    /// Removes the elements for which `f` returns true, visiting them in the order of `from`,
+    /// at most `limit` of them. Returns how many were removed.
+    pub fn remove_matching<F>(&mut self, from: End, limit: usize, mut f: F) -> usize
+    where
+        F: FnMut(&[u8]) -> bool,
+    {
+        let mut removed = 0;
+        let mut index = 0;
+        while index < self.len && removed < limit {
+            let position = match from {
+                End::Left => index,
+                End::Right => self.len - 1 - index,
+            };
+            if f(self.get(position).expect("position is in bounds")) {
+                self.remove(position);
+                removed += 1;
+            } else {
+                index += 1;
+            }
+        }
+        removed
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Vec<u8>> {
+        self.chunks.iter().flatten()
+    }
+
+    /// The range from `start` to `end` in redis' index semantics,
+    /// negative indexes count from the tail and out of range indexes are clamped.
+    #[must_use]
+    pub fn range(&self, start: i64, end: i64) -> Option<(usize, usize)> {
+        let len = i64::try_from(self.len).expect("lists are smaller than i64::MAX");
+        let start = if start < 0 {
+            (len + start).max(0)
+        } else {
+            start
+        };
+        let end = if end < 0 { len + end } else { end.min(len - 1) };
+        if start > end || start >= len {
+            return None;
+        }
+        Some((
+            usize::try_from(start).expect("not negative"),
+            usize::try_from(end).expect("not negative"),
+        ))
+    }
+
+    /// The position `index` refers to, negative indexes count from the tail.
+    #[must_use]
+    pub fn index(&self, index: i64) -> Option<usize> {
+        let len = i64::try_from(self.len).expect("lists are smaller than i64::MAX");
+        let index = if index < 0 { len + index } else { index };
+        usize::try_from(index)
+            .ok()
+            .filter(|index| *index < self.len)
+    }
+}
+
+impl FromIterator<Vec<u8>> for List {
+    fn from_iter<T: IntoIterator<Item = Vec<u8>>>(iter: T) -> Self {
+        let mut list = Self::new();
+        iter.into_iter().for_each(|value| list.push_back(value));
+        list
+    }
+}
+
+impl End {
+    /// How commands spell the end.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Left => "LEFT",
+            Self::Right => "RIGHT",
+        }
+    }
+}
+
+impl FromValue for End {
+    fn from_value(value: resp::Value) -> anyhow::Result<Self> {
+        if value.eq_ignore_ascii_case("LEFT") {
+            Ok(Self::Left)
+        } else if value.eq_ignore_ascii_case("RIGHT") {
+            Ok(Self::Right)
+        } else {
+            bail!(SYNTAX_ERROR)
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use super::*;
+
+fn list(len: usize) -> List {
+    (0..len).map(|i| i.to_string().into_bytes()).collect()
+}
+
+fn strings(list: &List) -> Vec<String> {
+    list.iter()
+        .map(|v| String::from_utf8(v.clone()).unwrap())
+        .collect()
+}
+
+#[test]
+fn push_and_pop_at_both_ends() {
+    let mut list = List::new();
+    list.push_back(b"b".to_vec());
+    list.push_front(b"a".to_vec());
+    list.push(End::Right, b"c".to_vec());
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.pop(End::Left), Some(b"a".to_vec()));
+    assert_eq!(list.pop_back(), Some(b"c".to_vec()));
+    assert_eq!(list.pop_back(), Some(b"b".to_vec()));
+    assert_eq!(list.pop_front(), None);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn elements_span_multiple_chunks() {
+    let len = CHUNK_SIZE * 3 + 5;
+    let list = list(len);
+    assert!(list.chunks.len() > 1);
+    for i in [0, 1, CHUNK_SIZE, len / 2, len - 1] {
+        assert_eq!(list.get(i), Some(&i.to_string().into_bytes()));
+    }
+    assert_eq!(list.get(len), None);
+    assert_eq!(list.iter().count(), len);
+}
+
+#[test]
+fn insert_splits_full_chunks() {
+    let mut list = list(CHUNK_SIZE);
+    list.insert(1, b"x".to_vec());
+    assert_eq!(list.len(), CHUNK_SIZE + 1);
+    assert_eq!(list.chunks.len(), 2);
+    assert_eq!(list.get(1), Some(&b"x".to_vec()));
+    assert_eq!(list.get(2), Some(&b"1".to_vec()));
+    list.insert(list.len(), b"end".to_vec());
+    assert_eq!(list.iter().last(), Some(&b"end".to_vec()));
+}
+
+#[test]
+fn remove_drops_empty_chunks() {
+    let mut list = List::new();
+    list.push_back(b"a".to_vec());
+    assert_eq!(list.remove(0), Some(b"a".to_vec()));
+    assert!(list.chunks.is_empty());
+    assert_eq!(list.remove(0), None);
+}
+
+#[test]
+fn trim_keeps_inclusive_range() {
+    let mut list = list(CHUNK_SIZE * 2 + 10);
+    list.trim(CHUNK_SIZE + 1, CHUNK_SIZE + 3);
+    assert_eq!(
+        strings(&list),
+        [CHUNK_SIZE + 1, CHUNK_SIZE + 2, CHUNK_SIZE + 3].map(|i| i.to_string())
+    );
+    list.trim(2, 1);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn remove_matching_from_either_end() {
+    let mut list: List = ["a", "b", "a", "c", "a"]
+        .map(|s| s.as_bytes().to_vec())
+        .into_iter()
+        .collect();
+    assert_eq!(list.remove_matching(End::Right, 1, |v| v == b"a"), 1);
+    assert_eq!(strings(&list), ["a", "b", "a", "c"]);
+    assert_eq!(
+        list.remove_matching(End::Left, usize::MAX, |v| v == b"a"),
+        2
+    );
+    assert_eq!(strings(&list), ["b", "c"]);
+}
+
+#[test]
+fn range_uses_redis_index_semantics() {
+    let list = list(5);
+    assert_eq!(list.range(0, -1), Some((0, 4)));
+    assert_eq!(list.range(-100, 100), Some((0, 4)));
+    assert_eq!(list.range(-2, -1), Some((3, 4)));
+    assert_eq!(list.range(3, 1), None);
+    assert_eq!(list.range(5, 10), None);
+    assert_eq!(List::new().range(0, -1), None);
+}
+
+#[test]
+fn index_counts_negative_from_tail() {
+    let list = list(3);
+    assert_eq!(list.index(0), Some(0));
+    assert_eq!(list.index(-1), Some(2));
+    assert_eq!(list.index(3), None);
+    assert_eq!(list.index(-4), None);
+}
@@ -0,0 +1,326 @@
+use std::time::{Duration, SystemTime};
+
+use anyhow::bail;
+
+use super::{
+    keyspace::{Db, Keyspace, WrongType},
+    BlockResult,
+};
+
+pub mod list;
+
+pub use list::{End, List};
+
+/// The key elements were popped from and the elements.
+pub type Popped = (Vec<u8>, Vec<Vec<u8>>);
+
+#[cfg(test)]
+mod tests;
+
+/// Lists stored in the keyspace, a list that becomes empty is removed like in redis.
+#[derive(Debug, Clone, Default)]
+pub struct ListRepository {
+    keyspace: Keyspace,
+}
+
+impl ListRepository {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_keyspace(keyspace: Keyspace) -> Self {
+        Self { keyspace }
+    }
+
+    /// Runs `f` on the list at `key`, returns `None` if there is no such key.
+    fn with_list<F, T>(&self, key: &[u8], now: SystemTime, f: F) -> Result<Option<T>, WrongType>
+    where
+        F: FnOnce(&mut List) -> T,
+    {
+        let mut db = self.keyspace.lock();
+        Self::update(&mut db, key, now, f)
+    }
+
+    fn update<F, T>(db: &mut Db, key: &[u8], now: SystemTime, f: F) -> Result<Option<T>, WrongType>
+    where
+        F: FnOnce(&mut List) -> T,
+    {
+        let Some(list) = db.get_as::<List>(key, now)? else {
+            return Ok(None);
+        };
+        let result = f(list);
+        if list.is_empty() {
+            db.remove(key, now);
+        }
+        Ok(Some(result))
+    }
+
+    /// Pushes `values` one after the other at `end` and returns the new length.
+    /// With `only_if_exists` nothing is pushed to a missing key and 0 is returned.
+    pub fn push(
+        &self,
+        key: &[u8],
+        values: Vec<Vec<u8>>,
+        end: End,
+        only_if_exists: bool,
+        now: SystemTime,
+    ) -> anyhow::Result<usize> {
+        let mut db = self.keyspace.lock();
+        let list = if only_if_exists {
+            match db.get_as::<List>(key, now)? {
+                Some(list) => list,
+                None => return Ok(0),
+            }
+        } else {
+            db.get_or_default::<List>(key, now)?
+        };
+        values.into_iter().for_each(|value| list.push(end, value));
+        let len = list.len();
+        self.keyspace.blocking().wake(key);
+        Ok(len)
+    }
+
+    /// Pops up to `count` elements from `end`, `None` if the key does not exist.
+    pub fn pop(
+        &self,
+        key: &[u8],
+        end: End,
+        count: usize,
+        now: SystemTime,
+    ) -> anyhow::Result<Option<Vec<Vec<u8>>>> {
+        Ok(self.with_list(key, now, |list| {
+            std::iter::from_fn(|| list.pop(end)).take(count).collect()
+        })?)
+    }
+
+    pub fn len(&self, key: &[u8], now: SystemTime) -> anyhow::Result<usize> {
+        Ok(self.with_list(key, now, |list| list.len())?.unwrap_or(0))
+    }
+
+    /// The elements from `start` to `stop`, both inclusive and negative ones counting from the tail.
+    pub fn range(
+        &self,
+        key: &[u8],
+        start: i64,
+        stop: i64,
+        now: SystemTime,
+    ) -> anyhow::Result<Vec<Vec<u8>>> {
+        let range = self.with_list(key, now, |list| match list.range(start, stop) {
+            Some((start, end)) => list
+                .iter()
+                .skip(start)
+                .take(end - start + 1)
+                .cloned()
+                .collect(),
+            None => Vec::new(),
+        })?;
+        Ok(range.unwrap_or_default())
+    }
+
+    pub fn index(
+        &self,
+        key: &[u8],
+        index: i64,
+        now: SystemTime,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        let element = self.with_list(key, now, |list| {
+            list.index(index).and_then(|index| list.get(index)).cloned()
+        })?;
+        Ok(element.flatten())
+    }
+
+    pub fn set(
+        &self,
+        key: &[u8],
+        index: i64,
+        value: Vec<u8>,
+        now: SystemTime,
+    ) -> anyhow::Result<()> {
+        let found = self.with_list(key, now, |list| {
+            let element = list.index(index).and_then(|index| list.get_mut(index));
+            element.map(|element| *element = value).is_some()
+        })?;
+        match found {
+            None => bail!("ERR no such key"),
+            Some(false) => bail!("ERR index out of range"),
+            Some(true) => Ok(()),
+        }
+    }
+
+    /// Inserts `value` next to the first `pivot` and returns the new length,
+    /// -1 if `pivot` was not found and 0 if the key does not exist.
+    pub fn insert(
+        &self,
+        key: &[u8],
+        before: bool,
+        pivot: &[u8],
+        value: Vec<u8>,
+        now: SystemTime,
+    ) -> anyhow::Result<i64> {
+        let len = self.with_list(key, now, |list| {
+            let Some(index) = list.iter().position(|element| element == pivot) else {
+                return -1;
+            };
+            list.insert(if before { index } else { index + 1 }, value);
+            i64::try_from(list.len()).expect("lists are smaller than i64::MAX")
+        })?;
+        Ok(len.unwrap_or(0))
+    }
+
+    /// Removes `count` occurrences of `value`, from the tail if `count` is negative
+    /// and all of them if it is 0. Returns how many were removed.
+    pub fn remove(
+        &self,
+        key: &[u8],
+        count: i64,
+        value: &[u8],
+        now: SystemTime,
+    ) -> anyhow::Result<usize> {
+        let from = if count < 0 { End::Right } else { End::Left };
+        let limit = match count {
+            0 => usize::MAX,
+            count => usize::try_from(count.unsigned_abs()).unwrap_or(usize::MAX),
+        };
+        let removed = self.with_list(key, now, |list| {
+            list.remove_matching(from, limit, |element| element == value)
+        })?;
+        Ok(removed.unwrap_or(0))
+    }
+
+    /// Keeps only the elements from `start` to `stop`, in the same index semantics as [`Self::range`].
+    pub fn trim(&self, key: &[u8], start: i64, stop: i64, now: SystemTime) -> anyhow::Result<()> {
+        self.with_list(key, now, |list| match list.range(start, stop) {
+            Some((start, end)) => list.trim(start, end),
+            None => *list = List::new(),
+        })?;
+        Ok(())
+    }
+
+    /// The indexes of the elements equal to `value`.
+    /// A negative `rank` searches from the tail and skips `|rank| - 1` matches,
+    /// at most `count` matches are returned (all if 0) and at most `max_len` elements compared (all if 0).
+    pub fn position(
+        &self,
+        key: &[u8],
+        value: &[u8],
+        rank: i64,
+        count: usize,
+        max_len: usize,
+        now: SystemTime,
+    ) -> anyhow::Result<Vec<usize>> {
+        let skip = usize::try_from(rank.unsigned_abs() - 1).unwrap_or(usize::MAX);
+        let count = if count == 0 { usize::MAX } else { count };
+        let max_len = if max_len == 0 { usize::MAX } else { max_len };
+        let positions = self.with_list(key, now, |list| {
+            let len = list.len();
+            let matches = |(_, element): &(usize, &Vec<u8>)| element.as_slice() == value;
+            if rank < 0 {
+                list.iter()
+                    .rev()
+                    .enumerate()
+                    .take(max_len)
+                    .filter(matches)
+                    .map(|(index, _)| len - 1 - index)
+                    .skip(skip)
+                    .take(count)
+                    .collect()
+            } else {
+                list.iter()
+                    .enumerate()
+                    .take(max_len)
+                    .filter(matches)
+                    .map(|(index, _)| index)
+                    .skip(skip)
+                    .take(count)
+                    .collect()
+            }
+        })?;
+        Ok(positions.unwrap_or_default())
+    }
+
+    /// Pops an element from `from` of `source` and pushes it to `to` of `destination`.
+    /// Returns `None` without touching `destination` if `source` does not exist.
+    pub fn move_element(
+        &self,
+        source: &[u8],
+        destination: &[u8],
+        from: End,
+        to: End,
+        now: SystemTime,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        let mut db = self.keyspace.lock();
+        if db.get_as::<List>(source, now)?.is_none() {
+            return Ok(None);
+        }
+        // Checked before popping so a wrong destination type leaves the source untouched.
+        db.get_as::<List>(destination, now)?;
+        let element = Self::update(&mut db, source, now, |list| list.pop(from))?.flatten();
+        let Some(element) = element else {
+            return Ok(None);
+        };
+        db.get_or_default::<List>(destination, now)?
+            .push(to, element.clone());
+        self.keyspace.blocking().wake(destination);
+        Ok(Some(element))
+    }
+
+    /// Pops up to `count` elements from `end` of the first of `keys` that holds a list.
+    pub fn pop_first(
+        &self,
+        keys: &[Vec<u8>],
+        end: End,
+        count: usize,
+        now: SystemTime,
+    ) -> anyhow::Result<Option<Popped>> {
+        let mut db = self.keyspace.lock();
+        for key in keys {
+            let popped = Self::update(&mut db, key, now, |list| {
+                std::iter::from_fn(|| list.pop(end)).take(count).collect()
+            })?;
+            if let Some(popped) = popped {
+                return Ok(Some((key.clone(), popped)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Like [`Self::pop_first`] but waits up to `timeout` for one of `keys` to be pushed to,
+    /// `None` waits forever. Clients blocked on the same key are served in the order they blocked.
+    pub fn pop_blocking(
+        &self,
+        keys: &[Vec<u8>],
+        end: End,
+        count: usize,
+        timeout: Option<Duration>,
+    ) -> BlockResult<Popped> {
+        self.keyspace.blocking().block(keys, timeout, || {
+            match self.pop_first(keys, end, count, SystemTime::now()) {
+                Ok(Some(popped)) => BlockResult::Found(popped),
+                Ok(None) => BlockResult::NotFound,
+                Err(err) => BlockResult::Err(err),
+            }
+        })
+    }
+
+    /// Like [`Self::move_element`] but waits up to `timeout` for `source` to be pushed to.
+    pub fn move_blocking(
+        &self,
+        source: &[u8],
+        destination: &[u8],
+        from: End,
+        to: End,
+        timeout: Option<Duration>,
+    ) -> BlockResult<Vec<u8>> {
+        self.keyspace
+            .blocking()
+            .block(&[source.to_vec()], timeout, || {
+                match self.move_element(source, destination, from, to, SystemTime::now()) {
+                    Ok(Some(element)) => BlockResult::Found(element),
+                    Ok(None) => BlockResult::NotFound,
+                    Err(err) => BlockResult::Err(err),
+                }
+            })
+    }
+}
@@ -0,0 +1,223 @@
+use std::time::{Duration, SystemTime};
+
+use super::{End, ListRepository};
+use crate::repository::{kv_repo::KvRepository, BlockResult, Keyspace};
+
+const NOW: SystemTime = SystemTime::UNIX_EPOCH;
+
+fn repo(values: &[&str]) -> ListRepository {
+    let repo = ListRepository::new();
+    let values = values.iter().map(|v| v.as_bytes().to_vec()).collect();
+    repo.push(b"list", values, End::Right, false, NOW).unwrap();
+    repo
+}
+
+fn range(repo: &ListRepository) -> Vec<String> {
+    repo.range(b"list", 0, -1, NOW)
+        .unwrap()
+        .into_iter()
+        .map(|v| String::from_utf8(v).unwrap())
+        .collect()
+}
+
+#[test]
+fn push_returns_new_length() {
+    let repo = repo(&["a", "b"]);
+    let len = repo
+        .push(
+            b"list",
+            vec![b"c".to_vec(), b"d".to_vec()],
+            End::Left,
+            false,
+            NOW,
+        )
+        .unwrap();
+    assert_eq!(len, 4);
+    assert_eq!(range(&repo), ["d", "c", "a", "b"]);
+}
+
+#[test]
+fn pushx_ignores_missing_key() {
+    let repo = ListRepository::new();
+    let len = repo
+        .push(b"list", vec![b"a".to_vec()], End::Left, true, NOW)
+        .unwrap();
+    assert_eq!(len, 0);
+    assert_eq!(repo.len(b"list", NOW).unwrap(), 0);
+}
+
+#[test]
+fn popping_last_element_removes_key() {
+    let keyspace = Keyspace::new();
+    let repo = ListRepository::with_keyspace(keyspace.clone());
+    repo.push(b"list", vec![b"a".to_vec()], End::Left, false, NOW)
+        .unwrap();
+    let popped = repo.pop(b"list", End::Right, 5, NOW).unwrap();
+    assert_eq!(popped, Some(vec![b"a".to_vec()]));
+    assert!(keyspace.lock().is_empty());
+    assert_eq!(repo.pop(b"list", End::Right, 1, NOW).unwrap(), None);
+}
+
+#[test]
+fn list_commands_on_string_are_wrong_type() {
+    let keyspace = Keyspace::new();
+    KvRepository::with_keyspace(keyspace.clone())
+        .set(b"list".to_vec(), b"value".to_vec(), None)
+        .unwrap();
+    let repo = ListRepository::with_keyspace(keyspace);
+    let err = repo
+        .push(b"list", vec![b"a".to_vec()], End::Left, false, NOW)
+        .unwrap_err();
+    assert!(err.to_string().starts_with("WRONGTYPE"));
+    assert!(repo.len(b"list", NOW).is_err());
+}
+
+#[test]
+fn set_reports_missing_key_and_index() {
+    let repo = repo(&["a", "b"]);
+    repo.set(b"list", -1, b"c".to_vec(), NOW).unwrap();
+    assert_eq!(range(&repo), ["a", "c"]);
+    let err = repo.set(b"list", 2, b"c".to_vec(), NOW).unwrap_err();
+    assert_eq!(err.to_string(), "ERR index out of range");
+    let err = repo.set(b"other", 0, b"c".to_vec(), NOW).unwrap_err();
+    assert_eq!(err.to_string(), "ERR no such key");
+}
+
+#[test]
+fn insert_next_to_pivot() {
+    let repo = repo(&["a", "c"]);
+    assert_eq!(
+        repo.insert(b"list", true, b"c", b"b".to_vec(), NOW)
+            .unwrap(),
+        3
+    );
+    assert_eq!(
+        repo.insert(b"list", false, b"c", b"d".to_vec(), NOW)
+            .unwrap(),
+        4
+    );
+    assert_eq!(
+        repo.insert(b"list", true, b"x", b"y".to_vec(), NOW)
+            .unwrap(),
+        -1
+    );
+    assert_eq!(
+        repo.insert(b"other", true, b"a", b"y".to_vec(), NOW)
+            .unwrap(),
+        0
+    );
+    assert_eq!(range(&repo), ["a", "b", "c", "d"]);
+}
+
+#[test]
+fn remove_by_count_sign() {
+    let repo = repo(&["a", "b", "a", "c", "a"]);
+    assert_eq!(repo.remove(b"list", -1, b"a", NOW).unwrap(), 1);
+    assert_eq!(range(&repo), ["a", "b", "a", "c"]);
+    assert_eq!(repo.remove(b"list", 0, b"a", NOW).unwrap(), 2);
+    assert_eq!(range(&repo), ["b", "c"]);
+}
+
+#[test]
+fn trim_to_empty_range_removes_key() {
+    let repo = repo(&["a", "b", "c"]);
+    repo.trim(b"list", 1, -1, NOW).unwrap();
+    assert_eq!(range(&repo), ["b", "c"]);
+    repo.trim(b"list", 5, 10, NOW).unwrap();
+    assert_eq!(repo.len(b"list", NOW).unwrap(), 0);
+}
+
+#[test]
+fn position_with_rank_count_and_max_len() {
+    let repo = repo(&["a", "b", "c", "1", "2", "3", "c", "c"]);
+    assert_eq!(repo.position(b"list", b"c", 1, 1, 0, NOW).unwrap(), [2]);
+    assert_eq!(repo.position(b"list", b"c", 2, 1, 0, NOW).unwrap(), [6]);
+    assert_eq!(repo.position(b"list", b"c", -1, 1, 0, NOW).unwrap(), [7]);
+    assert_eq!(
+        repo.position(b"list", b"c", 1, 0, 0, NOW).unwrap(),
+        [2, 6, 7]
+    );
+    assert_eq!(repo.position(b"list", b"c", -1, 2, 0, NOW).unwrap(), [7, 6]);
+    assert_eq!(repo.position(b"list", b"c", 1, 0, 3, NOW).unwrap(), [2]);
+}
+
+#[test]
+fn move_element_between_and_within_lists() {
+    let repo = repo(&["a", "b", "c"]);
+    let moved = repo
+        .move_element(b"list", b"other", End::Left, End::Right, NOW)
+        .unwrap();
+    assert_eq!(moved, Some(b"a".to_vec()));
+    assert_eq!(repo.len(b"other", NOW).unwrap(), 1);
+    repo.move_element(b"list", b"list", End::Left, End::Right, NOW)
+        .unwrap();
+    assert_eq!(range(&repo), ["c", "b"]);
+    let moved = repo
+        .move_element(b"missing", b"list", End::Left, End::Right, NOW)
+        .unwrap();
+    assert_eq!(moved, None);
+}
+
+#[test]
+fn pop_first_skips_missing_keys() {
+    let repo = repo(&["a", "b"]);
+    let keys = [b"missing".to_vec(), b"list".to_vec()];
+    let popped = repo.pop_first(&keys, End::Right, 1, NOW).unwrap();
+    assert_eq!(popped, Some((b"list".to_vec(), vec![b"b".to_vec()])));
+    assert_eq!(
+        repo.pop_first(&keys[..1], End::Right, 1, NOW).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn pop_blocking_times_out_on_empty_keys() {
+    let repo = ListRepository::new();
+    let result = repo.pop_blocking(
+        &[b"list".to_vec()],
+        End::Left,
+        1,
+        Some(Duration::from_millis(10)),
+    );
+    assert_eq!(result, BlockResult::NotFound);
+}
+
+#[test]
+fn pop_blocking_is_served_by_push() {
+    let repo = ListRepository::new();
+    let handle = {
+        let repo = repo.clone();
+        std::thread::spawn(move || {
+            repo.pop_blocking(&[b"other".to_vec(), b"list".to_vec()], End::Left, 1, None)
+        })
+    };
+    while repo.keyspace.blocking().waiting(b"list") == 0 {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    repo.push(b"list", vec![b"a".to_vec()], End::Right, false, NOW)
+        .unwrap();
+    let result = handle.join().unwrap();
+    assert_eq!(
+        result,
+        BlockResult::Found((b"list".to_vec(), vec![b"a".to_vec()]))
+    );
+    assert_eq!(repo.len(b"list", NOW).unwrap(), 0);
+}
+
+#[test]
+fn move_blocking_is_served_by_push() {
+    let repo = ListRepository::new();
+    let handle = {
+        let repo = repo.clone();
+        std::thread::spawn(move || {
+            repo.move_blocking(b"list", b"other", End::Left, End::Left, None)
+        })
+    };
+    while repo.keyspace.blocking().waiting(b"list") == 0 {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    repo.push(b"list", vec![b"a".to_vec()], End::Right, false, NOW)
+        .unwrap();
+    assert_eq!(handle.join().unwrap(), BlockResult::Found(b"a".to_vec()));
+    assert_eq!(repo.range(b"other", 0, -1, NOW).unwrap(), [b"a".to_vec()]);
+}
@@ -1,36 +1,172 @@
+use std::sync::Arc;
+
+pub mod blocking;
+pub mod hash_repo;
+pub mod key_repo;
+pub mod keyspace;
 pub mod kv_repo;
+pub mod list_repo;
+pub mod set_repo;
 pub mod stream_repo;
+pub mod table;
+pub mod zset_repo;
 
-#[derive(Debug, Clone)]
-pub struct Repository {
+pub use blocking::{BlockResult, Blocking};
+pub use keyspace::{Keyspace, WrongType};
+
+/// Typed views over a single [`Keyspace`], so a key only ever holds one type.
+#[derive(Debug)]
+struct Database {
+    keyspace: Keyspace,
+    key_repo: key_repo::KeyRepository,
     kv_repo: kv_repo::KvRepository,
+    hash_repo: hash_repo::HashRepository,
+    list_repo: list_repo::ListRepository,
+    set_repo: set_repo::SetRepository,
     stream_repo: stream_repo::StreamRepository,
+    zset_repo: zset_repo::ZSetRepository,
+}
+
+impl Database {
+    fn new(keyspace: Keyspace) -> Self {
+        Self {
+            key_repo: key_repo::KeyRepository::with_keyspace(keyspace.clone()),
+            kv_repo: kv_repo::KvRepository::with_keyspace(keyspace.clone()),
+            hash_repo: hash_repo::HashRepository::with_keyspace(keyspace.clone()),
+            list_repo: list_repo::ListRepository::with_keyspace(keyspace.clone()),
+            set_repo: set_repo::SetRepository::with_keyspace(keyspace.clone()),
+            stream_repo: stream_repo::StreamRepository::with_keyspace(keyspace.clone()),
+            zset_repo: zset_repo::ZSetRepository::with_keyspace(keyspace.clone()),
+            keyspace,
+        }
+    }
+}
+
+/// The numbered databases of the server, viewed through the selected one.
+/// Cloning is cheap and every clone shares the same databases.
+#[derive(Debug, Clone)]
+pub struct Repository {
+    databases: Arc<[Database]>,
+    selected: usize,
 }
 
 impl Repository {
+    /// How many databases there are unless configured otherwise, like redis.
+    pub const DEFAULT_DATABASES: usize = 16;
+
+    /// A single database holding `keyspace`.
     #[must_use]
-    pub fn new(kv_repo: kv_repo::KvRepository, stream_repo: stream_repo::StreamRepository) -> Self {
+    pub fn new(keyspace: Keyspace) -> Self {
         Self {
-            kv_repo,
-            stream_repo,
+            databases: Arc::new([Database::new(keyspace)]),
+            selected: 0,
         }
     }
+
+    /// `count` empty databases with the first one selected.
+    ///
+    /// # Panics
+    ///
+    /// If `count` is 0.
+    #[must_use]
+    pub fn with_databases(count: usize) -> Self {
+        assert!(count > 0, "there must be at least one database");
+        Self {
+            databases: (0..count).map(|_| Database::new(Keyspace::new())).collect(),
+            selected: 0,
+        }
+    }
+
+    /// The same databases with `index` selected, `None` if there is no such database.
+    #[must_use]
+    pub fn select(&self, index: usize) -> Option<Self> {
+        (index < self.databases.len()).then(|| Self {
+            databases: self.databases.clone(),
+            selected: index,
+        })
+    }
+
+    /// The index of the selected database.
+    #[must_use]
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// The number of databases.
+    #[must_use]
+    pub fn databases(&self) -> usize {
+        self.databases.len()
+    }
+
+    /// The keyspace of every database in order.
+    pub fn keyspaces(&self) -> impl Iterator<Item = &Keyspace> + '_ {
+        self.databases.iter().map(|database| &database.keyspace)
+    }
+
+    /// Swaps the contents of two databases, clients that selected one of them see the other.
+    /// Returns whether both exist.
+    pub fn swap(&self, a: usize, b: usize) -> bool {
+        let (Some(a), Some(b)) = (self.databases.get(a), self.databases.get(b)) else {
+            return false;
+        };
+        a.keyspace.swap(&b.keyspace);
+        true
+    }
+
+    /// Removes every key of every database.
+    pub fn clear(&self) {
+        self.databases
+            .iter()
+            .for_each(|database| database.key_repo.clear());
+    }
+
+    fn database(&self) -> &Database {
+        &self.databases[self.selected]
+    }
+
+    #[must_use]
+    pub fn keyspace(&self) -> &Keyspace {
+        &self.database().keyspace
+    }
+
+    #[must_use]
+    pub fn key_repo(&self) -> &key_repo::KeyRepository {
+        &self.database().key_repo
+    }
+
     #[must_use]
     pub fn kv_repo(&self) -> &kv_repo::KvRepository {
-        &self.kv_repo
+        &self.database().kv_repo
+    }
+
+    #[must_use]
+    pub fn hash_repo(&self) -> &hash_repo::HashRepository {
+        &self.database().hash_repo
+    }
+
+    #[must_use]
+    pub fn list_repo(&self) -> &list_repo::ListRepository {
+        &self.database().list_repo
+    }
+
+    #[must_use]
+    pub fn set_repo(&self) -> &set_repo::SetRepository {
+        &self.database().set_repo
     }
 
     #[must_use]
     pub fn stream_repo(&self) -> &stream_repo::StreamRepository {
-        &self.stream_repo
+        &self.database().stream_repo
+    }
+
+    #[must_use]
+    pub fn zset_repo(&self) -> &zset_repo::ZSetRepository {
+        &self.database().zset_repo
     }
 }
 
 impl Default for Repository {
     fn default() -> Self {
-        Self {
-            kv_repo: kv_repo::KvRepository::new(),
-            stream_repo: stream_repo::StreamRepository::new(),
-        }
+        Self::with_databases(Self::DEFAULT_DATABASES)
     }
 }
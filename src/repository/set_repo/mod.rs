@@ -0,0 +1,268 @@
+use std::time::SystemTime;
+
+use anyhow::bail;
+
+use super::keyspace::{Db, Item, Keyspace, Value, WrongType};
+use crate::{glob, random};
+
+pub mod set;
+
+pub use set::Set;
+
+#[cfg(test)]
+mod tests;
+
+/// How the sets of several keys are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Intersection,
+    Union,
+    /// The members of the first set that are in none of the others.
+    Difference,
+}
+
+/// Sets stored in the keyspace, a set that becomes empty is removed like in redis.
+#[derive(Debug, Clone, Default)]
+pub struct SetRepository {
+    keyspace: Keyspace,
+}
+
+impl SetRepository {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_keyspace(keyspace: Keyspace) -> Self {
+        Self { keyspace }
+    }
+
+    /// Runs `f` on the set at `key`, returns `None` if there is no such key.
+    fn with_set<F, T>(&self, key: &[u8], now: SystemTime, f: F) -> Result<Option<T>, WrongType>
+    where
+        F: FnOnce(&mut Set) -> T,
+    {
+        let mut db = self.keyspace.lock();
+        Self::update(&mut db, key, now, f)
+    }
+
+    fn update<F, T>(db: &mut Db, key: &[u8], now: SystemTime, f: F) -> Result<Option<T>, WrongType>
+    where
+        F: FnOnce(&mut Set) -> T,
+    {
+        let Some(set) = db.get_as::<Set>(key, now)? else {
+            return Ok(None);
+        };
+        let result = f(set);
+        if set.is_empty() {
+            db.remove(key, now);
+        }
+        Ok(Some(result))
+    }
+
+    /// Adds `members` and returns how many of them were new.
+    pub fn add(&self, key: &[u8], members: Vec<Vec<u8>>, now: SystemTime) -> anyhow::Result<usize> {
+        let mut db = self.keyspace.lock();
+        let set = db.get_or_default::<Set>(key, now)?;
+        Ok(members
+            .into_iter()
+            .filter(|member| set.insert(member.clone()))
+            .count())
+    }
+
+    /// Removes `members` and returns how many of them existed.
+    pub fn remove(
+        &self,
+        key: &[u8],
+        members: &[Vec<u8>],
+        now: SystemTime,
+    ) -> anyhow::Result<usize> {
+        let removed = self.with_set(key, now, |set| {
+            members.iter().filter(|member| set.remove(member)).count()
+        })?;
+        Ok(removed.unwrap_or(0))
+    }
+
+    pub fn is_member(&self, key: &[u8], member: &[u8], now: SystemTime) -> anyhow::Result<bool> {
+        let found = self.with_set(key, now, |set| set.contains(member))?;
+        Ok(found.unwrap_or(false))
+    }
+
+    pub fn are_members(
+        &self,
+        key: &[u8],
+        members: &[Vec<u8>],
+        now: SystemTime,
+    ) -> anyhow::Result<Vec<bool>> {
+        let found = self.with_set(key, now, |set| {
+            members.iter().map(|member| set.contains(member)).collect()
+        })?;
+        Ok(found.unwrap_or_else(|| vec![false; members.len()]))
+    }
+
+    pub fn members(&self, key: &[u8], now: SystemTime) -> anyhow::Result<Vec<Vec<u8>>> {
+        let members = self.with_set(key, now, |set| set.iter().collect())?;
+        Ok(members.unwrap_or_default())
+    }
+
+    pub fn len(&self, key: &[u8], now: SystemTime) -> anyhow::Result<usize> {
+        Ok(self.with_set(key, now, |set| set.len())?.unwrap_or(0))
+    }
+
+    /// Removes and returns up to `count` random members.
+    pub fn pop(&self, key: &[u8], count: usize, now: SystemTime) -> anyhow::Result<Vec<Vec<u8>>> {
+        let popped = self.with_set(key, now, |set| {
+            let popped: Vec<_> = random::sample_distinct(set.len(), count)
+                .into_iter()
+                .filter_map(|i| set.get(i))
+                .collect();
+            popped.iter().for_each(|member| {
+                set.remove(member);
+            });
+            popped
+        })?;
+        Ok(popped.unwrap_or_default())
+    }
+
+    /// Random members, `count` distinct ones if it is positive
+    /// and `-count` possibly repeated ones if it is negative, up to [`random::MAX_REPEATED`].
+    pub fn random_members(
+        &self,
+        key: &[u8],
+        count: i64,
+        now: SystemTime,
+    ) -> anyhow::Result<Vec<Vec<u8>>> {
+        let n = usize::try_from(count.unsigned_abs()).unwrap_or(usize::MAX);
+        // redis streams up to -(i64::MAX / 2) repeated ones, the reply is built whole here
+        if count < 0 && n > random::MAX_REPEATED {
+            bail!("ERR value is out of range");
+        }
+        let members = self.with_set(key, now, |set| {
+            let picked = if count < 0 {
+                random::sample_repeated(set.len(), n)
+            } else {
+                random::sample_distinct(set.len(), n)
+            };
+            picked.into_iter().filter_map(|i| set.get(i)).collect()
+        })?;
+        Ok(members.unwrap_or_default())
+    }
+
+    /// Moves `member` from `source` to `destination`, returns whether it was in `source`.
+    pub fn move_member(
+        &self,
+        source: &[u8],
+        destination: &[u8],
+        member: Vec<u8>,
+        now: SystemTime,
+    ) -> anyhow::Result<bool> {
+        let mut db = self.keyspace.lock();
+        // checked before removing so a wrong destination type leaves the source untouched
+        db.get_as::<Set>(destination, now)?;
+        let removed = Self::update(&mut db, source, now, |set| set.remove(&member))?;
+        if removed != Some(true) {
+            return Ok(false);
+        }
+        db.get_or_default::<Set>(destination, now)?.insert(member);
+        Ok(true)
+    }
+
+    /// Combines the sets at `keys`, missing keys count as empty sets.
+    pub fn combine(
+        &self,
+        operation: Operation,
+        keys: &[Vec<u8>],
+        now: SystemTime,
+    ) -> anyhow::Result<Set> {
+        let mut db = self.keyspace.lock();
+        Ok(Self::combine_in(&mut db, operation, keys, now)?)
+    }
+
+    /// Stores the result of [`Self::combine`] at `destination`, whatever it held before,
+    /// and returns its length.
+    pub fn combine_store(
+        &self,
+        operation: Operation,
+        destination: &[u8],
+        keys: &[Vec<u8>],
+        now: SystemTime,
+    ) -> anyhow::Result<usize> {
+        let mut db = self.keyspace.lock();
+        let set = Self::combine_in(&mut db, operation, keys, now)?;
+        let len = set.len();
+        if set.is_empty() {
+            db.remove(destination, now);
+        } else {
+            db.insert(destination.to_vec(), Item::new(Value::Set(set)));
+        }
+        Ok(len)
+    }
+
+    /// The length of the intersection of the sets at `keys`, counting stops at `limit` unless it is 0.
+    pub fn intersection_len(
+        &self,
+        keys: &[Vec<u8>],
+        limit: usize,
+        now: SystemTime,
+    ) -> anyhow::Result<usize> {
+        let limit = if limit == 0 { usize::MAX } else { limit };
+        let set = self.combine(Operation::Intersection, keys, now)?;
+        Ok(set.len().min(limit))
+    }
+
+    fn combine_in(
+        db: &mut Db,
+        operation: Operation,
+        keys: &[Vec<u8>],
+        now: SystemTime,
+    ) -> Result<Set, WrongType> {
+        let mut sets = Vec::with_capacity(keys.len());
+        for key in keys {
+            sets.push(db.get_as::<Set>(key, now)?.cloned().unwrap_or_default());
+        }
+        if operation == Operation::Intersection {
+            // every member of the result is in the smallest set
+            sets.sort_by_key(Set::len);
+        }
+        let Some((first, others)) = sets.split_first_mut() else {
+            return Ok(Set::new());
+        };
+        Ok(match operation {
+            Operation::Intersection => first
+                .iter()
+                .filter(|member| others.iter().all(|set| set.contains(member)))
+                .collect(),
+            Operation::Union => {
+                let mut union = std::mem::take(first);
+                others.iter().flat_map(Set::iter).for_each(|member| {
+                    union.insert(member);
+                });
+                union
+            }
+            Operation::Difference => first
+                .iter()
+                .filter(|member| !others.iter().any(|set| set.contains(member)))
+                .collect(),
+        })
+    }
+
+    /// Up to `count` members from `cursor` on, filtered by the glob `pattern`.
+    /// Members that exist for the whole scan are returned at least once.
+    /// Returns the cursor to continue from, 0 once every member was visited.
+    pub fn scan(
+        &self,
+        key: &[u8],
+        cursor: usize,
+        pattern: Option<&[u8]>,
+        count: usize,
+        now: SystemTime,
+    ) -> anyhow::Result<(usize, Vec<Vec<u8>>)> {
+        let scanned = self.with_set(key, now, |set| {
+            let (cursor, mut members) = set.scan(cursor, count);
+            members.retain(|member| pattern.is_none_or(|pattern| glob::matches(pattern, member)));
+            (cursor, members)
+        })?;
+        Ok(scanned.unwrap_or_default())
+    }
+}
@@ -0,0 +1,128 @@
+/// Sorted integers packed at the smallest width that fits all of them, like redis' intset.
+/// The width only grows, removing the one wide member keeps the others wide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntSet {
+    width: usize,
+    bytes: Vec<u8>,
+}
+
+impl Default for IntSet {
+    fn default() -> Self {
+        Self {
+            width: 2,
+            bytes: Vec::new(),
+        }
+    }
+}
+
+impl IntSet {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bytes.len() / self.width
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// The bytes used per member, 2, 4 or 8.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<i64> {
+        let bytes = self
+            .bytes
+            .get(index * self.width..(index + 1) * self.width)?;
+        Some(decode(bytes))
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: i64) -> bool {
+        width_of(value) <= self.width && self.search(value).is_ok()
+    }
+
+    /// Returns whether `value` was not in the set yet.
+    pub fn insert(&mut self, value: i64) -> bool {
+        let width = width_of(value);
+        if width > self.width {
+            self.widen(width);
+        }
+        let Err(index) = self.search(value) else {
+            return false;
+        };
+        let at = index * self.width;
+        self.bytes
+            .splice(at..at, value.to_le_bytes()[..self.width].iter().copied());
+        true
+    }
+
+    /// Returns whether `value` was in the set.
+    pub fn remove(&mut self, value: i64) -> bool {
+        if width_of(value) > self.width {
+            return false;
+        }
+        let Ok(index) = self.search(value) else {
+            return false;
+        };
+        self.bytes
+            .drain(index * self.width..(index + 1) * self.width);
+        true
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = i64> + '_ {
+        self.bytes.chunks_exact(self.width).map(decode)
+    }
+
+    fn search(&self, value: i64) -> Result<usize, usize> {
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match self.get(mid).expect("mid is in bounds").cmp(&value) {
+                std::cmp::Ordering::Less => low = mid + 1,
+                std::cmp::Ordering::Greater => high = mid,
+                std::cmp::Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+
+    fn widen(&mut self, width: usize) {
+        let values: Vec<_> = self.iter().collect();
+        self.width = width;
+        self.bytes = values
+            .into_iter()
+            .flat_map(|value| value.to_le_bytes()[..width].to_vec())
+            .collect();
+    }
+}
+
+fn width_of(value: i64) -> usize {
+    if i16::try_from(value).is_ok() {
+        2
+    } else if i32::try_from(value).is_ok() {
+        4
+    } else {
+        8
+    }
+}
+
+/// Sign extends the little endian `bytes` to an `i64`.
+fn decode(bytes: &[u8]) -> i64 {
+    let fill = if bytes.last().is_some_and(|byte| byte & 0x80 != 0) {
+        0xff
+    } else {
+        0
+    };
+    let mut buf = [fill; 8];
+    buf[..bytes.len()].copy_from_slice(bytes);
+    i64::from_le_bytes(buf)
+}
@@ -0,0 +1,139 @@
+pub use intset::IntSet;
+
+use crate::{repository::table::Table, resp::value::convert::parse_integer};
+
+mod intset;
+
+#[cfg(test)]
+mod tests;
+
+/// Sets with more integers than this are stored as a hash table, like `set-max-intset-entries`.
+pub const MAX_INTSET_ENTRIES: usize = 512;
+
+/// A set of byte strings.
+/// Small sets of integers are packed into an [`IntSet`] until a member that is not an integer
+/// is added or it grows past [`MAX_INTSET_ENTRIES`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Set {
+    Ints(IntSet),
+    Hash(Table<()>),
+}
+
+impl Default for Set {
+    fn default() -> Self {
+        Self::Ints(IntSet::new())
+    }
+}
+
+impl Set {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Ints(ints) => ints.len(),
+            Self::Hash(hash) => hash.len(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The name `OBJECT ENCODING` replies with.
+    #[must_use]
+    pub fn encoding(&self) -> &'static str {
+        match self {
+            Self::Ints(_) => "intset",
+            Self::Hash(_) => "hashtable",
+        }
+    }
+
+    #[must_use]
+    pub fn contains(&self, member: &[u8]) -> bool {
+        match self {
+            Self::Ints(ints) => parse_integer(member).is_some_and(|int| ints.contains(int)),
+            Self::Hash(hash) => hash.contains_key(member),
+        }
+    }
+
+    /// Returns whether `member` was not in the set yet.
+    pub fn insert(&mut self, member: Vec<u8>) -> bool {
+        if let Self::Ints(ints) = self {
+            match parse_integer(&member) {
+                Some(int) if ints.contains(int) => return false,
+                Some(int) if ints.len() < MAX_INTSET_ENTRIES => return ints.insert(int),
+                _ => self.convert(),
+            }
+        }
+        let Self::Hash(hash) = self else {
+            unreachable!("converted above")
+        };
+        hash.insert(member, ()).is_none()
+    }
+
+    /// Returns whether `member` was in the set.
+    pub fn remove(&mut self, member: &[u8]) -> bool {
+        match self {
+            Self::Ints(ints) => parse_integer(member).is_some_and(|int| ints.remove(int)),
+            Self::Hash(hash) => hash.remove(member).is_some(),
+        }
+    }
+
+    /// The member at `index` of the order [`Self::iter`] returns them in.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<Vec<u8>> {
+        match self {
+            Self::Ints(ints) => ints.get(index).map(|int| int.to_string().into_bytes()),
+            Self::Hash(hash) => hash.get_index(index).map(|(member, ())| member.clone()),
+        }
+    }
+
+    /// The members in no particular order.
+    pub fn iter(&self) -> Box<dyn Iterator<Item = Vec<u8>> + '_> {
+        match self {
+            Self::Ints(ints) => Box::new(ints.iter().map(|int| int.to_string().into_bytes())),
+            Self::Hash(hash) => Box::new(hash.keys().cloned()),
+        }
+    }
+
+    /// Up to `count` members from `cursor` on, see [`Table::scan`].
+    /// An intset is small so it is returned whole like redis does.
+    #[must_use]
+    pub fn scan(&self, cursor: usize, count: usize) -> (usize, Vec<Vec<u8>>) {
+        match self {
+            Self::Ints(_) => (0, self.iter().collect()),
+            Self::Hash(hash) => {
+                let (cursor, members) = hash.scan(cursor, count);
+                (
+                    cursor,
+                    members.into_iter().map(|(m, _)| m.clone()).collect(),
+                )
+            }
+        }
+    }
+
+    fn convert(&mut self) {
+        if let Self::Ints(ints) = self {
+            *self = Self::Hash(
+                ints.iter()
+                    .map(|int| (int.to_string().into_bytes(), ()))
+                    .collect(),
+            );
+        }
+    }
+}
+
+impl FromIterator<Vec<u8>> for Set {
+    fn from_iter<T: IntoIterator<Item = Vec<u8>>>(iter: T) -> Self {
+        let mut set = Self::new();
+        iter.into_iter().for_each(|member| {
+            set.insert(member);
+        });
+        set
+    }
+}
@@ -0,0 +1,69 @@
+use super::*;
+
+fn set(members: &[&str]) -> Set {
+    members.iter().map(|m| m.as_bytes().to_vec()).collect()
+}
+
+fn sorted(set: &Set) -> Vec<String> {
+    let mut members: Vec<_> = set.iter().map(|m| String::from_utf8(m).unwrap()).collect();
+    members.sort();
+    members
+}
+
+#[test]
+fn integers_use_intset() {
+    let set = set(&["3", "1", "-2", "1"]);
+    assert_eq!(set.encoding(), "intset");
+    assert_eq!(set.len(), 3);
+    assert_eq!(sorted(&set), ["-2", "1", "3"]);
+    assert!(set.contains(b"3"));
+    assert!(!set.contains(b"03"));
+}
+
+#[test]
+fn non_canonical_integers_convert_to_hash() {
+    let set = set(&["1", "007"]);
+    assert_eq!(set.encoding(), "hashtable");
+    assert_eq!(sorted(&set), ["007", "1"]);
+}
+
+#[test]
+fn strings_convert_to_hash() {
+    let mut set = set(&["1", "2"]);
+    assert!(set.insert(b"a".to_vec()));
+    assert_eq!(set.encoding(), "hashtable");
+    assert!(set.contains(b"1"));
+    assert!(set.remove(b"1"));
+    assert_eq!(sorted(&set), ["2", "a"]);
+}
+
+#[test]
+fn large_sets_convert_to_hash() {
+    let mut set: Set = (0..MAX_INTSET_ENTRIES)
+        .map(|i| i.to_string().into_bytes())
+        .collect();
+    assert_eq!(set.encoding(), "intset");
+    set.insert(b"-1".to_vec());
+    assert_eq!(set.encoding(), "hashtable");
+    assert_eq!(set.len(), MAX_INTSET_ENTRIES + 1);
+}
+
+#[test]
+fn intset_widens_and_stays_sorted() {
+    let mut ints = IntSet::new();
+    assert!(ints.insert(5));
+    assert!(ints.insert(-300));
+    assert_eq!(ints.width(), 2);
+    assert!(ints.insert(i64::from(i32::MAX) + 1));
+    assert_eq!(ints.width(), 8);
+    assert!(ints.insert(i64::MIN));
+    assert!(!ints.insert(5));
+    assert_eq!(
+        ints.iter().collect::<Vec<_>>(),
+        [i64::MIN, -300, 5, i64::from(i32::MAX) + 1]
+    );
+    assert!(ints.remove(-300));
+    assert!(!ints.remove(-300));
+    assert!(!ints.contains(i64::MAX));
+    assert_eq!(ints.len(), 3);
+}
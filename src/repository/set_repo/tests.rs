@@ -0,0 +1,175 @@
+use std::time::SystemTime;
+
+use super::{Operation, SetRepository};
+use crate::{
+    random,
+    repository::{kv_repo::KvRepository, Keyspace},
+};
+
+const NOW: SystemTime = SystemTime::UNIX_EPOCH;
+
+fn bytes(members: &[&str]) -> Vec<Vec<u8>> {
+    members.iter().map(|m| m.as_bytes().to_vec()).collect()
+}
+
+fn sorted(mut members: Vec<Vec<u8>>) -> Vec<String> {
+    members.sort();
+    members
+        .into_iter()
+        .map(|m| String::from_utf8(m).unwrap())
+        .collect()
+}
+
+fn repo() -> SetRepository {
+    let repo = SetRepository::new();
+    repo.add(b"a", bytes(&["1", "2", "3", "x"]), NOW).unwrap();
+    repo.add(b"b", bytes(&["2", "3", "4"]), NOW).unwrap();
+    repo.add(b"c", bytes(&["3", "x"]), NOW).unwrap();
+    repo
+}
+
+#[test]
+fn add_and_remove_count_changes() {
+    let repo = SetRepository::new();
+    assert_eq!(repo.add(b"s", bytes(&["a", "b", "a"]), NOW).unwrap(), 2);
+    assert_eq!(repo.remove(b"s", &bytes(&["a", "c"]), NOW).unwrap(), 1);
+    assert!(repo.is_member(b"s", b"b", NOW).unwrap());
+    assert_eq!(
+        repo.are_members(b"s", &bytes(&["a", "b"]), NOW).unwrap(),
+        [false, true]
+    );
+}
+
+#[test]
+fn removing_last_member_removes_key() {
+    let keyspace = Keyspace::new();
+    let repo = SetRepository::with_keyspace(keyspace.clone());
+    repo.add(b"s", bytes(&["a"]), NOW).unwrap();
+    repo.remove(b"s", &bytes(&["a"]), NOW).unwrap();
+    assert!(keyspace.lock().is_empty());
+}
+
+#[test]
+fn set_commands_on_string_are_wrong_type() {
+    let keyspace = Keyspace::new();
+    KvRepository::with_keyspace(keyspace.clone())
+        .set(b"s".to_vec(), b"value".to_vec(), None)
+        .unwrap();
+    let repo = SetRepository::with_keyspace(keyspace);
+    let err = repo.add(b"s", bytes(&["a"]), NOW).unwrap_err();
+    assert!(err.to_string().starts_with("WRONGTYPE"));
+    let err = repo
+        .combine(Operation::Union, &bytes(&["missing", "s"]), NOW)
+        .unwrap_err();
+    assert!(err.to_string().starts_with("WRONGTYPE"));
+}
+
+#[test]
+fn pop_removes_members() {
+    let repo = repo();
+    let popped = repo.pop(b"a", 3, NOW).unwrap();
+    assert_eq!(popped.len(), 3);
+    assert_eq!(repo.len(b"a", NOW).unwrap(), 1);
+    assert_eq!(repo.pop(b"a", 3, NOW).unwrap().len(), 1);
+    assert!(repo.pop(b"a", 3, NOW).unwrap().is_empty());
+}
+
+#[test]
+fn random_members_by_count_sign() {
+    let repo = repo();
+    assert_eq!(repo.random_members(b"b", 5, NOW).unwrap().len(), 3);
+    assert_eq!(repo.random_members(b"b", -5, NOW).unwrap().len(), 5);
+    assert_eq!(repo.len(b"b", NOW).unwrap(), 3);
+}
+
+#[test]
+fn random_members_rejects_huge_negative_counts() {
+    let repo = repo();
+    let err = repo.random_members(b"b", i64::MIN, NOW).unwrap_err();
+    assert_eq!(err.to_string(), "ERR value is out of range");
+    assert!(repo.random_members(b"missing", i64::MIN, NOW).is_err());
+    let max = i64::try_from(random::MAX_REPEATED).unwrap();
+    assert!(repo.random_members(b"b", -max - 1, NOW).is_err());
+    assert_eq!(
+        repo.random_members(b"b", -max, NOW).unwrap().len(),
+        random::MAX_REPEATED
+    );
+}
+
+#[test]
+fn move_member_between_sets() {
+    let repo = repo();
+    assert!(repo.move_member(b"a", b"new", b"x".to_vec(), NOW).unwrap());
+    assert!(!repo.move_member(b"a", b"new", b"x".to_vec(), NOW).unwrap());
+    assert_eq!(sorted(repo.members(b"new", NOW).unwrap()), ["x"]);
+}
+
+#[test]
+fn combine_sets() {
+    let repo = repo();
+    let keys = bytes(&["a", "b", "c"]);
+    let combine = |operation| {
+        sorted(
+            repo.combine(operation, &keys, NOW)
+                .unwrap()
+                .iter()
+                .collect(),
+        )
+    };
+    assert_eq!(combine(Operation::Intersection), ["3"]);
+    assert_eq!(combine(Operation::Union), ["1", "2", "3", "4", "x"]);
+    assert_eq!(combine(Operation::Difference), ["1"]);
+    let with_missing = repo
+        .combine(Operation::Intersection, &bytes(&["a", "missing"]), NOW)
+        .unwrap();
+    assert!(with_missing.is_empty());
+}
+
+#[test]
+fn combine_store_overwrites_destination() {
+    let keyspace = Keyspace::new();
+    let repo = SetRepository::with_keyspace(keyspace.clone());
+    repo.add(b"a", bytes(&["1", "2"]), NOW).unwrap();
+    KvRepository::with_keyspace(keyspace.clone())
+        .set(b"dst".to_vec(), b"value".to_vec(), None)
+        .unwrap();
+    let len = repo
+        .combine_store(Operation::Union, b"dst", &bytes(&["a"]), NOW)
+        .unwrap();
+    assert_eq!(len, 2);
+    assert_eq!(sorted(repo.members(b"dst", NOW).unwrap()), ["1", "2"]);
+    repo.combine_store(
+        Operation::Intersection,
+        b"dst",
+        &bytes(&["a", "missing"]),
+        NOW,
+    )
+    .unwrap();
+    assert_eq!(keyspace.lock().len(), 1);
+}
+
+#[test]
+fn intersection_len_stops_at_limit() {
+    let repo = repo();
+    let keys = bytes(&["a", "b"]);
+    assert_eq!(repo.intersection_len(&keys, 0, NOW).unwrap(), 2);
+    assert_eq!(repo.intersection_len(&keys, 1, NOW).unwrap(), 1);
+}
+
+#[test]
+fn scan_visits_every_member() {
+    let repo = repo();
+    let (mut cursor, mut members) = (0, Vec::new());
+    loop {
+        let (next, scanned) = repo.scan(b"a", cursor, None, 3, NOW).unwrap();
+        assert!(next == 0 || !scanned.is_empty());
+        members.extend(scanned);
+        if next == 0 {
+            break;
+        }
+        cursor = next;
+    }
+    assert_eq!(sorted(members), ["1", "2", "3", "x"]);
+    let (cursor, matched) = repo.scan(b"a", 0, Some(b"[0-9]"), 10, NOW).unwrap();
+    assert_eq!((cursor, matched.len()), (0, 3));
+}
@@ -1,14 +1,10 @@
-use std::{
-    collections::HashMap,
-    sync::{Arc, Mutex},
-};
+use std::time::SystemTime;
 
 use anyhow::{bail, Context};
 use stream::{Entry, EntryId, PartialEntryId, Stream};
 
-pub use block_result::BlockResult;
+use super::{keyspace::Keyspace, BlockResult};
 
-pub mod block_result;
 pub mod stream;
 
 #[cfg(test)]
@@ -18,35 +14,36 @@ pub type StreamRepository = LockingStreamRepository;
 
 #[derive(Debug, Clone)]
 pub struct LockingStreamRepository {
-    streams: Arc<Mutex<HashMap<String, Stream>>>,
-    listners: Arc<Mutex<HashMap<String, Vec<std::sync::mpsc::Sender<Event>>>>>,
+    keyspace: Keyspace,
 }
 
 impl LockingStreamRepository {
     #[must_use]
     pub fn new() -> Self {
-        Self {
-            streams: Arc::new(Mutex::new(HashMap::new())),
-            listners: Arc::default(),
-        }
+        Self::with_keyspace(Keyspace::new())
+    }
+
+    #[must_use]
+    pub fn with_keyspace(keyspace: Keyspace) -> Self {
+        Self { keyspace }
     }
 
     #[allow(clippy::needless_pass_by_value)]
     pub fn add_auto_increment(
         &self,
-        stream_key: impl ToString,
+        stream_key: impl AsRef<[u8]>,
         fields: Vec<stream::Field>,
         timestamp: &std::time::SystemTime,
-    ) -> EntryId {
+    ) -> anyhow::Result<EntryId> {
         self.notify_add(stream_key, |stream| {
-            stream.add_with_auto_key(fields, timestamp)
+            Ok(stream.add_with_auto_key(fields, timestamp))
         })
     }
 
     #[allow(clippy::needless_pass_by_value)]
     pub fn add(
         &self,
-        stream_key: impl ToString,
+        stream_key: impl AsRef<[u8]>,
         entry_id: impl PartialEntryId,
         fields: Vec<stream::Field>,
     ) -> anyhow::Result<EntryId> {
@@ -55,155 +52,124 @@ impl LockingStreamRepository {
         })
     }
 
-    fn notify_add<F, T>(&self, stream_key: impl ToString, f: F) -> T
+    fn notify_add<F, T>(&self, stream_key: impl AsRef<[u8]>, f: F) -> anyhow::Result<T>
     where
-        F: FnOnce(&mut Stream) -> T,
+        F: FnOnce(&mut Stream) -> anyhow::Result<T>,
     {
-        let mut lock = self.streams.lock().unwrap();
-        let stream = lock.entry(stream_key.to_string()).or_default();
-        self.wakeup_listers("0");
+        let (stream_key, now) = (stream_key.as_ref(), SystemTime::now());
+        let mut db = self.keyspace.lock();
+        let stream = db.get_or_default::<Stream>(stream_key, now)?;
+        let result = f(stream);
+        // a failed add must not leave behind the stream it created
+        if result.is_err() && stream.is_empty() {
+            db.remove(stream_key, now);
+        }
+        if result.is_ok() {
+            // reading does not consume entries, so every blocked reader can be served
+            self.keyspace.blocking().wake_all(stream_key);
+        }
+        result
+    }
+
+    /// Runs `f` on the stream at `stream_key`, fails if there is none or the key holds another type.
+    fn with_stream<F, T>(&self, stream_key: &[u8], f: F) -> anyhow::Result<T>
+    where
+        F: FnOnce(&Stream) -> anyhow::Result<T>,
+    {
+        let mut db = self.keyspace.lock();
+        let Some(stream) = db.get_as::<Stream>(stream_key, SystemTime::now())? else {
+            bail!("stream not found")
+        };
         f(stream)
     }
 
     #[allow(clippy::needless_pass_by_value)]
     pub fn read(
         &self,
-        stream_key: impl ToString,
+        stream_key: impl AsRef<[u8]>,
         entry_id: &EntryId,
         count: usize,
     ) -> anyhow::Result<Vec<Entry>> {
-        let lock = self.streams.lock().unwrap();
-        let Some(stream) = lock.get(&stream_key.to_string()) else {
-            bail!("stream not found")
-        };
-        Ok(stream.read(entry_id, count))
+        self.with_stream(stream_key.as_ref(), |stream| {
+            Ok(stream.read(entry_id, count))
+        })
     }
 
     #[allow(clippy::needless_pass_by_value)]
-    pub fn read_last(&self, stream_key: impl ToString) -> anyhow::Result<Entry> {
-        let lock = self.streams.lock().unwrap();
-        let Some(stream) = lock.get(&stream_key.to_string()) else {
-            todo!();
-        };
-        stream.read_last().context("stream empty")
+    pub fn read_last(&self, stream_key: impl AsRef<[u8]>) -> anyhow::Result<Entry> {
+        self.with_stream(stream_key.as_ref(), |stream| {
+            stream.read_last().context("stream empty")
+        })
     }
 
     #[allow(clippy::needless_pass_by_value)]
     pub fn range(
         &self,
-        stream_key: impl ToString,
+        stream_key: impl AsRef<[u8]>,
         start: &EntryId,
         end: &EntryId,
+        count: usize,
     ) -> anyhow::Result<Vec<Entry>> {
-        let lock = self.streams.lock().unwrap();
-        let Some(stream) = lock.get(&stream_key.to_string()) else {
-            bail!("stream not found")
-        };
-        Ok(stream.range(start, end))
+        self.with_stream(stream_key.as_ref(), |stream| {
+            Ok(stream.range(start, end, count))
+        })
+    }
+
+    /// Like [`Self::range`] but newest first, starting from `end`.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn rev_range(
+        &self,
+        stream_key: impl AsRef<[u8]>,
+        end: &EntryId,
+        start: &EntryId,
+        count: usize,
+    ) -> anyhow::Result<Vec<Entry>> {
+        self.with_stream(stream_key.as_ref(), |stream| {
+            Ok(stream.rev_range(end, start, count))
+        })
     }
 
     #[allow(clippy::needless_pass_by_value)]
     pub fn read_blocking(
         &self,
-        stream_key: impl ToString,
+        stream_key: impl AsRef<[u8]>,
         entry_id: &EntryId,
         count: usize,
         block_duration: Option<std::time::Duration>,
     ) -> BlockResult<Vec<Entry>> {
-        self.blocking_query(block_duration, |_repo| -> BlockResult<Vec<Entry>> {
-            let res = self.read(stream_key.to_string(), entry_id, count).unwrap();
-            if res.is_empty() {
-                BlockResult::NotFound
-            } else {
-                BlockResult::Found(res)
+        let stream_key = stream_key.as_ref();
+        self.blocking_query(stream_key, block_duration, |_repo| {
+            let mut db = self.keyspace.lock();
+            match db.get_as::<Stream>(stream_key, SystemTime::now()) {
+                Ok(Some(stream)) => match stream.read(entry_id, count) {
+                    res if res.is_empty() => BlockResult::NotFound,
+                    res => BlockResult::Found(res),
+                },
+                // the stream may still be created while blocking
+                Ok(None) => BlockResult::NotFound,
+                Err(err) => BlockResult::Err(err.into()),
             }
         })
     }
 
+    /// Runs `f` until it finds something, retrying whenever an entry is added to `stream_key`.
     fn blocking_query<F, T>(
         &self,
+        stream_key: &[u8],
         block_duration: Option<std::time::Duration>,
         f: F,
     ) -> BlockResult<T>
     where
         F: Fn(&Self) -> BlockResult<T>,
     {
-        let result = f(self);
-        if result.is_not_found() {
-            self.block(block_duration, f)
-        } else {
-            result
-        }
-    }
-
-    fn get_listner(&self, stream: impl ToString) -> std::sync::mpsc::Receiver<Event> {
-        let (tx, rx) = std::sync::mpsc::channel();
-        self.listners
-            .lock()
-            .unwrap()
-            .entry(stream.to_string())
-            .or_default()
-            .push(tx);
-        rx
-    }
-
-    fn get_listner_with_timeout(
-        &self,
-        stream: impl ToString,
-        block_duration: std::time::Duration,
-    ) -> std::sync::mpsc::Receiver<Event> {
-        let (tx, rx) = std::sync::mpsc::channel();
-
-        self.listners
-            .lock()
-            .unwrap()
-            .entry(stream.to_string())
-            .or_default()
-            .push(tx.clone());
-
-        std::thread::spawn(move || {
-            std::thread::sleep(block_duration);
-            _ = tx.send(Event::Timeout);
-        });
-        rx
-    }
-
-    fn block<F, T>(&self, block_duration: Option<std::time::Duration>, f: F) -> BlockResult<T>
-    where
-        F: Fn(&Self) -> BlockResult<T>,
-    {
-        let rx = if let Some(block_duration) = block_duration {
-            self.get_listner_with_timeout(0, block_duration)
-        } else {
-            self.get_listner(0)
-        };
-
-        for event in rx {
-            match event {
-                Event::Added => {
-                    let result = f(self);
-                    if !result.is_not_found() {
-                        return result;
-                    }
-                }
-                Event::Timeout => return BlockResult::NotFound,
-            }
-        }
-
-        BlockResult::NotFound
-    }
-
-    fn wakeup_listers(&self, stream: &str) {
-        let mut lock = self.listners.lock().unwrap();
-        let Some(listners) = lock.get_mut(stream) else {
-            return;
-        };
-        listners.retain(|sender| sender.send(Event::Added).is_ok());
+        self.keyspace
+            .blocking()
+            .block(&[stream_key.to_vec()], block_duration, || f(self))
     }
 
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.streams.lock().unwrap().is_empty()
+        self.keyspace.lock().is_empty()
     }
 }
 
@@ -212,8 +178,3 @@ impl Default for LockingStreamRepository {
         Self::new()
     }
 }
-
-enum Event {
-    Added,
-    Timeout,
-}
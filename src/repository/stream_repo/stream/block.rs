@@ -0,0 +1,120 @@
+use super::{Entry, EntryId, Field};
+
+/// Most entries packed into one block, like redis' `stream-node-max-entries`.
+const MAX_ENTRIES: usize = 100;
+/// Size a block stops taking entries at, like redis' `stream-node-max-bytes`.
+const MAX_BYTES: usize = 4096;
+
+/// Entries packed back to back into one buffer, like a listpack in redis.
+///
+/// Every entry is its timestamp relative to the first entry of the block, its id and
+/// the length of its fields followed by them, all numbers as varints. The id of the
+/// first entry is the key of the block in the stream index so it is not stored here.
+#[derive(Debug, Clone, Default)]
+pub(super) struct Block {
+    data: Vec<u8>,
+    len: usize,
+}
+
+impl Block {
+    pub(super) fn is_full(&self) -> bool {
+        self.len >= MAX_ENTRIES || self.data.len() >= MAX_BYTES
+    }
+
+    pub(super) fn memory_usage(&self) -> usize {
+        self.data.capacity()
+    }
+
+    /// Appends an entry, `master` is the id of the first entry in the block.
+    pub(super) fn push(&mut self, master: &EntryId, id: &EntryId, fields: &[Field]) {
+        let mut packed = Vec::new();
+        for field in fields {
+            write_bytes(&mut packed, &field.name);
+            write_bytes(&mut packed, &field.value);
+        }
+        write_varint(&mut self.data, id.timestamp - master.timestamp);
+        write_varint(&mut self.data, id.id);
+        write_bytes(&mut self.data, &packed);
+        self.len += 1;
+    }
+
+    /// The entries in the block, oldest first.
+    pub(super) fn iter<'a>(&'a self, master: &EntryId) -> Packed<'a> {
+        Packed {
+            data: &self.data,
+            timestamp: master.timestamp,
+        }
+    }
+}
+
+/// Walks the entries of a [`Block`], only decoding the fields of the ones asked for.
+pub(super) struct Packed<'a> {
+    data: &'a [u8],
+    timestamp: u64,
+}
+
+impl<'a> Iterator for Packed<'a> {
+    type Item = PackedEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+        let timestamp = self.timestamp + read_varint(&mut self.data);
+        let id = EntryId::new(timestamp, read_varint(&mut self.data));
+        let fields = read_bytes(&mut self.data);
+        Some(PackedEntry { id, fields })
+    }
+}
+
+pub(super) struct PackedEntry<'a> {
+    pub(super) id: EntryId,
+    fields: &'a [u8],
+}
+
+impl PackedEntry<'_> {
+    pub(super) fn into_entry(self) -> Entry {
+        let mut data = self.fields;
+        let mut fields = Vec::new();
+        while !data.is_empty() {
+            let name = read_bytes(&mut data);
+            fields.push(Field::new(name, read_bytes(&mut data)));
+        }
+        Entry::new(self.id, fields)
+    }
+}
+
+fn write_varint(data: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        // the low 7 bits with the continuation bit set
+        #[allow(clippy::cast_possible_truncation)]
+        data.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    data.push(value as u8);
+}
+
+fn write_bytes(data: &mut Vec<u8>, bytes: &[u8]) {
+    write_varint(data, bytes.len() as u64);
+    data.extend_from_slice(bytes);
+}
+
+fn read_varint(data: &mut &[u8]) -> u64 {
+    let mut value = 0;
+    for (i, byte) in data.iter().enumerate() {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            *data = &data[i + 1..];
+            return value;
+        }
+    }
+    unreachable!("blocks only hold complete varints")
+}
+
+fn read_bytes<'a>(data: &mut &'a [u8]) -> &'a [u8] {
+    let len = usize::try_from(read_varint(data)).expect("fits in memory");
+    let (bytes, rest) = data.split_at(len);
+    *data = rest;
+    bytes
+}
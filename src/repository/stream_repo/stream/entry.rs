@@ -1,20 +1,26 @@
+use std::sync::Arc;
+
 use crate::resp::value::IntoRespArray;
 use crate::{
     repository::stream_repo::{stream::Field, EntryId},
     resp,
 };
 
+/// Cloning an entry is cheap, the fields are shared between clones.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Entry {
     pub(super) id: EntryId,
-    pub(super) fields: Vec<Field>,
+    pub(super) fields: Arc<[Field]>,
 }
 
 impl Entry {
     #[allow(clippy::needless_pass_by_value)]
     #[must_use]
     pub fn new(id: EntryId, fields: Vec<Field>) -> Self {
-        Self { id, fields }
+        Self {
+            id,
+            fields: fields.into(),
+        }
     }
 
     #[must_use]
@@ -34,9 +40,9 @@ impl From<Entry> for resp::Value {
             resp::Value::simple_string(value.id),
             value
                 .fields
-                .into_iter()
-                .flat_map(|field| [field.name, field.value])
-                .map(resp::Value::simple_string)
+                .iter()
+                .flat_map(|field| [&field.name, &field.value])
+                .map(|bytes| resp::Value::bulk_bytes(bytes.clone()))
                 .collect(),
         ]
         .into_array()
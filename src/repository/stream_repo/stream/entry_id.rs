@@ -1,38 +1,16 @@
 use std::fmt::Display;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Ordered by timestamp first, then by id.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct EntryId {
     pub timestamp: u64,
     pub id: u64,
-    key: Vec<u8>,
-}
-
-impl Ord for EntryId {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match self.timestamp.cmp(&other.timestamp) {
-            core::cmp::Ordering::Equal => self.id.cmp(&other.id),
-            ord => ord,
-        }
-    }
-}
-
-impl PartialOrd for EntryId {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
 }
 
 impl EntryId {
     #[must_use]
-    pub fn new(timestamp: u64, id: u64) -> Self {
-        let key = timestamp
-            .to_string()
-            .as_bytes()
-            .iter()
-            .map(|b| b - b'0')
-            .chain(id.to_string().as_bytes().iter().map(|b| b - b'0'))
-            .collect();
-        Self { timestamp, id, key }
+    pub const fn new(timestamp: u64, id: u64) -> Self {
+        Self { timestamp, id }
     }
 
     #[must_use]
@@ -68,13 +46,25 @@ impl EntryId {
         } else {
             0
         };
-        let key = format!("{timestamp}{id}").as_bytes().to_vec();
-        Self { timestamp, id, key }
+        Self { timestamp, id }
     }
 
+    /// The id as 128 bit big endian, so keys sort like the ids they encode.
     #[must_use]
-    pub fn as_radix_key(&self) -> &[u8] {
-        &self.key
+    pub fn to_radix_key(&self) -> [u8; 16] {
+        (u128::from(self.timestamp) << 64 | u128::from(self.id)).to_be_bytes()
+    }
+
+    /// The id encoded by [`Self::to_radix_key`].
+    ///
+    /// # Panics
+    /// if `key` is not 16 bytes long.
+    #[must_use]
+    pub fn from_radix_key(key: &[u8]) -> Self {
+        let key = u128::from_be_bytes(key.try_into().expect("radix keys are 16 bytes"));
+        // truncation splits the key back into its halves
+        #[allow(clippy::cast_possible_truncation)]
+        Self::new((key >> 64) as u64, key as u64)
     }
 }
 
@@ -193,20 +183,21 @@ impl std::str::FromStr for EntryIdKind {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // TODO err handling
+        let parse = |s: &str| {
+            s.parse().map_err(|_| {
+                anyhow::anyhow!("ERR Invalid stream ID specified as stream command argument")
+            })
+        };
         match s {
             "*" => Ok(EntryIdKind::None(EmptyEntryId)),
-            timestamp if timestamp.ends_with("-*") => {
-                let timestamp = timestamp.split('-').next().unwrap();
-                Ok(EntryIdKind::Timestamp(TimestampEntryId::from_millis(
-                    timestamp.parse().unwrap(),
-                )))
-            }
+            timestamp if timestamp.ends_with("-*") => Ok(EntryIdKind::Timestamp(
+                TimestampEntryId::from_millis(parse(&timestamp[..timestamp.len() - 2])?),
+            )),
             full => {
-                let (timestamp, id) = full.split_once('-').unwrap();
+                let (timestamp, id) = full.split_once('-').unwrap_or((full, "0"));
                 Ok(EntryIdKind::Full(EntryId::new(
-                    timestamp.parse().unwrap(),
-                    id.parse().unwrap(),
+                    parse(timestamp)?,
+                    parse(id)?,
                 )))
             }
         }
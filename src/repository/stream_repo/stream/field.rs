@@ -1,15 +1,14 @@
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Field {
-    pub(super) name: String,
-    pub(super) value: String,
+    pub(super) name: Vec<u8>,
+    pub(super) value: Vec<u8>,
 }
 
 impl Field {
-    #[allow(clippy::needless_pass_by_value)]
-    pub fn new(name: impl ToString, value: impl ToString) -> Self {
+    pub fn new(name: impl Into<Vec<u8>>, value: impl Into<Vec<u8>>) -> Self {
         Self {
-            name: name.to_string(),
-            value: value.to_string(),
+            name: name.into(),
+            value: value.into(),
         }
     }
 }
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests;
 
+mod block;
 pub mod entry;
 pub mod entry_id;
 pub mod field;
@@ -10,39 +11,62 @@ use entry_id::TimestampEntryId;
 pub use entry_id::{EntryId, PartialEntryId};
 pub use field::Field;
 
+use anyhow::bail;
+use block::Block;
+
 use crate::radix::Radix;
 
-#[derive(Debug)]
+/// Entries packed into blocks, indexed by the id of the first entry of every block.
+#[derive(Debug, Clone)]
 pub struct Stream {
-    indexes: Radix<usize>,
-    entries: Vec<Entry>,
+    blocks: Radix<Block>,
+    len: usize,
+    last_id: Option<EntryId>,
+}
+
+/// Entries may be packed into blocks differently so only they are compared.
+impl PartialEq for Stream {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+            && self
+                .entries_from(&EntryId::new(0, 0))
+                .eq(other.entries_from(&EntryId::new(0, 0)))
+    }
 }
 
+impl Eq for Stream {}
+
 impl Stream {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            indexes: Radix::new(),
-            entries: Vec::new(),
+            blocks: Radix::new(),
+            len: 0,
+            last_id: None,
         }
     }
 
     fn next_id(&self, timestamp: &std::time::SystemTime) -> EntryId {
-        self.entries.last().map_or_else(
+        self.last_id.as_ref().map_or_else(
             || TimestampEntryId::new(timestamp).into_full(),
-            |e| e.id.next(timestamp),
+            |id| id.next(timestamp),
         )
     }
 
     fn min_next_id(&self) -> EntryId {
-        self.entries
-            .last()
-            .map_or(EntryId::min(), |e| &e.id + 1_u64)
+        self.last_id
+            .as_ref()
+            .map_or(EntryId::min(), |id| id + 1_u64)
     }
 
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.indexes.is_empty()
+        self.len == 0
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
     }
 
     pub fn add_with_auto_key(
@@ -51,11 +75,7 @@ impl Stream {
         timestamp: &std::time::SystemTime,
     ) -> EntryId {
         let key = self.next_id(timestamp);
-
-        self.indexes
-            .add(key.as_radix_key(), self.entries.len())
-            .unwrap();
-        self.entries.push(Entry::new(key.clone(), fields));
+        self.push(&key, &fields);
         key
     }
 
@@ -64,50 +84,112 @@ impl Stream {
         key: impl PartialEntryId,
         fields: Vec<Field>,
     ) -> anyhow::Result<EntryId> {
-        // TODO tests
         let key = key.into_entry_id_or_default(&self.min_next_id());
+        if key == EntryId::new(0, 0) {
+            bail!("ERR The ID specified in XADD must be greater than 0-0");
+        }
         if key < self.min_next_id() {
-            panic!();
+            bail!(
+                "ERR The ID specified in XADD is equal or smaller than the target stream top item"
+            );
         }
-        self.indexes
-            .add(key.as_radix_key(), self.entries.len())
-            .unwrap();
-        self.entries.push(Entry::new(key.clone(), fields));
+        self.push(&key, &fields);
         Ok(key)
     }
 
+    /// Appends to the last block, or starts a new one keyed by `id` when it is full.
+    fn push(&mut self, id: &EntryId, fields: &[Field]) {
+        let last = self
+            .blocks
+            .last()
+            .filter(|(_, block)| !block.is_full())
+            .map(|(master, _)| master);
+        match last {
+            Some(master) => {
+                let block = self.blocks.get_mut(&master).expect("was just found");
+                block.push(&EntryId::from_radix_key(&master), id, fields);
+            }
+            None => {
+                let mut block = Block::default();
+                block.push(id, id, fields);
+                self.blocks.insert(&id.to_radix_key(), block);
+            }
+        }
+        self.len += 1;
+        self.last_id = Some(id.clone());
+    }
+
+    /// Entries from `start` on, seeking to the block that may hold it.
+    fn entries_from(&self, start: &EntryId) -> impl Iterator<Item = Entry> + '_ {
+        let key = start.to_radix_key();
+        let first = self
+            .blocks
+            .seek_rev(&key)
+            .next()
+            .map_or(key.to_vec(), |(master, _)| master);
+        let start = start.clone();
+        self.blocks
+            .seek(&first)
+            .flat_map(|(master, block)| block.iter(&EntryId::from_radix_key(&master)))
+            .skip_while(move |entry| entry.id < start)
+            .map(block::PackedEntry::into_entry)
+    }
+
+    /// Entries up to `end`, newest first.
+    fn entries_until(&self, end: &EntryId) -> impl Iterator<Item = Entry> + '_ {
+        let end = end.clone();
+        self.blocks
+            .seek_rev(&end.to_radix_key())
+            .flat_map(|(master, block)| {
+                let mut entries: Vec<_> = block.iter(&EntryId::from_radix_key(&master)).collect();
+                entries.reverse();
+                entries
+            })
+            .skip_while(move |entry| entry.id > end)
+            .map(block::PackedEntry::into_entry)
+    }
+
+    /// At most `count` entries after `key`.
     #[must_use]
     pub fn read(&self, key: &EntryId, count: usize) -> Vec<Entry> {
-        let start = match self.entries.binary_search_by_key(key, |e| e.id.clone()) {
-            Ok(i) => i + 1,
-            Err(i) => i,
-        };
-        self.entries
-            .iter()
-            .skip(start)
+        self.entries_from(key)
+            .skip_while(|entry| entry.id == *key)
             .take(count)
-            .cloned()
             .collect()
     }
 
     #[must_use]
     pub fn read_last(&self) -> Option<Entry> {
-        self.entries.last().cloned()
+        self.entries_until(&EntryId::max()).next()
+    }
+
+    /// At most `count` entries between `start` and `end` inclusive.
+    #[must_use]
+    pub fn range(&self, start: &EntryId, end: &EntryId, count: usize) -> Vec<Entry> {
+        self.entries_from(start)
+            .take_while(|entry| entry.id <= *end)
+            .take(count)
+            .collect()
+    }
+
+    /// At most `count` entries between `start` and `end` inclusive, newest first.
+    #[must_use]
+    pub fn rev_range(&self, end: &EntryId, start: &EntryId, count: usize) -> Vec<Entry> {
+        self.entries_until(end)
+            .take_while(|entry| entry.id >= *start)
+            .take(count)
+            .collect()
     }
 
+    /// Bytes used by the index and the packed entries.
     #[must_use]
-    pub fn range(&self, start: &EntryId, end: &EntryId) -> Vec<Entry> {
-        let start = match self.entries.binary_search_by_key(start, |e| e.id.clone()) {
-            Ok(i) => i,
-            Err(i) => i,
-        };
-
-        let end = match self.entries.binary_search_by_key(end, |e| e.id.clone()) {
-            Ok(i) => i + 1,
-            Err(i) => i,
-        };
-
-        self.entries.iter().skip(start).take(end).cloned().collect()
+    pub fn memory_usage(&self) -> usize {
+        self.blocks.memory_usage()
+            + self
+                .blocks
+                .iter()
+                .map(|(_, block)| block.memory_usage())
+                .sum::<usize>()
     }
 }
 
@@ -117,7 +117,7 @@ fn read_last_returns_last_value() {
 #[test]
 fn range_returns_nothing_on_empty() {
     tester(|stream| {
-        let empty = stream.range(&EntryId::min(), &EntryId::max());
+        let empty = stream.range(&EntryId::min(), &EntryId::max(), usize::MAX);
         assert_eq!(empty, Vec::<Entry>::new());
     });
 }
@@ -125,7 +125,7 @@ fn range_returns_nothing_on_empty() {
 #[test]
 fn range_returns_everything_between_entry_min_and_max_inclusive() {
     seed_tester(|stream, entries| {
-        let read = stream.range(&EntryId::min(), &EntryId::max());
+        let read = stream.range(&EntryId::min(), &EntryId::max(), usize::MAX);
         assert_eq!(read, *entries);
     });
 }
@@ -133,14 +133,121 @@ fn range_returns_everything_between_entry_min_and_max_inclusive() {
 #[test]
 fn range_returns_nothing_when_no_keys_are_in_range() {
     seed_tester(|stream, entries| {
-        let read = stream.range(&(entries.last().unwrap().id() + 1), &EntryId::max());
+        let read = stream.range(
+            &(entries.last().unwrap().id() + 1),
+            &EntryId::max(),
+            usize::MAX,
+        );
         assert_eq!(read, Vec::<Entry>::new());
     });
 }
 #[test]
 fn range_returns_keys_in_range_inclusive() {
     seed_tester(|stream, entries| {
-        let read = stream.range(entries.first().unwrap().id(), entries.get(1).unwrap().id());
+        let read = stream.range(
+            entries.first().unwrap().id(),
+            entries.get(1).unwrap().id(),
+            usize::MAX,
+        );
         assert_eq!(read, entries[0..=1]);
     });
 }
+
+#[test]
+fn entry_with_binary_field_is_bulk_strings() {
+    let entry = Entry::new(
+        EntryId::new(1, 0),
+        vec![Field::new("name", vec![0xff, 0x00])],
+    );
+    assert_eq!(
+        crate::resp::Value::from(entry),
+        crate::resp::Value::Array(vec![
+            crate::resp::Value::simple_string("1-0"),
+            crate::resp::Value::Array(vec![
+                crate::resp::Value::bulk_string("name"),
+                crate::resp::Value::BulkByteString(vec![0xff, 0x00]),
+            ]),
+        ])
+    );
+}
+
+fn large_stream(len: u64) -> Stream {
+    let mut stream = Stream::new();
+    for i in 1..=len {
+        let field = Field::new("field", i.to_string());
+        stream
+            .try_add_with_key(EntryId::new(i / 3, i), vec![field])
+            .unwrap();
+    }
+    stream
+}
+
+#[test]
+fn range_seeks_across_blocks() {
+    let stream = large_stream(1000);
+    assert_eq!(stream.len(), 1000);
+    let ids = |entries: Vec<Entry>| entries.into_iter().map(|e| e.id.id).collect::<Vec<_>>();
+    let start = EntryId::new(450 / 3, 450);
+    let end = EntryId::new(460 / 3, 460);
+    assert_eq!(ids(stream.range(&start, &end, 3)), [450, 451, 452]);
+    assert_eq!(
+        ids(stream.range(&start, &end, usize::MAX)),
+        (450..=460).collect::<Vec<_>>()
+    );
+    assert_eq!(ids(stream.rev_range(&end, &start, 2)), [460, 459]);
+    assert_eq!(
+        ids(stream.rev_range(&end, &start, usize::MAX)),
+        (450..=460).rev().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        ids(stream.read(&EntryId::new(998 / 3, 998), 5)),
+        [999, 1000]
+    );
+    assert_eq!(stream.read_last().unwrap().id, EntryId::new(1000 / 3, 1000));
+    assert_eq!(
+        stream.read_last().unwrap().fields(),
+        [Field::new("field", "1000")]
+    );
+}
+
+#[test]
+fn packed_entries_take_less_memory_than_unpacked_ones() {
+    let stream = large_stream(1000);
+    let unpacked = std::mem::size_of::<Entry>() + std::mem::size_of::<Field>();
+    assert!(stream.memory_usage() < 1000 * unpacked);
+}
+
+#[test]
+fn add_with_key_fails_on_ids_not_after_the_last() {
+    let mut stream = large_stream(3);
+    let err = stream
+        .try_add_with_key(EntryId::new(1, 3), Vec::new())
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "ERR The ID specified in XADD is equal or smaller than the target stream top item"
+    );
+    let err = Stream::new()
+        .try_add_with_key(EntryId::new(0, 0), Vec::new())
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "ERR The ID specified in XADD must be greater than 0-0"
+    );
+    assert_eq!(stream.len(), 3);
+}
+
+#[test]
+fn radix_key_round_trips_and_sorts_like_the_id() {
+    let ids = [
+        EntryId::new(1, u64::MAX),
+        EntryId::new(2, 0),
+        EntryId::new(u64::MAX, 1),
+    ];
+    for pair in ids.windows(2) {
+        assert!(pair[0].to_radix_key() < pair[1].to_radix_key());
+    }
+    for id in ids {
+        assert_eq!(EntryId::from_radix_key(&id.to_radix_key()), id);
+    }
+}
@@ -30,7 +30,8 @@ fn xadd_creates_stream_if_key_does_not_exsist() {
             "myNewStream",
             vec![Field::new("mykey", "myValue")],
             &std::time::UNIX_EPOCH,
-        );
+        )
+        .unwrap();
         assert!(!repo.is_empty());
     });
 }
@@ -48,7 +49,8 @@ fn xread_returns_newly_added_field_if_done_on_a_new_stream() {
     tester(|repo| {
         let stream_key = "streamkey123";
         let fields = vec![Field::new("abc", "xyz")];
-        repo.add_auto_increment(stream_key, fields.clone(), &std::time::UNIX_EPOCH);
+        repo.add_auto_increment(stream_key, fields.clone(), &std::time::UNIX_EPOCH)
+            .unwrap();
         let read = repo
             .read(stream_key, &EntryId::new(0, 0), usize::MAX)
             .unwrap();
@@ -105,7 +107,8 @@ where
                                     stream_key,
                                     fields.clone(),
                                     &std::time::UNIX_EPOCH,
-                                ),
+                                )
+                                .unwrap(),
                                 fields,
                             )
                         })
@@ -146,7 +149,8 @@ fn xread_last() {
 #[should_panic(expected = "stream not found")]
 fn xrange_on_empty_repo_fails() {
     tester(|repo| {
-        repo.range("any", &EntryId::min(), &EntryId::max()).unwrap();
+        repo.range("any", &EntryId::min(), &EntryId::max(), usize::MAX)
+            .unwrap();
     });
 }
 
@@ -155,8 +159,14 @@ fn xrange_test() {
     seed_tester(|repo, stream_keys| {
         for (stream_key, entries) in stream_keys {
             let entry = entries.first().unwrap();
-            let found_values = repo.range(stream_key, entry.id(), entry.id()).unwrap();
-            assert_eq!(found_values, [entry.clone()], "{entry:?}, {entries:?}");
+            let found_values = repo
+                .range(stream_key, entry.id(), entry.id(), usize::MAX)
+                .unwrap();
+            assert_eq!(
+                found_values,
+                std::slice::from_ref(entry),
+                "{entry:?}, {entries:?}"
+            );
         }
     });
 }
@@ -171,8 +181,10 @@ fn blocking_query_does_not_block_when_it_finds_data() {
             let block_duration = std::time::Duration::from_millis(10);
 
             let entry = entries.first().unwrap();
-            let result = repo.blocking_query(Some(block_duration), |repo| {
-                let res = repo.range(key.clone(), entry.id(), entry.id()).unwrap();
+            let result = repo.blocking_query(key.as_bytes(), Some(block_duration), |repo| {
+                let res = repo
+                    .range(key.clone(), entry.id(), entry.id(), usize::MAX)
+                    .unwrap();
                 if res.is_empty() {
                     BlockResult::NotFound
                 } else {
@@ -182,7 +194,7 @@ fn blocking_query_does_not_block_when_it_finds_data() {
             let BlockResult::Found(value) = result else {
                 panic!()
             };
-            assert_eq!(value, [entry.clone()]);
+            assert_eq!(value, std::slice::from_ref(entry));
 
             let elapsed = start.elapsed();
             assert!(elapsed < block_duration);
@@ -198,14 +210,20 @@ fn blocking_query_blocks_on_no_data_and_returns_none() {
             let block_duration = std::time::Duration::from_millis(10);
 
             let entry_id = entries.last().unwrap().id() + 1;
-            let result = repo.blocking_query(Some(std::time::Duration::from_millis(100)), |repo| {
-                let res = repo.range(key.clone(), &entry_id, &entry_id).unwrap();
-                if res.is_empty() {
-                    BlockResult::NotFound
-                } else {
-                    BlockResult::Found(res)
-                }
-            });
+            let result = repo.blocking_query(
+                key.as_bytes(),
+                Some(std::time::Duration::from_millis(100)),
+                |repo| {
+                    let res = repo
+                        .range(key.clone(), &entry_id, &entry_id, usize::MAX)
+                        .unwrap();
+                    if res.is_empty() {
+                        BlockResult::NotFound
+                    } else {
+                        BlockResult::Found(res)
+                    }
+                },
+            );
 
             let elapsed = start.elapsed();
             assert_eq!(result, BlockResult::NotFound);
@@ -231,25 +249,30 @@ fn blocking_returns_data_recived_during_block() {
                 let stream_key = stream_key.clone();
                 let entry = entries.last().unwrap().id() + 1;
                 handle = std::thread::spawn(move || {
-                    repo2.blocking_query(Some(block_duration), |repo: &StreamRepository| {
-                        repo.range(stream_key.clone(), &entry, &entry)
-                            .map(|v| {
-                                if v.is_empty() {
-                                    BlockResult::NotFound
-                                } else {
-                                    BlockResult::Found(v)
-                                }
-                            })
-                            .unwrap()
-                    })
+                    repo2.blocking_query(
+                        stream_key.as_bytes(),
+                        Some(block_duration),
+                        |repo: &StreamRepository| {
+                            repo.range(stream_key.clone(), &entry, &entry, usize::MAX)
+                                .map(|v| {
+                                    if v.is_empty() {
+                                        BlockResult::NotFound
+                                    } else {
+                                        BlockResult::Found(v)
+                                    }
+                                })
+                                .unwrap()
+                        },
+                    )
                 });
             }
             std::thread::sleep(std::time::Duration::from_millis(1));
             assert!(!handle.is_finished(), "{:?}", handle.join());
 
             let new_field = vec![Field::new("thenewKey", "theNewValue")];
-            let key =
-                repo.add_auto_increment(stream_key, new_field.clone(), &std::time::UNIX_EPOCH);
+            let key = repo
+                .add_auto_increment(stream_key, new_field.clone(), &std::time::UNIX_EPOCH)
+                .unwrap();
 
             dbg!(key);
             std::thread::sleep(std::time::Duration::from_millis(4));
@@ -315,7 +338,8 @@ fn blocking_read_returns_data_recived_during_block() {
             assert!(!handle.is_finished(), "{:?}", handle.join());
 
             let new_value = vec![Field::new("someNewKey", "theNewValue")];
-            repo.add_auto_increment(stream_key, new_value.clone(), &std::time::UNIX_EPOCH);
+            repo.add_auto_increment(stream_key, new_value.clone(), &std::time::UNIX_EPOCH)
+                .unwrap();
 
             std::thread::sleep(std::time::Duration::from_millis(4));
             assert!(handle.is_finished());
@@ -0,0 +1,264 @@
+use std::{collections::hash_map::RandomState, hash::BuildHasher};
+
+use crate::random;
+
+#[cfg(test)]
+mod tests;
+
+/// Fewest buckets a table that holds anything has.
+const MIN_BUCKETS: usize = 4;
+/// The table shrinks once fewer than one in this many buckets are used, like redis.
+const MIN_FILL: usize = 8;
+
+/// A map from byte strings to `V` that can be scanned while it changes, like a redis dict.
+///
+/// Entries are chained into a power of two number of buckets by their hash, which grows with
+/// the table and shrinks again once most entries are removed. Scans walk the buckets with a
+/// reverse binary cursor, so an entry that is there for the whole scan is returned at least
+/// once however often the table is resized in between.
+/// The entries themselves are packed into one vector, so a random entry is a random index.
+#[derive(Debug, Clone)]
+pub struct Table<V> {
+    /// Packed in insertion order, removing an entry moves the last one into its place.
+    entries: Vec<Entry<V>>,
+    /// The first entry of every chain.
+    buckets: Vec<Option<usize>>,
+    hasher: RandomState,
+}
+
+#[derive(Debug, Clone)]
+struct Entry<V> {
+    key: Vec<u8>,
+    value: V,
+    hash: u64,
+    /// The next entry in the same bucket.
+    next: Option<usize>,
+}
+
+impl<V> Default for Table<V> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            buckets: Vec::new(),
+            hasher: RandomState::new(),
+        }
+    }
+}
+
+impl<V: PartialEq> PartialEq for Table<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<V: Eq> Eq for Table<V> {}
+
+impl<V> Table<V> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[must_use]
+    pub fn contains_key(&self, key: &[u8]) -> bool {
+        self.find(key).is_some()
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &[u8]) -> Option<&V> {
+        self.find(key).map(|index| &self.entries[index].value)
+    }
+
+    /// The entry at `index` of the packed entries, any index below [`Self::len`] is one.
+    #[must_use]
+    pub fn get_index(&self, index: usize) -> Option<(&Vec<u8>, &V)> {
+        let entry = self.entries.get(index)?;
+        Some((&entry.key, &entry.value))
+    }
+
+    pub fn get_mut(&mut self, key: &[u8]) -> Option<&mut V> {
+        self.find(key).map(|index| &mut self.entries[index].value)
+    }
+
+    /// Returns the value `key` had.
+    pub fn insert(&mut self, key: Vec<u8>, value: V) -> Option<V> {
+        if let Some(old) = self.get_mut(&key) {
+            return Some(std::mem::replace(old, value));
+        }
+        if self.len() >= self.buckets.len() {
+            self.resize((self.len() + 1).next_power_of_two().max(MIN_BUCKETS));
+        }
+        let hash = self.hasher.hash_one(key.as_slice());
+        let bucket = self.bucket(hash);
+        self.entries.push(Entry {
+            key,
+            value,
+            hash,
+            next: self.buckets[bucket],
+        });
+        self.buckets[bucket] = Some(self.entries.len() - 1);
+        None
+    }
+
+    pub fn remove(&mut self, key: &[u8]) -> Option<V> {
+        let index = self.find(key)?;
+        let next = self.entries[index].next;
+        *self.link_to(index) = next;
+        let last = self.entries.len() - 1;
+        if index != last {
+            // the last entry takes the place of the removed one
+            *self.link_to(last) = Some(index);
+        }
+        let value = self.entries.swap_remove(index).value;
+        if self.is_empty() {
+            self.clear();
+        } else if self.buckets.len() > MIN_BUCKETS && self.len() * MIN_FILL < self.buckets.len() {
+            self.resize(self.len().next_power_of_two().max(MIN_BUCKETS));
+        }
+        Some(value)
+    }
+
+    pub fn clear(&mut self) {
+        self.entries = Vec::new();
+        self.buckets = Vec::new();
+    }
+
+    /// The entries in insertion order, as long as nothing was removed.
+    pub fn iter(&self) -> impl Iterator<Item = (&Vec<u8>, &V)> + '_ {
+        self.entries.iter().map(|entry| (&entry.key, &entry.value))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &Vec<u8>> + '_ {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// The entries of the buckets from `cursor` on until at least `count` were found,
+    /// visiting at most ten times as many empty buckets like redis so one call stays fast.
+    /// Returns the cursor to continue from, 0 once every bucket was visited.
+    ///
+    /// Like redis the cursor counts up in its reversed bits, so the buckets a bucket is split
+    /// into when the table grows, or merged with when it shrinks, are visited right after it.
+    #[must_use]
+    pub fn scan(&self, cursor: usize, count: usize) -> (usize, Vec<(&Vec<u8>, &V)>) {
+        let mut entries = Vec::new();
+        if self.buckets.is_empty() {
+            return (0, entries);
+        }
+        let mask = self.buckets.len() - 1;
+        let mut cursor = cursor;
+        let mut empty = 0;
+        loop {
+            let mut chain = self.buckets[cursor & mask];
+            if chain.is_none() {
+                empty += 1;
+            }
+            while let Some(index) = chain {
+                let entry = &self.entries[index];
+                entries.push((&entry.key, &entry.value));
+                chain = entry.next;
+            }
+            // increments the bits the mask covers, starting from the highest one
+            cursor |= !mask;
+            cursor = cursor.reverse_bits().wrapping_add(1).reverse_bits();
+            if cursor == 0 || entries.len() >= count || empty >= count.saturating_mul(10) {
+                return (cursor, entries);
+            }
+        }
+    }
+
+    /// A random entry, picked from the packed entries so it takes one try however sparse
+    /// the buckets are.
+    #[must_use]
+    pub fn random(&self) -> Option<(&Vec<u8>, &V)> {
+        if self.is_empty() {
+            return None;
+        }
+        self.get_index(random::below(self.len()))
+    }
+
+    fn bucket(&self, hash: u64) -> usize {
+        // only the low bits are kept by the mask, dropping the high ones on 32 bit targets is fine
+        #[allow(clippy::cast_possible_truncation)]
+        let hash = hash as usize;
+        hash & (self.buckets.len() - 1)
+    }
+
+    fn find(&self, key: &[u8]) -> Option<usize> {
+        if self.buckets.is_empty() {
+            return None;
+        }
+        let hash = self.hasher.hash_one(key);
+        let mut chain = self.buckets[self.bucket(hash)];
+        while let Some(index) = chain {
+            let entry = &self.entries[index];
+            if entry.hash == hash && entry.key == key {
+                return Some(index);
+            }
+            chain = entry.next;
+        }
+        None
+    }
+
+    /// The link that points at the entry at `index`, either its bucket or the entry before it.
+    fn link_to(&mut self, index: usize) -> &mut Option<usize> {
+        let bucket = self.bucket(self.entries[index].hash);
+        if self.buckets[bucket] == Some(index) {
+            return &mut self.buckets[bucket];
+        }
+        let mut previous = self.buckets[bucket].expect("entries are in their bucket");
+        while self.entries[previous].next != Some(index) {
+            previous = self.entries[previous]
+                .next
+                .expect("entries are in their bucket");
+        }
+        &mut self.entries[previous].next
+    }
+
+    /// Rechains every entry into `buckets` buckets, giving back the memory a shrink frees.
+    fn resize(&mut self, buckets: usize) {
+        self.buckets = vec![None; buckets];
+        for index in 0..self.entries.len() {
+            let bucket = self.bucket(self.entries[index].hash);
+            self.entries[index].next = self.buckets[bucket];
+            self.buckets[bucket] = Some(index);
+        }
+        if self.entries.capacity() > buckets {
+            self.entries.shrink_to(buckets);
+        }
+    }
+}
+
+impl<V> std::ops::Index<&[u8]> for Table<V> {
+    type Output = V;
+
+    fn index(&self, key: &[u8]) -> &V {
+        self.get(key).expect("no entry for key")
+    }
+}
+
+impl<V> FromIterator<(Vec<u8>, V)> for Table<V> {
+    fn from_iter<T: IntoIterator<Item = (Vec<u8>, V)>>(iter: T) -> Self {
+        let mut table = Self::new();
+        iter.into_iter().for_each(|(key, value)| {
+            table.insert(key, value);
+        });
+        table
+    }
+}
@@ -0,0 +1,165 @@
+use super::Table;
+
+fn key(n: usize) -> Vec<u8> {
+    format!("key:{n}").into_bytes()
+}
+
+#[test]
+fn insert_get_remove() {
+    let mut table = Table::new();
+    assert_eq!(table.insert(b"a".to_vec(), 1), None);
+    assert_eq!(table.insert(b"a".to_vec(), 2), Some(1));
+    assert_eq!(table.get(b"a"), Some(&2));
+    assert_eq!(table.len(), 1);
+    assert_eq!(table.remove(b"a"), Some(2));
+    assert_eq!(table.remove(b"a"), None);
+    assert!(table.is_empty());
+    assert_eq!(table.random(), None);
+}
+
+#[test]
+fn removing_moves_the_last_entry_into_the_gap() {
+    let mut table: Table<usize> = (0..4).map(|n| (key(n), n)).collect();
+    table.remove(&key(1));
+    assert_eq!(table.values().copied().collect::<Vec<_>>(), [0, 3, 2]);
+    assert!((0..4).filter(|n| *n != 1).all(|n| table[&key(n)] == n));
+    table.insert(key(4), 4);
+    assert_eq!(table.len(), 4);
+}
+
+#[test]
+fn grows_and_shrinks_with_its_entries() {
+    let mut table: Table<usize> = (0..1000).map(|n| (key(n), n)).collect();
+    assert_eq!(table.buckets.len(), 1024);
+    (0..990).for_each(|n| {
+        table.remove(&key(n));
+    });
+    assert!(table.buckets.len() <= 16 * super::MIN_FILL);
+    assert!(table.entries.capacity() <= table.buckets.len());
+    assert!((990..1000).all(|n| table[&key(n)] == n));
+    (990..1000).for_each(|n| {
+        table.remove(&key(n));
+    });
+    assert!(table.buckets.is_empty());
+    assert_eq!(table.scan(0, 10), (0, Vec::new()));
+}
+
+#[test]
+fn equality_ignores_slot_order() {
+    let a: Table<usize> = (0..3).map(|n| (key(n), n)).collect();
+    let b: Table<usize> = (0..3).rev().map(|n| (key(n), n)).collect();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn scan_returns_entries_present_for_the_whole_scan() {
+    let mut table: Table<usize> = (0..100).map(|n| (key(n), n)).collect();
+    let mut seen = Vec::new();
+    let (mut cursor, mut added) = (0, 100);
+    loop {
+        let (next, entries) = table.scan(cursor, 7);
+        seen.extend(entries.into_iter().map(|(_, n)| *n));
+        // churn while scanning: drop a key that was seen, grow past the old size
+        if let Some(n) = seen.iter().find(|n| table.contains_key(&key(**n))) {
+            table.remove(&key(*n));
+        }
+        for _ in 0..3 {
+            table.insert(key(added), added);
+            added += 1;
+        }
+        if next == 0 {
+            break;
+        }
+        cursor = next;
+    }
+    assert!((0..100).all(|n| seen.contains(&n)));
+}
+
+#[test]
+fn scan_returns_entries_present_while_the_table_shrinks() {
+    let mut table: Table<usize> = (0..1000).map(|n| (key(n), n)).collect();
+    let mut seen = Vec::new();
+    let mut cursor = 0;
+    loop {
+        let (next, entries) = table.scan(cursor, 10);
+        seen.extend(entries.into_iter().map(|(_, n)| *n));
+        // everything but the first ten keys goes away during the scan
+        let gone: Vec<_> = table
+            .iter()
+            .filter(|(_, n)| **n >= 10)
+            .take(50)
+            .map(|(key, _)| key.clone())
+            .collect();
+        gone.iter().for_each(|key| {
+            table.remove(key);
+        });
+        if next == 0 {
+            break;
+        }
+        cursor = next;
+    }
+    assert!((0..10).all(|n| seen.contains(&n)));
+}
+
+#[test]
+fn scan_visits_bounded_empty_buckets() {
+    let mut table: Table<usize> = Table::new();
+    table.insert(key(0), 0);
+    table.resize(1024);
+    let (mut cursor, mut calls, mut seen) = (0, 0, 0);
+    loop {
+        let (next, entries) = table.scan(cursor, 1);
+        calls += 1;
+        seen += entries.len();
+        if next == 0 {
+            break;
+        }
+        cursor = next;
+    }
+    assert_eq!(seen, 1);
+    // ten empty buckets per call, and one more for the call that finds the entry
+    assert!(calls >= 1024 / 11);
+}
+
+#[test]
+fn random_picks_from_any_table() {
+    let mut table: Table<usize> = (0..1000).map(|n| (key(n), n)).collect();
+    (0..999).for_each(|n| {
+        table.remove(&key(n));
+    });
+    assert_eq!(table.random(), Some((&key(999), &999)));
+}
+
+#[test]
+fn matches_map_under_random_operations() {
+    let mut rng = crate::random::Rng::new(11);
+    let mut table = Table::new();
+    let mut map = std::collections::HashMap::new();
+    for round in 0..20_000 {
+        let n = rng.below(500);
+        // inserts outweigh removes in the first half, then the table shrinks again
+        let insert = if round < 10_000 {
+            rng.below(3) != 0
+        } else {
+            rng.below(5) == 0
+        };
+        if insert {
+            assert_eq!(table.insert(key(n), round), map.insert(key(n), round));
+        } else {
+            assert_eq!(table.remove(&key(n)), map.remove(&key(n)));
+        }
+    }
+    assert_eq!(table.len(), map.len());
+    assert!(map.iter().all(|(key, value)| table.get(key) == Some(value)));
+}
+
+#[test]
+fn get_index_reaches_every_entry() {
+    let table: Table<usize> = (0..10).map(|i| (key(i), i)).collect();
+    let mut values: Vec<_> = (0..table.len())
+        .map(|index| *table.get_index(index).unwrap().1)
+        .collect();
+    values.sort_unstable();
+    assert_eq!(values, (0..10).collect::<Vec<_>>());
+    assert_eq!(table.get_index(10), None);
+}
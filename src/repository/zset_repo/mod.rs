@@ -0,0 +1,424 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime},
+};
+
+use anyhow::bail;
+
+use super::{
+    keyspace::{Db, Item, Keyspace, Value, WrongType},
+    BlockResult,
+};
+use crate::{
+    command::parser::SYNTAX_ERROR,
+    glob,
+    resp::{self, FromValue},
+};
+
+pub mod sorted_set;
+
+pub use sorted_set::{Entry, LexBound, Limit, Range, ScoreBound, SortedSet};
+
+#[cfg(test)]
+mod tests;
+
+/// The key entries were popped from and the entries.
+pub type Popped = (Vec<u8>, Vec<Entry>);
+
+/// The conditions of `ZADD`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AddOptions {
+    /// Only add new members.
+    pub nx: bool,
+    /// Only update existing members.
+    pub xx: bool,
+    /// Only update to a greater score.
+    pub gt: bool,
+    /// Only update to a lower score.
+    pub lt: bool,
+    /// Add the score to the current one instead of replacing it.
+    pub incr: bool,
+}
+
+/// What `ZADD` did.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AddResult {
+    pub added: usize,
+    /// Existing members whose score changed.
+    pub updated: usize,
+    /// The score of the last member if it was written.
+    pub score: Option<f64>,
+}
+
+/// How the scores of a member in several sets are combined.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Aggregate {
+    #[default]
+    Sum,
+    Min,
+    Max,
+}
+
+impl Aggregate {
+    fn apply(self, a: f64, b: f64) -> f64 {
+        match self {
+            // inf + -inf is 0 like in redis
+            Self::Sum => non_nan(a + b),
+            Self::Min => a.min(b),
+            Self::Max => a.max(b),
+        }
+    }
+}
+
+impl FromValue for Aggregate {
+    fn from_value(value: resp::Value) -> anyhow::Result<Self> {
+        if value.eq_ignore_ascii_case("SUM") {
+            Ok(Self::Sum)
+        } else if value.eq_ignore_ascii_case("MIN") {
+            Ok(Self::Min)
+        } else if value.eq_ignore_ascii_case("MAX") {
+            Ok(Self::Max)
+        } else {
+            bail!(SYNTAX_ERROR)
+        }
+    }
+}
+
+/// How the sets of several keys are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Intersection,
+    Union,
+}
+
+fn non_nan(score: f64) -> f64 {
+    if score.is_nan() {
+        0.0
+    } else {
+        score
+    }
+}
+
+/// Sorted sets stored in the keyspace, a set that becomes empty is removed like in redis.
+#[derive(Debug, Clone, Default)]
+pub struct ZSetRepository {
+    keyspace: Keyspace,
+}
+
+impl ZSetRepository {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_keyspace(keyspace: Keyspace) -> Self {
+        Self { keyspace }
+    }
+
+    /// Runs `f` on the sorted set at `key`, returns `None` if there is no such key.
+    fn with_set<F, T>(&self, key: &[u8], now: SystemTime, f: F) -> Result<Option<T>, WrongType>
+    where
+        F: FnOnce(&mut SortedSet) -> T,
+    {
+        let mut db = self.keyspace.lock();
+        Self::update(&mut db, key, now, f)
+    }
+
+    fn update<F, T>(db: &mut Db, key: &[u8], now: SystemTime, f: F) -> Result<Option<T>, WrongType>
+    where
+        F: FnOnce(&mut SortedSet) -> T,
+    {
+        let Some(set) = db.get_as::<SortedSet>(key, now)? else {
+            return Ok(None);
+        };
+        let result = f(set);
+        if set.is_empty() {
+            db.remove(key, now);
+        }
+        Ok(Some(result))
+    }
+
+    /// Stores `set` at `key` whatever it held before, or removes `key` if `set` is empty.
+    fn store(&self, db: &mut Db, key: &[u8], set: SortedSet, now: SystemTime) -> usize {
+        let len = set.len();
+        if set.is_empty() {
+            db.remove(key, now);
+        } else {
+            db.insert(key.to_vec(), Item::new(Value::SortedSet(set)));
+            self.keyspace.blocking().wake(key);
+        }
+        len
+    }
+
+    pub fn add(
+        &self,
+        key: &[u8],
+        entries: Vec<Entry>,
+        options: AddOptions,
+        now: SystemTime,
+    ) -> anyhow::Result<AddResult> {
+        let mut db = self.keyspace.lock();
+        let set = db.get_or_default::<SortedSet>(key, now)?;
+        let mut result = AddResult::default();
+        let mut error = None;
+        for (member, score) in entries {
+            let current = set.score(&member);
+            let score = match current {
+                Some(current) if options.incr => current + score,
+                _ => score,
+            };
+            if score.is_nan() {
+                error = Some("ERR resulting score is not a number (NaN)");
+                break;
+            }
+            let skip = match current {
+                Some(current) => {
+                    options.nx
+                        || (options.gt && score <= current)
+                        || (options.lt && score >= current)
+                }
+                None => options.xx,
+            };
+            result.score = (!skip).then_some(score);
+            if skip {
+                continue;
+            }
+            match current {
+                Some(current) if current != score => result.updated += 1,
+                Some(_) => (),
+                None => result.added += 1,
+            }
+            set.insert(member, score);
+        }
+        if set.is_empty() {
+            db.remove(key, now);
+        } else if result.added > 0 {
+            self.keyspace.blocking().wake(key);
+        }
+        match error {
+            Some(error) => bail!(error),
+            None => Ok(result),
+        }
+    }
+
+    /// Removes `members` and returns how many of them existed.
+    pub fn remove(
+        &self,
+        key: &[u8],
+        members: &[Vec<u8>],
+        now: SystemTime,
+    ) -> anyhow::Result<usize> {
+        let removed = self.with_set(key, now, |set| {
+            members.iter().filter(|member| set.remove(member)).count()
+        })?;
+        Ok(removed.unwrap_or(0))
+    }
+
+    pub fn score(&self, key: &[u8], member: &[u8], now: SystemTime) -> anyhow::Result<Option<f64>> {
+        Ok(self.with_set(key, now, |set| set.score(member))?.flatten())
+    }
+
+    pub fn scores(
+        &self,
+        key: &[u8],
+        members: &[Vec<u8>],
+        now: SystemTime,
+    ) -> anyhow::Result<Vec<Option<f64>>> {
+        let scores = self.with_set(key, now, |set| {
+            members.iter().map(|member| set.score(member)).collect()
+        })?;
+        Ok(scores.unwrap_or_else(|| vec![None; members.len()]))
+    }
+
+    pub fn len(&self, key: &[u8], now: SystemTime) -> anyhow::Result<usize> {
+        Ok(self.with_set(key, now, |set| set.len())?.unwrap_or(0))
+    }
+
+    pub fn count(&self, key: &[u8], range: &Range, now: SystemTime) -> anyhow::Result<usize> {
+        Ok(self
+            .with_set(key, now, |set| set.count(range))?
+            .unwrap_or(0))
+    }
+
+    /// The rank of `member` and its score, counted from the highest score if `rev`.
+    pub fn rank(
+        &self,
+        key: &[u8],
+        member: &[u8],
+        rev: bool,
+        now: SystemTime,
+    ) -> anyhow::Result<Option<(usize, f64)>> {
+        let rank = self.with_set(key, now, |set| {
+            Some((set.rank(member, rev)?, set.score(member)?))
+        })?;
+        Ok(rank.flatten())
+    }
+
+    pub fn range(
+        &self,
+        key: &[u8],
+        range: &Range,
+        rev: bool,
+        limit: Option<Limit>,
+        now: SystemTime,
+    ) -> anyhow::Result<Vec<Entry>> {
+        let entries = self.with_set(key, now, |set| set.range(range, rev, limit))?;
+        Ok(entries.unwrap_or_default())
+    }
+
+    /// Stores the result of [`Self::range`] at `destination` and returns its length.
+    pub fn range_store(
+        &self,
+        destination: &[u8],
+        source: &[u8],
+        range: &Range,
+        rev: bool,
+        limit: Option<Limit>,
+        now: SystemTime,
+    ) -> anyhow::Result<usize> {
+        let mut db = self.keyspace.lock();
+        let entries = Self::update(&mut db, source, now, |set| set.range(range, rev, limit))?;
+        let set = entries.unwrap_or_default().into_iter().collect();
+        Ok(self.store(&mut db, destination, set, now))
+    }
+
+    /// Removes and returns up to `count` members with the lowest scores, or the highest if `max`.
+    pub fn pop(
+        &self,
+        key: &[u8],
+        count: usize,
+        max: bool,
+        now: SystemTime,
+    ) -> anyhow::Result<Vec<Entry>> {
+        let popped = self.with_set(key, now, |set| set.pop(count, max))?;
+        Ok(popped.unwrap_or_default())
+    }
+
+    /// Like [`Self::pop`] on the first of `keys` that holds a sorted set.
+    pub fn pop_first(
+        &self,
+        keys: &[Vec<u8>],
+        count: usize,
+        max: bool,
+        now: SystemTime,
+    ) -> anyhow::Result<Option<Popped>> {
+        let mut db = self.keyspace.lock();
+        for key in keys {
+            if let Some(popped) = Self::update(&mut db, key, now, |set| set.pop(count, max))? {
+                return Ok(Some((key.clone(), popped)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Like [`Self::pop_first`] but waits up to `timeout` for one of `keys` to be added to,
+    /// `None` waits forever.
+    pub fn pop_blocking(
+        &self,
+        keys: &[Vec<u8>],
+        count: usize,
+        max: bool,
+        timeout: Option<Duration>,
+    ) -> BlockResult<Popped> {
+        self.keyspace.blocking().block(keys, timeout, || {
+            match self.pop_first(keys, count, max, SystemTime::now()) {
+                Ok(Some(popped)) => BlockResult::Found(popped),
+                Ok(None) => BlockResult::NotFound,
+                Err(err) => BlockResult::Err(err),
+            }
+        })
+    }
+
+    /// Combines the sorted sets or sets at `keys` into `destination` and returns its length.
+    /// Scores are multiplied by the weight of their key, 1 if `weights` is empty,
+    /// and a member's scores are combined with `aggregate`.
+    pub fn combine_store(
+        &self,
+        operation: Operation,
+        destination: &[u8],
+        keys: &[Vec<u8>],
+        weights: &[f64],
+        aggregate: Aggregate,
+        now: SystemTime,
+    ) -> anyhow::Result<usize> {
+        let mut db = self.keyspace.lock();
+        let mut inputs = Vec::with_capacity(keys.len());
+        for key in keys {
+            inputs.push(Self::entries_of(&mut db, key, now)?);
+        }
+        let weight = |i: usize| weights.get(i).copied().unwrap_or(1.0);
+        let mut combined: HashMap<Vec<u8>, f64> = HashMap::new();
+        match operation {
+            Operation::Union => {
+                for (i, entries) in inputs.into_iter().enumerate() {
+                    for (member, score) in entries.into_iter().flatten() {
+                        let score = non_nan(score * weight(i));
+                        combined
+                            .entry(member)
+                            .and_modify(|current| *current = aggregate.apply(*current, score))
+                            .or_insert(score);
+                    }
+                }
+            }
+            Operation::Intersection => {
+                let mut inputs = inputs.into_iter();
+                if let Some(Some(first)) = inputs.next() {
+                    combined = first
+                        .into_iter()
+                        .map(|(member, score)| (member, non_nan(score * weight(0))))
+                        .collect();
+                }
+                for (i, entries) in inputs.enumerate() {
+                    let entries: HashMap<_, _> = entries.into_iter().flatten().collect();
+                    combined.retain(|member, current| match entries.get(member) {
+                        Some(score) => {
+                            *current = aggregate.apply(*current, non_nan(score * weight(i + 1)));
+                            true
+                        }
+                        None => false,
+                    });
+                }
+            }
+        }
+        Ok(self.store(&mut db, destination, combined.into_iter().collect(), now))
+    }
+
+    /// The entries at `key`, members of a plain set count with a score of 1.
+    fn entries_of(
+        db: &mut Db,
+        key: &[u8],
+        now: SystemTime,
+    ) -> Result<Option<Vec<Entry>>, WrongType> {
+        match db.get(key, now).map(|item| &item.value) {
+            None => Ok(None),
+            Some(Value::SortedSet(set)) => Ok(Some(
+                set.iter()
+                    .map(|(member, score)| (member.to_vec(), score))
+                    .collect(),
+            )),
+            Some(Value::Set(set)) => Ok(Some(set.iter().map(|member| (member, 1.0)).collect())),
+            Some(_) => Err(WrongType),
+        }
+    }
+
+    /// Up to `count` entries from `cursor` on, filtered by the glob `pattern`.
+    /// Members that exist for the whole scan are returned at least once.
+    /// Returns the cursor to continue from, 0 once every member was visited.
+    pub fn scan(
+        &self,
+        key: &[u8],
+        cursor: usize,
+        pattern: Option<&[u8]>,
+        count: usize,
+        now: SystemTime,
+    ) -> anyhow::Result<(usize, Vec<Entry>)> {
+        let scanned = self.with_set(key, now, |set| {
+            let (cursor, mut entries) = set.scan(cursor, count);
+            entries
+                .retain(|(member, _)| pattern.is_none_or(|pattern| glob::matches(pattern, member)));
+            (cursor, entries)
+        })?;
+        Ok(scanned.unwrap_or_default())
+    }
+}
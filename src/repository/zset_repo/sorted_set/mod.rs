@@ -0,0 +1,302 @@
+use anyhow::{anyhow, bail};
+
+pub use skiplist::SkipList;
+
+use crate::repository::table::Table;
+
+pub mod skiplist;
+
+#[cfg(test)]
+mod tests;
+
+/// A member and its score.
+pub type Entry = (Vec<u8>, f64);
+
+/// One end of a score range, `(1.5` excludes the score itself.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreBound {
+    pub score: f64,
+    pub exclusive: bool,
+}
+
+impl ScoreBound {
+    /// Parses `1.5`, `(1.5`, `-inf` and `+inf`.
+    pub fn parse(bytes: &[u8]) -> anyhow::Result<Self> {
+        let (exclusive, score) = match bytes.strip_prefix(b"(") {
+            Some(score) => (true, score),
+            None => (false, bytes),
+        };
+        let score = std::str::from_utf8(score)
+            .ok()
+            .and_then(|score| score.parse::<f64>().ok())
+            .filter(|score| !score.is_nan())
+            .ok_or_else(|| anyhow!("ERR min or max is not a float"))?;
+        Ok(Self { score, exclusive })
+    }
+
+    /// Whether `score` is below this bound used as a minimum.
+    fn is_below_min(&self, score: f64) -> bool {
+        if self.exclusive {
+            score <= self.score
+        } else {
+            score < self.score
+        }
+    }
+
+    /// Whether `score` is within this bound used as a maximum.
+    fn is_within_max(&self, score: f64) -> bool {
+        if self.exclusive {
+            score < self.score
+        } else {
+            score <= self.score
+        }
+    }
+}
+
+/// One end of a lexicographical range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LexBound {
+    /// `-`, before every member.
+    Min,
+    /// `+`, after every member.
+    Max,
+    /// `[member`
+    Inclusive(Vec<u8>),
+    /// `(member`
+    Exclusive(Vec<u8>),
+}
+
+impl LexBound {
+    pub fn parse(bytes: &[u8]) -> anyhow::Result<Self> {
+        match bytes {
+            b"-" => Ok(Self::Min),
+            b"+" => Ok(Self::Max),
+            [b'[', member @ ..] => Ok(Self::Inclusive(member.to_vec())),
+            [b'(', member @ ..] => Ok(Self::Exclusive(member.to_vec())),
+            _ => bail!("ERR min or max not valid string range item"),
+        }
+    }
+
+    fn is_below_min(&self, member: &[u8]) -> bool {
+        match self {
+            Self::Min => false,
+            Self::Max => true,
+            Self::Inclusive(min) => member < min.as_slice(),
+            Self::Exclusive(min) => member <= min.as_slice(),
+        }
+    }
+
+    fn is_within_max(&self, member: &[u8]) -> bool {
+        match self {
+            Self::Min => false,
+            Self::Max => true,
+            Self::Inclusive(max) => member <= max.as_slice(),
+            Self::Exclusive(max) => member < max.as_slice(),
+        }
+    }
+}
+
+/// Which members a range selects, always given as min and max.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Range {
+    /// Ranks where negative ones count from the end, counted from the end if reversed.
+    Rank(i64, i64),
+    Score(ScoreBound, ScoreBound),
+    /// Only meaningful when all members have the same score.
+    Lex(LexBound, LexBound),
+}
+
+/// `LIMIT offset count`, a negative count takes everything after the offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limit {
+    pub offset: usize,
+    pub count: Option<usize>,
+}
+
+/// A set of members ordered by score, indexed both by member and by rank.
+#[derive(Debug, Clone, Default)]
+pub struct SortedSet {
+    scores: Table<f64>,
+    list: SkipList,
+}
+
+impl PartialEq for SortedSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.0 == b.0 && a.1.to_bits() == b.1.to_bits())
+    }
+}
+
+impl Eq for SortedSet {}
+
+impl SortedSet {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.scores.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.scores.is_empty()
+    }
+
+    #[must_use]
+    pub fn score(&self, member: &[u8]) -> Option<f64> {
+        self.scores.get(member).copied()
+    }
+
+    /// Sets the score of `member`, returns whether it is new.
+    pub fn insert(&mut self, member: Vec<u8>, score: f64) -> bool {
+        // -0 and 0 are the same score
+        let score = if score == 0.0 { 0.0 } else { score };
+        match self.scores.insert(member.clone(), score) {
+            Some(old) if old == score => false,
+            Some(old) => {
+                self.list.remove(old, &member);
+                self.list.insert(score, member);
+                false
+            }
+            None => {
+                self.list.insert(score, member);
+                true
+            }
+        }
+    }
+
+    /// Returns whether `member` was in the set.
+    pub fn remove(&mut self, member: &[u8]) -> bool {
+        let Some(score) = self.scores.remove(member) else {
+            return false;
+        };
+        self.list.remove(score, member);
+        true
+    }
+
+    /// The 0 based rank of `member`, counted from the highest score if `rev`.
+    #[must_use]
+    pub fn rank(&self, member: &[u8], rev: bool) -> Option<usize> {
+        let rank = self.list.rank(self.score(member)?, member)?;
+        Some(if rev { self.len() - 1 - rank } else { rank })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&[u8], f64)> + '_ {
+        self.list.iter()
+    }
+
+    /// Up to `count` entries from `cursor` on, see [`Table::scan`].
+    #[must_use]
+    pub fn scan(&self, cursor: usize, count: usize) -> (usize, Vec<Entry>) {
+        let (cursor, entries) = self.scores.scan(cursor, count);
+        let entries = entries
+            .into_iter()
+            .map(|(member, score)| (member.clone(), *score))
+            .collect();
+        (cursor, entries)
+    }
+
+    /// The ranks `range` selects in ascending order, as a half open range.
+    fn ranks(&self, range: &Range, rev: bool) -> std::ops::Range<usize> {
+        let len = self.len();
+        match range {
+            Range::Rank(start, stop) => {
+                let len = i64::try_from(len).expect("sets are smaller than i64::MAX");
+                let start = if *start < 0 {
+                    (len + start).max(0)
+                } else {
+                    *start
+                };
+                let stop = if *stop < 0 {
+                    len + stop
+                } else {
+                    (*stop).min(len - 1)
+                };
+                if start > stop || start >= len {
+                    return 0..0;
+                }
+                let (start, stop) = if rev {
+                    (len - 1 - stop, len - 1 - start)
+                } else {
+                    (start, stop)
+                };
+                let start = usize::try_from(start).expect("not negative");
+                let stop = usize::try_from(stop).expect("not negative");
+                start..stop + 1
+            }
+            Range::Score(min, max) => {
+                let start = self.list.count_while(|score, _| min.is_below_min(score));
+                let end = self.list.count_while(|score, _| max.is_within_max(score));
+                start..end.max(start)
+            }
+            Range::Lex(min, max) => {
+                let start = self.list.count_while(|_, member| min.is_below_min(member));
+                let end = self.list.count_while(|_, member| max.is_within_max(member));
+                start..end.max(start)
+            }
+        }
+    }
+
+    /// The number of members in `range`.
+    #[must_use]
+    pub fn count(&self, range: &Range) -> usize {
+        self.ranks(range, false).len()
+    }
+
+    /// The members in `range`, from the highest score if `rev`, after applying `limit`.
+    #[must_use]
+    pub fn range(&self, range: &Range, rev: bool, limit: Option<Limit>) -> Vec<Entry> {
+        let ranks = self.ranks(range, rev);
+        let limit = limit.unwrap_or(Limit {
+            offset: 0,
+            count: None,
+        });
+        if ranks.is_empty() || limit.offset >= ranks.len() {
+            return Vec::new();
+        }
+        let start = if rev {
+            ranks.end - 1 - limit.offset
+        } else {
+            ranks.start + limit.offset
+        };
+        let take = (ranks.len() - limit.offset).min(limit.count.unwrap_or(usize::MAX));
+        self.list
+            .iter_from(start, rev)
+            .take(take)
+            .map(|(member, score)| (member.to_vec(), score))
+            .collect()
+    }
+
+    /// Removes and returns up to `count` members with the lowest scores, or the highest if `max`.
+    pub fn pop(&mut self, count: usize, max: bool) -> Vec<Entry> {
+        let range = Range::Rank(0, -1);
+        let popped = self.range(
+            &range,
+            max,
+            Some(Limit {
+                offset: 0,
+                count: Some(count),
+            }),
+        );
+        popped.iter().for_each(|(member, _)| {
+            self.remove(member);
+        });
+        popped
+    }
+}
+
+impl FromIterator<Entry> for SortedSet {
+    fn from_iter<T: IntoIterator<Item = Entry>>(iter: T) -> Self {
+        let mut set = Self::new();
+        iter.into_iter().for_each(|(member, score)| {
+            set.insert(member, score);
+        });
+        set
+    }
+}
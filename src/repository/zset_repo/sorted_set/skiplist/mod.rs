@@ -0,0 +1,284 @@
+use std::cmp::Ordering;
+
+use crate::random;
+
+#[cfg(test)]
+mod tests;
+
+const MAX_LEVEL: usize = 32;
+/// Nodes get each further level with a chance of 1 in 4, like redis' zskiplist.
+const LEVEL_CHANCE: usize = 4;
+const HEAD: usize = 0;
+
+/// Members ordered by score and then by their bytes, like redis' zskiplist.
+/// Every link records how many nodes it skips so ranks are found in O(log n).
+/// Nodes live in one arena and refer to each other by index.
+#[derive(Debug, Clone)]
+pub struct SkipList {
+    nodes: Vec<Node>,
+    free: Vec<usize>,
+    level: usize,
+    len: usize,
+    tail: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    member: Vec<u8>,
+    score: f64,
+    backward: Option<usize>,
+    levels: Vec<Level>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Level {
+    forward: Option<usize>,
+    span: usize,
+}
+
+impl Default for SkipList {
+    fn default() -> Self {
+        Self {
+            nodes: vec![Node {
+                levels: vec![Level::default(); MAX_LEVEL],
+                ..Node::default()
+            }],
+            free: Vec::new(),
+            level: 1,
+            len: 0,
+            tail: None,
+        }
+    }
+}
+
+/// Compares `(score, member)` pairs the way the list is ordered, scores are never NaN.
+#[must_use]
+pub fn compare(a: (f64, &[u8]), b: (f64, &[u8])) -> Ordering {
+    a.0.partial_cmp(&b.0)
+        .expect("scores are never NaN")
+        .then_with(|| a.1.cmp(b.1))
+}
+
+impl SkipList {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn forward(&self, node: usize, level: usize) -> Option<usize> {
+        self.nodes[node].levels[level].forward
+    }
+
+    fn is_before(&self, node: usize, score: f64, member: &[u8]) -> bool {
+        let node = &self.nodes[node];
+        compare((node.score, &node.member), (score, member)).is_lt()
+    }
+
+    /// The last node before `(score, member)` on every level.
+    fn predecessors(&self, score: f64, member: &[u8]) -> ([usize; MAX_LEVEL], [usize; MAX_LEVEL]) {
+        let mut update = [HEAD; MAX_LEVEL];
+        let mut rank = [0; MAX_LEVEL];
+        let mut x = HEAD;
+        for i in (0..self.level).rev() {
+            rank[i] = if i == self.level - 1 { 0 } else { rank[i + 1] };
+            while let Some(next) = self
+                .forward(x, i)
+                .filter(|next| self.is_before(*next, score, member))
+            {
+                rank[i] += self.nodes[x].levels[i].span;
+                x = next;
+            }
+            update[i] = x;
+        }
+        (update, rank)
+    }
+
+    fn random_level() -> usize {
+        random::with_rng(|rng| {
+            let mut level = 1;
+            while level < MAX_LEVEL && rng.below(LEVEL_CHANCE) == 0 {
+                level += 1;
+            }
+            level
+        })
+    }
+
+    /// Inserts a member that is not in the list yet.
+    pub fn insert(&mut self, score: f64, member: Vec<u8>) {
+        let (mut update, mut rank) = self.predecessors(score, &member);
+        let level = Self::random_level();
+        if level > self.level {
+            for i in self.level..level {
+                rank[i] = 0;
+                update[i] = HEAD;
+                self.nodes[HEAD].levels[i].span = self.len;
+            }
+            self.level = level;
+        }
+        let node = Node {
+            member,
+            score,
+            backward: (update[0] != HEAD).then_some(update[0]),
+            levels: vec![Level::default(); level],
+        };
+        let x = match self.free.pop() {
+            Some(x) => {
+                self.nodes[x] = node;
+                x
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+        for i in 0..level {
+            let previous = self.nodes[update[i]].levels[i];
+            self.nodes[x].levels[i] = Level {
+                forward: previous.forward,
+                span: previous.span - (rank[0] - rank[i]),
+            };
+            self.nodes[update[i]].levels[i] = Level {
+                forward: Some(x),
+                span: rank[0] - rank[i] + 1,
+            };
+        }
+        for (i, &node) in update.iter().enumerate().take(self.level).skip(level) {
+            self.nodes[node].levels[i].span += 1;
+        }
+        match self.forward(x, 0) {
+            Some(next) => self.nodes[next].backward = Some(x),
+            None => self.tail = Some(x),
+        }
+        self.len += 1;
+    }
+
+    /// Removes `(score, member)`, returns whether it was in the list.
+    pub fn remove(&mut self, score: f64, member: &[u8]) -> bool {
+        let (update, _) = self.predecessors(score, member);
+        let Some(x) = self.forward(update[0], 0) else {
+            return false;
+        };
+        if self.nodes[x].score != score || self.nodes[x].member != member {
+            return false;
+        }
+        for (i, previous) in update.iter().enumerate().take(self.level) {
+            let removed = self.nodes[x].levels.get(i).copied();
+            let previous = &mut self.nodes[*previous].levels[i];
+            match removed {
+                Some(removed) if previous.forward == Some(x) => {
+                    previous.span += removed.span;
+                    previous.span -= 1;
+                    previous.forward = removed.forward;
+                }
+                _ => previous.span -= 1,
+            }
+        }
+        let backward = self.nodes[x].backward;
+        match self.forward(x, 0) {
+            Some(next) => self.nodes[next].backward = backward,
+            None => self.tail = backward,
+        }
+        while self.level > 1 && self.forward(HEAD, self.level - 1).is_none() {
+            self.level -= 1;
+        }
+        self.nodes[x] = Node::default();
+        self.free.push(x);
+        self.len -= 1;
+        true
+    }
+
+    /// How many members in a row from the start `is_before` holds for,
+    /// it must hold for a prefix of the list and not after it.
+    pub fn count_while<F>(&self, is_before: F) -> usize
+    where
+        F: Fn(f64, &[u8]) -> bool,
+    {
+        let mut x = HEAD;
+        let mut count = 0;
+        for i in (0..self.level).rev() {
+            while let Some(next) = self.forward(x, i).filter(|next| {
+                let node = &self.nodes[*next];
+                is_before(node.score, &node.member)
+            }) {
+                count += self.nodes[x].levels[i].span;
+                x = next;
+            }
+        }
+        count
+    }
+
+    /// The 0 based rank of `(score, member)` if it is in the list.
+    #[must_use]
+    pub fn rank(&self, score: f64, member: &[u8]) -> Option<usize> {
+        let rank = self.count_while(|s, m| compare((s, m), (score, member)).is_lt());
+        self.get(rank)
+            .filter(|(m, s)| *s == score && *m == member)
+            .map(|_| rank)
+    }
+
+    fn node_at(&self, rank: usize) -> Option<usize> {
+        if rank >= self.len {
+            return None;
+        }
+        let target = rank + 1;
+        let (mut x, mut traversed) = (HEAD, 0);
+        for i in (0..self.level).rev() {
+            while let Some(next) = self.forward(x, i) {
+                let span = self.nodes[x].levels[i].span;
+                if traversed + span > target {
+                    break;
+                }
+                traversed += span;
+                x = next;
+            }
+            if traversed == target {
+                return Some(x);
+            }
+        }
+        None
+    }
+
+    /// The member and score at the 0 based `rank`.
+    #[must_use]
+    pub fn get(&self, rank: usize) -> Option<(&[u8], f64)> {
+        self.node_at(rank).map(|x| self.entry(x))
+    }
+
+    fn entry(&self, x: usize) -> (&[u8], f64) {
+        (&self.nodes[x].member, self.nodes[x].score)
+    }
+
+    /// The members from `rank` on, towards the end or towards the start if `rev`.
+    pub fn iter_from(&self, rank: usize, rev: bool) -> impl Iterator<Item = (&[u8], f64)> + '_ {
+        let mut next = self.node_at(rank);
+        std::iter::from_fn(move || {
+            let x = next?;
+            next = if rev {
+                self.nodes[x].backward
+            } else {
+                self.forward(x, 0)
+            };
+            Some(self.entry(x))
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&[u8], f64)> + '_ {
+        self.iter_from(0, false)
+    }
+
+    /// The last member.
+    #[must_use]
+    pub fn last(&self) -> Option<(&[u8], f64)> {
+        self.tail.map(|x| self.entry(x))
+    }
+}
@@ -0,0 +1,74 @@
+use super::*;
+
+fn list(entries: &[(f64, &str)]) -> SkipList {
+    let mut list = SkipList::new();
+    for (score, member) in entries {
+        list.insert(*score, member.as_bytes().to_vec());
+    }
+    list
+}
+
+fn members(list: &SkipList) -> Vec<String> {
+    list.iter()
+        .map(|(m, _)| String::from_utf8(m.to_vec()).unwrap())
+        .collect()
+}
+
+#[test]
+fn orders_by_score_then_member() {
+    let list = list(&[(2.0, "b"), (1.0, "z"), (2.0, "a"), (-1.0, "c")]);
+    assert_eq!(members(&list), ["c", "z", "a", "b"]);
+    assert_eq!(list.len(), 4);
+    assert_eq!(list.last(), Some((b"b".as_slice(), 2.0)));
+}
+
+#[test]
+fn rank_and_get_agree() {
+    let entries: Vec<_> = (0..200)
+        .map(|i| (f64::from(i % 17), i.to_string()))
+        .collect();
+    let mut list = SkipList::new();
+    for (score, member) in &entries {
+        list.insert(*score, member.clone().into_bytes());
+    }
+    for rank in 0..list.len() {
+        let (member, score) = list.get(rank).unwrap();
+        let member = member.to_vec();
+        assert_eq!(list.rank(score, &member), Some(rank));
+    }
+    assert_eq!(list.get(200), None);
+    assert_eq!(list.rank(1.0, b"missing"), None);
+}
+
+#[test]
+fn remove_keeps_ranks_and_links() {
+    let mut list = list(&[(1.0, "a"), (2.0, "b"), (3.0, "c"), (4.0, "d")]);
+    assert!(list.remove(2.0, b"b"));
+    assert!(!list.remove(2.0, b"b"));
+    assert!(!list.remove(3.0, b"a"));
+    assert_eq!(members(&list), ["a", "c", "d"]);
+    assert_eq!(list.rank(4.0, b"d"), Some(2));
+    assert!(list.remove(4.0, b"d"));
+    assert_eq!(list.last(), Some((b"c".as_slice(), 3.0)));
+    let back: Vec<_> = list.iter_from(1, true).map(|(m, _)| m.to_vec()).collect();
+    assert_eq!(back, [b"c".to_vec(), b"a".to_vec()]);
+}
+
+#[test]
+fn freed_nodes_are_reused() {
+    let mut list = list(&[(1.0, "a"), (2.0, "b")]);
+    let nodes = list.nodes.len();
+    list.remove(1.0, b"a");
+    list.insert(0.5, b"c".to_vec());
+    assert_eq!(list.nodes.len(), nodes);
+    assert_eq!(members(&list), ["c", "b"]);
+}
+
+#[test]
+fn count_while_counts_prefix() {
+    let list = list(&[(1.0, "a"), (2.0, "b"), (2.0, "c"), (3.0, "d")]);
+    assert_eq!(list.count_while(|score, _| score < 2.0), 1);
+    assert_eq!(list.count_while(|score, _| score <= 2.0), 3);
+    assert_eq!(list.count_while(|_, _| true), 4);
+    assert_eq!(list.count_while(|_, _| false), 0);
+}
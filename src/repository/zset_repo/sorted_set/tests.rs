@@ -0,0 +1,96 @@
+use super::*;
+
+fn set() -> SortedSet {
+    [("a", 1.0), ("b", 2.0), ("c", 2.0), ("d", 3.0), ("e", 10.0)]
+        .into_iter()
+        .map(|(m, s)| (m.as_bytes().to_vec(), s))
+        .collect()
+}
+
+fn members(entries: &[Entry]) -> Vec<String> {
+    entries
+        .iter()
+        .map(|(m, _)| String::from_utf8(m.clone()).unwrap())
+        .collect()
+}
+
+fn score(s: &str) -> ScoreBound {
+    ScoreBound::parse(s.as_bytes()).unwrap()
+}
+
+fn lex(s: &str) -> LexBound {
+    LexBound::parse(s.as_bytes()).unwrap()
+}
+
+#[test]
+fn insert_updates_score_and_order() {
+    let mut set = set();
+    assert!(!set.insert(b"a".to_vec(), 5.0));
+    assert!(set.insert(b"f".to_vec(), 0.0));
+    assert_eq!(set.score(b"a"), Some(5.0));
+    assert_eq!(set.rank(b"a", false), Some(4));
+    assert_eq!(set.rank(b"a", true), Some(1));
+    assert_eq!(set.rank(b"f", false), Some(0));
+    assert!(set.remove(b"f"));
+    assert_eq!(set.rank(b"f", false), None);
+}
+
+#[test]
+fn range_by_rank() {
+    let set = set();
+    let range = Range::Rank(0, 1);
+    assert_eq!(members(&set.range(&range, false, None)), ["a", "b"]);
+    assert_eq!(members(&set.range(&range, true, None)), ["e", "d"]);
+    let range = Range::Rank(-2, -1);
+    assert_eq!(members(&set.range(&range, false, None)), ["d", "e"]);
+    assert!(set.range(&Range::Rank(3, 1), false, None).is_empty());
+}
+
+#[test]
+fn range_by_score() {
+    let set = set();
+    let range = Range::Score(score("(1"), score("3"));
+    assert_eq!(members(&set.range(&range, false, None)), ["b", "c", "d"]);
+    assert_eq!(members(&set.range(&range, true, None)), ["d", "c", "b"]);
+    let range = Range::Score(score("-inf"), score("+inf"));
+    assert_eq!(set.count(&range), 5);
+    let limit = Limit {
+        offset: 1,
+        count: Some(2),
+    };
+    assert_eq!(members(&set.range(&range, false, Some(limit))), ["b", "c"]);
+    assert_eq!(members(&set.range(&range, true, Some(limit))), ["d", "c"]);
+    assert_eq!(set.count(&Range::Score(score("5"), score("(5"))), 0);
+}
+
+#[test]
+fn range_by_lex() {
+    let set: SortedSet = ["a", "b", "c", "d"]
+        .into_iter()
+        .map(|m| (m.as_bytes().to_vec(), 0.0))
+        .collect();
+    let range = Range::Lex(lex("[b"), lex("(d"));
+    assert_eq!(members(&set.range(&range, false, None)), ["b", "c"]);
+    assert_eq!(set.count(&Range::Lex(lex("-"), lex("+"))), 4);
+    assert_eq!(set.count(&Range::Lex(lex("+"), lex("-"))), 0);
+}
+
+#[test]
+fn bounds_reject_invalid_input() {
+    assert_eq!(
+        ScoreBound::parse(b"abc").unwrap_err().to_string(),
+        "ERR min or max is not a float"
+    );
+    assert_eq!(
+        LexBound::parse(b"b").unwrap_err().to_string(),
+        "ERR min or max not valid string range item"
+    );
+}
+
+#[test]
+fn pop_from_either_end() {
+    let mut set = set();
+    assert_eq!(members(&set.pop(2, false)), ["a", "b"]);
+    assert_eq!(members(&set.pop(1, true)), ["e"]);
+    assert_eq!(set.len(), 2);
+}
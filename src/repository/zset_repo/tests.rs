@@ -0,0 +1,269 @@
+use std::time::{Duration, SystemTime};
+
+use super::{
+    AddOptions, AddResult, Aggregate, LexBound, Limit, Operation, Range, ScoreBound, ZSetRepository,
+};
+use crate::repository::{set_repo::SetRepository, BlockResult, Keyspace};
+
+const NOW: SystemTime = SystemTime::UNIX_EPOCH;
+
+fn entries(entries: &[(&str, f64)]) -> Vec<(Vec<u8>, f64)> {
+    entries
+        .iter()
+        .map(|(member, score)| (member.as_bytes().to_vec(), *score))
+        .collect()
+}
+
+fn repo() -> ZSetRepository {
+    let repo = ZSetRepository::new();
+    repo.add(
+        b"z",
+        entries(&[("a", 1.0), ("b", 2.0), ("c", 3.0), ("d", 4.0)]),
+        AddOptions::default(),
+        NOW,
+    )
+    .unwrap();
+    repo
+}
+
+fn all() -> Range {
+    Range::Rank(0, -1)
+}
+
+#[test]
+fn add_counts_new_and_updated_members() {
+    let repo = repo();
+    let result = repo
+        .add(
+            b"z",
+            entries(&[("a", 1.0), ("b", 5.0), ("e", 0.5)]),
+            AddOptions::default(),
+            NOW,
+        )
+        .unwrap();
+    assert_eq!((result.added, result.updated), (1, 1));
+    assert_eq!(
+        repo.range(b"z", &all(), false, None, NOW).unwrap(),
+        entries(&[("e", 0.5), ("a", 1.0), ("c", 3.0), ("d", 4.0), ("b", 5.0)])
+    );
+}
+
+#[test]
+fn add_respects_conditions() {
+    let repo = repo();
+    let nx = AddOptions {
+        nx: true,
+        ..AddOptions::default()
+    };
+    repo.add(b"z", entries(&[("a", 9.0), ("e", 9.0)]), nx, NOW)
+        .unwrap();
+    assert_eq!(repo.score(b"z", b"a", NOW).unwrap(), Some(1.0));
+    assert_eq!(repo.score(b"z", b"e", NOW).unwrap(), Some(9.0));
+
+    let xx = AddOptions {
+        xx: true,
+        ..AddOptions::default()
+    };
+    repo.add(b"z", entries(&[("a", 9.0), ("f", 9.0)]), xx, NOW)
+        .unwrap();
+    assert_eq!(repo.score(b"z", b"a", NOW).unwrap(), Some(9.0));
+    assert_eq!(repo.score(b"z", b"f", NOW).unwrap(), None);
+
+    let gt = AddOptions {
+        gt: true,
+        ..AddOptions::default()
+    };
+    repo.add(b"z", entries(&[("b", 1.0), ("c", 7.0)]), gt, NOW)
+        .unwrap();
+    assert_eq!(
+        repo.scores(b"z", &[b"b".to_vec(), b"c".to_vec()], NOW)
+            .unwrap(),
+        [Some(2.0), Some(7.0)]
+    );
+}
+
+#[test]
+fn incr_returns_new_score_or_none_when_aborted() {
+    let repo = repo();
+    let incr = AddOptions {
+        incr: true,
+        ..AddOptions::default()
+    };
+    let result = repo.add(b"z", entries(&[("a", 2.5)]), incr, NOW).unwrap();
+    assert_eq!(result.score, Some(3.5));
+    let result = repo
+        .add(
+            b"z",
+            entries(&[("a", -1.0)]),
+            AddOptions { gt: true, ..incr },
+            NOW,
+        )
+        .unwrap();
+    assert_eq!(result, AddResult::default());
+    let err = repo
+        .add(
+            b"inf",
+            entries(&[("a", f64::INFINITY)]),
+            AddOptions::default(),
+            NOW,
+        )
+        .and_then(|_| repo.add(b"inf", entries(&[("a", f64::NEG_INFINITY)]), incr, NOW))
+        .unwrap_err();
+    assert!(err.to_string().contains("NaN"));
+}
+
+#[test]
+fn ranges_by_rank_score_and_lex() {
+    let repo = repo();
+    let range = |range: Range, rev: bool, limit: Option<Limit>| {
+        repo.range(b"z", &range, rev, limit, NOW)
+            .unwrap()
+            .into_iter()
+            .map(|(member, _)| String::from_utf8(member).unwrap())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(range(Range::Rank(1, 2), false, None), ["b", "c"]);
+    assert_eq!(range(Range::Rank(0, 1), true, None), ["d", "c"]);
+    let score = |bound: &str| ScoreBound::parse(bound.as_bytes()).unwrap();
+    assert_eq!(
+        range(Range::Score(score("(1"), score("+inf")), false, None),
+        ["b", "c", "d"]
+    );
+    assert_eq!(
+        range(
+            Range::Score(score("-inf"), score("3")),
+            true,
+            Some(Limit {
+                offset: 1,
+                count: Some(1)
+            })
+        ),
+        ["b"]
+    );
+    let lex = |bound: &str| LexBound::parse(bound.as_bytes()).unwrap();
+    assert_eq!(
+        range(Range::Lex(lex("[b"), lex("(d")), false, None),
+        ["b", "c"]
+    );
+    assert_eq!(
+        repo.count(b"z", &Range::Lex(lex("-"), lex("+")), NOW)
+            .unwrap(),
+        4
+    );
+}
+
+#[test]
+fn rank_counts_from_either_end() {
+    let repo = repo();
+    assert_eq!(repo.rank(b"z", b"b", false, NOW).unwrap(), Some((1, 2.0)));
+    assert_eq!(repo.rank(b"z", b"b", true, NOW).unwrap(), Some((2, 2.0)));
+    assert_eq!(repo.rank(b"z", b"x", false, NOW).unwrap(), None);
+}
+
+#[test]
+fn popping_everything_removes_key() {
+    let keyspace = Keyspace::new();
+    let repo = ZSetRepository::with_keyspace(keyspace.clone());
+    repo.add(
+        b"z",
+        entries(&[("a", 1.0), ("b", 2.0)]),
+        AddOptions::default(),
+        NOW,
+    )
+    .unwrap();
+    assert_eq!(
+        repo.pop(b"z", 1, true, NOW).unwrap(),
+        entries(&[("b", 2.0)])
+    );
+    assert_eq!(
+        repo.pop(b"z", 5, false, NOW).unwrap(),
+        entries(&[("a", 1.0)])
+    );
+    assert!(keyspace.lock().is_empty());
+}
+
+#[test]
+fn combine_store_weights_and_aggregates() {
+    let keyspace = Keyspace::new();
+    let repo = ZSetRepository::with_keyspace(keyspace.clone());
+    repo.add(
+        b"a",
+        entries(&[("x", 1.0), ("y", 2.0)]),
+        AddOptions::default(),
+        NOW,
+    )
+    .unwrap();
+    SetRepository::with_keyspace(keyspace)
+        .add(b"s", vec![b"y".to_vec(), b"z".to_vec()], NOW)
+        .unwrap();
+    let keys = [b"a".to_vec(), b"s".to_vec()];
+
+    let len = repo
+        .combine_store(
+            Operation::Union,
+            b"u",
+            &keys,
+            &[2.0, 3.0],
+            Aggregate::Sum,
+            NOW,
+        )
+        .unwrap();
+    assert_eq!(len, 3);
+    assert_eq!(
+        repo.range(b"u", &all(), false, None, NOW).unwrap(),
+        entries(&[("x", 2.0), ("z", 3.0), ("y", 7.0)])
+    );
+
+    let len = repo
+        .combine_store(
+            Operation::Intersection,
+            b"i",
+            &keys,
+            &[],
+            Aggregate::Max,
+            NOW,
+        )
+        .unwrap();
+    assert_eq!(len, 1);
+    assert_eq!(
+        repo.range(b"i", &all(), false, None, NOW).unwrap(),
+        entries(&[("y", 2.0)])
+    );
+}
+
+#[test]
+fn scan_visits_every_member() {
+    let repo = repo();
+    let mut cursor = 0;
+    let mut members = Vec::new();
+    loop {
+        let (next, entries) = repo.scan(b"z", cursor, None, 3, NOW).unwrap();
+        members.extend(entries);
+        if next == 0 {
+            break;
+        }
+        cursor = next;
+    }
+    assert_eq!(members.len(), 4);
+}
+
+#[test]
+fn blocking_pop_is_served_by_later_add() {
+    let repo = ZSetRepository::new();
+    let waiter = repo.clone();
+    let handle = std::thread::spawn(move || {
+        waiter.pop_blocking(&[b"z".to_vec()], 1, false, Some(Duration::from_secs(5)))
+    });
+    std::thread::sleep(Duration::from_millis(10));
+    repo.add(
+        b"z",
+        entries(&[("a", 1.0)]),
+        AddOptions::default(),
+        SystemTime::now(),
+    )
+    .unwrap();
+    assert_eq!(
+        handle.join().unwrap(),
+        BlockResult::Found((b"z".to_vec(), entries(&[("a", 1.0)])))
+    );
+}
@@ -1,3 +1,5 @@
+pub mod protocol;
 pub mod value;
 
-pub use value::Value;
+pub use protocol::Protocol;
+pub use value::{FromValue, IntoValue, Value, ValueRef};
@@ -0,0 +1,291 @@
+use std::{collections::HashMap, hash::Hash};
+
+use anyhow::{anyhow, bail};
+
+use super::Value;
+
+#[cfg(test)]
+mod tests;
+
+pub const NOT_AN_INTEGER: &str = "ERR value is not an integer or out of range";
+pub const NOT_A_FLOAT: &str = "ERR value is not a valid float";
+
+/// Converts a [`Value`] into a rust type.
+/// Numbers are also parsed from strings since that is how clients send arguments.
+pub trait FromValue: Sized {
+    fn from_value(value: Value) -> anyhow::Result<Self>;
+}
+
+/// Converts a rust type into the [`Value`] it is replied as.
+pub trait IntoValue {
+    fn into_value(self) -> Value;
+}
+
+impl FromValue for Value {
+    fn from_value(value: Value) -> anyhow::Result<Self> {
+        Ok(value)
+    }
+}
+
+impl FromValue for Vec<u8> {
+    fn from_value(value: Value) -> anyhow::Result<Self> {
+        value
+            .into_byte_string()
+            .map_err(|value| anyhow!("expected string, got {value:?}"))
+    }
+}
+
+impl FromValue for String {
+    fn from_value(value: Value) -> anyhow::Result<Self> {
+        match value {
+            Value::BulkByteString(bytes) => String::from_utf8(bytes)
+                .map_err(|_| anyhow!("ERR invalid argument, expected utf-8")),
+            value => value.expect_string(),
+        }
+    }
+}
+
+/// Parses an integer the way redis does, digits with an optional minus sign and no leading
+/// zeros, so "+1", " 1", "007" and "-0" are not integers.
+pub fn parse_integer<T>(bytes: &[u8]) -> Option<T>
+where
+    T: std::str::FromStr,
+{
+    let digits = bytes.strip_prefix(b"-").unwrap_or(bytes);
+    let canonical = match digits {
+        [] => false,
+        // zero has no sign
+        [b'0'] => digits.len() == bytes.len(),
+        [b'0', ..] => false,
+        _ => digits.iter().all(u8::is_ascii_digit),
+    };
+    if !canonical {
+        return None;
+    }
+    std::str::from_utf8(bytes).ok()?.parse().ok()
+}
+
+macro_rules! integer {
+    ($($ty:ty),*) => {$(
+        impl FromValue for $ty {
+            fn from_value(value: Value) -> anyhow::Result<Self> {
+                match value {
+                    Value::Integer(i) => i.try_into().map_err(|_| anyhow!(NOT_AN_INTEGER)),
+                    value => match value.into_byte_string() {
+                        Ok(bytes) => parse_integer(&bytes).ok_or_else(|| anyhow!(NOT_AN_INTEGER)),
+                        Err(_) => bail!(NOT_AN_INTEGER),
+                    },
+                }
+            }
+        }
+
+        impl IntoValue for $ty {
+            fn into_value(self) -> Value {
+                match i64::try_from(self) {
+                    Ok(i) => Value::Integer(i),
+                    Err(_) => Value::BigNumber(self.to_string()),
+                }
+            }
+        }
+    )*};
+}
+
+integer!(i16, i32, i64, u16, u32, u64, usize, isize);
+
+impl FromValue for f64 {
+    fn from_value(value: Value) -> anyhow::Result<Self> {
+        let float = match value {
+            Value::Double(f) => f,
+            #[allow(clippy::cast_precision_loss)]
+            Value::Integer(i) => i as f64,
+            value => value
+                .into_byte_string()
+                .ok()
+                .and_then(|bytes| String::from_utf8(bytes).ok())
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| anyhow!(NOT_A_FLOAT))?,
+        };
+        if float.is_nan() {
+            bail!(NOT_A_FLOAT);
+        }
+        Ok(float)
+    }
+}
+
+impl FromValue for bool {
+    fn from_value(value: Value) -> anyhow::Result<Self> {
+        match value {
+            Value::Boolean(b) => Ok(b),
+            Value::Integer(0) => Ok(false),
+            Value::Integer(1) => Ok(true),
+            value => bail!("expected boolean, got {value:?}"),
+        }
+    }
+}
+
+impl<T> FromValue for Option<T>
+where
+    T: FromValue,
+{
+    fn from_value(value: Value) -> anyhow::Result<Self> {
+        match value {
+            Value::Null | Value::NullString | Value::NullArray => Ok(None),
+            value => T::from_value(value).map(Some),
+        }
+    }
+}
+
+fn into_items(value: Value) -> anyhow::Result<Vec<Value>> {
+    match value {
+        Value::Array(items) | Value::Set(items) | Value::Push(items) => Ok(items),
+        value => bail!("expected array, got {value:?}"),
+    }
+}
+
+impl<T> FromValue for Vec<T>
+where
+    T: FromValue,
+{
+    fn from_value(value: Value) -> anyhow::Result<Self> {
+        into_items(value)?.into_iter().map(T::from_value).collect()
+    }
+}
+
+macro_rules! tuple {
+    ($len:literal; $($name:ident),*) => {
+        impl<$($name),*> FromValue for ($($name,)*)
+        where
+            $($name: FromValue,)*
+        {
+            fn from_value(value: Value) -> anyhow::Result<Self> {
+                let items = into_items(value)?;
+                if items.len() != $len {
+                    bail!("expected {} items, got {}", $len, items.len());
+                }
+                let mut items = items.into_iter();
+                Ok(($($name::from_value(items.next().expect("length checked above"))?,)*))
+            }
+        }
+
+        impl<$($name),*> IntoValue for ($($name,)*)
+        where
+            $($name: IntoValue,)*
+        {
+            #[allow(non_snake_case)]
+            fn into_value(self) -> Value {
+                let ($($name,)*) = self;
+                Value::Array(vec![$($name.into_value()),*])
+            }
+        }
+    };
+}
+
+tuple!(1; A);
+tuple!(2; A, B);
+tuple!(3; A, B, C);
+tuple!(4; A, B, C, D);
+
+/// Accepts both a resp3 map and the flat key value array resp2 uses instead.
+impl<K, V> FromValue for HashMap<K, V>
+where
+    K: FromValue + Eq + Hash,
+    V: FromValue,
+{
+    fn from_value(value: Value) -> anyhow::Result<Self> {
+        let pairs = match value {
+            Value::Map(pairs) => pairs,
+            value => {
+                let items = into_items(value)?;
+                if !items.len().is_multiple_of(2) {
+                    bail!("expected key value pairs, got {} items", items.len());
+                }
+                let mut items = items.into_iter();
+                std::iter::from_fn(|| Some((items.next()?, items.next()?))).collect()
+            }
+        };
+        pairs
+            .into_iter()
+            .map(|(key, value)| Ok((K::from_value(key)?, V::from_value(value)?)))
+            .collect()
+    }
+}
+
+impl IntoValue for Value {
+    fn into_value(self) -> Value {
+        self
+    }
+}
+
+impl IntoValue for () {
+    fn into_value(self) -> Value {
+        Value::ok()
+    }
+}
+
+impl IntoValue for String {
+    fn into_value(self) -> Value {
+        Value::BulkString(self)
+    }
+}
+
+impl IntoValue for &str {
+    fn into_value(self) -> Value {
+        Value::bulk_string(self)
+    }
+}
+
+impl IntoValue for Vec<u8> {
+    fn into_value(self) -> Value {
+        Value::bulk_bytes(self)
+    }
+}
+
+impl IntoValue for &[u8] {
+    fn into_value(self) -> Value {
+        Value::bulk_bytes(self.to_vec())
+    }
+}
+
+impl IntoValue for f64 {
+    fn into_value(self) -> Value {
+        Value::Double(self)
+    }
+}
+
+impl IntoValue for bool {
+    fn into_value(self) -> Value {
+        Value::Boolean(self)
+    }
+}
+
+impl<T> IntoValue for Option<T>
+where
+    T: IntoValue,
+{
+    fn into_value(self) -> Value {
+        self.map_or(Value::Null, IntoValue::into_value)
+    }
+}
+
+impl<T> IntoValue for Vec<T>
+where
+    T: IntoValue,
+{
+    fn into_value(self) -> Value {
+        Value::Array(self.into_iter().map(IntoValue::into_value).collect())
+    }
+}
+
+impl<K, V> IntoValue for HashMap<K, V>
+where
+    K: IntoValue,
+    V: IntoValue,
+{
+    fn into_value(self) -> Value {
+        Value::Map(
+            self.into_iter()
+                .map(|(key, value)| (key.into_value(), value.into_value()))
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use super::*;
+
+#[test]
+fn integers_parse_from_strings_and_integers() {
+    assert_eq!(i64::from_value(Value::bulk_string("-42")).unwrap(), -42);
+    assert_eq!(
+        usize::from_value(Value::BulkByteString(b"7".to_vec())).unwrap(),
+        7
+    );
+    assert_eq!(u32::from_value(Value::Integer(3)).unwrap(), 3);
+}
+
+#[test]
+fn invalid_integers_are_redis_errors() {
+    for value in [
+        Value::bulk_string("abc"),
+        Value::bulk_string("+1"),
+        Value::bulk_string(" 1"),
+        Value::bulk_string("007"),
+        Value::bulk_string("-0"),
+        Value::bulk_string("-1"),
+        Value::Integer(-1),
+        Value::Null,
+    ] {
+        let err = usize::from_value(value).unwrap_err();
+        assert_eq!(err.to_string(), NOT_AN_INTEGER);
+    }
+}
+
+#[test]
+fn floats_parse_from_strings_but_not_nan() {
+    assert_eq!(f64::from_value(Value::bulk_string("1.5")).unwrap(), 1.5);
+    assert_eq!(
+        f64::from_value(Value::bulk_string("-inf")).unwrap(),
+        f64::NEG_INFINITY
+    );
+    assert_eq!(f64::from_value(Value::Integer(2)).unwrap(), 2.0);
+    let err = f64::from_value(Value::bulk_string("nan")).unwrap_err();
+    assert_eq!(err.to_string(), NOT_A_FLOAT);
+}
+
+#[test]
+fn byte_strings_keep_binary_content() {
+    let bytes = vec![0xff, 0x00];
+    assert_eq!(
+        Vec::<u8>::from_value(Value::BulkByteString(bytes.clone())).unwrap(),
+        bytes
+    );
+    assert!(String::from_value(Value::BulkByteString(bytes)).is_err());
+}
+
+#[test]
+fn option_is_none_for_nulls() {
+    assert_eq!(Option::<i64>::from_value(Value::NullString).unwrap(), None);
+    assert_eq!(
+        Option::<i64>::from_value(Value::Integer(1)).unwrap(),
+        Some(1)
+    );
+}
+
+#[test]
+fn collections_convert_item_by_item() {
+    let value = Value::Array(vec![Value::bulk_string("a"), Value::Integer(1)]);
+    assert_eq!(
+        <(String, i64)>::from_value(value.clone()).unwrap(),
+        ("a".to_string(), 1)
+    );
+    assert!(<(String, i64, i64)>::from_value(value).is_err());
+
+    let value = Value::Array(vec![Value::bulk_string("1"), Value::Integer(2)]);
+    assert_eq!(Vec::<u64>::from_value(value).unwrap(), vec![1, 2]);
+}
+
+#[test]
+fn map_converts_from_map_and_flat_array() {
+    let expected = HashMap::from([("a".to_string(), 1), ("b".to_string(), 2)]);
+    let map = Value::Map(vec![
+        (Value::bulk_string("a"), Value::Integer(1)),
+        (Value::bulk_string("b"), Value::Integer(2)),
+    ]);
+    assert_eq!(HashMap::<String, i64>::from_value(map).unwrap(), expected);
+    let flat = Value::Array(vec![
+        Value::bulk_string("a"),
+        Value::Integer(1),
+        Value::bulk_string("b"),
+        Value::Integer(2),
+    ]);
+    assert_eq!(HashMap::<String, i64>::from_value(flat).unwrap(), expected);
+}
+
+#[test]
+fn into_value_round_trips() {
+    let value = (b"key".to_vec(), vec![Some(1_i64), None]).into_value();
+    assert_eq!(
+        value,
+        Value::Array(vec![
+            Value::bulk_string("key"),
+            Value::Array(vec![Value::Integer(1), Value::Null]),
+        ])
+    );
+    let (key, items) = <(Vec<u8>, Vec<Option<i64>>)>::from_value(value).unwrap();
+    assert_eq!(key, b"key");
+    assert_eq!(items, vec![Some(1), None]);
+}
+
+#[test]
+fn large_unsigned_integers_become_big_numbers() {
+    assert_eq!(
+        u64::MAX.into_value(),
+        Value::BigNumber(u64::MAX.to_string())
+    );
+}
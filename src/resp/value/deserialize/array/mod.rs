@@ -1,18 +1,20 @@
-use crate::resp::Value;
+use crate::resp::ValueRef;
 
-use super::info::DeserializeInfo;
+use super::{info::DeserializeInfo, limits::PREALLOCATE_MAX, Limits};
 
 #[cfg(test)]
 mod tests;
 
-pub fn deserialize_array(
-    mut bytes: &[u8],
+pub fn deserialize_array<'a>(
+    mut bytes: &'a [u8],
     items: usize,
-) -> anyhow::Result<DeserializeInfo<Vec<Value>>> {
-    let mut result = Vec::with_capacity(items);
+    limits: &Limits,
+    depth: usize,
+) -> anyhow::Result<DeserializeInfo<Vec<ValueRef<'a>>>> {
+    let mut result = Vec::with_capacity(items.min(PREALLOCATE_MAX));
     let mut length = 0;
     for _ in 0..items {
-        let (value, bytes_consumed) = super::deserialize_value(bytes)?;
+        let (value, bytes_consumed) = super::deserialize_nested_value(bytes, limits, depth)?;
         result.push(value);
         length += bytes_consumed;
         bytes = &bytes[bytes_consumed..];
@@ -0,0 +1,27 @@
+use crate::resp::ValueRef;
+
+use super::{info::DeserializeInfo, map::deserialize_map, Limits};
+
+#[cfg(test)]
+mod tests;
+
+pub struct Attribute<'a> {
+    pub attributes: Vec<(ValueRef<'a>, ValueRef<'a>)>,
+    pub value: ValueRef<'a>,
+}
+
+/// Deserializes the attribute pairs and the reply they are attached to.
+pub fn deserialize_attribute<'a>(
+    bytes: &'a [u8],
+    pairs: usize,
+    limits: &Limits,
+    depth: usize,
+) -> anyhow::Result<DeserializeInfo<Attribute<'a>>> {
+    let (attributes, attributes_consumed) = deserialize_map(bytes, pairs, limits, depth)?.into();
+    let (value, value_consumed) =
+        super::deserialize_nested_value(&bytes[attributes_consumed..], limits, depth)?;
+    Ok(DeserializeInfo::new(
+        Attribute { attributes, value },
+        attributes_consumed + value_consumed,
+    ))
+}
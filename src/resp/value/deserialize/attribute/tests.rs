@@ -0,0 +1,21 @@
+use super::*;
+
+#[test]
+fn deserialize_attribute_includes_following_value() {
+    let bytes = b"+ttl\r\n:3600\r\n$5\r\nhello\r\n";
+    let (Attribute { attributes, value }, bytes_consumed) =
+        deserialize_attribute(bytes, 1, &Limits::default(), 0)
+            .unwrap()
+            .into();
+    assert_eq!(
+        attributes,
+        [(ValueRef::SimpleString("ttl"), ValueRef::Integer(3600))]
+    );
+    assert_eq!(value, crate::resp::Value::bulk_string("hello"));
+    assert_eq!(bytes_consumed, bytes.len());
+}
+
+#[test]
+fn deserialize_attribute_without_value_fails() {
+    assert!(deserialize_attribute(b"+ttl\r\n:3600\r\n", 1, &Limits::default(), 0).is_err());
+}
@@ -0,0 +1,15 @@
+use anyhow::bail;
+
+use super::simple_string::deserialize_simple_string;
+
+#[cfg(test)]
+mod tests;
+
+pub fn deserialize_big_number(bytes: &[u8]) -> anyhow::Result<(&str, usize)> {
+    let (s, bytes_consumed) = deserialize_simple_string(bytes)?;
+    let digits = s.strip_prefix(['-', '+']).unwrap_or(s);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        bail!("invalid big number: {s}");
+    }
+    Ok((s, bytes_consumed))
+}
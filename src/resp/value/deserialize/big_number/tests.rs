@@ -0,0 +1,19 @@
+use super::*;
+
+#[test]
+fn deserialize_big_number_test() {
+    let bytes = b"3492890328409238509324850943850943825024385\r\n";
+    let (n, bytes_consumed) = deserialize_big_number(bytes).unwrap();
+    assert_eq!(n, "3492890328409238509324850943850943825024385");
+    assert_eq!(bytes_consumed, bytes.len());
+
+    let (n, _) = deserialize_big_number(b"-12\r\n").unwrap();
+    assert_eq!(n, "-12");
+}
+
+#[test]
+fn deserialize_invalid_big_number_fails() {
+    for bytes in [b"12a\r\n".to_vec(), b"-\r\n".to_vec(), b"\r\n".to_vec()] {
+        assert!(deserialize_big_number(&bytes).is_err());
+    }
+}
@@ -0,0 +1,16 @@
+use anyhow::bail;
+
+use super::simple_string::deserialize_simple_string;
+
+#[cfg(test)]
+mod tests;
+
+pub fn deserialize_boolean(bytes: &[u8]) -> anyhow::Result<(bool, usize)> {
+    let (s, bytes_consumed) = deserialize_simple_string(bytes)?;
+    let value = match s {
+        "t" => true,
+        "f" => false,
+        _ => bail!("invalid boolean: {s}"),
+    };
+    Ok((value, bytes_consumed))
+}
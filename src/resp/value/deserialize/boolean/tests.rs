@@ -0,0 +1,14 @@
+use super::*;
+
+#[test]
+fn deserialize_boolean_test() {
+    assert_eq!(deserialize_boolean(b"t\r\n").unwrap(), (true, 3));
+    assert_eq!(deserialize_boolean(b"f\r\n").unwrap(), (false, 3));
+}
+
+#[test]
+fn deserialize_invalid_boolean_fails() {
+    for bytes in [b"true\r\n".to_vec(), b"1\r\n".to_vec(), b"\r\n".to_vec()] {
+        assert!(deserialize_boolean(&bytes).is_err());
+    }
+}
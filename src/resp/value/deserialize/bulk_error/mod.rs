@@ -0,0 +1,15 @@
+use super::{bulk_string::deserialize_bulk_string, info::DeserializeInfo};
+
+#[cfg(test)]
+mod tests;
+
+pub fn deserialize_bulk_error(
+    bytes: &[u8],
+    length: usize,
+) -> anyhow::Result<DeserializeInfo<&str>> {
+    let info = deserialize_bulk_string(bytes, length)?;
+    Ok(DeserializeInfo::new(
+        std::str::from_utf8(info.value)?,
+        info.bytes_read,
+    ))
+}
@@ -0,0 +1,9 @@
+use super::*;
+
+#[test]
+fn deserialize_bulk_error_test() {
+    let bytes = b"SYNTAX invalid syntax\r\n";
+    let info = deserialize_bulk_error(bytes, 21).unwrap();
+    assert_eq!(info.value, "SYNTAX invalid syntax");
+    assert_eq!(info.bytes_read, bytes.len());
+}
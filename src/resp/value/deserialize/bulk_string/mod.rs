@@ -1,4 +1,6 @@
-use crate::resp::value::deserialize::util::FindLinefeed;
+use anyhow::bail;
+
+use crate::resp::value::deserialize::{util::FindLinefeed, Incomplete};
 
 #[cfg(test)]
 mod tests;
@@ -6,8 +8,15 @@ mod tests;
 pub fn deserialize_bulk_string(
     bytes: &[u8],
     length: usize,
-) -> anyhow::Result<super::info::DeserializeInfo<Vec<u8>>> {
-    assert!(&bytes[length..].is_at_linefeed().unwrap(),);
-    let s = bytes[..length].to_vec();
-    Ok(super::info::DeserializeInfo::new(s, length + 2))
+) -> anyhow::Result<super::info::DeserializeInfo<&[u8]>> {
+    if bytes.len() < length + 2 {
+        return Err(Incomplete.into());
+    }
+    if !bytes[length..].is_at_linefeed()? {
+        bail!("expected linefeed after bulk string of length {length}");
+    }
+    Ok(super::info::DeserializeInfo::new(
+        &bytes[..length],
+        length + 2,
+    ))
 }
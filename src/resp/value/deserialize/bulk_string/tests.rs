@@ -4,5 +4,5 @@ use super::*;
 fn deserialize_bulk_string_test() {
     let bytes = b"hello\r\n";
     let length = 5;
-    let info = deserialize_bulk_string(bytes, length).unwrap();
+    let _info = deserialize_bulk_string(bytes, length).unwrap();
 }
@@ -0,0 +1,126 @@
+use std::io::Read;
+
+use anyhow::bail;
+
+use super::{try_deserialize_value_ref, util::GetHeader, IsIncomplete, Length, Limits};
+use crate::resp::{Value, ValueRef};
+
+#[cfg(test)]
+mod tests;
+
+const MIN_READ_SIZE: usize = 4096;
+
+/// Buffers bytes read from a stream and decodes complete frames out of them.
+/// Frames that are split over several reads are decoded once the remaining bytes arrive.
+#[derive(Debug, Default)]
+pub struct Decoder {
+    buf: Vec<u8>,
+    position: usize,
+    limits: Limits,
+}
+
+impl Decoder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_limits(limits: Limits) -> Self {
+        Self {
+            limits,
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn limits(&self) -> &Limits {
+        &self.limits
+    }
+
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.limits = limits;
+    }
+
+    /// The bytes buffered but not yet decoded.
+    #[must_use]
+    pub fn pending(&self) -> &[u8] {
+        &self.buf[self.position..]
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.pending().is_empty()
+    }
+
+    pub fn extend(&mut self, bytes: &[u8]) {
+        self.compact();
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Does a single read from `reader` into the buffer and returns the number of bytes read.
+    /// The read size grows with the pending bytes so large frames need fewer reads.
+    pub fn read_from<R>(&mut self, reader: &mut R) -> std::io::Result<usize>
+    where
+        R: Read,
+    {
+        self.compact();
+        let len = self.buf.len();
+        self.buf.resize(len + len.max(MIN_READ_SIZE), 0);
+        let result = reader.read(&mut self.buf[len..]);
+        self.buf
+            .truncate(len + result.as_ref().copied().unwrap_or(0));
+        result
+    }
+
+    /// Decodes the next frame, returns `None` if it has not been fully buffered yet.
+    pub fn decode(&mut self) -> anyhow::Result<Option<(Value, usize)>> {
+        Ok(self
+            .decode_ref()?
+            .map(|(value, bytes_consumed)| (value.into_value(), bytes_consumed)))
+    }
+
+    /// Like [`Decoder::decode`] but the value borrows from the buffer until the next read.
+    pub fn decode_ref(&mut self) -> anyhow::Result<Option<(ValueRef<'_>, usize)>> {
+        let pending = &self.buf[self.position..];
+        let Some((value, bytes_consumed)) = try_deserialize_value_ref(pending, &self.limits)?
+        else {
+            if pending.len() > self.limits.max_query_buffer_len {
+                bail!("query buffer limit reached");
+            }
+            return Ok(None);
+        };
+        self.position += bytes_consumed;
+        Ok(Some((value, bytes_consumed)))
+    }
+
+    /// Decodes a bulk payload that is not followed by a linefeed,
+    /// which is how the rdb file is sent during the replication handshake.
+    pub fn decode_unterminated_bulk(&mut self) -> anyhow::Result<Option<Vec<u8>>> {
+        let bytes = self.pending();
+        match bytes.first() {
+            None => return Ok(None),
+            Some(b'$') => (),
+            Some(byte) => bail!("expected bulk header got: {:?}", *byte as char),
+        }
+        let (length, header_size) = match bytes.get_header() {
+            Ok(header) => header,
+            Err(err) if err.is_incomplete() => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        let length = self.limits.check(Length::Bulk, length)?;
+        let Some(payload) = bytes.get(header_size..header_size + length) else {
+            return Ok(None);
+        };
+        let payload = payload.to_vec();
+        self.position += header_size + length;
+        Ok(Some(payload))
+    }
+
+    fn compact(&mut self) {
+        if self.position > 0 {
+            self.buf.drain(..self.position);
+            self.position = 0;
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use super::*;
+use crate::resp::value::serialize_value;
+
+#[test]
+fn decoder_returns_none_until_frame_is_complete() {
+    let value = Value::Array(vec![
+        Value::bulk_string("SET"),
+        Value::bulk_string("abc"),
+        Value::bulk_string("xyz"),
+    ]);
+    let bytes = serialize_value(&value);
+    let mut decoder = Decoder::new();
+    for byte in &bytes[..bytes.len() - 1] {
+        decoder.extend(std::slice::from_ref(byte));
+        assert_eq!(decoder.decode().unwrap(), None);
+    }
+    decoder.extend(&bytes[bytes.len() - 1..]);
+    assert_eq!(decoder.decode().unwrap(), Some((value, bytes.len())));
+    assert!(decoder.is_empty());
+}
+
+#[test]
+fn decoder_decodes_every_frame_in_buffer() {
+    let mut decoder = Decoder::new();
+    decoder.extend(b"+PING\r\n+PING\r\n+PI");
+    assert_eq!(
+        decoder.decode().unwrap(),
+        Some((Value::simple_string("PING"), 7))
+    );
+    assert_eq!(
+        decoder.decode().unwrap(),
+        Some((Value::simple_string("PING"), 7))
+    );
+    assert_eq!(decoder.decode().unwrap(), None);
+    assert_eq!(decoder.pending(), b"+PI");
+}
+
+#[test]
+fn decoder_returns_err_on_invalid_frame() {
+    let mut decoder = Decoder::new();
+    decoder.extend(b"$3\r\nabcd\r\n");
+    assert!(decoder.decode().is_err());
+}
+
+#[test]
+fn decoder_treats_trailing_cr_as_incomplete() {
+    let mut decoder = Decoder::new();
+    decoder.extend(b"+OK\r");
+    assert_eq!(decoder.decode().unwrap(), None);
+    decoder.extend(b"\n");
+    assert_eq!(
+        decoder.decode().unwrap(),
+        Some((Value::simple_string("OK"), 5))
+    );
+}
+
+#[test]
+fn decoder_reads_large_bulk_string_in_chunks() {
+    let payload = "x".repeat(100_000);
+    let bytes = serialize_value(&Value::bulk_string(&payload));
+    let mut reader = std::io::Cursor::new(bytes);
+    let mut decoder = Decoder::new();
+    let value = loop {
+        if let Some((value, _)) = decoder.decode().unwrap() {
+            break value;
+        }
+        assert_ne!(decoder.read_from(&mut reader).unwrap(), 0);
+    };
+    assert_eq!(value, Value::bulk_string(&payload));
+}
+
+#[test]
+fn decode_unterminated_bulk_waits_for_whole_payload() {
+    let mut decoder = Decoder::new();
+    decoder.extend(b"$5\r\nabc");
+    assert_eq!(decoder.decode_unterminated_bulk().unwrap(), None);
+    decoder.extend(b"de+OK\r\n");
+    assert_eq!(
+        decoder.decode_unterminated_bulk().unwrap(),
+        Some(b"abcde".to_vec())
+    );
+    assert_eq!(
+        decoder.decode().unwrap(),
+        Some((Value::simple_string("OK"), 5))
+    );
+}
+
+#[test]
+fn decoder_errors_when_incomplete_frame_exceeds_query_buffer_limit() {
+    let mut decoder = Decoder::with_limits(Limits {
+        max_query_buffer_len: 8,
+        ..Limits::default()
+    });
+    decoder.extend(b"$16\r\nabc");
+    assert_eq!(decoder.decode().unwrap(), None);
+    decoder.extend(b"defgh");
+    let err = decoder.decode().unwrap_err();
+    assert_eq!(err.to_string(), "query buffer limit reached");
+}
+
+#[test]
+fn decode_unterminated_bulk_rejects_oversized_length() {
+    let mut decoder = Decoder::with_limits(Limits {
+        max_bulk_len: 4,
+        ..Limits::default()
+    });
+    decoder.extend(b"$5\r\nabcde");
+    assert!(decoder.decode_unterminated_bulk().is_err());
+}
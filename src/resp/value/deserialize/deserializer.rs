@@ -1,19 +1,25 @@
 use info::DeserializeInfo;
+use limits::Length;
 
 use super::*;
 
 pub struct Deserializer<'a> {
     pub bytes: &'a [u8],
     pub offset: usize,
+    pub limits: Limits,
 }
 
 impl<'a> Deserializer<'a> {
-    pub fn new(bytes: &'a [u8], offset: usize) -> Self {
-        Self { bytes, offset }
+    pub fn new(bytes: &'a [u8], offset: usize, limits: Limits) -> Self {
+        Self {
+            bytes,
+            offset,
+            limits,
+        }
     }
     pub fn advance<F, T>(&mut self, f: F) -> anyhow::Result<DeserializeInfo<T>>
     where
-        F: Fn(&[u8]) -> anyhow::Result<DeserializeInfo<T>>,
+        F: Fn(&'a [u8]) -> anyhow::Result<DeserializeInfo<T>>,
     {
         let info = f(self.bytes)?;
         self.offset += info.bytes_read;
@@ -21,7 +27,7 @@ impl<'a> Deserializer<'a> {
     }
     pub fn deserialize<F, T, R>(self, f: F) -> anyhow::Result<DeserializeInfo<T>>
     where
-        F: Fn(&[u8]) -> anyhow::Result<R>,
+        F: Fn(&'a [u8]) -> anyhow::Result<R>,
         R: Into<DeserializeInfo<T>>,
     {
         let mut info: DeserializeInfo<T> = f(&self.bytes[self.offset..])?.into();
@@ -29,22 +35,23 @@ impl<'a> Deserializer<'a> {
         Ok(info)
     }
 
-    pub fn deserialize_header<F, T, R>(self, null: T, f: F) -> anyhow::Result<DeserializeInfo<T>>
+    /// Reads the length header and checks it against the limit for `length`
+    /// before handing the payload to `f`.
+    pub(super) fn deserialize_header<F, T, R>(
+        self,
+        null: T,
+        length: Length,
+        f: F,
+    ) -> anyhow::Result<DeserializeInfo<T>>
     where
-        F: Fn(&[u8], usize) -> anyhow::Result<R>,
+        F: Fn(&'a [u8], usize) -> anyhow::Result<R>,
         R: Into<DeserializeInfo<T>>,
     {
-        dbg!(&self.bytes[self.offset..]);
-        let (payload_size, header_size) = self.bytes[self.offset..].get_header().unwrap();
-        dbg!(payload_size);
+        let (payload_size, header_size) = self.bytes[self.offset..].get_header()?;
         if payload_size == -1 {
             return Ok(DeserializeInfo::new(null, self.offset + header_size));
         }
-        if payload_size < 0 {
-            bail!("negative payload size: {payload_size}");
-        }
-        assert!(payload_size > 0, "negative payload size: {payload_size}");
-        let payload_size = payload_size.try_into().unwrap();
+        let payload_size = self.limits.check(length, payload_size)?;
         let info = f(&self.bytes[self.offset + header_size..], payload_size)?.into();
         Ok(DeserializeInfo::new(
             info.value,
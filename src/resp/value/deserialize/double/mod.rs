@@ -0,0 +1,9 @@
+use super::simple_string::deserialize_simple_string;
+
+#[cfg(test)]
+mod tests;
+
+pub fn deserialize_double(bytes: &[u8]) -> anyhow::Result<(f64, usize)> {
+    let (s, bytes_consumed) = deserialize_simple_string(bytes)?;
+    Ok((s.parse()?, bytes_consumed))
+}
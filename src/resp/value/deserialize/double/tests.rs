@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn deserialize_double_test() {
+    let inputs = [
+        (b"1.23\r\n".to_vec(), 1.23),
+        (b"-0.5\r\n".to_vec(), -0.5),
+        (b"10\r\n".to_vec(), 10.0),
+        (b"1.5e3\r\n".to_vec(), 1500.0),
+        (b"inf\r\n".to_vec(), f64::INFINITY),
+        (b"-inf\r\n".to_vec(), f64::NEG_INFINITY),
+    ];
+    for (bytes, expected) in inputs {
+        let (n, bytes_consumed) = deserialize_double(&bytes).unwrap();
+        assert!((n - expected).abs() < f64::EPSILON || n == expected);
+        assert_eq!(bytes_consumed, bytes.len());
+    }
+}
+
+#[test]
+fn deserialize_nan_double() {
+    let (n, _) = deserialize_double(b"nan\r\n").unwrap();
+    assert!(n.is_nan());
+}
+
+#[test]
+fn deserialize_invalid_double_fails() {
+    assert!(deserialize_double(b"1.2.3\r\n").is_err());
+}
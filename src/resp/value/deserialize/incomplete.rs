@@ -0,0 +1,15 @@
+/// Returned by the deserializers when the bytes end before the frame does.
+/// The frame might still be valid once more bytes are read.
+#[derive(Debug, thiserror::Error)]
+#[error("incomplete frame, more bytes needed")]
+pub struct Incomplete;
+
+pub trait IsIncomplete {
+    fn is_incomplete(&self) -> bool;
+}
+
+impl IsIncomplete for anyhow::Error {
+    fn is_incomplete(&self) -> bool {
+        self.is::<Incomplete>()
+    }
+}
@@ -0,0 +1,98 @@
+use anyhow::bail;
+
+use super::Incomplete;
+
+#[cfg(test)]
+mod tests;
+
+/// Deserializes an inline command, a single line of space separated arguments
+/// like `SET key "some value"\r\n`. The `\r` before the newline is optional.
+/// Lines longer than `max_len` are rejected.
+pub fn deserialize_inline(bytes: &[u8], max_len: usize) -> anyhow::Result<(Vec<Vec<u8>>, usize)> {
+    let newline = match bytes.iter().position(|byte| *byte == b'\n') {
+        Some(newline) if newline <= max_len => newline,
+        None if bytes.len() <= max_len => return Err(Incomplete.into()),
+        _ => bail!("too big inline request"),
+    };
+    let line = bytes[..newline]
+        .strip_suffix(b"\r")
+        .unwrap_or(&bytes[..newline]);
+    Ok((split_args(line)?, newline + 1))
+}
+
+/// Splits a line into arguments the same way redis does for inline commands.
+/// Arguments can be quoted with `"` which supports escapes like `\n` and `\x3f`,
+/// or with `'` which only supports `\'`.
+pub fn split_args(line: &[u8]) -> anyhow::Result<Vec<Vec<u8>>> {
+    let mut args = Vec::new();
+    let mut i = 0;
+    loop {
+        while line.get(i).is_some_and(u8::is_ascii_whitespace) {
+            i += 1;
+        }
+        if i == line.len() {
+            return Ok(args);
+        }
+        let mut arg = Vec::new();
+        let mut quote = None;
+        loop {
+            let Some(&byte) = line.get(i) else {
+                if quote.is_some() {
+                    bail!("unbalanced quotes in request");
+                }
+                break;
+            };
+            match quote {
+                Some(b'"') if byte == b'\\' && line.get(i + 1) == Some(&b'x') => {
+                    if let Some(hex) = line.get(i + 2..i + 4).and_then(parse_hex) {
+                        arg.push(hex);
+                        i += 3;
+                    } else {
+                        arg.push(b'x');
+                        i += 1;
+                    }
+                }
+                Some(b'"') if byte == b'\\' && i + 1 < line.len() => {
+                    i += 1;
+                    arg.push(match line[i] {
+                        b'n' => b'\n',
+                        b'r' => b'\r',
+                        b't' => b'\t',
+                        b'b' => 0x08,
+                        b'a' => 0x07,
+                        other => other,
+                    });
+                }
+                Some(b'\'') if byte == b'\\' && line.get(i + 1) == Some(&b'\'') => {
+                    arg.push(b'\'');
+                    i += 1;
+                }
+                Some(closing) if byte == closing => {
+                    // the closing quote must be followed by a space or the end of the line
+                    if line
+                        .get(i + 1)
+                        .is_some_and(|next| !next.is_ascii_whitespace())
+                    {
+                        bail!("unbalanced quotes in request");
+                    }
+                    i += 1;
+                    break;
+                }
+                Some(_) => arg.push(byte),
+                None if byte.is_ascii_whitespace() => break,
+                None if byte == b'"' || byte == b'\'' => quote = Some(byte),
+                None => arg.push(byte),
+            }
+            i += 1;
+        }
+        args.push(arg);
+    }
+}
+
+fn parse_hex(digits: &[u8]) -> Option<u8> {
+    let digits = std::str::from_utf8(digits).ok()?;
+    if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    u8::from_str_radix(digits, 16).ok()
+}
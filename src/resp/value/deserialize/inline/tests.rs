@@ -0,0 +1,69 @@
+use super::*;
+use crate::resp::value::deserialize::Limits;
+
+fn args(args: &[&str]) -> Vec<Vec<u8>> {
+    args.iter().map(|arg| arg.as_bytes().to_vec()).collect()
+}
+
+#[test]
+fn deserialize_inline_ping() {
+    assert_eq!(
+        deserialize_inline(b"PING\r\n", Limits::DEFAULT_MAX_INLINE_LEN).unwrap(),
+        (args(&["PING"]), 6)
+    );
+}
+
+#[test]
+fn deserialize_inline_accepts_newline_without_cr() {
+    assert_eq!(
+        deserialize_inline(b"SET a b\nGET a\n", Limits::DEFAULT_MAX_INLINE_LEN).unwrap(),
+        (args(&["SET", "a", "b"]), 8)
+    );
+}
+
+#[test]
+fn deserialize_inline_without_newline_is_incomplete() {
+    let err = deserialize_inline(b"SET a b", Limits::DEFAULT_MAX_INLINE_LEN).unwrap_err();
+    assert!(err.is::<Incomplete>());
+}
+
+#[test]
+fn split_args_splits_on_any_whitespace() {
+    assert_eq!(
+        split_args(b"  SET\tkey   value  ").unwrap(),
+        args(&["SET", "key", "value"])
+    );
+    assert_eq!(split_args(b"   ").unwrap(), Vec::<Vec<u8>>::new());
+}
+
+#[test]
+fn split_args_handles_double_quotes_and_escapes() {
+    assert_eq!(
+        split_args(br#"SET "my key" "a\tb\n\x41\x4g\"""#).unwrap(),
+        args(&["SET", "my key", "a\tb\nAx4g\""])
+    );
+    assert_eq!(split_args(br#""""#).unwrap(), args(&[""]));
+}
+
+#[test]
+fn split_args_handles_single_quotes() {
+    assert_eq!(
+        split_args(br"SET 'it\'s' 'a\nb'").unwrap(),
+        args(&["SET", "it's", "a\\nb"])
+    );
+}
+
+#[test]
+fn split_args_decodes_non_utf8_bytes() {
+    assert_eq!(
+        split_args(br#""\xff\x00""#).unwrap(),
+        vec![vec![0xff, 0x00]]
+    );
+}
+
+#[test]
+fn split_args_returns_err_on_unbalanced_quotes() {
+    assert!(split_args(br#"SET "abc"#).is_err());
+    assert!(split_args(br"SET 'abc").is_err());
+    assert!(split_args(br#"SET "abc"def"#).is_err());
+}
@@ -0,0 +1,9 @@
+use super::simple_string::deserialize_simple_string;
+
+#[cfg(test)]
+mod tests;
+
+pub fn deserialize_integer(bytes: &[u8]) -> anyhow::Result<(i64, usize)> {
+    let (digits, bytes_consumed) = deserialize_simple_string(bytes)?;
+    Ok((digits.parse()?, bytes_consumed))
+}
@@ -0,0 +1,22 @@
+use super::*;
+
+#[test]
+fn deserialize_integer_test() {
+    let inputs = [
+        (b"0\r\n".to_vec(), 0),
+        (b"42\r\n".to_vec(), 42),
+        (b"+7\r\n".to_vec(), 7),
+        (b"-1234\r\n".to_vec(), -1234),
+    ];
+    for (bytes, expected) in inputs {
+        let (n, bytes_consumed) = deserialize_integer(&bytes).unwrap();
+        assert_eq!(n, expected);
+        assert_eq!(bytes_consumed, bytes.len());
+    }
+}
+
+#[test]
+fn deserialize_integer_with_invalid_digits_fails() {
+    assert!(deserialize_integer(b"12a\r\n").is_err());
+    assert!(deserialize_integer(b"\r\n").is_err());
+}
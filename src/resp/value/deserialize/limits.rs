@@ -0,0 +1,72 @@
+/// Upper bounds for the lengths a peer can declare. They are checked before
+/// anything is allocated so a single frame can not exhaust the server's memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Same as redis' `proto-max-bulk-len`.
+    pub max_bulk_len: usize,
+    /// The number of items an array, map, set or push can declare.
+    pub max_multibulk_len: usize,
+    /// The longest line accepted for inline commands.
+    pub max_inline_len: usize,
+    /// The most bytes buffered while waiting for the rest of a frame.
+    pub max_query_buffer_len: usize,
+    /// How deep arrays, maps, sets, pushes and attributes can be nested in each other.
+    pub max_nesting_depth: usize,
+}
+
+impl Limits {
+    pub const DEFAULT_MAX_BULK_LEN: usize = 512 * 1024 * 1024;
+    pub const DEFAULT_MAX_MULTIBULK_LEN: usize = 1024 * 1024;
+    pub const DEFAULT_MAX_INLINE_LEN: usize = 64 * 1024;
+    pub const DEFAULT_MAX_QUERY_BUFFER_LEN: usize = 1024 * 1024 * 1024;
+    pub const DEFAULT_MAX_NESTING_DEPTH: usize = 128;
+
+    pub(super) fn check(&self, length: Length, declared: isize) -> anyhow::Result<usize> {
+        let max = match length {
+            Length::Bulk => self.max_bulk_len,
+            Length::Multibulk => self.max_multibulk_len,
+        };
+        match usize::try_from(declared) {
+            Ok(declared) if declared <= max => Ok(declared),
+            _ => anyhow::bail!("invalid {length} length"),
+        }
+    }
+
+    pub(super) fn check_depth(&self, depth: usize) -> anyhow::Result<()> {
+        if depth > self.max_nesting_depth {
+            anyhow::bail!("invalid nesting depth");
+        }
+        Ok(())
+    }
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_bulk_len: Self::DEFAULT_MAX_BULK_LEN,
+            max_multibulk_len: Self::DEFAULT_MAX_MULTIBULK_LEN,
+            max_inline_len: Self::DEFAULT_MAX_INLINE_LEN,
+            max_query_buffer_len: Self::DEFAULT_MAX_QUERY_BUFFER_LEN,
+            max_nesting_depth: Self::DEFAULT_MAX_NESTING_DEPTH,
+        }
+    }
+}
+
+/// Aggregates never preallocate room for more items than this,
+/// the rest is only allocated once the items actually arrive.
+pub(super) const PREALLOCATE_MAX: usize = 1024;
+
+#[derive(Debug, Clone, Copy)]
+pub(super) enum Length {
+    Bulk,
+    Multibulk,
+}
+
+impl std::fmt::Display for Length {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bulk => f.write_str("bulk"),
+            Self::Multibulk => f.write_str("multibulk"),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::resp::ValueRef;
+
+use super::{info::DeserializeInfo, limits::PREALLOCATE_MAX, Limits};
+
+#[cfg(test)]
+mod tests;
+
+pub fn deserialize_map<'a>(
+    mut bytes: &'a [u8],
+    pairs: usize,
+    limits: &Limits,
+    depth: usize,
+) -> anyhow::Result<DeserializeInfo<Vec<(ValueRef<'a>, ValueRef<'a>)>>> {
+    let mut result = Vec::with_capacity(pairs.min(PREALLOCATE_MAX));
+    let mut length = 0;
+    for _ in 0..pairs {
+        let (key, key_consumed) = super::deserialize_nested_value(bytes, limits, depth)?;
+        bytes = &bytes[key_consumed..];
+        let (value, value_consumed) = super::deserialize_nested_value(bytes, limits, depth)?;
+        bytes = &bytes[value_consumed..];
+        result.push((key, value));
+        length += key_consumed + value_consumed;
+    }
+    Ok(DeserializeInfo::new(result, length))
+}
@@ -0,0 +1,31 @@
+use super::*;
+
+#[test]
+fn deserialize_map_test() {
+    let bytes = b"+first\r\n:1\r\n+second\r\n:2\r\n";
+    let (map, bytes_consumed) = deserialize_map(bytes, 2, &Limits::default(), 0)
+        .unwrap()
+        .into();
+    assert_eq!(
+        map,
+        [
+            (ValueRef::SimpleString("first"), ValueRef::Integer(1)),
+            (ValueRef::SimpleString("second"), ValueRef::Integer(2)),
+        ]
+    );
+    assert_eq!(bytes_consumed, bytes.len());
+}
+
+#[test]
+fn deserialize_empty_map_consumes_no_bytes() {
+    let (map, bytes_consumed) = deserialize_map(b"+ignored\r\n", 0, &Limits::default(), 0)
+        .unwrap()
+        .into();
+    assert!(map.is_empty());
+    assert_eq!(bytes_consumed, 0);
+}
+
+#[test]
+fn deserialize_map_missing_value_fails() {
+    assert!(deserialize_map(b"+key\r\n", 1, &Limits::default(), 0).is_err());
+}
@@ -1,32 +1,110 @@
-use crate::resp::Value;
-use anyhow::bail;
+use std::borrow::Cow;
+
+use crate::resp::{Value, ValueRef};
 use array::deserialize_array;
+use attribute::deserialize_attribute;
+use big_number::deserialize_big_number;
+use boolean::deserialize_boolean;
+use bulk_error::deserialize_bulk_error;
 use bulk_string::deserialize_bulk_string;
+use double::deserialize_double;
 use info::MapValue;
+use inline::deserialize_inline;
+use integer::deserialize_integer;
+use limits::Length;
+use map::deserialize_map;
+use null::deserialize_null;
+use simple_error::deserialize_simple_error;
 use simple_string::deserialize_simple_string;
 use util::GetHeader;
+use verbatim_string::deserialize_verbatim_string;
 
+pub use decoder::Decoder;
 pub use deserializer::Deserializer;
+pub use incomplete::{Incomplete, IsIncomplete};
+pub use limits::Limits;
 
 pub mod array;
+pub mod attribute;
+pub mod big_number;
+pub mod boolean;
+pub mod bulk_error;
 pub mod bulk_string;
+pub mod decoder;
 mod deserializer;
+pub mod double;
+mod incomplete;
 mod info;
+pub mod inline;
 pub mod integer;
+mod limits;
+pub mod map;
+pub mod null;
 pub mod simple_error;
 pub mod simple_string;
 pub mod util;
+pub mod verbatim_string;
 
-use super::{
-    identifier::{GetIdentifier, Identifier},
-    IntoRespArray,
-};
+use super::identifier::{GetIdentifier, Identifier};
 
 #[cfg(test)]
 mod tests;
 
+/// Like [`deserialize_value`] but returns `None` if `bytes` ends before the frame does.
+pub fn try_deserialize_value(bytes: &[u8]) -> anyhow::Result<Option<(Value, usize)>> {
+    Ok(try_deserialize_value_ref(bytes, &Limits::default())?
+        .map(|(value, bytes_consumed)| (value.into_value(), bytes_consumed)))
+}
+
+/// Like [`deserialize_value_ref`] but returns `None` if `bytes` ends before the frame does.
+pub fn try_deserialize_value_ref<'a>(
+    bytes: &'a [u8],
+    limits: &Limits,
+) -> anyhow::Result<Option<(ValueRef<'a>, usize)>> {
+    match deserialize_value_ref_with_limits(bytes, limits) {
+        Ok(value) => Ok(Some(value)),
+        Err(err) if err.is_incomplete() => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Deserializes a value, or an inline command if `bytes` does not start with an identifier.
 pub fn deserialize_value(bytes: &[u8]) -> anyhow::Result<(Value, usize)> {
-    let mut deserializer = Deserializer::new(bytes, 0);
+    let (value, bytes_consumed) = deserialize_value_ref(bytes)?;
+    Ok((value.into_value(), bytes_consumed))
+}
+
+/// Like [`deserialize_value`] but borrows the strings from `bytes` instead of copying them.
+pub fn deserialize_value_ref(bytes: &[u8]) -> anyhow::Result<(ValueRef<'_>, usize)> {
+    deserialize_value_ref_with_limits(bytes, &Limits::default())
+}
+
+/// Like [`deserialize_value_ref`] but rejects frames declaring lengths above `limits`.
+pub fn deserialize_value_ref_with_limits<'a>(
+    bytes: &'a [u8],
+    limits: &Limits,
+) -> anyhow::Result<(ValueRef<'a>, usize)> {
+    match bytes.first() {
+        None => Err(Incomplete.into()),
+        Some(byte) if Identifier::from_byte(*byte).is_err() => {
+            deserialize_inline_value(bytes, limits)
+        }
+        Some(_) => deserialize_nested_value(bytes, limits, 0),
+    }
+}
+
+/// Deserializes a value inside an aggregate, where inline commands are not allowed.
+/// `depth` is the number of aggregates the value is nested in.
+fn deserialize_nested_value<'a>(
+    bytes: &'a [u8],
+    limits: &Limits,
+    depth: usize,
+) -> anyhow::Result<(ValueRef<'a>, usize)> {
+    if bytes.is_empty() {
+        return Err(Incomplete.into());
+    }
+    limits.check_depth(depth)?;
+    let mut deserializer = Deserializer::new(bytes, 0, *limits);
     let ident = deserializer
         .advance(|bytes| {
             let ident = bytes.get_identifier()?;
@@ -38,33 +116,111 @@ pub fn deserialize_value(bytes: &[u8]) -> anyhow::Result<(Value, usize)> {
     let value = match ident {
         Identifier::SimpleString => deserializer
             .deserialize(deserialize_simple_string)?
-            .map_value(Value::SimpleString),
-        Identifier::SimpleError => todo!(),
-        Identifier::Integer => todo!(),
-        Identifier::BulkString => {
-            deserializer.deserialize_header(Value::NullString, |bytes, length| {
-                deserialize_bulk_string(bytes, length).map_value(|bytes| {
-                    match String::from_utf8(bytes) {
-                        Ok(s) => Value::BulkString(s),
-                        Err(err) => Value::BulkByteString(err.into_bytes()),
+            .map_value(ValueRef::SimpleString),
+        Identifier::SimpleError => deserializer
+            .deserialize(deserialize_simple_error)?
+            .map_value(ValueRef::SimpleError),
+        Identifier::Integer => deserializer
+            .deserialize(deserialize_integer)?
+            .map_value(ValueRef::Integer),
+        Identifier::BulkString => deserializer.deserialize_header(
+            ValueRef::NullString,
+            Length::Bulk,
+            |bytes, length| {
+                deserialize_bulk_string(bytes, length)
+                    .map_value(|bytes| ValueRef::BulkString(Cow::Borrowed(bytes)))
+            },
+        )?,
+        Identifier::Array => deserializer.deserialize_header(
+            ValueRef::NullArray,
+            Length::Multibulk,
+            |bytes, length| {
+                deserialize_array(bytes, length, limits, depth + 1).map_value(ValueRef::Array)
+            },
+        )?,
+        Identifier::Null => deserializer
+            .deserialize(deserialize_null)?
+            .map_value(|()| ValueRef::Null),
+        Identifier::Boolean => deserializer
+            .deserialize(deserialize_boolean)?
+            .map_value(ValueRef::Boolean),
+        Identifier::Double => deserializer
+            .deserialize(deserialize_double)?
+            .map_value(ValueRef::Double),
+        Identifier::BigNumber => deserializer
+            .deserialize(deserialize_big_number)?
+            .map_value(ValueRef::BigNumber),
+        Identifier::BulkError => {
+            deserializer.deserialize_header(ValueRef::Null, Length::Bulk, |bytes, length| {
+                deserialize_bulk_error(bytes, length).map_value(ValueRef::BulkError)
+            })?
+        }
+        Identifier::VerbatimString => {
+            deserializer.deserialize_header(ValueRef::Null, Length::Bulk, |bytes, length| {
+                deserialize_verbatim_string(bytes, length).map_value(|verbatim| {
+                    ValueRef::VerbatimString {
+                        encoding: verbatim.encoding,
+                        text: verbatim.text,
                     }
                 })
             })?
         }
-        Identifier::Array => deserializer
-            .deserialize_header(Value::NullArray, |bytes, length| {
-                deserialize_array(bytes, length).map_value(|value| value.into_array())
-            })?,
-        Identifier::Null => todo!(),
-        Identifier::Boolean => todo!(),
-        Identifier::Double => todo!(),
-        Identifier::BigNumber => todo!(),
-        Identifier::BulkError => todo!(),
-        Identifier::VerbatimString => todo!(),
-        Identifier::Map => todo!(),
-        Identifier::Attribute => todo!(),
-        Identifier::Set => todo!(),
-        Identifier::Pushe => todo!(),
+        Identifier::Map => deserializer.deserialize_header(
+            ValueRef::Null,
+            Length::Multibulk,
+            |bytes, length| {
+                deserialize_map(bytes, length, limits, depth + 1).map_value(ValueRef::Map)
+            },
+        )?,
+        Identifier::Attribute => deserializer.deserialize_header(
+            ValueRef::Null,
+            Length::Multibulk,
+            |bytes, length| {
+                deserialize_attribute(bytes, length, limits, depth + 1).map_value(|attribute| {
+                    ValueRef::Attribute {
+                        attributes: attribute.attributes,
+                        value: Box::new(attribute.value),
+                    }
+                })
+            },
+        )?,
+        Identifier::Set => deserializer.deserialize_header(
+            ValueRef::Null,
+            Length::Multibulk,
+            |bytes, length| {
+                deserialize_array(bytes, length, limits, depth + 1).map_value(ValueRef::Set)
+            },
+        )?,
+        Identifier::Pushe => deserializer.deserialize_header(
+            ValueRef::Null,
+            Length::Multibulk,
+            |bytes, length| {
+                deserialize_array(bytes, length, limits, depth + 1).map_value(ValueRef::Push)
+            },
+        )?,
     };
     Ok(value.into())
 }
+
+/// Deserializes an inline command into an array of bulk strings. Empty lines are skipped.
+fn deserialize_inline_value<'a>(
+    bytes: &'a [u8],
+    limits: &Limits,
+) -> anyhow::Result<(ValueRef<'a>, usize)> {
+    let mut bytes_consumed = 0;
+    loop {
+        let (args, line_length) =
+            deserialize_inline(&bytes[bytes_consumed..], limits.max_inline_len)?;
+        bytes_consumed += line_length;
+        if !args.is_empty() {
+            let value = args
+                .into_iter()
+                .map(|arg| ValueRef::BulkString(Cow::Owned(arg)))
+                .collect();
+            return Ok((ValueRef::Array(value), bytes_consumed));
+        }
+        if bytes_consumed == bytes.len() {
+            return Err(Incomplete.into());
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use anyhow::bail;
+
+use super::{util::FindLinefeed, Incomplete};
+
+#[cfg(test)]
+mod tests;
+
+pub fn deserialize_null(bytes: &[u8]) -> anyhow::Result<((), usize)> {
+    if bytes.len() < 2 {
+        return Err(Incomplete.into());
+    }
+    if !bytes.is_at_linefeed()? {
+        bail!("expected linefeed after null identifier");
+    }
+    Ok(((), 2))
+}
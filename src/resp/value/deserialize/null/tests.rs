@@ -0,0 +1,12 @@
+use super::*;
+
+#[test]
+fn deserialize_null_consumes_linefeed() {
+    let ((), bytes_consumed) = deserialize_null(b"\r\n").unwrap();
+    assert_eq!(bytes_consumed, 2);
+}
+
+#[test]
+fn deserialize_null_with_payload_fails() {
+    assert!(deserialize_null(b"abc\r\n").is_err());
+}
@@ -0,0 +1,8 @@
+use super::simple_string::deserialize_simple_string;
+
+#[cfg(test)]
+mod tests;
+
+pub fn deserialize_simple_error(bytes: &[u8]) -> anyhow::Result<(&str, usize)> {
+    deserialize_simple_string(bytes)
+}
@@ -0,0 +1,14 @@
+use super::*;
+
+#[test]
+fn deserialize_simple_error_test() {
+    let bytes = b"ERR unknown command\r\n";
+    let (err, bytes_consumed) = deserialize_simple_error(bytes).unwrap();
+    assert_eq!(err, "ERR unknown command");
+    assert_eq!(bytes_consumed, bytes.len());
+}
+
+#[test]
+fn deserialize_simple_error_without_linefeed_fails() {
+    assert!(deserialize_simple_error(b"ERR").is_err());
+}
@@ -1,12 +1,9 @@
-use super::util::FindLinefeed;
+use super::{util::FindLinefeed, Incomplete};
 
 #[cfg(test)]
 mod tests;
 
-pub fn deserialize_simple_string(bytes: &[u8]) -> anyhow::Result<(String, usize)> {
-    let linefeed = bytes.find_linefeed().unwrap().unwrap();
-    Ok((
-        String::from_utf8(bytes[..linefeed].to_vec()).unwrap(),
-        linefeed + 2,
-    ))
+pub fn deserialize_simple_string(bytes: &[u8]) -> anyhow::Result<(&str, usize)> {
+    let linefeed = bytes.find_linefeed()?.ok_or(Incomplete)?;
+    Ok((std::str::from_utf8(&bytes[..linefeed])?, linefeed + 2))
 }
@@ -1,9 +1,10 @@
 use super::*;
+use crate::resp::value::IntoRespArray;
 
 #[test]
 fn deserialize_bytes_test() {
     let bytes = b"+helloWorld\r\n";
-    let (value, bytes_consumed): (Value, usize) = deserialize_value(bytes).unwrap();
+    let (_value, _bytes_consumed): (Value, usize) = deserialize_value(bytes).unwrap();
 }
 
 #[test]
@@ -14,7 +15,7 @@ fn deserialize_simple_string_value() {
     let Value::SimpleString(value) = value else {
         panic!()
     };
-    let (s, s_consumed) = deserialize_simple_string(&bytes[1..]).unwrap();
+    let (s, _s_consumed) = deserialize_simple_string(&bytes[1..]).unwrap();
     assert_eq!(value, s);
     assert_eq!(consumed, bytes.len());
 }
@@ -96,7 +97,9 @@ fn deserialize_array_value_test() {
 fn deserialize_array_matches_deserialize_array() {
     let bytes = b"*2\r\n+hello\r\n$5\r\nworld\r\n";
 
-    let (arr, _bytes_consumed) = deserialize_array(&bytes[4..], 2).unwrap().into();
+    let (arr, _bytes_consumed) = deserialize_array(&bytes[4..], 2, &Limits::default(), 0)
+        .unwrap()
+        .into();
 
     let (value, bytes_consumed) = deserialize_value(bytes).unwrap();
     let Value::Array(arr_value) = value else {
@@ -108,7 +111,9 @@ fn deserialize_array_matches_deserialize_array() {
 #[test]
 fn deserialize_nested_array_test() {
     let bytes = b"*1\r\n+simpleStr\r\n";
-    let (arr, consumed) = deserialize_array(bytes, 1).unwrap().into();
+    let (arr, consumed) = deserialize_array(bytes, 1, &Limits::default(), 0)
+        .unwrap()
+        .into();
     assert_eq!(
         arr[0],
         Value::Array(vec![Value::SimpleString("simpleStr".into())])
@@ -134,3 +139,245 @@ fn deserialize_bulk_array_with_more_negative_number_fails() {
         assert!(deserialize_value(&value).is_err());
     }
 }
+
+#[test]
+fn deserialize_empty_bulk_string_and_array() {
+    assert_eq!(
+        deserialize_value(b"$0\r\n\r\n").unwrap(),
+        (Value::BulkString(String::new()), 6)
+    );
+    assert_eq!(
+        deserialize_value(b"*0\r\n").unwrap(),
+        (Value::Array(Vec::new()), 4)
+    );
+}
+
+#[test]
+fn deserialize_resp3_scalar_values() {
+    let inputs = [
+        (b":-42\r\n".to_vec(), Value::Integer(-42)),
+        (
+            b"-ERR bad\r\n".to_vec(),
+            Value::SimpleError("ERR bad".into()),
+        ),
+        (b"_\r\n".to_vec(), Value::Null),
+        (b"#t\r\n".to_vec(), Value::Boolean(true)),
+        (b"#f\r\n".to_vec(), Value::Boolean(false)),
+        (b",3.5\r\n".to_vec(), Value::Double(3.5)),
+        (b",-inf\r\n".to_vec(), Value::Double(f64::NEG_INFINITY)),
+        (
+            b"(123456789012345678901234567890\r\n".to_vec(),
+            Value::BigNumber("123456789012345678901234567890".into()),
+        ),
+        (
+            b"!9\r\nERR oops!\r\n".to_vec(),
+            Value::BulkError("ERR oops!".into()),
+        ),
+        (
+            b"=8\r\ntxt:text\r\n".to_vec(),
+            Value::VerbatimString {
+                encoding: "txt".into(),
+                text: "text".into(),
+            },
+        ),
+    ];
+    for (bytes, expected) in inputs {
+        let (value, bytes_consumed) = deserialize_value(&bytes).unwrap();
+        assert_eq!(value, expected, "{}", String::from_utf8_lossy(&bytes));
+        assert_eq!(bytes_consumed, bytes.len());
+    }
+}
+
+#[test]
+fn deserialize_integer_value_does_not_match_other_integer() {
+    let (value, _) = deserialize_value(b":1\r\n").unwrap();
+    assert_ne!(value, Value::Integer(2));
+}
+
+#[test]
+fn deserialize_map_value_test() {
+    let bytes = b"%2\r\n+first\r\n:1\r\n$6\r\nsecond\r\n#f\r\n";
+    let (value, bytes_consumed) = deserialize_value(bytes).unwrap();
+    assert_eq!(
+        value,
+        Value::Map(vec![
+            (Value::simple_string("first"), Value::Integer(1)),
+            (Value::bulk_string("second"), Value::Boolean(false)),
+        ])
+    );
+    assert_eq!(bytes_consumed, bytes.len());
+}
+
+#[test]
+fn deserialize_set_and_push_values() {
+    let (set, consumed) = deserialize_value(b"~2\r\n+a\r\n:1\r\n").unwrap();
+    assert_eq!(
+        set,
+        Value::Set(vec![Value::simple_string("a"), Value::Integer(1)])
+    );
+    assert_eq!(consumed, 12);
+
+    let bytes = b">3\r\n$7\r\nmessage\r\n$7\r\nchannel\r\n$5\r\nhello\r\n";
+    let (push, consumed) = deserialize_value(bytes).unwrap();
+    assert_eq!(
+        push,
+        Value::Push(Value::bulk_strings("message; channel; hello"))
+    );
+    assert_eq!(consumed, bytes.len());
+}
+
+#[test]
+fn deserialize_nested_aggregates() {
+    let bytes = b"%1\r\n+key\r\n~2\r\n%1\r\n+inner\r\n_\r\n*1\r\n,1.5\r\n";
+    let (value, bytes_consumed) = deserialize_value(bytes).unwrap();
+    assert_eq!(
+        value,
+        Value::Map(vec![(
+            Value::simple_string("key"),
+            Value::Set(vec![
+                Value::Map(vec![(Value::simple_string("inner"), Value::Null)]),
+                Value::Array(vec![Value::Double(1.5)]),
+            ])
+        )])
+    );
+    assert_eq!(bytes_consumed, bytes.len());
+}
+
+#[test]
+fn deserialize_attribute_value_wraps_reply() {
+    let bytes = b"|1\r\n+key-popularity\r\n%1\r\n$1\r\na\r\n,0.19\r\n*1\r\n:2039123\r\n";
+    let (value, bytes_consumed) = deserialize_value(bytes).unwrap();
+    assert_eq!(
+        value,
+        Value::Attribute {
+            attributes: vec![(
+                Value::simple_string("key-popularity"),
+                Value::Map(vec![(Value::bulk_string("a"), Value::Double(0.19))])
+            )],
+            value: Box::new(Value::Array(vec![Value::Integer(2_039_123)])),
+        }
+    );
+    assert_eq!(bytes_consumed, bytes.len());
+}
+
+#[test]
+fn deserialize_truncated_aggregate_fails() {
+    assert!(deserialize_value(b"%1\r\n+key\r\n").is_err());
+    assert!(deserialize_value(b"~2\r\n+a\r\n").is_err());
+}
+
+#[test]
+fn try_deserialize_value_returns_none_for_every_prefix_of_a_frame() {
+    let value = Value::Map(vec![
+        (
+            Value::bulk_string("abc"),
+            Value::Array(vec![Value::Integer(-12)]),
+        ),
+        (Value::simple_string("xyz"), Value::Double(1.5)),
+        (Value::Boolean(true), Value::Null),
+    ]);
+    let bytes = crate::resp::value::serialize_value(&value);
+    for end in 0..bytes.len() {
+        assert!(
+            try_deserialize_value(&bytes[..end]).unwrap().is_none(),
+            "prefix of length {end} should be incomplete"
+        );
+    }
+    assert_eq!(
+        try_deserialize_value(&bytes).unwrap(),
+        Some((value, bytes.len()))
+    );
+}
+
+#[test]
+fn try_deserialize_value_returns_err_on_invalid_frame() {
+    assert!(try_deserialize_value(b"+abc\n").is_err());
+    assert!(try_deserialize_value(b"$3\r\nabcd\r\n").is_err());
+}
+
+#[test]
+fn deserialize_inline_command_as_array_of_bulk_strings() {
+    assert_eq!(
+        deserialize_value(b"SET a \"b c\"\r\n").unwrap(),
+        (Value::bulk_strings("SET; a; b c").into_array(), 13)
+    );
+}
+
+#[test]
+fn deserialize_inline_command_skips_empty_lines() {
+    assert_eq!(
+        deserialize_value(b"\r\n  \nPING\n").unwrap(),
+        (Value::bulk_strings("PING").into_array(), 10)
+    );
+    assert!(try_deserialize_value(b"\r\n\r\n").unwrap().is_none());
+}
+
+#[test]
+fn deserialize_rejects_lengths_over_the_limits() {
+    let limits = Limits {
+        max_bulk_len: 4,
+        max_multibulk_len: 2,
+        ..Limits::default()
+    };
+    let err = try_deserialize_value_ref(b"$5\r\nhello\r\n", &limits).unwrap_err();
+    assert_eq!(err.to_string(), "invalid bulk length");
+    let err = try_deserialize_value_ref(b"*3\r\n", &limits).unwrap_err();
+    assert_eq!(err.to_string(), "invalid multibulk length");
+    let err = try_deserialize_value_ref(b"%3\r\n", &limits).unwrap_err();
+    assert_eq!(err.to_string(), "invalid multibulk length");
+    assert!(try_deserialize_value_ref(b"$4\r\nhell\r\n", &limits)
+        .unwrap()
+        .is_some());
+}
+
+#[test]
+fn deserialize_rejects_negative_lengths() {
+    assert!(try_deserialize_value(b"$-2\r\n").is_err());
+    assert!(try_deserialize_value(b"*-5\r\n").is_err());
+    assert_eq!(
+        try_deserialize_value(b"$-1\r\n").unwrap(),
+        Some((Value::NullString, 5))
+    );
+}
+
+#[test]
+fn deserialize_huge_array_header_waits_for_items() {
+    assert!(try_deserialize_value(b"*1000000\r\n:1\r\n")
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn deserialize_rejects_too_long_inline_command() {
+    let limits = Limits {
+        max_inline_len: 8,
+        ..Limits::default()
+    };
+    assert!(try_deserialize_value_ref(b"PING\r\n", &limits).is_ok());
+    let err = try_deserialize_value_ref(b"SET key value\r\n", &limits).unwrap_err();
+    assert_eq!(err.to_string(), "too big inline request");
+}
+
+#[test]
+fn deserialize_rejects_aggregates_nested_too_deep() {
+    let limits = Limits {
+        max_nesting_depth: 2,
+        ..Limits::default()
+    };
+    assert!(
+        try_deserialize_value_ref(b"*1\r\n%1\r\n+a\r\n~0\r\n", &limits)
+            .unwrap()
+            .is_some()
+    );
+    let err = try_deserialize_value_ref(b"*1\r\n*1\r\n*1\r\n:1\r\n", &limits).unwrap_err();
+    assert_eq!(err.to_string(), "invalid nesting depth");
+    let err = try_deserialize_value_ref(b"|1\r\n+a\r\n*1\r\n>1\r\n:1\r\n", &limits).unwrap_err();
+    assert_eq!(err.to_string(), "invalid nesting depth");
+}
+
+#[test]
+fn deserialize_deeply_nested_frame_does_not_overflow_the_stack() {
+    let bytes = b"*1\r\n".repeat(200_000);
+    let err = try_deserialize_value(&bytes).unwrap_err();
+    assert_eq!(err.to_string(), "invalid nesting depth");
+}
@@ -1,5 +1,6 @@
+use super::Incomplete;
 use crate::resp::value::identifier::Identifier;
-use anyhow::{anyhow, bail};
+use anyhow::bail;
 
 #[cfg(test)]
 mod tests;
@@ -14,13 +15,11 @@ pub fn deserialize_header(mut bytes: &[u8]) -> anyhow::Result<(isize, usize)> {
         bytes = &bytes[1..];
         length += 1;
     }
-    let linefeed = bytes
-        .find_linefeed()?
-        .ok_or(anyhow!("linefeed not found"))?;
+    let linefeed = bytes.find_linefeed()?.ok_or(Incomplete)?;
     length += linefeed + 2;
     let digits = &bytes[..linefeed];
     let digits = String::from_utf8(digits.to_vec())?;
-    let number = dbg!(digits).parse()?;
+    let number = digits.parse()?;
     Ok((number, length))
 }
 
@@ -52,8 +51,11 @@ impl FindLinefeed for [u8] {
                 return Ok(Some(i));
             }
         }
-        if [b'\r', b'\n'].map(Some).contains(&self.last().copied()) {
-            bail!("found single linefeed or cr");
+        match self.last() {
+            // the linefeed might be in the next read
+            Some(b'\r') => return Err(Incomplete.into()),
+            Some(b'\n') => bail!("found single linefeed"),
+            _ => (),
         }
 
         Ok(None)
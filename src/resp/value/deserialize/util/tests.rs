@@ -64,7 +64,7 @@ fn is_at_linefeed_test() {
 #[test]
 fn get_header_test() {
     let bytes = b"$10\r\nabc\r\n";
-    let (header_value, bytes_consumed) = deserialize_header(bytes).unwrap();
+    let (_header_value, _bytes_consumed) = deserialize_header(bytes).unwrap();
 }
 
 #[test]
@@ -85,7 +85,7 @@ fn get_header_length_test() {
 #[test]
 fn get_header_on_slice() {
     let bytes = b"$10\r\n";
-    let (length, bytes_consumed) = bytes.get_header().unwrap();
+    let (_length, _bytes_consumed) = bytes.get_header().unwrap();
 }
 
 #[test]
@@ -0,0 +1,29 @@
+use anyhow::bail;
+
+use super::{bulk_string::deserialize_bulk_string, info::DeserializeInfo};
+
+#[cfg(test)]
+mod tests;
+
+pub struct VerbatimString<'a> {
+    pub encoding: &'a str,
+    pub text: &'a str,
+}
+
+pub fn deserialize_verbatim_string(
+    bytes: &[u8],
+    length: usize,
+) -> anyhow::Result<DeserializeInfo<VerbatimString<'_>>> {
+    let info = deserialize_bulk_string(bytes, length)?;
+    let s = std::str::from_utf8(info.value)?;
+    if s.len() < 4 || s.as_bytes()[3] != b':' {
+        bail!("verbatim string missing encoding: {s}");
+    }
+    Ok(DeserializeInfo::new(
+        VerbatimString {
+            encoding: &s[..3],
+            text: &s[4..],
+        },
+        info.bytes_read,
+    ))
+}
@@ -0,0 +1,16 @@
+use super::*;
+
+#[test]
+fn deserialize_verbatim_string_test() {
+    let bytes = b"txt:Some string\r\n";
+    let info = deserialize_verbatim_string(bytes, 15).unwrap();
+    assert_eq!(info.value.encoding, "txt");
+    assert_eq!(info.value.text, "Some string");
+    assert_eq!(info.bytes_read, bytes.len());
+}
+
+#[test]
+fn deserialize_verbatim_string_without_encoding_fails() {
+    assert!(deserialize_verbatim_string(b"Some\r\n", 4).is_err());
+    assert!(deserialize_verbatim_string(b"txtSome\r\n", 7).is_err());
+}
@@ -39,7 +39,7 @@ impl Identifier {
             b'!' => Self::BulkError,
             b'=' => Self::VerbatimString,
             b'%' => Self::Map,
-            b'`' => Self::Attribute,
+            b'|' => Self::Attribute,
             b'~' => Self::Set,
             b'>' => Self::Pushe,
             _ => bail!(
@@ -65,7 +65,7 @@ impl Identifier {
             Self::BulkError => b'!',
             Self::VerbatimString => b'=',
             Self::Map => b'%',
-            Self::Attribute => b'`',
+            Self::Attribute => b'|',
             Self::Set => b'~',
             Self::Pushe => b'>',
         }
@@ -2,7 +2,7 @@ use super::*;
 
 #[test]
 fn get_identifier_from_byte() {
-    let identifer = Identifier::from_byte(b'+');
+    let _identifer = Identifier::from_byte(b'+');
 }
 
 const fn get_all_idents_variants() -> [Identifier; 15] {
@@ -27,7 +27,7 @@ const fn get_all_idents_variants() -> [Identifier; 15] {
 
 const fn get_all_ident_bytes() -> [u8; 15] {
     [
-        b'+', b'-', b':', b'$', b'*', b'_', b'#', b',', b'(', b'!', b'=', b'%', b'`', b'~', b'>',
+        b'+', b'-', b':', b'$', b'*', b'_', b'#', b',', b'(', b'!', b'=', b'%', b'|', b'~', b'>',
     ]
 }
 
@@ -57,7 +57,7 @@ fn to_byte_identifier_test() {
 #[test]
 fn get_identifier_length_test() {
     let ident = Identifier::SimpleString;
-    let length: usize = ident.get_byte_length();
+    let _length: usize = ident.get_byte_length();
 }
 
 #[test]
@@ -81,7 +81,7 @@ fn invalid_identifier_returns_err_test() {
 #[test]
 fn get_identifier_from_slice_test() {
     let b = b"+";
-    let identifier = b.get_identifier().unwrap();
+    let _identifier = b.get_identifier().unwrap();
 }
 
 #[test]
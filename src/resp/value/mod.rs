@@ -1,10 +1,14 @@
+pub mod convert;
 pub mod deserialize;
 pub mod identifier;
 pub mod serialize;
+pub mod value_ref;
 
 use anyhow::anyhow;
+pub use convert::{FromValue, IntoValue};
 pub use deserialize::deserialize_value;
-pub use serialize::serialize_value;
+pub use serialize::{serialize_into, serialize_into_as, serialize_value, serialize_value_as};
+pub use value_ref::ValueRef;
 
 #[derive(Debug, Clone)]
 pub enum Value {
@@ -18,6 +22,23 @@ pub enum Value {
     Array(Vec<Self>),
     NullArray,
     Raw(Vec<u8>),
+
+    Null,
+    Boolean(bool),
+    Double(f64),
+    BigNumber(String),
+    BulkError(String),
+    VerbatimString {
+        encoding: String,
+        text: String,
+    },
+    Map(Vec<(Self, Self)>),
+    Attribute {
+        attributes: Vec<(Self, Self)>,
+        value: Box<Self>,
+    },
+    Set(Vec<Self>),
+    Push(Vec<Self>),
 }
 
 impl Value {
@@ -31,6 +52,15 @@ impl Value {
         Self::BulkString(s.to_string())
     }
 
+    /// A bulk string that keeps `bytes` as a `String` when they are valid utf-8.
+    #[must_use]
+    pub fn bulk_bytes(bytes: Vec<u8>) -> Self {
+        match String::from_utf8(bytes) {
+            Ok(s) => Self::BulkString(s),
+            Err(err) => Self::BulkByteString(err.into_bytes()),
+        }
+    }
+
     pub fn bulk_strings(s: impl ToString) -> Vec<Self> {
         Self::bulk_string_pat(s, ";")
     }
@@ -57,12 +87,7 @@ impl Value {
     pub fn into_string(self) -> Result<String, Self> {
         match self {
             Value::SimpleString(s) | Value::BulkString(s) => Ok(s),
-            Value::NullString | Value::NullArray | Value::BulkByteString(_) | Value::Array(_) => {
-                Err(self)
-            }
-            Value::Integer(_) => todo!(),
-            Value::SimpleError(_) => todo!(),
-            Value::Raw(_) => todo!(),
+            _ => Err(self),
         }
     }
 
@@ -107,12 +132,8 @@ impl Value {
     pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
         match self {
             Value::SimpleString(s) | Value::BulkString(s) => s.eq_ignore_ascii_case(other),
-            Value::NullString | Value::NullArray | Value::BulkByteString(_) | Value::Array(_) => {
-                false
-            }
-            Value::Integer(_) => todo!(),
-            Value::SimpleError(_) => todo!(),
-            Value::Raw(_) => todo!(),
+            Value::BulkByteString(bytes) => bytes.eq_ignore_ascii_case(other.as_bytes()),
+            _ => false,
         }
     }
 }
@@ -151,7 +172,37 @@ impl PartialEq for Value {
                 l0 == r0.as_bytes()
             }
             (Self::BulkByteString(l0), Self::BulkByteString(r0)) => l0 == r0,
-            (Self::Array(l0), Self::Array(r0)) => l0 == r0,
+            (Self::Array(l0), Self::Array(r0))
+            | (Self::Set(l0), Self::Set(r0))
+            | (Self::Push(l0), Self::Push(r0)) => l0 == r0,
+            (Self::SimpleError(l0), Self::SimpleError(r0))
+            | (Self::BigNumber(l0), Self::BigNumber(r0))
+            | (Self::BulkError(l0), Self::BulkError(r0)) => l0 == r0,
+            (Self::Integer(l0), Self::Integer(r0)) => l0 == r0,
+            (Self::Boolean(l0), Self::Boolean(r0)) => l0 == r0,
+            (Self::Double(l0), Self::Double(r0)) => l0 == r0 || (l0.is_nan() && r0.is_nan()),
+            (
+                Self::VerbatimString {
+                    encoding: l_encoding,
+                    text: l_text,
+                },
+                Self::VerbatimString {
+                    encoding: r_encoding,
+                    text: r_text,
+                },
+            ) => l_encoding == r_encoding && l_text == r_text,
+            (Self::Map(l0), Self::Map(r0)) => l0 == r0,
+            (
+                Self::Attribute {
+                    attributes: l_attributes,
+                    value: l_value,
+                },
+                Self::Attribute {
+                    attributes: r_attributes,
+                    value: r_value,
+                },
+            ) => l_attributes == r_attributes && l_value == r_value,
+            (Self::Raw(l0), Self::Raw(r0)) => l0 == r0,
             _ => core::mem::discriminant(self) == core::mem::discriminant(other),
         }
     }
@@ -163,12 +214,8 @@ impl PartialEq<&str> for Value {
     fn eq(&self, other: &&str) -> bool {
         match self {
             Value::SimpleString(s) | Value::BulkString(s) => other == s,
-            Value::NullString | Value::NullArray | Value::BulkByteString(_) | Value::Array(_) => {
-                false
-            }
-            Value::Integer(_) => todo!(),
-            Value::SimpleError(_) => todo!(),
-            Value::Raw(_) => todo!(),
+            Value::BulkByteString(bytes) => other.as_bytes() == bytes,
+            _ => false,
         }
     }
 }
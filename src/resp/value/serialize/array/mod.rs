@@ -1,13 +1,39 @@
-use crate::resp::{value::identifier::Identifier, Value};
+use std::io::Write;
 
-use super::util::ExtendHeader;
+use crate::resp::{value::identifier::Identifier, Protocol, Value};
+
+use super::util::{to_vec, WriteResp};
 
 #[cfg(test)]
 mod tests;
 
 pub fn serialize_array(arr: &[Value]) -> Vec<u8> {
-    let mut bytes = Vec::new();
-    bytes.extend_header(&Identifier::Array, arr.len().try_into().unwrap());
-    bytes.extend(arr.iter().flat_map(super::serialize_value));
-    bytes
+    serialize_array_as(arr, Protocol::Resp3)
+}
+
+pub fn serialize_array_as(arr: &[Value], protocol: Protocol) -> Vec<u8> {
+    to_vec(0, |bytes| write_array(arr, protocol, bytes))
+}
+
+pub fn write_array<W>(arr: &[Value], protocol: Protocol, writer: &mut W) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    write_aggregate(&Identifier::Array, arr, protocol, writer)
+}
+
+/// Writes a header followed by every item, used by all the list like aggregates.
+pub(super) fn write_aggregate<W>(
+    identifier: &Identifier,
+    items: &[Value],
+    protocol: Protocol,
+    writer: &mut W,
+) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    writer.write_header(identifier, items.len())?;
+    items
+        .iter()
+        .try_for_each(|item| super::serialize_into_as(item, protocol, writer))
 }
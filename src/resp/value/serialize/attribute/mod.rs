@@ -0,0 +1,38 @@
+use std::io::Write;
+
+use crate::resp::{value::identifier::Identifier, Protocol, Value};
+
+use super::{
+    map::write_pairs,
+    util::{to_vec, WriteResp},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// RESP2 peers can't receive attributes so only the annotated value is written to them.
+pub fn serialize_attribute(
+    attributes: &[(Value, Value)],
+    value: &Value,
+    protocol: Protocol,
+) -> Vec<u8> {
+    to_vec(0, |bytes| {
+        write_attribute(attributes, value, protocol, bytes)
+    })
+}
+
+pub fn write_attribute<W>(
+    attributes: &[(Value, Value)],
+    value: &Value,
+    protocol: Protocol,
+    writer: &mut W,
+) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    if protocol == Protocol::Resp3 {
+        writer.write_header(&Identifier::Attribute, attributes.len())?;
+        write_pairs(attributes, protocol, writer)?;
+    }
+    super::serialize_into_as(value, protocol, writer)
+}
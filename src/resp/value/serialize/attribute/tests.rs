@@ -0,0 +1,19 @@
+use super::*;
+
+#[test]
+fn serialize_attribute_test() {
+    let attributes = [(Value::simple_string("ttl"), Value::Integer(10))];
+    assert_eq!(
+        serialize_attribute(&attributes, &Value::simple_string("OK"), Protocol::Resp3),
+        b"|1\r\n+ttl\r\n:10\r\n+OK\r\n"
+    );
+}
+
+#[test]
+fn serialize_attribute_as_resp2_only_writes_value() {
+    let attributes = [(Value::simple_string("ttl"), Value::Integer(10))];
+    assert_eq!(
+        serialize_attribute(&attributes, &Value::simple_string("OK"), Protocol::Resp2),
+        b"+OK\r\n"
+    );
+}
@@ -0,0 +1,30 @@
+use std::io::Write;
+
+use crate::resp::{value::identifier::Identifier, Protocol};
+
+use super::{
+    bulk_string::write_bulk_string,
+    util::{to_vec, WriteResp},
+};
+
+#[cfg(test)]
+mod tests;
+
+#[must_use]
+pub fn serialize_big_number(n: &str, protocol: Protocol) -> Vec<u8> {
+    to_vec(n.len() + 3, |bytes| write_big_number(n, protocol, bytes))
+}
+
+pub fn write_big_number<W>(n: &str, protocol: Protocol, writer: &mut W) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    match protocol {
+        Protocol::Resp2 => write_bulk_string(n, writer),
+        Protocol::Resp3 => {
+            writer.write_identifier(&Identifier::BigNumber)?;
+            writer.write_all(n.as_bytes())?;
+            writer.write_linefeed()
+        }
+    }
+}
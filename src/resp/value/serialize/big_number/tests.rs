@@ -0,0 +1,18 @@
+use super::*;
+
+#[test]
+fn serialize_big_number_test() {
+    let n = "3492890328409238509324850943850943825024385";
+    let mut expected = b"(".to_vec();
+    expected.extend(n.as_bytes());
+    expected.extend(b"\r\n");
+    assert_eq!(serialize_big_number(n, Protocol::Resp3), expected);
+}
+
+#[test]
+fn serialize_big_number_as_resp2_is_bulk_string() {
+    assert_eq!(
+        serialize_big_number("-12", Protocol::Resp2),
+        b"$3\r\n-12\r\n"
+    );
+}
@@ -0,0 +1,30 @@
+use std::io::Write;
+
+use crate::resp::{value::identifier::Identifier, Protocol};
+
+use super::{
+    integer::write_int,
+    util::{to_vec, WriteResp},
+};
+
+#[cfg(test)]
+mod tests;
+
+#[must_use]
+pub fn serialize_boolean(value: bool, protocol: Protocol) -> Vec<u8> {
+    to_vec(4, |bytes| write_boolean(value, protocol, bytes))
+}
+
+pub fn write_boolean<W>(value: bool, protocol: Protocol, writer: &mut W) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    match protocol {
+        Protocol::Resp2 => write_int(value.into(), writer),
+        Protocol::Resp3 => {
+            writer.write_identifier(&Identifier::Boolean)?;
+            writer.write_all(if value { b"t" } else { b"f" })?;
+            writer.write_linefeed()
+        }
+    }
+}
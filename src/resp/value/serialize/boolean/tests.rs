@@ -0,0 +1,13 @@
+use super::*;
+
+#[test]
+fn serialize_boolean_test() {
+    assert_eq!(serialize_boolean(true, Protocol::Resp3), b"#t\r\n");
+    assert_eq!(serialize_boolean(false, Protocol::Resp3), b"#f\r\n");
+}
+
+#[test]
+fn serialize_boolean_as_resp2_is_integer() {
+    assert_eq!(serialize_boolean(true, Protocol::Resp2), b":1\r\n");
+    assert_eq!(serialize_boolean(false, Protocol::Resp2), b":0\r\n");
+}
@@ -1,6 +1,8 @@
+use std::io::Write;
+
 use crate::resp::value::identifier::Identifier;
 
-use super::util::{ExtendHeader, ExtendLinefeed};
+use super::util::{to_vec, WriteResp};
 
 #[cfg(test)]
 mod tests;
@@ -8,9 +10,16 @@ mod tests;
 #[must_use]
 pub fn serialize_bulk_byte_string(s: &[u8]) -> Vec<u8> {
     let identifier_header_linefeed_padding = 10;
-    let mut bytes = Vec::with_capacity(s.len() + identifier_header_linefeed_padding);
-    bytes.extend_header(&Identifier::BulkString, s.len().try_into().unwrap());
-    bytes.extend(s);
-    bytes.extend_linefeed();
-    bytes
+    to_vec(s.len() + identifier_header_linefeed_padding, |bytes| {
+        write_bulk_byte_string(s, bytes)
+    })
+}
+
+pub fn write_bulk_byte_string<W>(s: &[u8], writer: &mut W) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    writer.write_header(&Identifier::BulkString, s.len())?;
+    writer.write_all(s)?;
+    writer.write_linefeed()
 }
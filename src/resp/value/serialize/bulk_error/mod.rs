@@ -0,0 +1,33 @@
+use std::io::Write;
+
+use crate::resp::{value::identifier::Identifier, Protocol};
+
+use super::{
+    simple_error::write_simple_error,
+    util::{to_vec, WriteResp},
+};
+
+#[cfg(test)]
+mod tests;
+
+#[must_use]
+pub fn serialize_bulk_error(s: &str, protocol: Protocol) -> Vec<u8> {
+    let identifier_header_linefeed_padding = 10;
+    to_vec(s.len() + identifier_header_linefeed_padding, |bytes| {
+        write_bulk_error(s, protocol, bytes)
+    })
+}
+
+pub fn write_bulk_error<W>(s: &str, protocol: Protocol, writer: &mut W) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    match protocol {
+        Protocol::Resp2 => write_simple_error(&s.replace(['\r', '\n'], " "), writer),
+        Protocol::Resp3 => {
+            writer.write_header(&Identifier::BulkError, s.len())?;
+            writer.write_all(s.as_bytes())?;
+            writer.write_linefeed()
+        }
+    }
+}
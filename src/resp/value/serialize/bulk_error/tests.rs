@@ -0,0 +1,17 @@
+use super::*;
+
+#[test]
+fn serialize_bulk_error_test() {
+    assert_eq!(
+        serialize_bulk_error("SYNTAX invalid syntax", Protocol::Resp3),
+        b"!21\r\nSYNTAX invalid syntax\r\n"
+    );
+}
+
+#[test]
+fn serialize_bulk_error_as_resp2_is_single_line_simple_error() {
+    assert_eq!(
+        serialize_bulk_error("ERR multi\r\nline", Protocol::Resp2),
+        b"-ERR multi  line\r\n"
+    );
+}
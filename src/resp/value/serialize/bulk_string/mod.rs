@@ -1,6 +1,6 @@
-use crate::resp::value::identifier::Identifier;
+use std::io::Write;
 
-use super::util::{ExtendHeader, ExtendLinefeed};
+use super::{bulk_byte_string::write_bulk_byte_string, util::to_vec};
 
 #[cfg(test)]
 mod tests;
@@ -8,9 +8,14 @@ mod tests;
 #[must_use]
 pub fn serialize_bulk_string(s: &str) -> Vec<u8> {
     let identifier_header_linefeed_padding = 10;
-    let mut bytes = Vec::with_capacity(s.len() + identifier_header_linefeed_padding);
-    bytes.extend_header(&Identifier::BulkString, s.len().try_into().unwrap());
-    bytes.extend(s.as_bytes());
-    bytes.extend_linefeed();
-    bytes
+    to_vec(s.len() + identifier_header_linefeed_padding, |bytes| {
+        write_bulk_string(s, bytes)
+    })
+}
+
+pub fn write_bulk_string<W>(s: &str, writer: &mut W) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    write_bulk_byte_string(s.as_bytes(), writer)
 }
@@ -0,0 +1,42 @@
+use std::io::Write;
+
+use crate::resp::{value::identifier::Identifier, Protocol};
+
+use super::{
+    bulk_string::write_bulk_string,
+    util::{to_vec, WriteResp},
+};
+
+#[cfg(test)]
+mod tests;
+
+#[must_use]
+pub fn serialize_double(n: f64, protocol: Protocol) -> Vec<u8> {
+    to_vec(32, |bytes| write_double(n, protocol, bytes))
+}
+
+pub fn write_double<W>(n: f64, protocol: Protocol, writer: &mut W) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    let digits = format_double(n);
+    match protocol {
+        Protocol::Resp2 => write_bulk_string(&digits, writer),
+        Protocol::Resp3 => {
+            writer.write_identifier(&Identifier::Double)?;
+            writer.write_all(digits.as_bytes())?;
+            writer.write_linefeed()
+        }
+    }
+}
+
+#[must_use]
+pub fn format_double(n: f64) -> String {
+    if n.is_nan() {
+        "nan".to_string()
+    } else if n.is_infinite() {
+        if n.is_sign_positive() { "inf" } else { "-inf" }.to_string()
+    } else {
+        n.to_string()
+    }
+}
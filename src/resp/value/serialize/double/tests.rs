@@ -0,0 +1,21 @@
+use super::*;
+
+#[test]
+fn serialize_double_test() {
+    assert_eq!(serialize_double(1.5, Protocol::Resp3), b",1.5\r\n");
+    assert_eq!(serialize_double(-10.0, Protocol::Resp3), b",-10\r\n");
+    assert_eq!(
+        serialize_double(f64::INFINITY, Protocol::Resp3),
+        b",inf\r\n"
+    );
+    assert_eq!(
+        serialize_double(f64::NEG_INFINITY, Protocol::Resp3),
+        b",-inf\r\n"
+    );
+    assert_eq!(serialize_double(f64::NAN, Protocol::Resp3), b",nan\r\n");
+}
+
+#[test]
+fn serialize_double_as_resp2_is_bulk_string() {
+    assert_eq!(serialize_double(3.25, Protocol::Resp2), b"$4\r\n3.25\r\n");
+}
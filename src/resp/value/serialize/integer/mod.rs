@@ -1,17 +1,25 @@
+use std::io::Write;
+
 use crate::resp::value::identifier::Identifier;
 
-use super::util::{ExtendIdentifier, ExtendLinefeed as _};
+use super::util::{to_vec, WriteResp};
 
 #[cfg(test)]
 mod tests;
 
 #[must_use]
 pub fn serialize_int(n: i64) -> Vec<u8> {
-    let binding = n.to_string();
-    let digits = binding.as_bytes();
-    let mut result = Vec::with_capacity(1 + 1 + digits.len() + 2);
-    result.extend_identifier(&Identifier::Integer);
-    result.extend(digits);
-    result.extend_linefeed();
-    result
+    let (identifier_len, max_digits, linefeed_len) = (1, 20, 2);
+    to_vec(identifier_len + max_digits + linefeed_len, |bytes| {
+        write_int(n, bytes)
+    })
+}
+
+pub fn write_int<W>(n: i64, writer: &mut W) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    writer.write_identifier(&Identifier::Integer)?;
+    writer.write_int(n)?;
+    writer.write_linefeed()
 }
@@ -0,0 +1,43 @@
+use std::io::Write;
+
+use crate::resp::{value::identifier::Identifier, Protocol, Value};
+
+use super::util::{to_vec, WriteResp};
+
+#[cfg(test)]
+mod tests;
+
+/// RESP2 has no map type so the pairs are flattened into an array of `2 * pairs.len()` items.
+pub fn serialize_map(pairs: &[(Value, Value)], protocol: Protocol) -> Vec<u8> {
+    to_vec(0, |bytes| write_map(pairs, protocol, bytes))
+}
+
+pub fn write_map<W>(
+    pairs: &[(Value, Value)],
+    protocol: Protocol,
+    writer: &mut W,
+) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    match protocol {
+        Protocol::Resp2 => writer.write_header(&Identifier::Array, pairs.len() * 2)?,
+        Protocol::Resp3 => writer.write_header(&Identifier::Map, pairs.len())?,
+    }
+    write_pairs(pairs, protocol, writer)
+}
+
+pub(super) fn write_pairs<W>(
+    pairs: &[(Value, Value)],
+    protocol: Protocol,
+    writer: &mut W,
+) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    for (key, value) in pairs {
+        super::serialize_into_as(key, protocol, writer)?;
+        super::serialize_into_as(value, protocol, writer)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use super::*;
+
+fn pairs() -> Vec<(Value, Value)> {
+    vec![
+        (Value::simple_string("first"), Value::Integer(1)),
+        (Value::simple_string("second"), Value::Boolean(true)),
+    ]
+}
+
+#[test]
+fn serialize_map_test() {
+    assert_eq!(
+        serialize_map(&pairs(), Protocol::Resp3),
+        b"%2\r\n+first\r\n:1\r\n+second\r\n#t\r\n"
+    );
+}
+
+#[test]
+fn serialize_map_as_resp2_is_flat_array() {
+    assert_eq!(
+        serialize_map(&pairs(), Protocol::Resp2),
+        b"*4\r\n+first\r\n:1\r\n+second\r\n:1\r\n"
+    );
+}
+
+#[test]
+fn serialize_empty_map() {
+    assert_eq!(serialize_map(&[], Protocol::Resp3), b"%0\r\n");
+    assert_eq!(serialize_map(&[], Protocol::Resp2), b"*0\r\n");
+}
@@ -1,30 +1,84 @@
-use super::super::Value;
+use std::io::Write;
+
+use super::super::{Protocol, Value};
 
 pub mod array;
+pub mod attribute;
+pub mod big_number;
+pub mod boolean;
 pub mod bulk_byte_string;
+pub mod bulk_error;
 pub mod bulk_string;
+pub mod double;
 pub mod integer;
+pub mod map;
+pub mod null;
 pub mod null_array;
 pub mod null_string;
+pub mod push;
+pub mod set;
 pub mod simple_error;
 pub mod simple_string;
 mod util;
+pub mod verbatim_string;
 
 #[cfg(test)]
 pub(super) mod tests;
 
+/// Serializes the value as is, RESP3 types included.
 #[must_use]
 pub fn serialize_value(value: &Value) -> Vec<u8> {
+    serialize_value_as(value, Protocol::Resp3)
+}
+
+/// Serializes the value for a peer speaking `protocol`.
+#[must_use]
+pub fn serialize_value_as(value: &Value, protocol: Protocol) -> Vec<u8> {
+    util::to_vec(0, |bytes| serialize_into_as(value, protocol, bytes))
+}
+
+/// Writes the value as is, RESP3 types included, straight into `writer`.
+pub fn serialize_into<W>(value: &Value, writer: &mut W) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    serialize_into_as(value, Protocol::Resp3, writer)
+}
+
+/// Writes the value for a peer speaking `protocol` straight into `writer`,
+/// nested values are written in place instead of being collected first.
+pub fn serialize_into_as<W>(
+    value: &Value,
+    protocol: Protocol,
+    writer: &mut W,
+) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
     match value {
-        Value::SimpleString(s) => simple_string::serialize_simple_string(s),
-        Value::BulkString(s) => bulk_string::serialize_bulk_string(s),
-        Value::BulkByteString(bytes) => bulk_byte_string::serialize_bulk_byte_string(bytes),
-        Value::NullString => null_string::serialize_null_string().to_vec(),
-        Value::Array(arr) => array::serialize_array(arr),
-        Value::NullArray => null_array::serialize_null_array().to_vec(),
-        Value::Integer(i) => integer::serialize_int(*i),
-        Value::SimpleError(s) => simple_error::serialize_simple_error(s),
-        Value::Raw(raw) => raw.clone(),
+        Value::SimpleString(s) => simple_string::write_simple_string(s, writer),
+        Value::BulkString(s) => bulk_string::write_bulk_string(s, writer),
+        Value::BulkByteString(bytes) => bulk_byte_string::write_bulk_byte_string(bytes, writer),
+        Value::NullString => writer.write_all(&null_string::serialize_null_string()),
+        Value::Array(arr) => array::write_array(arr, protocol, writer),
+        Value::NullArray => writer.write_all(&null_array::serialize_null_array()),
+        Value::Integer(i) => integer::write_int(*i, writer),
+        Value::SimpleError(s) => simple_error::write_simple_error(s, writer),
+        Value::Raw(raw) => writer.write_all(raw),
+        Value::Null => null::write_null(protocol, writer),
+        Value::Boolean(b) => boolean::write_boolean(*b, protocol, writer),
+        Value::Double(n) => double::write_double(*n, protocol, writer),
+        Value::BigNumber(n) => big_number::write_big_number(n, protocol, writer),
+        Value::BulkError(s) => bulk_error::write_bulk_error(s, protocol, writer),
+        Value::VerbatimString { encoding, text } => {
+            verbatim_string::write_verbatim_string(encoding, text, protocol, writer)
+        }
+        Value::Map(pairs) => map::write_map(pairs, protocol, writer),
+        Value::Attribute { attributes, value } => {
+            attribute::write_attribute(attributes, value, protocol, writer)
+        }
+        Value::Set(items) => set::write_set(items, protocol, writer),
+        Value::Push(items) => push::write_push(items, protocol, writer),
     }
 }
 
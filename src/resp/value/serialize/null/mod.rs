@@ -0,0 +1,29 @@
+use std::io::Write;
+
+use crate::resp::{value::identifier::Identifier, Protocol};
+
+use super::{
+    null_string::serialize_null_string,
+    util::{to_vec, WriteResp},
+};
+
+#[cfg(test)]
+mod tests;
+
+#[must_use]
+pub fn serialize_null(protocol: Protocol) -> Vec<u8> {
+    to_vec(5, |bytes| write_null(protocol, bytes))
+}
+
+pub fn write_null<W>(protocol: Protocol, writer: &mut W) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    match protocol {
+        Protocol::Resp2 => writer.write_all(&serialize_null_string()),
+        Protocol::Resp3 => {
+            writer.write_identifier(&Identifier::Null)?;
+            writer.write_linefeed()
+        }
+    }
+}
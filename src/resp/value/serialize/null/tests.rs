@@ -0,0 +1,11 @@
+use super::*;
+
+#[test]
+fn serialize_null_test() {
+    assert_eq!(serialize_null(Protocol::Resp3), b"_\r\n");
+}
+
+#[test]
+fn serialize_null_as_resp2_is_null_string() {
+    assert_eq!(serialize_null(Protocol::Resp2), serialize_null_string());
+}
@@ -0,0 +1,14 @@
+use super::*;
+
+#[test]
+fn serialize_push_test() {
+    let items = Value::bulk_strings("message; news");
+    assert_eq!(
+        serialize_push(&items, Protocol::Resp3),
+        b">2\r\n$7\r\nmessage\r\n$4\r\nnews\r\n"
+    );
+    assert_eq!(
+        serialize_push(&items, Protocol::Resp2),
+        b"*2\r\n$7\r\nmessage\r\n$4\r\nnews\r\n"
+    );
+}
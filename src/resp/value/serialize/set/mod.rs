@@ -0,0 +1,23 @@
+use std::io::Write;
+
+use crate::resp::{value::identifier::Identifier, Protocol, Value};
+
+use super::{array::write_aggregate, util::to_vec};
+
+#[cfg(test)]
+mod tests;
+
+pub fn serialize_set(items: &[Value], protocol: Protocol) -> Vec<u8> {
+    to_vec(0, |bytes| write_set(items, protocol, bytes))
+}
+
+pub fn write_set<W>(items: &[Value], protocol: Protocol, writer: &mut W) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    let identifier = match protocol {
+        Protocol::Resp2 => Identifier::Array,
+        Protocol::Resp3 => Identifier::Set,
+    };
+    write_aggregate(&identifier, items, protocol, writer)
+}
@@ -0,0 +1,14 @@
+use super::*;
+
+#[test]
+fn serialize_set_test() {
+    let items = [Value::simple_string("a"), Value::Integer(1)];
+    assert_eq!(
+        serialize_set(&items, Protocol::Resp3),
+        b"~2\r\n+a\r\n:1\r\n"
+    );
+    assert_eq!(
+        serialize_set(&items, Protocol::Resp2),
+        b"*2\r\n+a\r\n:1\r\n"
+    );
+}
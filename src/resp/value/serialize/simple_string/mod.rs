@@ -1,6 +1,8 @@
+use std::io::Write;
+
 use crate::resp::value::identifier::Identifier;
 
-use super::util::{ExtendIdentifier, ExtendLinefeed as _};
+use super::util::{to_vec, WriteResp};
 
 #[cfg(test)]
 mod tests;
@@ -8,9 +10,16 @@ mod tests;
 #[must_use]
 pub fn serialize_simple_string(s: &str) -> Vec<u8> {
     let (identifier_len, linefeed_len) = (1, 2);
-    let mut bytes = Vec::with_capacity(s.len() + identifier_len + linefeed_len);
-    bytes.extend_identifier(&Identifier::SimpleString);
-    bytes.extend(s.as_bytes());
-    bytes.extend_linefeed();
-    bytes
+    to_vec(s.len() + identifier_len + linefeed_len, |bytes| {
+        write_simple_string(s, bytes)
+    })
+}
+
+pub fn write_simple_string<W>(s: &str, writer: &mut W) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    writer.write_identifier(&Identifier::SimpleString)?;
+    writer.write_all(s.as_bytes())?;
+    writer.write_linefeed()
 }
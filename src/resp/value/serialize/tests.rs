@@ -70,7 +70,7 @@ fn serialize_integer_value_test() {
 #[test]
 fn serialize_value_test() {
     let value = Value::SimpleString("hello world".to_string());
-    let bytes: Vec<u8> = serialize_value(&value);
+    let _bytes: Vec<u8> = serialize_value(&value);
 }
 
 #[test]
@@ -89,3 +89,91 @@ fn serialize_array_contains_serialized_value_test() {
         );
     }
 }
+
+fn example_of_resp3_values() -> Vec<Value> {
+    vec![
+        Value::Null,
+        Value::Boolean(true),
+        Value::Double(-2.5),
+        Value::BigNumber("1234567890123456789012345678901234567890".into()),
+        Value::BulkError("ERR something".into()),
+        Value::VerbatimString {
+            encoding: "txt".into(),
+            text: "hello".into(),
+        },
+        Value::Map(vec![(
+            Value::bulk_string("key"),
+            Value::Set(vec![Value::Integer(1), Value::Null]),
+        )]),
+        Value::Attribute {
+            attributes: vec![(Value::simple_string("a"), Value::Integer(1))],
+            value: Box::new(Value::bulk_string("value")),
+        },
+        Value::Push(vec![Value::bulk_string("message"), Value::Double(0.5)]),
+    ]
+}
+
+#[test]
+fn serialized_resp3_values_deserialize_to_same_value() {
+    for value in example_of_resp3_values() {
+        let bytes = serialize_value(&value);
+        let (deserialized, bytes_consumed) = crate::resp::value::deserialize_value(&bytes).unwrap();
+        assert_eq!(deserialized, value);
+        assert_eq!(bytes_consumed, bytes.len());
+    }
+}
+
+#[test]
+fn serialize_resp2_values_same_in_both_protocols() {
+    for value in example_of_all_values() {
+        assert_eq!(
+            serialize_value_as(&value, Protocol::Resp2),
+            serialize_value_as(&value, Protocol::Resp3)
+        );
+    }
+}
+
+#[test]
+fn serialize_nested_resp3_values_as_resp2() {
+    let value = Value::Array(vec![
+        Value::Map(vec![(Value::bulk_string("k"), Value::Null)]),
+        Value::Set(vec![Value::Boolean(false)]),
+    ]);
+    assert_eq!(
+        serialize_value_as(&value, Protocol::Resp2),
+        b"*2\r\n*2\r\n$1\r\nk\r\n$-1\r\n*1\r\n:0\r\n"
+    );
+}
+
+#[test]
+fn serialize_into_appends_same_bytes_as_serialize_value() {
+    let value = Value::Array(example_of_all_values());
+    let mut bytes = b"prefix".to_vec();
+    serialize_into(&value, &mut bytes).unwrap();
+    assert_eq!(bytes[..6], *b"prefix");
+    assert_eq!(bytes[6..], serialize_value(&value));
+}
+
+#[test]
+fn serialize_into_returns_writer_errors() {
+    struct Full;
+    impl std::io::Write for Full {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::WriteZero.into())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+    let err = serialize_into(&Value::Array(example_of_all_values()), &mut Full).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+}
+
+#[test]
+fn serialize_int_extremes() {
+    assert_eq!(
+        serialize_int(i64::MIN),
+        format!(":{}\r\n", i64::MIN).as_bytes()
+    );
+    assert_eq!(serialize_int(0), b":0\r\n");
+}
@@ -1,36 +1,61 @@
+use std::io::Write;
+
 use crate::resp::value::identifier::Identifier;
 
 #[cfg(test)]
 mod tests;
 
-pub trait ExtendLinefeed {
-    fn extend_linefeed(&mut self);
-}
+/// Writes the pieces every frame is built from.
+/// Numbers are formatted on the stack so nothing is allocated per frame.
+pub trait WriteResp: Write {
+    fn write_linefeed(&mut self) -> std::io::Result<()> {
+        self.write_all(b"\r\n")
+    }
 
-impl ExtendLinefeed for Vec<u8> {
-    fn extend_linefeed(&mut self) {
-        self.extend(b"\r\n");
+    fn write_identifier(&mut self, identifier: &Identifier) -> std::io::Result<()> {
+        self.write_all(&[identifier.as_byte()])
     }
-}
 
-pub trait ExtendIdentifier {
-    fn extend_identifier(&mut self, identifier: &Identifier);
-}
+    fn write_int(&mut self, n: i64) -> std::io::Result<()> {
+        if n < 0 {
+            self.write_all(b"-")?;
+        }
+        self.write_uint(n.unsigned_abs())
+    }
 
-impl ExtendIdentifier for Vec<u8> {
-    fn extend_identifier(&mut self, identifier: &Identifier) {
-        self.push(identifier.as_byte());
+    fn write_uint(&mut self, n: u64) -> std::io::Result<()> {
+        let mut buf = [0; 20];
+        self.write_all(format_uint(n, &mut buf))
     }
-}
 
-pub trait ExtendHeader {
-    fn extend_header(&mut self, identifier: &Identifier, length: isize);
+    fn write_header(&mut self, identifier: &Identifier, length: usize) -> std::io::Result<()> {
+        self.write_identifier(identifier)?;
+        self.write_uint(length as u64)?;
+        self.write_linefeed()
+    }
 }
 
-impl ExtendHeader for Vec<u8> {
-    fn extend_header(&mut self, identifier: &Identifier, length: isize) {
-        self.extend_identifier(identifier);
-        self.extend(length.to_string().as_bytes());
-        self.extend_linefeed();
+impl<W> WriteResp for W where W: Write + ?Sized {}
+
+/// Writes the digits of `n` to the end of `buf` and returns them.
+pub fn format_uint(mut n: u64, buf: &mut [u8; 20]) -> &[u8] {
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            return &buf[start..];
+        }
     }
 }
+
+/// Runs a writer based serializer against a fresh `Vec`.
+pub(super) fn to_vec<F>(capacity: usize, f: F) -> Vec<u8>
+where
+    F: FnOnce(&mut Vec<u8>) -> std::io::Result<()>,
+{
+    let mut bytes = Vec::with_capacity(capacity);
+    f(&mut bytes).expect("writing to a vec can not fail");
+    bytes
+}
@@ -1,33 +1,51 @@
 use super::*;
 
 #[test]
-fn extend_linefeed_test() {
+fn write_linefeed_test() {
     let mut v: Vec<u8> = Vec::new();
-    v.extend_linefeed();
+    v.write_linefeed().unwrap();
     assert_eq!(v, b"\r\n");
 }
 
 #[test]
-fn extend_identifier_test() {
+fn write_identifier_test() {
     let mut v = Vec::new();
-    v.extend_identifier(&Identifier::SimpleString);
+    v.write_identifier(&Identifier::SimpleString).unwrap();
     assert_eq!(v[0], Identifier::SimpleString.as_byte());
 }
 
 #[test]
-fn extend_header_test() {
+fn write_header_test() {
     let mut v = Vec::new();
     let identifier = &Identifier::BulkString;
-    v.extend_header(identifier, 10);
+    v.write_header(identifier, 10).unwrap();
     let mut expected = Vec::new();
-    expected.extend_identifier(identifier);
+    expected.write_identifier(identifier).unwrap();
     expected.extend(b"10");
-    expected.extend_linefeed();
+    expected.write_linefeed().unwrap();
     assert_eq!(v, expected);
 }
 
+#[test]
+fn write_int_matches_to_string() {
+    for n in [0, 1, -1, 9, 10, -10, 12345, i64::MAX, i64::MIN] {
+        let mut v = Vec::new();
+        v.write_int(n).unwrap();
+        assert_eq!(v, n.to_string().as_bytes());
+    }
+}
+
+#[test]
+fn format_uint_handles_largest_value() {
+    let mut buf = [0; 20];
+    assert_eq!(
+        format_uint(u64::MAX, &mut buf),
+        u64::MAX.to_string().as_bytes()
+    );
+}
+
 #[test]
 #[ignore = "todo"]
-fn extend_header_length_test() {
+fn write_header_length_test() {
     todo!()
 }
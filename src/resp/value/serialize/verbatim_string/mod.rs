@@ -0,0 +1,42 @@
+use std::io::Write;
+
+use crate::resp::{value::identifier::Identifier, Protocol};
+
+use super::{
+    bulk_string::write_bulk_string,
+    util::{to_vec, WriteResp},
+};
+
+#[cfg(test)]
+mod tests;
+
+#[must_use]
+pub fn serialize_verbatim_string(encoding: &str, text: &str, protocol: Protocol) -> Vec<u8> {
+    let identifier_header_linefeed_padding = 10;
+    to_vec(
+        encoding.len() + 1 + text.len() + identifier_header_linefeed_padding,
+        |bytes| write_verbatim_string(encoding, text, protocol, bytes),
+    )
+}
+
+pub fn write_verbatim_string<W>(
+    encoding: &str,
+    text: &str,
+    protocol: Protocol,
+    writer: &mut W,
+) -> std::io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    match protocol {
+        Protocol::Resp2 => write_bulk_string(text, writer),
+        Protocol::Resp3 => {
+            let length = encoding.len() + 1 + text.len();
+            writer.write_header(&Identifier::VerbatimString, length)?;
+            writer.write_all(encoding.as_bytes())?;
+            writer.write_all(b":")?;
+            writer.write_all(text.as_bytes())?;
+            writer.write_linefeed()
+        }
+    }
+}
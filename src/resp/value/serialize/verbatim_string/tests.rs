@@ -0,0 +1,17 @@
+use super::*;
+
+#[test]
+fn serialize_verbatim_string_test() {
+    assert_eq!(
+        serialize_verbatim_string("txt", "Some string", Protocol::Resp3),
+        b"=15\r\ntxt:Some string\r\n"
+    );
+}
+
+#[test]
+fn serialize_verbatim_string_as_resp2_is_bulk_string_of_text() {
+    assert_eq!(
+        serialize_verbatim_string("txt", "Some string", Protocol::Resp2),
+        b"$11\r\nSome string\r\n"
+    );
+}
@@ -0,0 +1,108 @@
+use std::borrow::Cow;
+
+use super::Value;
+
+#[cfg(test)]
+mod tests;
+
+/// A value borrowing its strings from the buffer it was deserialized from.
+/// Only converted into an owned [`Value`] when the data has to outlive the buffer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueRef<'a> {
+    SimpleString(&'a str),
+    SimpleError(&'a str),
+    /// Owned when the bytes had to be unescaped, like quoted inline arguments.
+    BulkString(Cow<'a, [u8]>),
+    NullString,
+    Integer(i64),
+
+    Array(Vec<Self>),
+    NullArray,
+
+    Null,
+    Boolean(bool),
+    Double(f64),
+    BigNumber(&'a str),
+    BulkError(&'a str),
+    VerbatimString {
+        encoding: &'a str,
+        text: &'a str,
+    },
+    Map(Vec<(Self, Self)>),
+    Attribute {
+        attributes: Vec<(Self, Self)>,
+        value: Box<Self>,
+    },
+    Set(Vec<Self>),
+    Push(Vec<Self>),
+}
+
+impl ValueRef<'_> {
+    /// The content of a simple or bulk string.
+    #[must_use]
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::SimpleString(s) => Some(s.as_bytes()),
+            Self::BulkString(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn into_value(self) -> Value {
+        match self {
+            Self::SimpleString(s) => Value::SimpleString(s.to_string()),
+            Self::SimpleError(s) => Value::SimpleError(s.to_string()),
+            Self::BulkString(bytes) => Value::bulk_bytes(bytes.into_owned()),
+            Self::NullString => Value::NullString,
+            Self::Integer(i) => Value::Integer(i),
+            Self::Array(values) => Value::Array(into_values(values)),
+            Self::NullArray => Value::NullArray,
+            Self::Null => Value::Null,
+            Self::Boolean(b) => Value::Boolean(b),
+            Self::Double(d) => Value::Double(d),
+            Self::BigNumber(s) => Value::BigNumber(s.to_string()),
+            Self::BulkError(s) => Value::BulkError(s.to_string()),
+            Self::VerbatimString { encoding, text } => Value::VerbatimString {
+                encoding: encoding.to_string(),
+                text: text.to_string(),
+            },
+            Self::Map(pairs) => Value::Map(into_pairs(pairs)),
+            Self::Attribute { attributes, value } => Value::Attribute {
+                attributes: into_pairs(attributes),
+                value: Box::new(value.into_value()),
+            },
+            Self::Set(values) => Value::Set(into_values(values)),
+            Self::Push(values) => Value::Push(into_values(values)),
+        }
+    }
+}
+
+fn into_values(values: Vec<ValueRef<'_>>) -> Vec<Value> {
+    values.into_iter().map(ValueRef::into_value).collect()
+}
+
+fn into_pairs(pairs: Vec<(ValueRef<'_>, ValueRef<'_>)>) -> Vec<(Value, Value)> {
+    pairs
+        .into_iter()
+        .map(|(key, value)| (key.into_value(), value.into_value()))
+        .collect()
+}
+
+impl From<ValueRef<'_>> for Value {
+    fn from(value: ValueRef<'_>) -> Self {
+        value.into_value()
+    }
+}
+
+impl PartialEq<Value> for ValueRef<'_> {
+    fn eq(&self, other: &Value) -> bool {
+        self.clone().into_value() == *other
+    }
+}
+
+impl PartialEq<ValueRef<'_>> for Value {
+    fn eq(&self, other: &ValueRef<'_>) -> bool {
+        other == self
+    }
+}
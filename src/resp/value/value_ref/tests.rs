@@ -0,0 +1,60 @@
+use super::*;
+use crate::resp::value::deserialize::deserialize_value_ref;
+
+#[test]
+fn bulk_string_borrows_from_buffer() {
+    let bytes = b"$5\r\nhello\r\n";
+    let (value, bytes_consumed) = deserialize_value_ref(bytes).unwrap();
+    let ValueRef::BulkString(Cow::Borrowed(s)) = value else {
+        panic!("expected borrowed bulk string, got {value:?}");
+    };
+    assert_eq!(s, b"hello");
+    assert!(std::ptr::eq(s.as_ptr(), bytes[4..].as_ptr()));
+    assert_eq!(bytes_consumed, bytes.len());
+}
+
+#[test]
+fn array_items_borrow_from_buffer() {
+    let bytes = b"*2\r\n$3\r\nGET\r\n+key\r\n";
+    let (value, _) = deserialize_value_ref(bytes).unwrap();
+    assert_eq!(
+        value,
+        ValueRef::Array(vec![
+            ValueRef::BulkString(Cow::Borrowed(b"GET")),
+            ValueRef::SimpleString("key"),
+        ])
+    );
+}
+
+#[test]
+fn into_value_matches_owned_deserializer() {
+    let bytes = b"%2\r\n+a\r\n=7\r\ntxt:abc\r\n$2\r\n\xff\x00\r\n*-1\r\n";
+    let (value, _) = deserialize_value_ref(bytes).unwrap();
+    let (owned, _) = crate::resp::value::deserialize_value(bytes).unwrap();
+    assert_eq!(value.into_value(), owned);
+    assert_eq!(
+        owned,
+        Value::Map(vec![
+            (
+                Value::simple_string("a"),
+                Value::VerbatimString {
+                    encoding: "txt".into(),
+                    text: "abc".into()
+                }
+            ),
+            (Value::BulkByteString(vec![0xff, 0x00]), Value::NullArray),
+        ])
+    );
+}
+
+#[test]
+fn inline_arguments_are_owned() {
+    let (value, _) = deserialize_value_ref(b"SET \"a\\nb\"\r\n").unwrap();
+    assert_eq!(
+        value,
+        ValueRef::Array(vec![
+            ValueRef::BulkString(Cow::Borrowed(b"SET")),
+            ValueRef::BulkString(Cow::Owned(b"a\nb".to_vec())),
+        ])
+    );
+}